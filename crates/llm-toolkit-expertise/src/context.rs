@@ -88,6 +88,30 @@ pub enum ContextProfile {
 }
 
 impl ContextProfile {
+    /// Create a profile that activates only at the given task health
+    ///
+    /// Shorthand for a `Conditional` profile with no task type or user state
+    /// constraints. Use it for escalation fragments, such as recovery guidance
+    /// that should appear only when a task is struggling.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use llm_toolkit_expertise::{ContextProfile, KnowledgeFragment, TaskHealth, WeightedFragment};
+    ///
+    /// let recovery = WeightedFragment::new(KnowledgeFragment::Text(
+    ///     "Stop and restate the goal before trying again".to_string(),
+    /// ))
+    /// .with_context(ContextProfile::on_task_health(TaskHealth::OffTrack));
+    /// ```
+    pub fn on_task_health(task_health: TaskHealth) -> Self {
+        ContextProfile::Conditional {
+            task_types: Vec::new(),
+            user_states: Vec::new(),
+            task_health: Some(task_health),
+        }
+    }
+
     /// Check if this context profile matches the given context
     pub fn matches(&self, context: &ContextMatcher) -> bool {
         match self {
@@ -239,4 +263,13 @@ mod tests {
         assert!(profile.matches(&matching_context));
         assert!(!profile.matches(&non_matching_context));
     }
+
+    #[test]
+    fn test_on_task_health_profile() {
+        let profile = ContextProfile::on_task_health(TaskHealth::OffTrack);
+
+        assert!(profile.matches(&ContextMatcher::new().with_task_health(TaskHealth::OffTrack)));
+        assert!(!profile.matches(&ContextMatcher::new().with_task_health(TaskHealth::OnTrack)));
+        assert!(!profile.matches(&ContextMatcher::new()));
+    }
 }
@@ -44,6 +44,18 @@ pub enum KnowledgeFragment {
 
     /// Free-form text knowledge
     Text(String),
+
+    /// Reference to another fragment of the same expertise by anchor name
+    ///
+    /// When rendered as part of an [`Expertise`](crate::types::Expertise), this
+    /// resolves to the content of the fragment registered with
+    /// [`WeightedFragment::with_anchor`](crate::types::WeightedFragment::with_anchor),
+    /// so shared knowledge only has to be written once. (Unrelated to
+    /// [`Anchor`], which is a positive/negative example pair.)
+    Reference {
+        /// Anchor name of the referenced fragment
+        anchor: String,
+    },
 }
 
 impl KnowledgeFragment {
@@ -91,6 +103,10 @@ impl KnowledgeFragment {
             KnowledgeFragment::Text(text) => {
                 format!("{}\n", text)
             }
+            // Resolved by Expertise when rendering; standalone, only the name is known
+            KnowledgeFragment::Reference { anchor } => {
+                format!("(See: {})\n", anchor)
+            }
         }
     }
 
@@ -102,6 +118,7 @@ impl KnowledgeFragment {
             KnowledgeFragment::QualityStandard { .. } => "Quality",
             KnowledgeFragment::ToolDefinition(_) => "Tool",
             KnowledgeFragment::Text(_) => "Text",
+            KnowledgeFragment::Reference { .. } => "Reference",
         }
     }
 
@@ -119,6 +136,7 @@ impl KnowledgeFragment {
             }
             KnowledgeFragment::ToolDefinition(_) => "Tool definition".to_string(),
             KnowledgeFragment::Text(text) => truncate(text, 50),
+            KnowledgeFragment::Reference { anchor } => format!("-> {}", truncate(anchor, 47)),
         }
     }
 }
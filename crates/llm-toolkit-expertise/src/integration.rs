@@ -3,7 +3,7 @@
 //! This module provides implementations of llm-toolkit traits when the
 //! "integration" feature is enabled.
 
-use crate::Expertise;
+use crate::{Expertise, WeightedFragment};
 use llm_toolkit::agent::{Capability, ToExpertise};
 use llm_toolkit::prompt::{PromptPart, ToPrompt};
 
@@ -20,6 +20,17 @@ impl ToPrompt for Expertise {
     }
 }
 
+impl ToPrompt for WeightedFragment {
+    fn to_prompt_parts(&self) -> Vec<PromptPart> {
+        vec![PromptPart::Text(self.fragment.to_prompt())]
+    }
+
+    fn to_prompt(&self) -> String {
+        // Priority and context only matter when rendered as part of an Expertise
+        self.fragment.to_prompt()
+    }
+}
+
 impl ToExpertise for Expertise {
     fn description(&self) -> &str {
         // If explicit description exists, return it
@@ -45,7 +56,8 @@ impl ToExpertise for Expertise {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{KnowledgeFragment, WeightedFragment};
+    use crate::{KnowledgeFragment, Priority};
+    use llm_toolkit::agent::{Payload, PayloadContent};
 
     #[test]
     fn test_to_prompt_trait() {
@@ -75,6 +87,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_weighted_fragment_to_prompt() {
+        let fragment = WeightedFragment::new(KnowledgeFragment::Text(
+            "Prefer iterators over index loops".to_string(),
+        ))
+        .with_priority(Priority::High);
+
+        // Text fragments render with a trailing newline
+        assert_eq!(
+            ToPrompt::to_prompt(&fragment),
+            "Prefer iterators over index loops\n"
+        );
+        let parts = ToPrompt::to_prompt_parts(&fragment);
+        assert!(
+            matches!(parts.as_slice(), [PromptPart::Text(text)] if text == "Prefer iterators over index loops\n")
+        );
+    }
+
+    #[test]
+    fn test_expertise_in_payload() {
+        let expertise = Expertise::new("rust-reviewer", "1.0").with_fragment(
+            WeightedFragment::new(KnowledgeFragment::Text("Check error handling".to_string())),
+        );
+
+        let payload = Payload::from_prompt(expertise);
+
+        match payload.contents() {
+            [PayloadContent::Text(text)] => {
+                assert!(text.contains("Expertise: rust-reviewer"));
+                assert!(text.contains("Check error handling"));
+            }
+            other => panic!("Expected a single text part, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_auto_description() {
         // No explicit description - should fallback to id
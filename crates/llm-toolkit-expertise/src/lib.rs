@@ -137,8 +137,10 @@ pub mod types;
 // Re-export main types
 pub use context::{ContextMatcher, ContextProfile, Priority, TaskHealth};
 pub use fragment::{Anchor, KnowledgeFragment};
-pub use render::{ContextualPrompt, RenderContext};
-pub use types::{Expertise, WeightedFragment};
+pub use render::{
+    ActivationReason, ContextCondition, ContextualPrompt, FragmentDecision, RenderContext,
+};
+pub use types::{Expertise, ExpertiseValidationError, ReferenceError, WeightedFragment};
 
 // Optional integration with llm-toolkit
 #[cfg(feature = "integration")]
@@ -163,6 +165,56 @@ pub fn save_expertise_schema(path: impl AsRef<std::path::Path>) -> std::io::Resu
     std::fs::write(path, json)
 }
 
+/// Error loading an Expertise definition with [`load_expertise`]
+#[derive(Debug)]
+pub enum LoadExpertiseError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file is not a valid Expertise JSON document
+    Parse(serde_json::Error),
+    /// The definition parsed but failed [`Expertise::validate`]
+    Invalid(Vec<ExpertiseValidationError>),
+}
+
+impl std::fmt::Display for LoadExpertiseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadExpertiseError::Io(e) => write!(f, "Failed to read expertise: {}", e),
+            LoadExpertiseError::Parse(e) => write!(f, "Failed to parse expertise: {}", e),
+            LoadExpertiseError::Invalid(errors) => {
+                let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                write!(f, "Invalid expertise: {}", messages.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadExpertiseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadExpertiseError::Io(e) => Some(e),
+            LoadExpertiseError::Parse(e) => Some(e),
+            LoadExpertiseError::Invalid(_) => None,
+        }
+    }
+}
+
+/// Load an Expertise from a JSON file and validate it
+///
+/// Runs [`Expertise::validate`] after parsing. To skip validation, read the
+/// file and deserialize it with `serde_json` directly.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read, isn't valid Expertise JSON,
+/// or fails validation.
+pub fn load_expertise(path: impl AsRef<std::path::Path>) -> Result<Expertise, LoadExpertiseError> {
+    let json = std::fs::read_to_string(path).map_err(LoadExpertiseError::Io)?;
+    let expertise: Expertise = serde_json::from_str(&json).map_err(LoadExpertiseError::Parse)?;
+    expertise.validate().map_err(LoadExpertiseError::Invalid)?;
+    Ok(expertise)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,6 +226,30 @@ mod tests {
         assert!(schema.get("$schema").is_some());
     }
 
+    #[test]
+    fn test_load_expertise_validates() {
+        let dir = std::env::temp_dir().join(format!("expertise-load-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let valid = dir.join("valid.json");
+        let expertise = Expertise::new("test", "1.0").with_fragment(WeightedFragment::new(
+            KnowledgeFragment::Text("Test".to_string()),
+        ));
+        std::fs::write(&valid, serde_json::to_string(&expertise).unwrap()).unwrap();
+        assert_eq!(load_expertise(&valid).unwrap().id, "test");
+
+        let invalid = dir.join("invalid.json");
+        std::fs::write(&invalid, r#"{"id": "", "version": "1.0", "content": []}"#).unwrap();
+        match load_expertise(&invalid) {
+            Err(LoadExpertiseError::Invalid(errors)) => {
+                assert_eq!(errors, vec![ExpertiseValidationError::EmptyId]);
+            }
+            other => panic!("Expected Invalid error, got {:?}", other),
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_basic_expertise_creation() {
         let expertise = Expertise::new("test", "1.0")
@@ -5,6 +5,7 @@
 //! on runtime context.
 
 use crate::context::{ContextMatcher, ContextProfile, TaskHealth};
+use crate::types::ReferenceError;
 
 /// Runtime context for prompt rendering
 ///
@@ -115,40 +116,78 @@ impl RenderContext {
     /// assert!(context.matches(&profile));
     /// ```
     pub fn matches(&self, profile: &ContextProfile) -> bool {
+        !matches!(self.explain(profile), ActivationReason::Unmatched { .. })
+    }
+
+    /// Explain whether and why a ContextProfile matches this context
+    ///
+    /// Uses the same rules as [`matches()`](Self::matches), but reports which
+    /// conditions failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_toolkit_expertise::render::{ActivationReason, ContextCondition, RenderContext};
+    /// use llm_toolkit_expertise::context::{ContextProfile, TaskHealth};
+    ///
+    /// let context = RenderContext::new().with_task_type("debug");
+    ///
+    /// let profile = ContextProfile::Conditional {
+    ///     task_types: vec!["security-review".to_string()],
+    ///     user_states: vec![],
+    ///     task_health: Some(TaskHealth::AtRisk),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     context.explain(&profile),
+    ///     ActivationReason::Unmatched {
+    ///         unmet: vec![ContextCondition::TaskType, ContextCondition::TaskHealth],
+    ///     }
+    /// );
+    /// ```
+    pub fn explain(&self, profile: &ContextProfile) -> ActivationReason {
         match profile {
-            ContextProfile::Always => true,
+            ContextProfile::Always => ActivationReason::Always,
             ContextProfile::Conditional {
                 task_types,
                 user_states,
                 task_health,
             } => {
+                let mut unmet = Vec::new();
+
                 // Check task_type match
-                let task_type_match = if task_types.is_empty() {
-                    true // No task type constraint
-                } else {
-                    self.task_type
+                if !task_types.is_empty()
+                    && !self
+                        .task_type
                         .as_ref()
                         .map(|tt| task_types.contains(tt))
                         .unwrap_or(false)
-                };
+                {
+                    unmet.push(ContextCondition::TaskType);
+                }
 
                 // Check user_state match (at least one must match)
-                let user_state_match = if user_states.is_empty() {
-                    true // No user state constraint
-                } else {
-                    self.user_states
+                if !user_states.is_empty()
+                    && !self
+                        .user_states
                         .iter()
                         .any(|state| user_states.contains(state))
-                };
+                {
+                    unmet.push(ContextCondition::UserState);
+                }
 
                 // Check task_health match
-                let task_health_match = if let Some(required_health) = task_health {
-                    self.task_health.as_ref() == Some(required_health)
+                if let Some(required_health) = task_health
+                    && self.task_health.as_ref() != Some(required_health)
+                {
+                    unmet.push(ContextCondition::TaskHealth);
+                }
+
+                if unmet.is_empty() {
+                    ActivationReason::Matched
                 } else {
-                    true // No health constraint
-                };
-
-                task_type_match && user_state_match && task_health_match
+                    ActivationReason::Unmatched { unmet }
+                }
             }
         }
     }
@@ -182,6 +221,48 @@ impl From<ContextMatcher> for RenderContext {
     }
 }
 
+/// A single condition of a [`ContextProfile::Conditional`] profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextCondition {
+    /// The current task type is not one of `task_types`
+    TaskType,
+    /// None of the current user states is in `user_states`
+    UserState,
+    /// The current task health differs from `task_health`
+    TaskHealth,
+}
+
+/// Why a fragment's ContextProfile did or did not match a RenderContext
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActivationReason {
+    /// The profile is `Always`, so the fragment is always active
+    Always,
+    /// Every condition of a `Conditional` profile matched
+    Matched,
+    /// Some conditions of a `Conditional` profile did not match
+    Unmatched {
+        /// The conditions that failed, in declaration order
+        unmet: Vec<ContextCondition>,
+    },
+    /// The profile matched, but the fragment is a
+    /// [`KnowledgeFragment::Reference`](crate::types::KnowledgeFragment::Reference)
+    /// that does not resolve, so nothing was rendered for it
+    BrokenReference(ReferenceError),
+}
+
+/// Activation decision for one fragment during context-aware rendering
+///
+/// Returned by [`Expertise::render_with_trace()`](crate::types::Expertise::render_with_trace).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FragmentDecision {
+    /// Index of the fragment in `Expertise::content`
+    pub index: usize,
+    /// Whether the fragment was included in the rendered prompt
+    pub included: bool,
+    /// Why the fragment was or was not included
+    pub reason: ActivationReason,
+}
+
 /// Context-aware prompt renderer (Phase 2)
 ///
 /// A wrapper type that combines an `Expertise` with a `RenderContext` to enable
@@ -5,6 +5,8 @@
 
 use crate::context::{ContextMatcher, ContextProfile, Priority};
 use crate::fragment::KnowledgeFragment;
+#[cfg(feature = "token-budget")]
+use llm_toolkit::prompt::tokens::estimate_tokens;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -111,6 +113,62 @@ impl Expertise {
         self
     }
 
+    /// Layer another expertise on top of this one
+    ///
+    /// Useful for combining a shared base expertise with a role-specific one.
+    /// The merge rules are:
+    ///
+    /// - `id`: kept from `self`
+    /// - `version`: the higher of the two (compared numerically per
+    ///   dot-separated component, e.g. `"1.10"` > `"1.9"`)
+    /// - `description`: `other`'s if it has one, otherwise `self`'s
+    /// - `tags`: union of both, duplicates removed, `self`'s tags first
+    /// - `content`: `self`'s fragments followed by `other`'s
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use llm_toolkit_expertise::{Expertise, KnowledgeFragment, WeightedFragment};
+    ///
+    /// let base = Expertise::new("base", "1.0")
+    ///     .with_tag("lang:rust")
+    ///     .with_fragment(WeightedFragment::new(KnowledgeFragment::Text(
+    ///         "Write idiomatic Rust".to_string(),
+    ///     )));
+    /// let reviewer = Expertise::new("reviewer", "1.2")
+    ///     .with_tag("lang:rust")
+    ///     .with_tag("role:reviewer")
+    ///     .with_fragment(WeightedFragment::new(KnowledgeFragment::Text(
+    ///         "Point out missing tests".to_string(),
+    ///     )));
+    ///
+    /// let merged = base.merge(reviewer);
+    /// assert_eq!(merged.id, "base");
+    /// assert_eq!(merged.version, "1.2");
+    /// assert_eq!(merged.tags, vec!["lang:rust", "role:reviewer"]);
+    /// assert_eq!(merged.content.len(), 2);
+    /// ```
+    pub fn merge(mut self, other: Expertise) -> Self {
+        if compare_versions(&other.version, &self.version).is_gt() {
+            self.version = other.version;
+        }
+
+        if other.description.is_some() {
+            self.description = other.description;
+        }
+
+        let mut tags = Vec::with_capacity(self.tags.len() + other.tags.len());
+        for tag in self.tags.into_iter().chain(other.tags) {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        self.tags = tags;
+
+        self.content.extend(other.content);
+        self
+    }
+
     /// Get the description, auto-generating if not explicitly set
     ///
     /// If no explicit description was set via [`with_description()`](Self::with_description),
@@ -199,7 +257,28 @@ impl Expertise {
     ///
     /// Fragments are ordered by priority (Critical → High → Normal → Low)
     pub fn to_prompt(&self) -> String {
-        self.to_prompt_with_context(&ContextMatcher::default())
+        self.to_prompt_with_min_priority(Priority::Low)
+    }
+
+    /// Generate a prompt string containing only fragments at or above `min`
+    ///
+    /// Useful for compact prompts, e.g. `Priority::High` keeps only Critical
+    /// and High fragments. Fragments are ordered as in [`to_prompt()`](Self::to_prompt).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use llm_toolkit_expertise::{Expertise, KnowledgeFragment, Priority, WeightedFragment};
+    ///
+    /// let expertise = Expertise::new("test", "1.0").with_fragment(
+    ///     WeightedFragment::new(KnowledgeFragment::Text("Background".to_string()))
+    ///         .with_priority(Priority::Low),
+    /// );
+    ///
+    /// assert!(!expertise.to_prompt_with_min_priority(Priority::High).contains("Background"));
+    /// ```
+    pub fn to_prompt_with_min_priority(&self, min: Priority) -> String {
+        self.render_prompt(&ContextMatcher::default(), min)
     }
 
     /// Generate a prompt string with render context filtering (Phase 2)
@@ -226,9 +305,76 @@ impl Expertise {
     /// let prompt = expertise.to_prompt_with_render_context(&context);
     /// ```
     pub fn to_prompt_with_render_context(&self, context: &crate::render::RenderContext) -> String {
-        // Convert RenderContext to ContextMatcher for now
-        // In the future, we can refactor to use RenderContext directly
-        self.to_prompt_with_context(&context.to_context_matcher())
+        self.render_with_trace(context).0
+    }
+
+    /// Generate a prompt string and report why each fragment was included or skipped
+    ///
+    /// Returns the same prompt as [`to_prompt_with_render_context()`](Self::to_prompt_with_render_context)
+    /// along with one [`FragmentDecision`](crate::render::FragmentDecision) per
+    /// fragment, in declaration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_toolkit_expertise::{ContextProfile, Expertise, KnowledgeFragment, WeightedFragment};
+    /// use llm_toolkit_expertise::render::{ActivationReason, ContextCondition, RenderContext};
+    ///
+    /// let expertise = Expertise::new("test", "1.0").with_fragment(
+    ///     WeightedFragment::new(KnowledgeFragment::Text("Explain basics".to_string()))
+    ///         .with_context(ContextProfile::Conditional {
+    ///             task_types: vec![],
+    ///             user_states: vec!["beginner".to_string()],
+    ///             task_health: None,
+    ///         }),
+    /// );
+    ///
+    /// let (prompt, decisions) = expertise.render_with_trace(&RenderContext::new());
+    /// assert!(!prompt.contains("Explain basics"));
+    /// assert!(!decisions[0].included);
+    /// assert_eq!(
+    ///     decisions[0].reason,
+    ///     ActivationReason::Unmatched { unmet: vec![ContextCondition::UserState] }
+    /// );
+    /// ```
+    pub fn render_with_trace(
+        &self,
+        context: &crate::render::RenderContext,
+    ) -> (String, Vec<crate::render::FragmentDecision>) {
+        let decisions: Vec<_> = self
+            .content
+            .iter()
+            .enumerate()
+            .map(|(index, fragment)| {
+                let reason = match context.explain(&fragment.context) {
+                    unmatched @ crate::render::ActivationReason::Unmatched { .. } => unmatched,
+                    // Mirror render_fragments, which skips unresolvable references
+                    reason => match self.resolve_fragment(&fragment.fragment) {
+                        Ok(_) => reason,
+                        Err(error) => crate::render::ActivationReason::BrokenReference(error),
+                    },
+                };
+                crate::render::FragmentDecision {
+                    index,
+                    included: matches!(
+                        reason,
+                        crate::render::ActivationReason::Always
+                            | crate::render::ActivationReason::Matched
+                    ),
+                    reason,
+                }
+            })
+            .collect();
+
+        let prompt = self.render_fragments(
+            decisions
+                .iter()
+                .filter(|d| d.included)
+                .map(|d| &self.content[d.index])
+                .collect(),
+        );
+
+        (prompt, decisions)
     }
 
     /// Generate a prompt string with context filtering (legacy API)
@@ -238,6 +384,75 @@ impl Expertise {
     /// **Note**: Consider using `to_prompt_with_render_context()` for the new API
     /// with improved context matching.
     pub fn to_prompt_with_context(&self, context: &ContextMatcher) -> String {
+        self.render_prompt(context, Priority::Low)
+    }
+
+    /// Generate a prompt string that fits within a token budget
+    ///
+    /// Fragments that don't match `context` are skipped. The rest are scored
+    /// by priority first and context relevance second (a conditional fragment
+    /// scores higher the more of its conditions the context satisfies), then
+    /// added highest score first until the next one would push the rendered
+    /// prompt over `max_tokens`. Ties keep their original order.
+    ///
+    /// Token counts use [`llm_toolkit::prompt::tokens::estimate_tokens`], so the
+    /// budget is approximate. If even the header exceeds the budget, the
+    /// result contains no fragments. Requires the `token-budget` feature (also
+    /// enabled by `integration`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use llm_toolkit_expertise::{Expertise, KnowledgeFragment, Priority, WeightedFragment};
+    /// use llm_toolkit_expertise::render::RenderContext;
+    ///
+    /// let expertise = Expertise::new("test", "1.0")
+    ///     .with_fragment(
+    ///         WeightedFragment::new(KnowledgeFragment::Text("Never leak secrets".to_string()))
+    ///             .with_priority(Priority::Critical),
+    ///     );
+    ///
+    /// let prompt = expertise.to_prompt_within_budget(&RenderContext::new(), 100);
+    /// assert!(prompt.contains("Never leak secrets"));
+    /// ```
+    #[cfg(feature = "token-budget")]
+    pub fn to_prompt_within_budget(
+        &self,
+        context: &crate::render::RenderContext,
+        max_tokens: usize,
+    ) -> String {
+        let mut candidates: Vec<_> = self
+            .content
+            .iter()
+            .filter(|f| context.matches(&f.context))
+            .collect();
+        // Stable sort: equal scores keep their declaration order
+        candidates.sort_by_key(|f| {
+            std::cmp::Reverse((f.priority, context_relevance(&f.context, context)))
+        });
+
+        let mut selected = Vec::new();
+        for fragment in candidates {
+            selected.push(fragment);
+            if estimate_tokens(&self.render_fragments(selected.clone())) > max_tokens {
+                selected.pop();
+                break;
+            }
+        }
+
+        self.render_fragments(selected)
+    }
+
+    fn render_prompt(&self, context: &ContextMatcher, min_priority: Priority) -> String {
+        self.render_fragments(
+            self.content
+                .iter()
+                .filter(|f| f.priority >= min_priority && f.context.matches(context))
+                .collect(),
+        )
+    }
+
+    fn render_fragments(&self, mut sorted_fragments: Vec<&WeightedFragment>) -> String {
         let mut result = format!("# Expertise: {} (v{})\n\n", self.id, self.version);
 
         if !self.tags.is_empty() {
@@ -249,16 +464,24 @@ impl Expertise {
         result.push_str("---\n\n");
 
         // Sort fragments by priority (highest first)
-        let mut sorted_fragments: Vec<_> = self
-            .content
-            .iter()
-            .filter(|f| f.context.matches(context))
-            .collect();
         sorted_fragments.sort_by(|a, b| b.priority.cmp(&a.priority));
 
         // Group by priority
         let mut current_priority: Option<Priority> = None;
         for weighted in sorted_fragments {
+            // Unresolvable references are skipped (see validate_references)
+            let fragment = match self.resolve_fragment(&weighted.fragment) {
+                Ok(fragment) => fragment,
+                Err(error) => {
+                    tracing::warn!(
+                        expertise = %self.id,
+                        "Skipping fragment while rendering: {}",
+                        error
+                    );
+                    continue;
+                }
+            };
+
             // Add priority header if changed
             if current_priority != Some(weighted.priority) {
                 current_priority = Some(weighted.priority);
@@ -266,13 +489,142 @@ impl Expertise {
             }
 
             // Add fragment content
-            result.push_str(&weighted.fragment.to_prompt());
+            result.push_str(&fragment.to_prompt());
             result.push('\n');
         }
 
         result
     }
 
+    /// Resolve a fragment, following [`KnowledgeFragment::Reference`]s
+    ///
+    /// Non-reference fragments resolve to themselves. References are followed
+    /// (possibly through several hops) to the fragment whose anchor they name.
+    /// If several fragments share an anchor, the first one wins.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReferenceError::UnknownAnchor`] if an anchor doesn't exist and
+    /// [`ReferenceError::Cycle`] for self or mutual references.
+    pub fn resolve_fragment<'a>(
+        &'a self,
+        fragment: &'a KnowledgeFragment,
+    ) -> Result<&'a KnowledgeFragment, ReferenceError> {
+        let mut current = fragment;
+        let mut visited: Vec<String> = Vec::new();
+
+        while let KnowledgeFragment::Reference { anchor } = current {
+            if visited.contains(anchor) {
+                visited.push(anchor.clone());
+                return Err(ReferenceError::Cycle(visited));
+            }
+            visited.push(anchor.clone());
+
+            current = self
+                .content
+                .iter()
+                .find(|wf| wf.anchor.as_deref() == Some(anchor.as_str()))
+                .map(|wf| &wf.fragment)
+                .ok_or_else(|| ReferenceError::UnknownAnchor(anchor.clone()))?;
+        }
+
+        Ok(current)
+    }
+
+    /// Check that every reference in this expertise resolves
+    ///
+    /// Rendering skips fragments whose reference can't be resolved, logging a
+    /// `tracing` warning for each; call this after building an expertise to
+    /// surface those problems as errors instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use llm_toolkit_expertise::{Expertise, KnowledgeFragment, WeightedFragment};
+    ///
+    /// let expertise = Expertise::new("test", "1.0")
+    ///     .with_fragment(
+    ///         WeightedFragment::new(KnowledgeFragment::Text("Use cargo clippy".to_string()))
+    ///             .with_anchor("lint"),
+    ///     )
+    ///     .with_fragment(WeightedFragment::new(KnowledgeFragment::Reference {
+    ///         anchor: "lint".to_string(),
+    ///     }));
+    ///
+    /// assert!(expertise.validate_references().is_ok());
+    /// ```
+    pub fn validate_references(&self) -> Result<(), ReferenceError> {
+        for weighted in &self.content {
+            self.resolve_fragment(&weighted.fragment)?;
+        }
+        Ok(())
+    }
+
+    /// Check this expertise for semantic problems
+    ///
+    /// Deserialization only checks structure; this catches definitions that
+    /// parse but are likely mistakes: an empty id, duplicate tags, conditional
+    /// fragments without any conditions, and references that don't resolve.
+    /// All problems are reported, not just the first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use llm_toolkit_expertise::{Expertise, ExpertiseValidationError};
+    ///
+    /// let expertise = Expertise::new("", "1.0").with_tag("rust").with_tag("rust");
+    ///
+    /// assert_eq!(
+    ///     expertise.validate(),
+    ///     Err(vec![
+    ///         ExpertiseValidationError::EmptyId,
+    ///         ExpertiseValidationError::DuplicateTag("rust".to_string()),
+    ///     ])
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ExpertiseValidationError>> {
+        let mut errors = Vec::new();
+
+        if self.id.trim().is_empty() {
+            errors.push(ExpertiseValidationError::EmptyId);
+        }
+
+        let mut seen_tags: Vec<&String> = Vec::new();
+        for tag in &self.tags {
+            if seen_tags.contains(&tag) {
+                // Report each duplicated tag once
+                if !errors.contains(&ExpertiseValidationError::DuplicateTag(tag.clone())) {
+                    errors.push(ExpertiseValidationError::DuplicateTag(tag.clone()));
+                }
+            } else {
+                seen_tags.push(tag);
+            }
+        }
+
+        for (index, weighted) in self.content.iter().enumerate() {
+            if let ContextProfile::Conditional {
+                task_types,
+                user_states,
+                task_health: None,
+            } = &weighted.context
+                && task_types.is_empty()
+                && user_states.is_empty()
+            {
+                errors.push(ExpertiseValidationError::EmptyConditional { index });
+            }
+
+            if let Err(error) = self.resolve_fragment(&weighted.fragment) {
+                errors.push(ExpertiseValidationError::BrokenReference { index, error });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Generate a Mermaid graph representation
     pub fn to_mermaid(&self) -> String {
         let mut result = String::from("graph TD\n");
@@ -355,6 +707,52 @@ impl Expertise {
         result
     }
 
+    /// Generate a GraphViz DOT representation
+    ///
+    /// Emits a `digraph` with the expertise as root and one node per fragment,
+    /// colored by priority like [`to_mermaid()`](Self::to_mermaid). Conditional
+    /// fragments are drawn with a dashed border. Render with e.g.
+    /// `dot -Tsvg expertise.dot -o expertise.svg`.
+    pub fn to_dot(&self) -> String {
+        let mut result = String::from("digraph expertise {\n");
+        result.push_str("    rankdir=TB;\n");
+        result.push_str("    node [shape=box, style=\"rounded,filled\"];\n\n");
+
+        result.push_str(&format!(
+            "    root [label=\"Expertise: {} (v{})\", fillcolor=\"#ffffff\"];\n",
+            dot_escape(&self.id),
+            dot_escape(&self.version)
+        ));
+
+        for (i, weighted) in self.content.iter().enumerate() {
+            let (fill, stroke) = match weighted.priority {
+                Priority::Critical => ("#ff6b6b", "#c92a2a"),
+                Priority::High => ("#ffd93d", "#f08c00"),
+                Priority::Normal => ("#a0e7e5", "#4ecdc4"),
+                Priority::Low => ("#e0e0e0", "#999999"),
+            };
+            let style = match weighted.context {
+                ContextProfile::Always => "rounded,filled",
+                ContextProfile::Conditional { .. } => "rounded,filled,dashed",
+            };
+
+            result.push_str(&format!(
+                "    f{} [label=\"{} [{}]\\n{}\", fillcolor=\"{}\", color=\"{}\", style=\"{}\"];\n",
+                i,
+                weighted.priority.label(),
+                weighted.fragment.type_label(),
+                dot_escape(&weighted.fragment.summary()),
+                fill,
+                stroke,
+                style
+            ));
+            result.push_str(&format!("    root -> f{};\n", i));
+        }
+
+        result.push_str("}\n");
+        result
+    }
+
     /// Generate a simple tree representation
     pub fn to_tree(&self) -> String {
         let mut result = format!("Expertise: {} (v{})\n", self.id, self.version);
@@ -436,6 +834,10 @@ pub struct WeightedFragment {
 
     /// Fragment: The actual knowledge content
     pub fragment: KnowledgeFragment,
+
+    /// Anchor: Optional name for referencing this fragment from others
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
 }
 
 impl WeightedFragment {
@@ -445,6 +847,7 @@ impl WeightedFragment {
             priority: Priority::default(),
             context: ContextProfile::default(),
             fragment,
+            anchor: None,
         }
     }
 
@@ -459,6 +862,131 @@ impl WeightedFragment {
         self.context = context;
         self
     }
+
+    /// Set an anchor name so other fragments can include this one via
+    /// [`KnowledgeFragment::Reference`]
+    pub fn with_anchor(mut self, anchor: impl Into<String>) -> Self {
+        self.anchor = Some(anchor.into());
+        self
+    }
+}
+
+/// Error resolving a [`KnowledgeFragment::Reference`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReferenceError {
+    /// No fragment in the expertise has this anchor
+    UnknownAnchor(String),
+    /// Following references leads back to an anchor already visited
+    Cycle(Vec<String>),
+}
+
+impl std::fmt::Display for ReferenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReferenceError::UnknownAnchor(anchor) => {
+                write!(f, "Reference to unknown anchor '{}'", anchor)
+            }
+            ReferenceError::Cycle(path) => {
+                write!(f, "Reference cycle: {}", path.join(" -> "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReferenceError {}
+
+/// A semantic problem found by [`Expertise::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExpertiseValidationError {
+    /// The id is empty or whitespace
+    EmptyId,
+    /// The same tag appears more than once
+    DuplicateTag(String),
+    /// A `Conditional` fragment has no task types, user states, or task health,
+    /// so it is active in every context (it should be `Always`)
+    EmptyConditional {
+        /// Index of the fragment in `Expertise::content`
+        index: usize,
+    },
+    /// A `Reference` fragment does not resolve
+    BrokenReference {
+        /// Index of the fragment in `Expertise::content`
+        index: usize,
+        /// Why the reference failed
+        error: ReferenceError,
+    },
+}
+
+impl std::fmt::Display for ExpertiseValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpertiseValidationError::EmptyId => write!(f, "Expertise id is empty"),
+            ExpertiseValidationError::DuplicateTag(tag) => write!(f, "Duplicate tag '{}'", tag),
+            ExpertiseValidationError::EmptyConditional { index } => write!(
+                f,
+                "Fragment {} is Conditional without any conditions; use ContextProfile::Always",
+                index
+            ),
+            ExpertiseValidationError::BrokenReference { index, error } => {
+                write!(f, "Fragment {}: {}", index, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExpertiseValidationError {}
+
+/// Escape a string for use inside a double-quoted DOT label
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Number of conditions in `profile` that `context` satisfies
+///
+/// Assumes the profile already matches; `Always` fragments score zero, so a
+/// fragment targeted at the current situation outranks a generic one.
+#[cfg(feature = "token-budget")]
+fn context_relevance(profile: &ContextProfile, context: &crate::render::RenderContext) -> usize {
+    match profile {
+        ContextProfile::Always => 0,
+        ContextProfile::Conditional {
+            task_types,
+            user_states,
+            task_health,
+        } => {
+            usize::from(!task_types.is_empty())
+                + user_states
+                    .iter()
+                    .filter(|state| context.user_states.contains(state))
+                    .count()
+                + usize::from(task_health.is_some())
+        }
+    }
+}
+
+/// Compare version strings component-wise, numerically where possible
+///
+/// Components that aren't numbers (e.g. `"1.0-beta"`) fall back to string order.
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    let mut a_parts = a.split('.');
+    let mut b_parts = b.split('.');
+    loop {
+        let ordering = match (a_parts.next(), b_parts.next()) {
+            (None, None) => return std::cmp::Ordering::Equal,
+            // "1.0.1" is newer than "1.0"
+            (Some(_), None) => return std::cmp::Ordering::Greater,
+            (None, Some(_)) => return std::cmp::Ordering::Less,
+            (Some(x), Some(y)) => match (x.parse::<u64>(), y.parse::<u64>()) {
+                (Ok(x), Ok(y)) => x.cmp(&y),
+                _ => x.cmp(y),
+            },
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -481,6 +1009,64 @@ mod tests {
         assert_eq!(expertise.content.len(), 1);
     }
 
+    #[test]
+    fn test_merge() {
+        let base = Expertise::new("base", "1.9")
+            .with_description("Base description")
+            .with_tags(vec!["lang:rust".to_string(), "style:concise".to_string()])
+            .with_fragment(WeightedFragment::new(KnowledgeFragment::Text(
+                "Base knowledge".to_string(),
+            )))
+            .with_fragment(WeightedFragment::new(KnowledgeFragment::Text(
+                "More base knowledge".to_string(),
+            )));
+        let role = Expertise::new("role", "1.10")
+            .with_description("Role description")
+            .with_tags(vec!["role:reviewer".to_string(), "lang:rust".to_string()])
+            .with_fragment(WeightedFragment::new(KnowledgeFragment::Text(
+                "Role knowledge".to_string(),
+            )));
+
+        let merged = base.merge(role);
+
+        assert_eq!(merged.id, "base");
+        assert_eq!(merged.version, "1.10");
+        assert_eq!(merged.description, Some("Role description".to_string()));
+        assert_eq!(
+            merged.tags,
+            vec!["lang:rust", "style:concise", "role:reviewer"]
+        );
+        assert_eq!(merged.content.len(), 3);
+
+        let prompt = merged.to_prompt();
+        let base_pos = prompt.find("Base knowledge").unwrap();
+        let more_pos = prompt.find("More base knowledge").unwrap();
+        let role_pos = prompt.find("Role knowledge").unwrap();
+        assert!(base_pos < more_pos);
+        assert!(more_pos < role_pos);
+    }
+
+    #[test]
+    fn test_merge_keeps_own_description_and_higher_version() {
+        let base = Expertise::new("base", "2.0.1").with_description("Base description");
+        let role = Expertise::new("role", "2.0");
+
+        let merged = base.merge(role);
+
+        assert_eq!(merged.version, "2.0.1");
+        assert_eq!(merged.description, Some("Base description".to_string()));
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        use std::cmp::Ordering;
+
+        assert_eq!(compare_versions("1.10", "1.9"), Ordering::Greater);
+        assert_eq!(compare_versions("1.0", "1.0"), Ordering::Equal);
+        assert_eq!(compare_versions("1.0", "1.0.1"), Ordering::Less);
+        assert_eq!(compare_versions("1.0-beta", "1.0-alpha"), Ordering::Greater);
+    }
+
     #[test]
     fn test_to_prompt_ordering() {
         let expertise = Expertise::new("test", "1.0")
@@ -508,6 +1094,384 @@ mod tests {
         assert!(normal_pos < low_pos);
     }
 
+    #[test]
+    fn test_to_prompt_with_min_priority() {
+        let expertise = Expertise::new("test", "1.0")
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text("Low priority".to_string()))
+                    .with_priority(Priority::Low),
+            )
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text("High priority".to_string()))
+                    .with_priority(Priority::High),
+            )
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text("Critical priority".to_string()))
+                    .with_priority(Priority::Critical),
+            );
+
+        let compact = expertise.to_prompt_with_min_priority(Priority::High);
+        assert!(!compact.contains("Low priority"));
+        assert!(compact.contains("High priority"));
+        assert!(compact.contains("Critical priority"));
+
+        let full = expertise.to_prompt_with_min_priority(Priority::Low);
+        assert!(full.contains("Low priority"));
+        assert_eq!(full, expertise.to_prompt());
+    }
+
+    #[test]
+    #[cfg(feature = "token-budget")]
+    fn test_to_prompt_within_budget_keeps_critical_drops_low() {
+        use crate::render::RenderContext;
+
+        let expertise = Expertise::new("test", "1.0")
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text(
+                    "Background reading about the history of the borrow checker and its \
+                     many design iterations over the years"
+                        .to_string(),
+                ))
+                .with_priority(Priority::Low),
+            )
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text("Never commit secrets".to_string()))
+                    .with_priority(Priority::Critical),
+            );
+
+        let unlimited = expertise.to_prompt_within_budget(&RenderContext::new(), usize::MAX);
+        assert!(unlimited.contains("Background reading"));
+
+        let budget = estimate_tokens(&expertise.to_prompt_with_min_priority(Priority::Critical));
+        let compact = expertise.to_prompt_within_budget(&RenderContext::new(), budget);
+        assert!(compact.contains("Never commit secrets"));
+        assert!(!compact.contains("Background reading"));
+        assert!(estimate_tokens(&compact) <= budget);
+    }
+
+    #[test]
+    #[cfg(feature = "token-budget")]
+    fn test_to_prompt_within_budget_prefers_context_relevant_fragments() {
+        use crate::render::RenderContext;
+
+        let expertise = Expertise::new("test", "1.0")
+            .with_fragment(WeightedFragment::new(KnowledgeFragment::Text(
+                "Generic advice".to_string(),
+            )))
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text("Security advice".to_string()))
+                    .with_context(ContextProfile::Conditional {
+                        task_types: vec!["security-review".to_string()],
+                        user_states: vec![],
+                        task_health: None,
+                    }),
+            )
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text("Debugging advice".to_string()))
+                    .with_context(ContextProfile::Conditional {
+                        task_types: vec!["debug".to_string()],
+                        user_states: vec![],
+                        task_health: None,
+                    }),
+            );
+        let context = RenderContext::new().with_task_type("security-review");
+
+        // Room for exactly one fragment
+        let one_fragment = Expertise::new("test", "1.0").with_fragment(WeightedFragment::new(
+            KnowledgeFragment::Text("Security advice".to_string()),
+        ));
+        let budget = estimate_tokens(&one_fragment.to_prompt());
+
+        let prompt = expertise.to_prompt_within_budget(&context, budget);
+        assert!(prompt.contains("Security advice"));
+        assert!(!prompt.contains("Generic advice"));
+        assert!(!prompt.contains("Debugging advice"));
+    }
+
+    #[test]
+    fn test_render_with_trace() {
+        use crate::context::TaskHealth;
+        use crate::render::{ActivationReason, ContextCondition, RenderContext};
+
+        let expertise = Expertise::new("test", "1.0")
+            .with_fragment(WeightedFragment::new(KnowledgeFragment::Text(
+                "Always shown".to_string(),
+            )))
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text("Security focus".to_string()))
+                    .with_context(ContextProfile::Conditional {
+                        task_types: vec!["security-review".to_string()],
+                        user_states: vec![],
+                        task_health: None,
+                    }),
+            )
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text("Slow down".to_string()))
+                    .with_context(ContextProfile::Conditional {
+                        task_types: vec!["debug".to_string()],
+                        user_states: vec![],
+                        task_health: Some(TaskHealth::AtRisk),
+                    }),
+            );
+        let context = RenderContext::new().with_task_type("security-review");
+
+        let (prompt, decisions) = expertise.render_with_trace(&context);
+
+        assert_eq!(decisions.len(), 3);
+        assert_eq!(decisions[0].index, 0);
+        assert!(decisions[0].included);
+        assert_eq!(decisions[0].reason, ActivationReason::Always);
+
+        assert_eq!(decisions[1].index, 1);
+        assert!(decisions[1].included);
+        assert_eq!(decisions[1].reason, ActivationReason::Matched);
+
+        assert_eq!(decisions[2].index, 2);
+        assert!(!decisions[2].included);
+        assert_eq!(
+            decisions[2].reason,
+            ActivationReason::Unmatched {
+                unmet: vec![ContextCondition::TaskType, ContextCondition::TaskHealth]
+            }
+        );
+
+        assert!(prompt.contains("Always shown"));
+        assert!(prompt.contains("Security focus"));
+        assert!(!prompt.contains("Slow down"));
+        assert_eq!(prompt, expertise.to_prompt_with_render_context(&context));
+    }
+
+    #[test]
+    fn test_render_with_trace_excludes_dangling_reference() {
+        use crate::render::{ActivationReason, RenderContext};
+
+        let expertise = Expertise::new("test", "1.0")
+            .with_fragment(WeightedFragment::new(KnowledgeFragment::Text(
+                "Always shown".to_string(),
+            )))
+            .with_fragment(WeightedFragment::new(KnowledgeFragment::Reference {
+                anchor: "missing".to_string(),
+            }));
+
+        let (prompt, decisions) = expertise.render_with_trace(&RenderContext::new());
+
+        assert!(decisions[0].included);
+        assert!(!decisions[1].included);
+        assert_eq!(
+            decisions[1].reason,
+            ActivationReason::BrokenReference(ReferenceError::UnknownAnchor("missing".to_string()))
+        );
+
+        // Trace and rendered text agree: only the included fragment is rendered
+        let included_only = Expertise::new("test", "1.0").with_fragment(WeightedFragment::new(
+            KnowledgeFragment::Text("Always shown".to_string()),
+        ));
+        assert_eq!(
+            prompt,
+            included_only.to_prompt_with_render_context(&RenderContext::new())
+        );
+    }
+
+    #[test]
+    fn test_recovery_fragment_only_under_degraded_health() {
+        use crate::context::TaskHealth;
+        use crate::render::RenderContext;
+
+        let expertise = Expertise::new("test", "1.0")
+            .with_fragment(WeightedFragment::new(KnowledgeFragment::Text(
+                "Review the code".to_string(),
+            )))
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text(
+                    "Verify each claim before continuing".to_string(),
+                ))
+                .with_priority(Priority::High)
+                .with_context(ContextProfile::on_task_health(TaskHealth::AtRisk)),
+            )
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text(
+                    "Stop and ask the user to restate the goal".to_string(),
+                ))
+                .with_priority(Priority::Critical)
+                .with_context(ContextProfile::on_task_health(TaskHealth::OffTrack)),
+            );
+
+        let healthy = expertise.to_prompt_with_render_context(
+            &RenderContext::new().with_task_health(TaskHealth::OnTrack),
+        );
+        assert!(healthy.contains("Review the code"));
+        assert!(!healthy.contains("Verify each claim"));
+        assert!(!healthy.contains("Stop and ask"));
+
+        let unknown = expertise.to_prompt_with_render_context(&RenderContext::new());
+        assert!(!unknown.contains("Verify each claim"));
+        assert!(!unknown.contains("Stop and ask"));
+
+        let at_risk = expertise.to_prompt_with_render_context(
+            &RenderContext::new().with_task_health(TaskHealth::AtRisk),
+        );
+        assert!(at_risk.contains("Verify each claim"));
+        assert!(!at_risk.contains("Stop and ask"));
+
+        let off_track = expertise.to_prompt_with_render_context(
+            &RenderContext::new().with_task_health(TaskHealth::OffTrack),
+        );
+        assert!(off_track.contains("Stop and ask"));
+        assert!(!off_track.contains("Verify each claim"));
+    }
+
+    #[test]
+    fn test_reference_renders_target_content() {
+        let expertise = Expertise::new("test", "1.0")
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text(
+                    "Run cargo clippy before committing".to_string(),
+                ))
+                .with_priority(Priority::Low)
+                .with_anchor("lint"),
+            )
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Reference {
+                    anchor: "lint".to_string(),
+                })
+                .with_priority(Priority::Critical),
+            );
+
+        assert!(expertise.validate_references().is_ok());
+
+        let prompt = expertise.to_prompt();
+        assert_eq!(
+            prompt.matches("Run cargo clippy before committing").count(),
+            2
+        );
+        assert!(!prompt.contains("(See: lint)"));
+
+        // The reference keeps its own priority
+        let compact = expertise.to_prompt_with_min_priority(Priority::Critical);
+        assert_eq!(
+            compact
+                .matches("Run cargo clippy before committing")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_cyclic_reference_is_skipped() {
+        let expertise = Expertise::new("test", "1.0")
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Reference {
+                    anchor: "b".to_string(),
+                })
+                .with_anchor("a"),
+            )
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Reference {
+                    anchor: "a".to_string(),
+                })
+                .with_anchor("b"),
+            )
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Reference {
+                    anchor: "self".to_string(),
+                })
+                .with_anchor("self"),
+            )
+            .with_fragment(WeightedFragment::new(KnowledgeFragment::Text(
+                "Still rendered".to_string(),
+            )));
+
+        assert_eq!(
+            expertise.validate_references(),
+            Err(ReferenceError::Cycle(vec![
+                "b".to_string(),
+                "a".to_string(),
+                "b".to_string()
+            ]))
+        );
+        assert_eq!(
+            expertise
+                .resolve_fragment(&expertise.content[2].fragment)
+                .unwrap_err(),
+            ReferenceError::Cycle(vec!["self".to_string(), "self".to_string()])
+        );
+
+        let prompt = expertise.to_prompt();
+        assert!(prompt.contains("Still rendered"));
+        assert!(!prompt.contains("See:"));
+    }
+
+    #[test]
+    fn test_unknown_reference() {
+        let expertise = Expertise::new("test", "1.0").with_fragment(WeightedFragment::new(
+            KnowledgeFragment::Reference {
+                anchor: "missing".to_string(),
+            },
+        ));
+
+        let err = expertise.validate_references().unwrap_err();
+        assert_eq!(err, ReferenceError::UnknownAnchor("missing".to_string()));
+        assert_eq!(err.to_string(), "Reference to unknown anchor 'missing'");
+    }
+
+    #[test]
+    fn test_validate_valid_expertise() {
+        let expertise = Expertise::new("rust-reviewer", "1.0")
+            .with_tags(vec!["lang:rust".to_string(), "role:reviewer".to_string()])
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text("Review code".to_string()))
+                    .with_anchor("review"),
+            )
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Reference {
+                    anchor: "review".to_string(),
+                })
+                .with_context(ContextProfile::on_task_health(
+                    crate::context::TaskHealth::AtRisk,
+                )),
+            );
+
+        assert_eq!(expertise.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_reports_all_problems() {
+        let expertise = Expertise::new("  ", "1.0")
+            .with_tags(vec![
+                "a".to_string(),
+                "b".to_string(),
+                "a".to_string(),
+                "a".to_string(),
+            ])
+            .with_fragment(WeightedFragment::new(KnowledgeFragment::Text(
+                "Fine".to_string(),
+            )))
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text("Dead condition".to_string()))
+                    .with_context(ContextProfile::Conditional {
+                        task_types: vec![],
+                        user_states: vec![],
+                        task_health: None,
+                    }),
+            )
+            .with_fragment(WeightedFragment::new(KnowledgeFragment::Reference {
+                anchor: "missing".to_string(),
+            }));
+
+        assert_eq!(
+            expertise.validate(),
+            Err(vec![
+                ExpertiseValidationError::EmptyId,
+                ExpertiseValidationError::DuplicateTag("a".to_string()),
+                ExpertiseValidationError::EmptyConditional { index: 1 },
+                ExpertiseValidationError::BrokenReference {
+                    index: 2,
+                    error: ReferenceError::UnknownAnchor("missing".to_string()),
+                },
+            ])
+        );
+    }
+
     #[test]
     fn test_context_filtering() {
         let expertise = Expertise::new("test", "1.0")
@@ -561,4 +1525,37 @@ mod tests {
         assert!(mermaid.contains("Expertise: test"));
         assert!(mermaid.contains("Test content"));
     }
+
+    #[test]
+    fn test_to_dot() {
+        let expertise = Expertise::new("test", "1.0")
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text("Always applies".to_string()))
+                    .with_priority(Priority::Critical),
+            )
+            .with_fragment(
+                WeightedFragment::new(KnowledgeFragment::Text(
+                    "Say \"hello\" to beginners".to_string(),
+                ))
+                .with_context(ContextProfile::Conditional {
+                    task_types: vec![],
+                    user_states: vec!["beginner".to_string()],
+                    task_health: None,
+                }),
+            );
+
+        let dot = expertise.to_dot();
+        assert!(dot.starts_with("digraph"));
+        assert!(dot.contains("root [label=\"Expertise: test (v1.0)\""));
+        assert!(dot.contains("f0 [label=\"CRITICAL [Text]\\nAlways applies\""));
+        assert!(dot.contains("f1 [label=\"NORMAL [Text]\\nSay \\\"hello\\\" to beginners\""));
+        assert!(dot.contains("root -> f0;"));
+        assert!(dot.contains("root -> f1;"));
+        assert!(!dot.contains("f2 "));
+
+        let conditional_line = dot.lines().find(|l| l.contains("f1 [")).unwrap();
+        assert!(conditional_line.contains("dashed"));
+        let always_line = dot.lines().find(|l| l.contains("f0 [")).unwrap();
+        assert!(!always_line.contains("dashed"));
+    }
 }
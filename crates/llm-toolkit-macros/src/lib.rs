@@ -1,40 +1,74 @@
+use llm_toolkit_template::parse_template_placeholders_with_mode;
 use proc_macro::TokenStream;
 use proc_macro_crate::{FoundCrate, crate_name};
 use quote::quote;
 use regex::Regex;
 use syn::{
     Data, DeriveInput, Meta, Token,
+    ext::IdentExt,
     parse::{Parse, ParseStream},
     parse_macro_input,
     punctuated::Punctuated,
 };
 
-/// Parse template placeholders using regex to find :mode patterns
-/// Returns a list of (field_name, optional_mode)
-fn parse_template_placeholders_with_mode(template: &str) -> Vec<(String, Option<String>)> {
-    let mut placeholders = Vec::new();
-    let mut seen_fields = std::collections::HashSet::new();
-
-    // First, find all {{ field:mode }} patterns
-    let mode_pattern = Regex::new(r"\{\{\s*(\w+)\s*:\s*(\w+)\s*\}\}").unwrap();
-    for cap in mode_pattern.captures_iter(template) {
-        let field_name = cap[1].to_string();
-        let mode = cap[2].to_string();
-        placeholders.push((field_name.clone(), Some(mode)));
-        seen_fields.insert(field_name);
+/// Generates a `to_prompt_localized` override for `#[prompt(template_i18n(...))]`.
+///
+/// Each language code becomes a match arm selecting its template; unknown codes
+/// render `default_template`. Returns an empty stream when no localized
+/// templates are declared, leaving the trait's default in place.
+fn generate_to_prompt_localized(
+    templates: &[(String, String)],
+    default_template: &str,
+    context_fields: &[proc_macro2::TokenStream],
+    crate_path: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if templates.is_empty() {
+        return quote! {};
     }
 
-    // Then, find all standard {{ field }} patterns (without mode)
-    let standard_pattern = Regex::new(r"\{\{\s*(\w+)\s*\}\}").unwrap();
-    for cap in standard_pattern.captures_iter(template) {
-        let field_name = cap[1].to_string();
-        // Check if this field was already captured with a mode
-        if !seen_fields.contains(&field_name) {
-            placeholders.push((field_name, None));
+    let langs = templates.iter().map(|(lang, _)| lang);
+    let sources = templates.iter().map(|(_, template)| template);
+
+    quote! {
+        fn to_prompt_localized(&self, lang: &str) -> String {
+            let source = match lang {
+                #(#langs => #sources,)*
+                _ => #default_template,
+            };
+
+            let mut env = #crate_path::minijinja::Environment::new();
+            env.add_template("prompt", source).unwrap_or_else(|e| {
+                panic!("Failed to parse template for language '{}': {}", lang, e)
+            });
+
+            let tmpl = env.get_template("prompt").unwrap();
+
+            let mut context = std::collections::HashMap::new();
+            #(#context_fields)*
+
+            tmpl.render(context).unwrap_or_else(|e| {
+                format!("Failed to render prompt: {}", e)
+            })
         }
     }
+}
 
-    placeholders
+/// Rewrites every `{{ field:mode }}` placeholder for the given pair to `{{ key }}`.
+///
+/// Uses the same whitespace tolerance as
+/// [`MODE_PLACEHOLDER_PATTERN`](llm_toolkit_template::MODE_PLACEHOLDER_PATTERN), so any spelling
+/// that `parse_template_placeholders_with_mode` reports is also replaced.
+fn replace_mode_placeholder(template: &str, field: &str, mode: &str, key: &str) -> String {
+    let pattern = format!(
+        r"\{{\{{\s*{}\s*:\s*{}\s*\}}\}}",
+        regex::escape(field),
+        regex::escape(mode)
+    );
+    let replacement = format!("{{{{ {} }}}}", key);
+    Regex::new(&pattern)
+        .unwrap()
+        .replace_all(template, regex::NoExpand(&replacement))
+        .into_owned()
 }
 
 /// Extract doc comments from attributes
@@ -83,27 +117,41 @@ fn generate_example_only_parts(
             continue;
         }
 
+        // Fields serde never serializes would only mislead the model
+        let serialization = parse_serde_serialization(&field.attrs);
+        if matches!(serialization, SerdeSerialization::Never) {
+            continue;
+        }
+
         // Check if field has example attribute
         if let Some(example) = attrs.example {
-            // Use the provided example value
+            // Use the provided example value (also shows optional fields as a hint)
             field_values.push(quote! {
                 json_obj.insert(#field_name_str.to_string(), serde_json::Value::String(#example.to_string()));
             });
-        } else if has_default {
-            // Use Default value if available
-            field_values.push(quote! {
-                let default_value = serde_json::to_value(&default_instance.#field_name)
-                    .unwrap_or(serde_json::Value::Null);
-                json_obj.insert(#field_name_str.to_string(), default_value);
-            });
-        } else {
-            // Use self's actual value
-            field_values.push(quote! {
-                let value = serde_json::to_value(&self.#field_name)
-                    .unwrap_or(serde_json::Value::Null);
-                json_obj.insert(#field_name_str.to_string(), value);
-            });
+            continue;
         }
+
+        // Use Default value if available, otherwise self's actual value
+        let source = if has_default {
+            quote! { default_instance }
+        } else {
+            quote! { self }
+        };
+        let insert = quote! {
+            let value = serde_json::to_value(&#source.#field_name)
+                .unwrap_or(serde_json::Value::Null);
+            json_obj.insert(#field_name_str.to_string(), value);
+        };
+        field_values.push(match serialization {
+            // Optional field: shown only when serde would actually write this value
+            SerdeSerialization::Unless(predicate) => quote! {
+                if !#predicate(&#source.#field_name) {
+                    #insert
+                }
+            },
+            _ => insert,
+        });
     }
 
     if has_default {
@@ -132,34 +180,37 @@ fn generate_example_only_parts(
     }
 }
 
+/// Generate example JSON from the type's `ProvideExample` implementation
+fn generate_custom_example_parts(
+    crate_path: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! {
+        {
+            let json_value = <Self as #crate_path::prompt::ProvideExample>::example();
+            let json_str = #crate_path::serde_json::to_string_pretty(&json_value)
+                .unwrap_or_else(|_| "{}".to_string());
+            vec![#crate_path::prompt::PromptPart::Text(json_str)]
+        }
+    }
+}
+
 /// Generate schema-only representation for a struct
 fn generate_schema_only_parts(
     struct_name: &str,
     struct_docs: &str,
     fields: &syn::punctuated::Punctuated<syn::Field, syn::Token![,]>,
     crate_path: &proc_macro2::TokenStream,
+    generics: &syn::Generics,
     _has_type_marker: bool,
 ) -> proc_macro2::TokenStream {
+    let type_params: Vec<&syn::Ident> = generics.type_params().map(|p| &p.ident).collect();
     let mut field_schema_parts = vec![];
     let mut nested_type_collectors = vec![];
 
     // Process fields to build runtime schema generation
-    for field in fields.iter() {
+    for field in fields.iter().filter(|field| is_schema_field(field)) {
         let field_name = field.ident.as_ref().unwrap();
         let field_name_str = field_name.to_string();
-        let attrs = parse_field_prompt_attrs(&field.attrs);
-
-        // Skip __type field - it's metadata that shouldn't be in the schema
-        // LLMs misinterpret "__type": "string" as "output the literal string 'string'"
-        // The __type field will be automatically added during deserialization via #[serde(default)]
-        if field_name_str == "__type" {
-            continue;
-        }
-
-        // Skip if marked to skip
-        if attrs.skip {
-            continue;
-        }
 
         // Get field documentation
         let field_docs = extract_doc_comments(&field.attrs);
@@ -179,9 +230,10 @@ fn generate_schema_only_parts(
                 String::new()
             };
 
+            let type_name = schema_type_name(vec_inner_type.unwrap(), &type_params, crate_path);
             field_schema_parts.push(quote! {
                 {
-                    let type_name = stringify!(#vec_inner_type);
+                    let type_name = #type_name;
                     format!("  {}: {}[];{}", #field_name_str, type_name, #comment)
                 }
             });
@@ -214,9 +266,10 @@ fn generate_schema_only_parts(
                     if let Some(value_type) = inner_map_value {
                         let is_value_primitive = is_primitive_type(value_type);
                         if !is_value_primitive {
+                            let type_name = schema_type_name(value_type, &type_params, crate_path);
                             field_schema_parts.push(quote! {
                                 {
-                                    let type_name = stringify!(#value_type);
+                                    let type_name = #type_name;
                                     format!("  {}: Record<string, {}> | null;{}", #field_name_str, type_name, #comment)
                                 }
                             });
@@ -235,9 +288,11 @@ fn generate_schema_only_parts(
                     if let Some(element_type) = inner_set_element {
                         let is_element_primitive = is_primitive_type(element_type);
                         if !is_element_primitive {
+                            let type_name =
+                                schema_type_name(element_type, &type_params, crate_path);
                             field_schema_parts.push(quote! {
                                 {
-                                    let type_name = stringify!(#element_type);
+                                    let type_name = #type_name;
                                     format!("  {}: {}[] | null;{}", #field_name_str, type_name, #comment)
                                 }
                             });
@@ -256,9 +311,11 @@ fn generate_schema_only_parts(
                     if let Some(element_type) = inner_vec_element {
                         let is_element_primitive = is_primitive_type(element_type);
                         if !is_element_primitive {
+                            let type_name =
+                                schema_type_name(element_type, &type_params, crate_path);
                             field_schema_parts.push(quote! {
                                 {
-                                    let type_name = stringify!(#element_type);
+                                    let type_name = #type_name;
                                     format!("  {}: {}[] | null;{}", #field_name_str, type_name, #comment)
                                 }
                             });
@@ -278,9 +335,10 @@ fn generate_schema_only_parts(
 
                     if !is_inner_primitive {
                         // Non-primitive inner type: use type reference
+                        let type_name = schema_type_name(inner, &type_params, crate_path);
                         field_schema_parts.push(quote! {
                             {
-                                let type_name = stringify!(#inner);
+                                let type_name = #type_name;
                                 format!("  {}: {} | null;{}", #field_name_str, type_name, #comment)
                             }
                         });
@@ -312,9 +370,10 @@ fn generate_schema_only_parts(
 
                 if !is_value_primitive {
                     // Non-primitive value type: use type reference
+                    let type_name = schema_type_name(value_type, &type_params, crate_path);
                     field_schema_parts.push(quote! {
                         {
-                            let type_name = stringify!(#value_type);
+                            let type_name = #type_name;
                             format!("  {}: Record<string, {}>;{}", #field_name_str, type_name, #comment)
                         }
                     });
@@ -345,9 +404,10 @@ fn generate_schema_only_parts(
 
                 if !is_element_primitive {
                     // Non-primitive element type: use type reference
+                    let type_name = schema_type_name(element_type, &type_params, crate_path);
                     field_schema_parts.push(quote! {
                         {
-                            let type_name = stringify!(#element_type);
+                            let type_name = #type_name;
                             format!("  {}: {}[];{}", #field_name_str, type_name, #comment)
                         }
                     });
@@ -378,9 +438,10 @@ fn generate_schema_only_parts(
                     String::new()
                 };
 
+                let type_name = schema_type_name(field_type, &type_params, crate_path);
                 field_schema_parts.push(quote! {
                     {
-                        let type_name = stringify!(#field_type);
+                        let type_name = #type_name;
                         format!("  {}: {};{}", #field_name_str, type_name, #comment)
                     }
                 });
@@ -462,6 +523,135 @@ fn generate_schema_only_parts(
     }
 }
 
+/// Generate the body of `prompt_schema_json()` for a struct
+///
+/// Produces `{"properties": {"field": {"type": "..."}}, "required": [...]}` using the
+/// same type names as the TypeScript schema. Field names are the keys serde
+/// deserializes (`#[serde(rename)]` / `#[serde(rename_all)]` applied), and
+/// `#[prompt(skip)]` fields are kept since serde still accepts them. Option fields
+/// and fields with `#[serde(default)]` are not required. Structs with a
+/// `#[serde(flatten)]` field accept arbitrary keys, so they get no schema.
+fn generate_schema_json(
+    attrs: &[syn::Attribute],
+    fields: &syn::punctuated::Punctuated<syn::Field, syn::Token![,]>,
+    crate_path: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if fields
+        .iter()
+        .any(|field| has_serde_flag(&field.attrs, "flatten"))
+    {
+        return quote! { None };
+    }
+
+    let struct_has_default = has_serde_default(attrs);
+    let rename_rule = parse_serde_rename_all(attrs);
+    let mut properties = Vec::new();
+    let mut required = Vec::new();
+
+    for field in fields.iter() {
+        let ident = field.ident.as_ref().unwrap().unraw().to_string();
+
+        // __type is a marker and fields serde never reads are not part of the schema
+        if ident == "__type"
+            || has_serde_flag(&field.attrs, "skip")
+            || has_serde_flag(&field.attrs, "skip_deserializing")
+        {
+            continue;
+        }
+
+        let field_name_str = parse_serde_variant_rename(&field.attrs).unwrap_or_else(|| {
+            rename_rule
+                .map(|rule| rule.apply_to_field(&ident))
+                .unwrap_or(ident)
+        });
+
+        let type_str = format_type_for_schema(&field.ty);
+        properties.push(format!(
+            "{:?}: {{\"type\": {:?}}}",
+            field_name_str, type_str
+        ));
+
+        let (is_option, _) = extract_option_inner_type(&field.ty);
+        if !is_option && !struct_has_default && !has_serde_default(&field.attrs) {
+            required.push(format!("{:?}", field_name_str));
+        }
+    }
+
+    let schema_json = format!(
+        "{{\"properties\": {{{}}}, \"required\": [{}]}}",
+        properties.join(", "),
+        required.join(", ")
+    );
+
+    quote! {
+        #crate_path::serde_json::from_str(#schema_json).ok()
+    }
+}
+
+/// Check for #[serde(default)] or #[serde(default = "...")]
+fn has_serde_default(attrs: &[syn::Attribute]) -> bool {
+    has_serde_flag(attrs, "default")
+}
+
+/// Check for a serde attribute by name, e.g. `flatten` in #[serde(flatten)]
+fn has_serde_flag(attrs: &[syn::Attribute], name: &str) -> bool {
+    for attr in attrs {
+        if attr.path().is_ident("serde")
+            && let Ok(meta_list) = attr.meta.require_list()
+            && let Ok(metas) =
+                meta_list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        {
+            for meta in metas {
+                if meta.path().is_ident(name) {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+/// How serde serializes a field, per #[serde(skip)], #[serde(skip_serializing)]
+/// and #[serde(skip_serializing_if = "...")]
+enum SerdeSerialization {
+    Always,
+    Never,
+    Unless(syn::ExprPath),
+}
+
+/// Parse the serde attributes that keep a field out of serialized output
+fn parse_serde_serialization(attrs: &[syn::Attribute]) -> SerdeSerialization {
+    for attr in attrs {
+        if attr.path().is_ident("serde")
+            && let Ok(meta_list) = attr.meta.require_list()
+            && let Ok(metas) =
+                meta_list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        {
+            for meta in metas {
+                match meta {
+                    Meta::Path(path)
+                        if path.is_ident("skip") || path.is_ident("skip_serializing") =>
+                    {
+                        return SerdeSerialization::Never;
+                    }
+                    Meta::NameValue(nv) if nv.path.is_ident("skip_serializing_if") => {
+                        if let syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(lit_str),
+                            ..
+                        }) = &nv.value
+                            && let Ok(path) = lit_str.parse::<syn::ExprPath>()
+                        {
+                            return SerdeSerialization::Unless(path);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    SerdeSerialization::Always
+}
+
 /// Extract inner type from Vec<T>, returns (is_vec, inner_type)
 fn extract_vec_inner_type(ty: &syn::Type) -> (bool, Option<&syn::Type>) {
     if let syn::Type::Path(type_path) = ty
@@ -560,6 +750,118 @@ fn extract_expandable_type(ty: &syn::Type) -> &syn::Type {
     ty
 }
 
+/// Check if a type mentions one of the given generic type parameters
+fn mentions_type_param(ty: &syn::Type, type_params: &[&syn::Ident]) -> bool {
+    fn walk(tokens: proc_macro2::TokenStream, type_params: &[&syn::Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => type_params.iter().any(|p| **p == ident),
+            proc_macro2::TokenTree::Group(group) => walk(group.stream(), type_params),
+            _ => false,
+        })
+    }
+    !type_params.is_empty() && walk(quote!(#ty), type_params)
+}
+
+/// Generate the expression naming a field type in the TypeScript schema
+///
+/// Concrete types are stringified at compile time. Types that mention a generic
+/// parameter are named at runtime, so `Vec<T>` shows up as `Item[]` once `T = Item`.
+fn schema_type_name(
+    ty: &syn::Type,
+    type_params: &[&syn::Ident],
+    crate_path: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if mentions_type_param(ty, type_params) {
+        quote! { #crate_path::prompt::short_type_name(std::any::type_name::<#ty>()) }
+    } else {
+        quote! { stringify!(#ty) }
+    }
+}
+
+/// Check if a field is rendered in the TypeScript schema
+///
+/// `__type` is metadata LLMs misread as "output the literal string 'string'"; it is
+/// filled in during deserialization via #[serde(default)]. `#[prompt(skip)]` and
+/// `#[serde(skip)]` fields are never part of the expected JSON, and `PhantomData`
+/// carries no data.
+fn is_schema_field(field: &syn::Field) -> bool {
+    let is_phantom = matches!(
+        &field.ty,
+        syn::Type::Path(type_path)
+            if type_path.path.segments.last().is_some_and(|s| s.ident == "PhantomData")
+    );
+    field.ident.as_ref().is_some_and(|ident| ident != "__type")
+        && !parse_field_prompt_attrs(&field.attrs).skip
+        && !has_serde_flag(&field.attrs, "skip")
+        && !is_phantom
+}
+
+/// Add a `ToPrompt` bound to the type parameters used by schema fields
+///
+/// Generated schemas call `<T as ToPrompt>::prompt_schema()` for generic fields,
+/// so the concrete type's schema is included at monomorphization. Parameters
+/// that only appear in skipped or `PhantomData` fields stay unbounded.
+fn add_to_prompt_bounds(
+    generics: &syn::Generics,
+    fields: &syn::punctuated::Punctuated<syn::Field, syn::Token![,]>,
+    crate_path: &proc_macro2::TokenStream,
+) -> syn::Generics {
+    let schema_types: Vec<&syn::Type> = fields
+        .iter()
+        .filter(|field| is_schema_field(field))
+        .map(|field| &field.ty)
+        .collect();
+
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        let ident = param.ident.clone();
+        if schema_types
+            .iter()
+            .any(|ty| mentions_type_param(ty, &[&ident]))
+        {
+            param
+                .bounds
+                .push(syn::parse_quote!(#crate_path::prompt::ToPrompt));
+        }
+    }
+    generics
+}
+
+/// Generate the body of `prompt_schema()` for a struct from its schema parts
+///
+/// The schema is cached like the enum path does. A static inside a generic fn is
+/// shared by every instantiation, so generic structs rebuild it on each call.
+fn generate_prompt_schema_body(
+    schema_parts: &proc_macro2::TokenStream,
+    crate_path: &proc_macro2::TokenStream,
+    is_generic: bool,
+) -> proc_macro2::TokenStream {
+    let build = quote! {
+        {
+            let schema_parts = #schema_parts;
+            schema_parts
+                .into_iter()
+                .filter_map(|part| match part {
+                    #crate_path::prompt::PromptPart::Text(text) => Some(text),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    };
+
+    if is_generic {
+        build
+    } else {
+        quote! {
+            use std::sync::OnceLock;
+            static SCHEMA_CACHE: OnceLock<String> = OnceLock::new();
+
+            SCHEMA_CACHE.get_or_init(|| #build).clone()
+        }
+    }
+}
+
 /// Check if a type is a primitive type (should not be expanded as nested object)
 fn is_primitive_type(ty: &syn::Type) -> bool {
     if let syn::Type::Path(type_path) = ty
@@ -619,6 +921,12 @@ fn is_primitive_type(ty: &syn::Type) -> bool {
                 | "f32"
                 | "f64"
                 | "bool"
+                // Date/time types rendered as ISO-8601 strings (chrono / time features)
+                | "DateTime"
+                | "NaiveDate"
+                | "NaiveDateTime"
+                | "NaiveTime"
+                | "OffsetDateTime"
         )
     } else {
         // References, arrays, etc. are considered primitive for now
@@ -650,6 +958,9 @@ fn format_type_for_schema(ty: &syn::Type) -> String {
                     | "u64" | "u128" | "usize" => "number".to_string(),
                     "f32" | "f64" => "number".to_string(),
                     "bool" => "boolean".to_string(),
+                    "DateTime" | "NaiveDate" | "NaiveDateTime" | "NaiveTime" | "OffsetDateTime" => {
+                        "string".to_string()
+                    }
                     "Vec" => {
                         if let syn::PathArguments::AngleBracketed(args) = &last_segment.arguments
                             && let Some(syn::GenericArgument::Type(inner_type)) = args.args.first()
@@ -733,16 +1044,29 @@ fn parse_prompt_attributes(attrs: &[syn::Attribute]) -> PromptAttributes {
 }
 
 /// Generate example value for a type in JSON format
-fn generate_example_value_for_type(type_str: &str) -> String {
+///
+/// `hint` comes from `#[prompt(example = "...")]` or the field's doc comment. It is
+/// used when it fits the type (any text for strings, a parseable literal for numbers
+/// and booleans) and ignored otherwise.
+fn generate_example_value_for_type(type_str: &str, hint: Option<&str>) -> String {
     match type_str {
-        "string" => "\"example\"".to_string(),
-        "number" => "0".to_string(),
-        "boolean" => "false".to_string(),
+        "string" => match hint {
+            Some(hint) => format!("\"{}\"", hint.replace('\\', "\\\\").replace('"', "\\\"")),
+            None => "\"example\"".to_string(),
+        },
+        "number" => match hint.map(str::trim) {
+            Some(hint) if hint.parse::<f64>().is_ok() => hint.to_string(),
+            _ => "0".to_string(),
+        },
+        "boolean" => match hint.map(str::trim) {
+            Some(hint @ ("true" | "false")) => hint.to_string(),
+            _ => "false".to_string(),
+        },
         s if s.ends_with("[]") => "[]".to_string(),
         s if s.contains("|") => {
             // For union types like "string | null", use the first type
             let first_type = s.split('|').next().unwrap().trim();
-            generate_example_value_for_type(first_type)
+            generate_example_value_for_type(first_type, hint)
         }
         custom_type => {
             // For custom types, reference the type definition
@@ -753,7 +1077,26 @@ fn generate_example_value_for_type(type_str: &str) -> String {
     }
 }
 
-/// Parse #[serde(rename = "...")] attribute on enum variant
+/// Returns the example hint for an enum variant field.
+///
+/// An explicit `#[prompt(example = "...")]` wins; otherwise the first sentence of the
+/// field's doc comment is used.
+fn field_example_hint(attrs: &[syn::Attribute]) -> Option<String> {
+    if let Some(example) = parse_field_prompt_attrs(attrs).example {
+        return Some(example);
+    }
+
+    let docs = extract_doc_comments(attrs);
+    let sentence = match docs.find(". ") {
+        Some(end) => &docs[..end],
+        None => docs.trim_end_matches('.'),
+    }
+    .trim();
+
+    (!sentence.is_empty()).then(|| sentence.to_string())
+}
+
+/// Parse #[serde(rename = "...")] attribute on enum variant or struct field
 fn parse_serde_variant_rename(attrs: &[syn::Attribute]) -> Option<String> {
     for attr in attrs {
         if attr.path().is_ident("serde")
@@ -790,6 +1133,8 @@ enum RenameRule {
     ScreamingSnakeCase,
     KebabCase,
     ScreamingKebabCase,
+    /// Words separated by spaces, each capitalized (prompt-only, not a serde rule)
+    TitleCase,
 }
 
 impl RenameRule {
@@ -804,6 +1149,7 @@ impl RenameRule {
             "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnakeCase),
             "kebab-case" => Some(Self::KebabCase),
             "SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebabCase),
+            "Title Case" => Some(Self::TitleCase),
             _ => None,
         }
     }
@@ -867,6 +1213,46 @@ impl RenameRule {
                 }
                 result
             }
+            Self::TitleCase => {
+                // Convert PascalCase to Title Case
+                let mut result = String::new();
+                for (i, ch) in name.chars().enumerate() {
+                    if ch.is_uppercase() && i > 0 {
+                        result.push(' ');
+                    }
+                    result.push(ch);
+                }
+                result
+            }
+        }
+    }
+
+    /// Apply rename rule to a snake_case field name
+    fn apply_to_field(&self, name: &str) -> String {
+        let capitalize = |word: &str| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+            }
+        };
+
+        match self {
+            Self::None | Self::LowerCase | Self::SnakeCase => name.to_string(),
+            Self::UpperCase | Self::ScreamingSnakeCase => name.to_uppercase(),
+            Self::PascalCase => name.split('_').map(capitalize).collect(),
+            Self::CamelCase => {
+                let pascal: String = name.split('_').map(capitalize).collect();
+                Self::CamelCase.apply(&pascal)
+            }
+            Self::KebabCase => name.replace('_', "-"),
+            Self::ScreamingKebabCase => name.replace('_', "-").to_uppercase(),
+            Self::TitleCase => name
+                .split('_')
+                .filter(|word| !word.is_empty())
+                .map(capitalize)
+                .collect::<Vec<_>>()
+                .join(" "),
         }
     }
 }
@@ -898,9 +1284,13 @@ fn parse_serde_rename_all(attrs: &[syn::Attribute]) -> Option<RenameRule> {
     None
 }
 
-/// Parse #[serde(tag = "...")] attribute on enum
-/// Returns Some(tag_name) if present, None otherwise
-fn parse_serde_tag(attrs: &[syn::Attribute]) -> Option<String> {
+/// Parse #[serde(tag = "...")] and #[serde(content = "...")] attributes on enum
+/// Returns Some((tag_name, content_name)) if a tag is present, None otherwise.
+/// content_name is Some only for adjacently tagged enums.
+fn parse_serde_tag(attrs: &[syn::Attribute]) -> Option<(String, Option<String>)> {
+    let mut tag = None;
+    let mut content = None;
+
     for attr in attrs {
         if attr.path().is_ident("serde")
             && let Ok(meta_list) = attr.meta.require_list()
@@ -911,19 +1301,23 @@ fn parse_serde_tag(attrs: &[syn::Attribute]) -> Option<String> {
             {
                 for meta in metas {
                     if let Meta::NameValue(nv) = meta
-                        && nv.path.is_ident("tag")
                         && let syn::Expr::Lit(syn::ExprLit {
                             lit: syn::Lit::Str(lit_str),
                             ..
                         }) = nv.value
                     {
-                        return Some(lit_str.value());
+                        if nv.path.is_ident("tag") {
+                            tag = Some(lit_str.value());
+                        } else if nv.path.is_ident("content") {
+                            content = Some(lit_str.value());
+                        }
                     }
                 }
             }
         }
     }
-    None
+
+    tag.map(|tag| (tag, content))
 }
 
 /// Parse #[serde(untagged)] attribute on enum
@@ -964,6 +1358,44 @@ struct FieldPromptAttrs {
     /// Force use of from_serialize() for dot access in templates
     /// This is the default for non-primitive types with Serialize
     as_serialize: bool,
+    /// Inline the nested struct's key-value lines at the top level
+    /// instead of rendering them under the field key
+    flatten: bool,
+    /// Explicit position in key-value output (lower values come first)
+    order: Option<i64>,
+    /// Error for an `order` value that is not an integer literal,
+    /// reported by the derive at the value's span
+    order_error: Option<syn::Error>,
+}
+
+/// Parse the value of `#[prompt(order = N)]`, accepting negative integers
+fn parse_order_value(value: &syn::Expr) -> syn::Result<i64> {
+    let invalid = || {
+        syn::Error::new_spanned(
+            value,
+            "`order` expects an integer literal, e.g. #[prompt(order = 1)]",
+        )
+    };
+    match value {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit_int),
+            ..
+        }) => lit_int.base10_parse::<i64>(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => match expr.as_ref() {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit_int),
+                ..
+            }) => format!("-{}", lit_int.base10_digits())
+                .parse::<i64>()
+                .map_err(|e| syn::Error::new_spanned(value, e)),
+            _ => Err(invalid()),
+        },
+        _ => Err(invalid()),
+    }
 }
 
 /// Parse #[prompt(...)] attributes for struct fields
@@ -1019,6 +1451,15 @@ fn parse_field_prompt_attrs(attrs: &[syn::Attribute]) -> FieldPromptAttrs {
                             Meta::Path(path) if path.is_ident("as_serialize") => {
                                 result.as_serialize = true;
                             }
+                            Meta::Path(path) if path.is_ident("flatten") => {
+                                result.flatten = true;
+                            }
+                            Meta::NameValue(nv) if nv.path.is_ident("order") => {
+                                match parse_order_value(&nv.value) {
+                                    Ok(order) => result.order = Some(order),
+                                    Err(e) => result.order_error = Some(e),
+                                }
+                            }
                             _ => {}
                         }
                     }
@@ -1034,6 +1475,9 @@ fn parse_field_prompt_attrs(attrs: &[syn::Attribute]) -> FieldPromptAttrs {
                 } else if meta_list.tokens.to_string() == "as_serialize" {
                     // Handle simple #[prompt(as_serialize)] case
                     result.as_serialize = true;
+                } else if meta_list.tokens.to_string() == "flatten" {
+                    // Handle simple #[prompt(flatten)] case
+                    result.flatten = true;
                 }
             }
         }
@@ -1084,6 +1528,22 @@ fn parse_field_prompt_attrs(attrs: &[syn::Attribute]) -> FieldPromptAttrs {
 /// ## For Enums
 ///
 /// For enums, the macro generates a descriptive prompt based on doc comments and attributes, outlining the available variants. See the documentation on the `ToPrompt` trait for more details.
+///
+/// Add `#[prompt(derive_display)]` on the enum to also implement `std::fmt::Display`
+/// using `to_prompt()`. Leave it off if the enum already has its own `Display` impl.
+///
+/// ```rust,ignore
+/// #[derive(ToPrompt)]
+/// #[prompt(derive_display)]
+/// enum Status {
+///     /// Work is in progress
+///     Active,
+///     /// Work is finished
+///     Done,
+/// }
+///
+/// assert_eq!(format!("{}", Status::Active), Status::Active.to_prompt());
+/// ```
 #[proc_macro_derive(ToPrompt, attributes(prompt))]
 pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -1110,13 +1570,30 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
             let enum_docs = extract_doc_comments(&input.attrs);
 
             // Check for serde tagging strategy attributes
-            let serde_tag = parse_serde_tag(&input.attrs);
-            let is_internally_tagged = serde_tag.is_some();
+            let (serde_tag, serde_content) = match parse_serde_tag(&input.attrs) {
+                Some((tag, content)) => (Some(tag), content),
+                None => (None, None),
+            };
+            let is_adjacently_tagged = serde_tag.is_some() && serde_content.is_some();
+            let is_internally_tagged = serde_tag.is_some() && !is_adjacently_tagged;
             let is_untagged = parse_serde_untagged(&input.attrs);
 
             // Check for #[serde(rename_all = "...")] attribute
             let rename_rule = parse_serde_rename_all(&input.attrs);
 
+            // Check for enum-level #[prompt(derive_display)] attribute
+            let derive_display = input.attrs.iter().any(|attr| {
+                attr.path().is_ident("prompt")
+                    && attr
+                        .parse_args_with(Punctuated::<Meta, syn::Token![,]>::parse_terminated)
+                        .map(|metas| {
+                            metas
+                                .iter()
+                                .any(|meta| meta.path().is_ident("derive_display"))
+                        })
+                        .unwrap_or(false)
+            });
+
             // Generate TypeScript-style union type with descriptions
             // Format:
             // /**
@@ -1170,20 +1647,28 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                 // Check variant type: Unit, Struct, or Tuple
                 let variant_line = match &variant.fields {
                     syn::Fields::Unit => {
+                        // Unit variant: "VariantName"
+                        // Adjacently Tagged: { tag: "VariantName" } (content is omitted)
+                        let unit_value = if is_adjacently_tagged && let Some(tag_name) = &serde_tag
+                        {
+                            format!("{{ {}: \"{}\" }}", tag_name, variant_value)
+                        } else {
+                            format!("\"{}\"", variant_value)
+                        };
+
                         // Collect example for Unit variant (if first one)
                         if example_unit.is_none() {
-                            example_unit = Some(format!("\"{}\"", variant_value));
+                            example_unit = Some(unit_value.clone());
                         }
 
-                        // Unit variant: "VariantName"
                         if let Some(desc) = &prompt_attrs.description {
-                            format!("  | \"{}\"  // {}", variant_value, desc)
+                            format!("  | {}  // {}", unit_value, desc)
                         } else {
                             let docs = extract_doc_comments(&variant.attrs);
                             if !docs.is_empty() {
-                                format!("  | \"{}\"  // {}", variant_value, docs)
+                                format!("  | {}  // {}", unit_value, docs)
                             } else {
-                                format!("  | \"{}\"", variant_value)
+                                format!("  | {}", unit_value)
                             }
                         }
                     }
@@ -1211,7 +1696,11 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                             }
 
                             // Generate example value for this field
-                            let example_value = generate_example_value_for_type(&field_type);
+                            let example_hint = field_example_hint(&field.attrs);
+                            let example_value = generate_example_value_for_type(
+                                &field_type,
+                                example_hint.as_deref(),
+                            );
                             example_field_parts.push(format!("{}: {}", field_name, example_value));
                         }
 
@@ -1222,6 +1711,14 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                         if example_struct.is_none() {
                             if is_untagged || is_internally_tagged {
                                 example_struct = Some(format!("{{ {} }}", example_field_str));
+                            } else if is_adjacently_tagged
+                                && let (Some(tag_name), Some(content_name)) =
+                                    (&serde_tag, &serde_content)
+                            {
+                                example_struct = Some(format!(
+                                    "{{ {}: \"{}\", {}: {{ {} }} }}",
+                                    tag_name, variant_value, content_name, example_field_str
+                                ));
                             } else {
                                 example_struct = Some(format!(
                                     "{{ \"{}\": {{ {} }} }}",
@@ -1250,6 +1747,15 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                         } else if is_internally_tagged {
                             // Internally Tagged format: { type: "VariantName", field1: Type1, ... }
                             format!("  | {{ {} }}{}", field_str, comment)
+                        } else if is_adjacently_tagged
+                            && let (Some(tag_name), Some(content_name)) =
+                                (&serde_tag, &serde_content)
+                        {
+                            // Adjacently Tagged format: { t: "VariantName", c: { field1: Type1, ... } }
+                            format!(
+                                "  | {{ {}: \"{}\", {}: {{ {} }} }}{}",
+                                tag_name, variant_value, content_name, field_str, comment
+                            )
                         } else {
                             // Externally Tagged format (default): { "VariantName": { field1: Type1, ... } }
                             format!(
@@ -1278,7 +1784,11 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                         // Generate example values for tuple elements
                         let example_values: Vec<String> = field_types
                             .iter()
-                            .map(|type_str| generate_example_value_for_type(type_str))
+                            .zip(fields.unnamed.iter())
+                            .map(|(type_str, field)| {
+                                let example_hint = field_example_hint(&field.attrs);
+                                generate_example_value_for_type(type_str, example_hint.as_deref())
+                            })
                             .collect();
                         let example_tuple_str = example_values.join(", ");
 
@@ -1286,6 +1796,14 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                         if example_tuple.is_none() {
                             if is_untagged || is_internally_tagged {
                                 example_tuple = Some(format!("[{}]", example_tuple_str));
+                            } else if is_adjacently_tagged
+                                && let (Some(tag_name), Some(content_name)) =
+                                    (&serde_tag, &serde_content)
+                            {
+                                example_tuple = Some(format!(
+                                    "{{ {}: \"{}\", {}: [{}] }}",
+                                    tag_name, variant_value, content_name, example_tuple_str
+                                ));
                             } else {
                                 example_tuple = Some(format!(
                                     "{{ \"{}\": [{}] }}",
@@ -1312,6 +1830,15 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                             // Untagged or Internally Tagged: bare array [Type1, Type2, ...]
                             // (Internally Tagged enums don't support tuple variants well)
                             format!("  | [{}]{}", tuple_str, comment)
+                        } else if is_adjacently_tagged
+                            && let (Some(tag_name), Some(content_name)) =
+                                (&serde_tag, &serde_content)
+                        {
+                            // Adjacently Tagged format: { t: "VariantName", c: [tuple elements] }
+                            format!(
+                                "  | {{ {}: \"{}\", {}: [{}] }}{}",
+                                tag_name, variant_value, content_name, tuple_str, comment
+                            )
                         } else {
                             // Externally Tagged format (default): { "VariantName": [tuple elements] }
                             format!(
@@ -1584,6 +2111,19 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                 }
             };
 
+            // Cache the schema like the struct path does. A static inside a generic fn is
+            // shared by every instantiation, so enums with type parameters are rebuilt each call.
+            let prompt_schema_body = if input.generics.type_params().next().is_none() {
+                quote! {
+                    use std::sync::OnceLock;
+                    static SCHEMA_CACHE: OnceLock<String> = OnceLock::new();
+
+                    SCHEMA_CACHE.get_or_init(|| #prompt_string).clone()
+                }
+            } else {
+                quote! { #prompt_string }
+            };
+
             let expanded = quote! {
                 impl #impl_generics #crate_path::prompt::ToPrompt for #enum_name #ty_generics #where_clause {
                     fn to_prompt_parts(&self) -> Vec<#crate_path::prompt::PromptPart> {
@@ -1593,20 +2133,48 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                     #to_prompt_impl
 
                     fn prompt_schema() -> String {
-                        #prompt_string
+                        #prompt_schema_body
+                    }
+                }
+            };
+
+            // Opt-in only, so enums with a hand-written Display impl are unaffected
+            let display_impl = if derive_display {
+                quote! {
+                    impl #impl_generics ::std::fmt::Display for #enum_name #ty_generics #where_clause {
+                        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                            f.write_str(&#crate_path::prompt::ToPrompt::to_prompt(self))
+                        }
                     }
                 }
+            } else {
+                quote! {}
+            };
+
+            let expanded = quote! {
+                #expanded
+                #display_impl
             };
 
             TokenStream::from(expanded)
         }
         Data::Struct(data_struct) => {
+            // Reject malformed #[prompt(order = ...)] values up front, whichever format is used
+            for field in &data_struct.fields {
+                if let Some(e) = parse_field_prompt_attrs(&field.attrs).order_error {
+                    return e.to_compile_error().into();
+                }
+            }
+
             // Parse struct-level prompt attributes for template, template_file, mode, and validate
             let mut template_attr = None;
             let mut template_file_attr = None;
             let mut mode_attr = None;
             let mut validate_attr = false;
             let mut type_marker_attr = false;
+            let mut custom_example_attr = false;
+            let mut rename_all_attr = None;
+            let mut template_i18n_attr: Vec<(String, String)> = Vec::new();
 
             for attr in &input.attrs {
                 if attr.path().is_ident("prompt") {
@@ -1616,6 +2184,38 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                     {
                         for meta in metas {
                             match meta {
+                                Meta::List(list) if list.path.is_ident("template_i18n") => {
+                                    // #[prompt(template_i18n(en = "...", ja = "..."))]
+                                    let entries = match list.parse_args_with(
+                                        Punctuated::<syn::MetaNameValue, syn::Token![,]>::parse_terminated,
+                                    ) {
+                                        Ok(entries) => entries,
+                                        Err(e) => return e.to_compile_error().into(),
+                                    };
+                                    for entry in entries {
+                                        let Some(lang) = entry.path.get_ident() else {
+                                            return syn::Error::new_spanned(
+                                                &entry.path,
+                                                "Expected a language code such as `en` or `ja`",
+                                            )
+                                            .to_compile_error()
+                                            .into();
+                                        };
+                                        if let syn::Expr::Lit(expr_lit) = &entry.value
+                                            && let syn::Lit::Str(lit_str) = &expr_lit.lit
+                                        {
+                                            template_i18n_attr
+                                                .push((lang.to_string(), lit_str.value()));
+                                        } else {
+                                            return syn::Error::new_spanned(
+                                                &entry.value,
+                                                "template_i18n values must be string literals",
+                                            )
+                                            .to_compile_error()
+                                            .into();
+                                        }
+                                    }
+                                }
                                 Meta::NameValue(nv) if nv.path.is_ident("template") => {
                                     if let syn::Expr::Lit(expr_lit) = nv.value
                                         && let syn::Lit::Str(lit_str) = expr_lit.lit
@@ -1655,6 +2255,30 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                                     // Support both #[prompt(type_marker)] and #[prompt(type_marker = true)]
                                     type_marker_attr = true;
                                 }
+                                Meta::Path(path) if path.is_ident("custom_example") => {
+                                    // #[prompt(custom_example)] renders ProvideExample::example()
+                                    custom_example_attr = true;
+                                }
+                                Meta::NameValue(nv) if nv.path.is_ident("rename_all") => {
+                                    if let syn::Expr::Lit(expr_lit) = nv.value
+                                        && let syn::Lit::Str(lit_str) = expr_lit.lit
+                                    {
+                                        match RenameRule::from_str(&lit_str.value()) {
+                                            Some(rule) => rename_all_attr = Some(rule),
+                                            None => {
+                                                return syn::Error::new(
+                                                    lit_str.span(),
+                                                    format!(
+                                                        "Unknown rename_all rule '{}'. Expected one of: lowercase, UPPERCASE, PascalCase, camelCase, snake_case, SCREAMING_SNAKE_CASE, kebab-case, SCREAMING-KEBAB-CASE, Title Case",
+                                                        lit_str.value()
+                                                    ),
+                                                )
+                                                .to_compile_error()
+                                                .into();
+                                            }
+                                        }
+                                    }
+                                }
                                 _ => {}
                             }
                         }
@@ -1677,8 +2301,19 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
 
                 let mut full_path = None;
 
+                // Strategy 0: Try relative to LLM_TOOLKIT_TEMPLATE_DIR (shared template folder)
+                // Read at build time; takes precedence over every other search root
+                if let Ok(template_dir) = std::env::var("LLM_TOOLKIT_TEMPLATE_DIR") {
+                    let candidate = std::path::Path::new(&template_dir).join(&file_path);
+                    if candidate.exists() {
+                        full_path = Some(candidate);
+                    }
+                }
+
                 // Strategy 1: Try relative to CARGO_MANIFEST_DIR (normal compilation)
-                if let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") {
+                if full_path.is_none()
+                    && let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR")
+                {
                     // Check if this is a trybuild temporary directory
                     let is_trybuild = manifest_dir.contains("target/tests/trybuild");
 
@@ -1756,6 +2391,11 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                         file_path
                     );
 
+                    if let Ok(template_dir) = std::env::var("LLM_TOOLKIT_TEMPLATE_DIR") {
+                        let candidate = std::path::Path::new(&template_dir).join(&file_path);
+                        error_msg.push_str(&format!("\n  - {}", candidate.display()));
+                    }
+
                     if let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") {
                         let candidate = std::path::Path::new(&manifest_dir).join(&file_path);
                         error_msg.push_str(&format!("\n  - {}", candidate.display()));
@@ -1768,7 +2408,9 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
 
                     error_msg.push_str("\n\nPlease ensure:");
                     error_msg.push_str("\n  1. The template file exists");
-                    error_msg.push_str("\n  2. The path is relative to CARGO_MANIFEST_DIR");
+                    error_msg.push_str(
+                        "\n  2. The path is relative to CARGO_MANIFEST_DIR (or LLM_TOOLKIT_TEMPLATE_DIR)",
+                    );
                     error_msg.push_str("\n  3. There are no typos in the path");
 
                     return syn::Error::new(input.ident.span(), error_msg)
@@ -1799,6 +2441,19 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                 template_attr
             };
 
+            if !template_i18n_attr.is_empty() && mode_attr.is_some() {
+                return syn::Error::new(
+                    input.ident.span(),
+                    "The `template_i18n` attribute cannot be combined with `mode`.",
+                )
+                .to_compile_error()
+                .into();
+            }
+
+            // Without an explicit template, the first localized template is the default
+            let template_str =
+                template_str.or_else(|| template_i18n_attr.first().map(|(_, t)| t.clone()));
+
             // Perform validation if requested
             if validate_attr && let Some(template) = &template_str {
                 // Validate Jinja syntax
@@ -1848,13 +2503,15 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
 
             let name = input.ident;
             let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+            let is_generic = input.generics.type_params().next().is_some();
 
             // Extract struct name and doc comment for use in schema generation
             let struct_docs = extract_doc_comments(&input.attrs);
 
             // Check if this is a mode-based struct (mode attribute present)
-            let is_mode_based =
-                mode_attr.is_some() || (template_str.is_none() && struct_docs.contains("mode"));
+            let is_mode_based = mode_attr.is_some()
+                || custom_example_attr
+                || (template_str.is_none() && struct_docs.contains("mode"));
 
             let expanded = if is_mode_based || mode_attr.is_some() {
                 // Mode-based generation: support schema_only, example_only, full
@@ -1868,6 +2525,10 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
 
                 let struct_name_str = name.to_string();
 
+                // Schemas of generic fields need the parameter's own schema
+                let schema_generics = add_to_prompt_bounds(&input.generics, fields, &crate_path);
+                let (impl_generics, ty_generics, where_clause) = schema_generics.split_for_impl();
+
                 // Check if struct derives Default
                 let has_default = input.attrs.iter().any(|attr| {
                     if attr.path().is_ident("derive")
@@ -1895,11 +2556,19 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                     &struct_docs,
                     fields,
                     &crate_path,
+                    &input.generics,
                     type_marker_attr,
                 );
+                let prompt_schema_body =
+                    generate_prompt_schema_body(&schema_parts, &crate_path, is_generic);
+                let schema_json = generate_schema_json(&input.attrs, fields, &crate_path);
 
                 // Generate example parts
-                let example_parts = generate_example_only_parts(fields, has_default, &crate_path);
+                let example_parts = if custom_example_attr {
+                    generate_custom_example_parts(&crate_path)
+                } else {
+                    generate_example_only_parts(fields, has_default, &crate_path)
+                };
 
                 quote! {
                     impl #impl_generics #crate_path::prompt::ToPrompt for #name #ty_generics #where_clause {
@@ -1946,20 +2615,11 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                         }
 
                         fn prompt_schema() -> String {
-                            use std::sync::OnceLock;
-                            static SCHEMA_CACHE: OnceLock<String> = OnceLock::new();
-
-                            SCHEMA_CACHE.get_or_init(|| {
-                                let schema_parts = #schema_parts;
-                                schema_parts
-                                    .into_iter()
-                                    .filter_map(|part| match part {
-                                        #crate_path::prompt::PromptPart::Text(text) => Some(text),
-                                        _ => None,
-                                    })
-                                    .collect::<Vec<_>>()
-                                    .join("\n")
-                            }).clone()
+                            #prompt_schema_body
+                        }
+
+                        fn prompt_schema_json() -> Option<#crate_path::serde_json::Value> {
+                            #schema_json
                         }
                     }
                 }
@@ -1981,11 +2641,22 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                     &struct_docs,
                     fields,
                     &crate_path,
+                    &input.generics,
                     type_marker_attr,
                 );
-
-                // Parse template to detect mode syntax
-                let placeholders = parse_template_placeholders_with_mode(&template);
+                let prompt_schema_body =
+                    generate_prompt_schema_body(&schema_parts, &crate_path, is_generic);
+                let schema_json = generate_schema_json(&input.attrs, fields, &crate_path);
+
+                // Parse template to detect mode syntax (localized templates share the context)
+                let mut placeholders = parse_template_placeholders_with_mode(&template);
+                for (_, localized) in &template_i18n_attr {
+                    for placeholder in parse_template_placeholders_with_mode(localized) {
+                        if !placeholders.contains(&placeholder) {
+                            placeholders.push(placeholder);
+                        }
+                    }
+                }
                 // Only use custom mode processing if template actually contains :mode syntax
                 let has_mode_syntax = placeholders.iter().any(|(field_name, mode)| {
                     mode.is_some()
@@ -2012,6 +2683,7 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                     // Build custom context for fields with mode specifications
                     let mut context_fields = Vec::new();
                     let mut modified_template = template.clone();
+                    let mut modified_i18n = template_i18n_attr.clone();
 
                     // Process each placeholder with mode
                     for (field_name, mode_opt) in &placeholders {
@@ -2020,9 +2692,20 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                             let unique_key = format!("{}__{}", field_name, mode);
 
                             // Replace {{ field:mode }} with {{ field__mode }} in template
-                            let pattern = format!("{{{{ {}:{} }}}}", field_name, mode);
-                            let replacement = format!("{{{{ {} }}}}", unique_key);
-                            modified_template = modified_template.replace(&pattern, &replacement);
+                            modified_template = replace_mode_placeholder(
+                                &modified_template,
+                                field_name,
+                                mode,
+                                &unique_key,
+                            );
+                            for (_, localized) in modified_i18n.iter_mut() {
+                                *localized = replace_mode_placeholder(
+                                    localized,
+                                    field_name,
+                                    mode,
+                                    &unique_key,
+                                );
+                            }
 
                             // Find the corresponding field
                             let field_ident =
@@ -2186,6 +2869,13 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                         }
                     }
 
+                    let localized_method = generate_to_prompt_localized(
+                        &modified_i18n,
+                        &modified_template,
+                        &context_fields,
+                        &crate_path,
+                    );
+
                     quote! {
                         impl #impl_generics #crate_path::prompt::ToPrompt for #name #ty_generics #where_clause {
                             fn to_prompt_parts(&self) -> Vec<#crate_path::prompt::PromptPart> {
@@ -2235,21 +2925,14 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                                 })
                             }
 
+                            #localized_method
+
                             fn prompt_schema() -> String {
-                                use std::sync::OnceLock;
-                                static SCHEMA_CACHE: OnceLock<String> = OnceLock::new();
+                                #prompt_schema_body
+                            }
 
-                                SCHEMA_CACHE.get_or_init(|| {
-                                    let schema_parts = #schema_parts;
-                                    schema_parts
-                                        .into_iter()
-                                        .filter_map(|part| match part {
-                                            #crate_path::prompt::PromptPart::Text(text) => Some(text),
-                                            _ => None,
-                                        })
-                                        .collect::<Vec<_>>()
-                                        .join("\n")
-                                }).clone()
+                            fn prompt_schema_json() -> Option<#crate_path::serde_json::Value> {
+                                #schema_json
                             }
                         }
                     }
@@ -2424,6 +3107,13 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                         }
                     }
 
+                    let simple_localized_method = generate_to_prompt_localized(
+                        &template_i18n_attr,
+                        &template,
+                        &simple_context_fields,
+                        &crate_path,
+                    );
+
                     quote! {
                         impl #impl_generics #crate_path::prompt::ToPrompt for #name #ty_generics #where_clause {
                             fn to_prompt_parts(&self) -> Vec<#crate_path::prompt::PromptPart> {
@@ -2473,21 +3163,14 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                                 })
                             }
 
+                            #simple_localized_method
+
                             fn prompt_schema() -> String {
-                                use std::sync::OnceLock;
-                                static SCHEMA_CACHE: OnceLock<String> = OnceLock::new();
+                                #prompt_schema_body
+                            }
 
-                                SCHEMA_CACHE.get_or_init(|| {
-                                    let schema_parts = #schema_parts;
-                                    schema_parts
-                                        .into_iter()
-                                        .filter_map(|part| match part {
-                                            #crate_path::prompt::PromptPart::Text(text) => Some(text),
-                                            _ => None,
-                                        })
-                                        .collect::<Vec<_>>()
-                                        .join("\n")
-                                }).clone()
+                            fn prompt_schema_json() -> Option<#crate_path::serde_json::Value> {
+                                #schema_json
                             }
                         }
                     }
@@ -2503,11 +3186,15 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                     );
                 };
 
+                // Fields without #[prompt(order = N)] keep declaration order,
+                // placed after all explicitly ordered fields
+                const UNORDERED_BASE: i64 = 1_000_000;
+
                 // Separate image fields from text fields
                 let mut text_field_parts = Vec::new();
                 let mut image_field_parts = Vec::new();
 
-                for f in fields.iter() {
+                for (index, f) in fields.iter().enumerate() {
                     let field_name = f.ident.as_ref().unwrap();
                     let attrs = parse_field_prompt_attrs(&f.attrs);
 
@@ -2516,33 +3203,49 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                         continue;
                     }
 
+                    let order = attrs.order.unwrap_or(UNORDERED_BASE + index as i64);
+
                     if attrs.image {
                         // This field is marked as an image
                         image_field_parts.push(quote! {
                             parts.extend(self.#field_name.to_prompt_parts());
                         });
+                    } else if attrs.flatten {
+                        // Flattened field: emit the nested struct's own lines
+                        // directly instead of nesting them under a key
+                        text_field_parts.push((order, quote! {
+                            for part in #crate_path::prompt::ToPrompt::to_prompt_parts(&self.#field_name) {
+                                if let #crate_path::prompt::PromptPart::Text(text) = part {
+                                    if !text.is_empty() {
+                                        text_parts.push(text);
+                                    }
+                                }
+                            }
+                        }));
                     } else {
                         // This is a regular text field
                         // Determine the key based on priority:
                         // 1. #[prompt(rename = "new_name")]
                         // 2. Doc comment
-                        // 3. Field name (fallback)
+                        // 3. Field name, with struct-level #[prompt(rename_all = "...")] applied
                         let key = if let Some(rename) = attrs.rename {
                             rename
                         } else {
                             let doc_comment = extract_doc_comments(&f.attrs);
                             if !doc_comment.is_empty() {
                                 doc_comment
+                            } else if let Some(rule) = rename_all_attr {
+                                rule.apply_to_field(&field_name.to_string())
                             } else {
                                 field_name.to_string()
                             }
                         };
 
                         // Determine the value based on format_with attribute
-                        let value_expr = if let Some(format_with) = attrs.format_with {
+                        let value_expr = if let Some(format_with) = &attrs.format_with {
                             // Parse the function path string into a syn::Path
                             let func_path: syn::Path =
-                                syn::parse_str(&format_with).unwrap_or_else(|_| {
+                                syn::parse_str(format_with).unwrap_or_else(|_| {
                                     panic!("Invalid function path: {}", format_with)
                                 });
                             quote! { #func_path(&self.#field_name) }
@@ -2550,12 +3253,44 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                             quote! { self.#field_name.to_prompt() }
                         };
 
-                        text_field_parts.push(quote! {
-                            text_parts.push(format!("{}: {}", #key, #value_expr));
-                        });
+                        if attrs.format_with.is_none() && !is_primitive_type(&f.ty) {
+                            // Nested ToPrompt value: render multi-line output as an
+                            // indented block under the key so deeper levels stay readable
+                            text_field_parts.push((
+                                order,
+                                quote! {
+                                    {
+                                        let value = #value_expr;
+                                        if value.contains('\n') {
+                                            text_parts.push(format!(
+                                                "{}:\n{}",
+                                                #key,
+                                                #crate_path::prompt::ToPrompt::to_prompt_with_indent(&self.#field_name, 1)
+                                            ));
+                                        } else {
+                                            text_parts.push(format!("{}: {}", #key, value));
+                                        }
+                                    }
+                                },
+                            ));
+                        } else {
+                            text_field_parts.push((
+                                order,
+                                quote! {
+                                    text_parts.push(format!("{}: {}", #key, #value_expr));
+                                },
+                            ));
+                        }
                     }
                 }
 
+                // Apply #[prompt(order = N)]; the sort is stable so ties keep declaration order
+                text_field_parts.sort_by_key(|(order, _)| *order);
+                let text_field_parts: Vec<_> = text_field_parts
+                    .into_iter()
+                    .map(|(_, tokens)| tokens)
+                    .collect();
+
                 // Generate schema parts for prompt_schema()
                 let struct_name_str = name.to_string();
                 let schema_parts = generate_schema_only_parts(
@@ -2563,8 +3298,12 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                     &struct_docs,
                     fields,
                     &crate_path,
+                    &input.generics,
                     false, // type_marker is false for simple structs
                 );
+                let prompt_schema_body =
+                    generate_prompt_schema_body(&schema_parts, &crate_path, is_generic);
+                let schema_json = generate_schema_json(&input.attrs, fields, &crate_path);
 
                 // Generate the implementation with to_prompt_parts()
                 quote! {
@@ -2593,20 +3332,11 @@ pub fn to_prompt_derive(input: TokenStream) -> TokenStream {
                         }
 
                         fn prompt_schema() -> String {
-                            use std::sync::OnceLock;
-                            static SCHEMA_CACHE: OnceLock<String> = OnceLock::new();
-
-                            SCHEMA_CACHE.get_or_init(|| {
-                                let schema_parts = #schema_parts;
-                                schema_parts
-                                    .into_iter()
-                                    .filter_map(|part| match part {
-                                        #crate_path::prompt::PromptPart::Text(text) => Some(text),
-                                        _ => None,
-                                    })
-                                    .collect::<Vec<_>>()
-                                    .join("\n")
-                            }).clone()
+                            #prompt_schema_body
+                        }
+
+                        fn prompt_schema_json() -> Option<#crate_path::serde_json::Value> {
+                            #schema_json
                         }
                     }
                 }
@@ -2626,6 +3356,10 @@ struct TargetInfo {
     name: String,
     template: Option<String>,
     field_configs: std::collections::HashMap<String, FieldTargetConfig>,
+    /// Unknown target names render this target instead of failing
+    is_default: bool,
+    /// Output format from `format = "..."`; only `"json"` is supported
+    format: Option<String>,
 }
 
 /// Configuration for how a field should be handled for a specific target
@@ -2722,9 +3456,14 @@ fn parse_struct_prompt_for_attrs(attrs: &[syn::Attribute]) -> Vec<TargetInfo> {
         {
             let mut target_name = None;
             let mut template = None;
+            let mut is_default = false;
+            let mut format = None;
 
             for meta in metas {
                 match meta {
+                    Meta::Path(path) if path.is_ident("default") => {
+                        is_default = true;
+                    }
                     Meta::NameValue(nv) if nv.path.is_ident("name") => {
                         if let syn::Expr::Lit(syn::ExprLit {
                             lit: syn::Lit::Str(lit_str),
@@ -2743,6 +3482,15 @@ fn parse_struct_prompt_for_attrs(attrs: &[syn::Attribute]) -> Vec<TargetInfo> {
                             template = Some(lit_str.value());
                         }
                     }
+                    Meta::NameValue(nv) if nv.path.is_ident("format") => {
+                        if let syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(lit_str),
+                            ..
+                        }) = nv.value
+                        {
+                            format = Some(lit_str.value());
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -2752,6 +3500,8 @@ fn parse_struct_prompt_for_attrs(attrs: &[syn::Attribute]) -> Vec<TargetInfo> {
                     name,
                     template,
                     field_configs: std::collections::HashMap::new(),
+                    is_default,
+                    format,
                 });
             }
         }
@@ -2760,6 +3510,24 @@ fn parse_struct_prompt_for_attrs(attrs: &[syn::Attribute]) -> Vec<TargetInfo> {
     targets
 }
 
+/// Compile error for two fields emitting the same key within one `ToPromptSet` target
+fn duplicate_prompt_set_key_error(
+    field: &syn::Ident,
+    existing_field: &str,
+    key: &str,
+    target: &str,
+) -> TokenStream {
+    syn::Error::new(
+        field.span(),
+        format!(
+            "Fields `{}` and `{}` both render as key '{}' for target '{}'; rename one of them",
+            existing_field, field, key, target
+        ),
+    )
+    .to_compile_error()
+    .into()
+}
+
 #[proc_macro_derive(ToPromptSet, attributes(prompt_for))]
 pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -2806,6 +3574,44 @@ pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
     // Parse struct-level attributes to find targets
     let mut targets = parse_struct_prompt_for_attrs(&input.attrs);
 
+    if targets.iter().filter(|t| t.is_default).count() > 1 {
+        return syn::Error::new(
+            input.ident.span(),
+            "Only one `#[prompt_for(..., default)]` target may be declared",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    for target in &targets {
+        match target.format.as_deref() {
+            None => {}
+            Some("json") if target.template.is_some() => {
+                return syn::Error::new(
+                    input.ident.span(),
+                    format!(
+                        "Target '{}' cannot use both `template` and `format = \"json\"`",
+                        target.name
+                    ),
+                )
+                .to_compile_error()
+                .into();
+            }
+            Some("json") => {}
+            Some(other) => {
+                return syn::Error::new(
+                    input.ident.span(),
+                    format!(
+                        "Unsupported format '{}' for target '{}' (supported: \"json\")",
+                        other, target.name
+                    ),
+                )
+                .to_compile_error()
+                .into();
+            }
+        }
+    }
+
     // Parse field-level attributes
     for field in fields.iter() {
         let field_name = field.ident.as_ref().unwrap().to_string();
@@ -2830,6 +3636,8 @@ pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
                         name: target_name.clone(),
                         template: None,
                         field_configs: std::collections::HashMap::new(),
+                        is_default: false,
+                        format: None,
                     });
                 }
 
@@ -2843,8 +3651,19 @@ pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
     // Generate match arms for each target
     let mut match_arms = Vec::new();
 
+    // Collect all target names (in declaration order) for introspection and error reporting
+    let target_names: Vec<String> = targets.iter().map(|t| t.name.clone()).collect();
+
+    // The default target goes last so its wildcard arm also catches unknown names
+    targets.sort_by_key(|t| t.is_default);
+
     for target in &targets {
         let target_name = &target.name;
+        let arm_pattern = if target.is_default {
+            quote! { _ }
+        } else {
+            quote! { #target_name }
+        };
 
         if let Some(template_str) = &target.template {
             // Template-based generation
@@ -2864,7 +3683,7 @@ pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
             }
 
             match_arms.push(quote! {
-                #target_name => {
+                #arm_pattern => {
                     let mut parts = Vec::new();
 
                     #(#image_parts)*
@@ -2883,9 +3702,23 @@ pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
                 }
             });
         } else {
-            // Key-value based generation
+            // Key-value based generation, or a compact JSON object with `format = "json"`
+            let is_json = target.format.as_deref() == Some("json");
+            let json_value_for = |field_name: &syn::Ident| {
+                quote! {
+                    #crate_path::serde_json::to_value(&self.#field_name).map_err(|e| {
+                        #crate_path::prompt::PromptSetError::SerializationFailed {
+                            target: #target_name.to_string(),
+                            source: e,
+                        }
+                    })?
+                }
+            };
             let mut text_field_parts = Vec::new();
             let mut image_field_parts = Vec::new();
+            // Output key -> field that emits it, to catch rename collisions
+            let mut emitted_keys: std::collections::HashMap<String, String> =
+                std::collections::HashMap::new();
 
             for field in fields.iter() {
                 let field_name = field.ident.as_ref().unwrap();
@@ -2920,6 +3753,16 @@ pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
                         });
                     } else {
                         let key = cfg.rename.clone().unwrap_or_else(|| field_name_str.clone());
+                        if let Some(existing) =
+                            emitted_keys.insert(key.clone(), field_name_str.clone())
+                        {
+                            return duplicate_prompt_set_key_error(
+                                field_name,
+                                &existing,
+                                &key,
+                                target_name,
+                            );
+                        }
 
                         let value_expr = if let Some(format_with) = &cfg.format_with {
                             // Parse the function path - if it fails, generate code that will produce a compile error
@@ -2941,10 +3784,36 @@ pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
                             quote! { self.#field_name.to_prompt() }
                         };
 
-                        text_field_parts.push(quote! {
-                            text_parts.push(format!("{}: {}", #key, #value_expr));
-                        });
+                        if is_json {
+                            // format_with output is a string; other fields keep their serde shape
+                            let json_value = if cfg.format_with.is_some() {
+                                quote! { #crate_path::serde_json::Value::String(#value_expr) }
+                            } else {
+                                json_value_for(field_name)
+                            };
+                            text_field_parts.push(quote! {
+                                map.insert(#key.to_string(), #json_value);
+                            });
+                        } else {
+                            text_field_parts.push(quote! {
+                                text_parts.push(format!("{}: {}", #key, #value_expr));
+                            });
+                        }
                     }
+                } else if let Some(existing) =
+                    emitted_keys.insert(field_name_str.clone(), field_name_str.clone())
+                {
+                    return duplicate_prompt_set_key_error(
+                        field_name,
+                        &existing,
+                        &field_name_str,
+                        target_name,
+                    );
+                } else if is_json {
+                    let json_value = json_value_for(field_name);
+                    text_field_parts.push(quote! {
+                        map.insert(#field_name_str.to_string(), #json_value);
+                    });
                 } else {
                     // Default handling for fields without specific config
                     text_field_parts.push(quote! {
@@ -2953,8 +3822,28 @@ pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
                 }
             }
 
+            if is_json {
+                match_arms.push(quote! {
+                    #arm_pattern => {
+                        let mut parts = Vec::new();
+
+                        #(#image_field_parts)*
+
+                        let mut map = #crate_path::serde_json::Map::new();
+                        #(#text_field_parts)*
+
+                        parts.push(#crate_path::prompt::PromptPart::Text(
+                            #crate_path::serde_json::Value::Object(map).to_string(),
+                        ));
+
+                        Ok(parts)
+                    }
+                });
+                continue;
+            }
+
             match_arms.push(quote! {
-                #target_name => {
+                #arm_pattern => {
                     let mut parts = Vec::new();
 
                     #(#image_field_parts)*
@@ -2972,19 +3861,18 @@ pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
         }
     }
 
-    // Collect all target names for error reporting
-    let target_names: Vec<String> = targets.iter().map(|t| t.name.clone()).collect();
-
-    // Add default case for unknown targets
-    match_arms.push(quote! {
-        _ => {
-            let available = vec![#(#target_names.to_string()),*];
-            Err(#crate_path::prompt::PromptSetError::TargetNotFound {
-                target: target.to_string(),
-                available,
-            })
-        }
-    });
+    // Add error case for unknown targets, unless a default target already handles them
+    if !targets.iter().any(|t| t.is_default) {
+        match_arms.push(quote! {
+            _ => {
+                let available = vec![#(#target_names.to_string()),*];
+                Err(#crate_path::prompt::PromptSetError::TargetNotFound {
+                    target: target.to_string(),
+                    available,
+                })
+            }
+        });
+    }
 
     let struct_name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
@@ -2996,6 +3884,10 @@ pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
                     #(#match_arms)*
                 }
             }
+
+            fn available_targets(&self) -> Vec<&'static str> {
+                vec![#(#target_names),*]
+            }
         }
     };
 
@@ -3004,84 +3896,224 @@ pub fn to_prompt_set_derive(input: TokenStream) -> TokenStream {
 
 /// Wrapper struct for parsing a comma-separated list of types
 struct TypeList {
+    header: Option<syn::LitStr>,
+    separator: Option<syn::LitStr>,
     types: Punctuated<syn::Type, Token![,]>,
 }
 
 impl Parse for TypeList {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut header = None;
+        let mut separator = None;
+
+        // Optional leading `key = "value"` settings before the type list
+        while input.peek(syn::Ident) && input.peek2(Token![=]) {
+            let key: syn::Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let value: syn::LitStr = input.parse()?;
+
+            let slot = match key.to_string().as_str() {
+                "header" => &mut header,
+                "separator" => &mut separator,
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("unknown option `{}`; expected `header` or `separator`", key),
+                    ));
+                }
+            };
+            if slot.is_some() {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("duplicate option `{}`", key),
+                ));
+            }
+            *slot = Some(value);
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
         Ok(TypeList {
+            header,
+            separator,
             types: Punctuated::parse_terminated(input)?,
         })
     }
 }
 
-/// Generates a formatted Markdown examples section for the provided types.
+/// Generates a formatted Markdown examples section for the provided types.
+///
+/// This macro accepts a comma-separated list of types and generates a single
+/// formatted Markdown string containing examples of each type.
+///
+/// The header line and the `---` separator can be overridden with optional
+/// leading settings: `examples_section!(header = "## Reference Types", separator = "===", User, Concept)`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let examples = examples_section!(User, Concept);
+/// // Produces a string like:
+/// // ---
+/// // ### Examples
+/// //
+/// // Here are examples of the data structures you should use.
+/// //
+/// // ---
+/// // #### `User`
+/// // {...json...}
+/// // ---
+/// // #### `Concept`
+/// // {...json...}
+/// // ---
+/// ```
+#[proc_macro]
+pub fn examples_section(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as TypeList);
+
+    let found_crate =
+        crate_name("llm-toolkit").expect("llm-toolkit should be present in `Cargo.toml`");
+    let _crate_path = match found_crate {
+        FoundCrate::Itself => quote!(crate),
+        FoundCrate::Name(name) => {
+            let ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
+            quote!(::#ident)
+        }
+    };
+
+    let header = input
+        .header
+        .as_ref()
+        .map_or_else(|| "### Examples".to_string(), syn::LitStr::value);
+    let separator = input
+        .separator
+        .as_ref()
+        .map_or_else(|| "---".to_string(), syn::LitStr::value);
+
+    // Generate code for each type
+    let mut type_sections = Vec::new();
+
+    for ty in input.types.iter() {
+        // Extract the type name as a string
+        let type_name_str = quote!(#ty).to_string();
+
+        // Generate the section for this type
+        type_sections.push(quote! {
+            {
+                let type_name = #type_name_str;
+                let json_example = <#ty as Default>::default().to_prompt_with_mode("example_only");
+                format!("{}\n#### `{}`\n{}", #separator, type_name, json_example)
+            }
+        });
+    }
+
+    // Build the complete examples string
+    let expanded = quote! {
+        {
+            let mut sections = Vec::new();
+            sections.push(#separator.to_string());
+            sections.push(#header.to_string());
+            sections.push("".to_string());
+            sections.push("Here are examples of the data structures you should use.".to_string());
+            sections.push("".to_string());
+
+            #(sections.push(#type_sections);)*
+
+            sections.push(#separator.to_string());
+
+            sections.join("\n")
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Generates a formatted Markdown section with the schema and an example for each provided type.
 ///
-/// This macro accepts a comma-separated list of types and generates a single
-/// formatted Markdown string containing examples of each type.
+/// Like `examples_section!`, but each type gets its `prompt_schema()` followed by its
+/// `example_only` rendering, so a single block describes both the shape and a sample
+/// value. Every listed type must implement `ToPrompt` and `Default`. Accepts the same
+/// optional leading `header = "..."` and `separator = "..."` settings.
 ///
 /// # Example
 ///
 /// ```rust,ignore
-/// let examples = examples_section!(User, Concept);
+/// let section = schema_and_examples_section!(User, Concept);
 /// // Produces a string like:
 /// // ---
-/// // ### Examples
+/// // ### Types and Examples
 /// //
-/// // Here are examples of the data structures you should use.
+/// // Here are the data structures you should use, each with an example.
 /// //
 /// // ---
 /// // #### `User`
+/// // ##### Schema
+/// // {...schema...}
+/// // ##### Example
 /// // {...json...}
 /// // ---
 /// // #### `Concept`
-/// // {...json...}
+/// // ...
 /// // ---
 /// ```
 #[proc_macro]
-pub fn examples_section(input: TokenStream) -> TokenStream {
+pub fn schema_and_examples_section(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as TypeList);
 
     let found_crate =
         crate_name("llm-toolkit").expect("llm-toolkit should be present in `Cargo.toml`");
-    let _crate_path = match found_crate {
-        FoundCrate::Itself => quote!(crate),
+    let crate_path = match found_crate {
+        FoundCrate::Itself => {
+            // Use absolute path to support examples/tests/bins of llm-toolkit itself
+            let ident = syn::Ident::new("llm_toolkit", proc_macro2::Span::call_site());
+            quote!(::#ident)
+        }
         FoundCrate::Name(name) => {
             let ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
             quote!(::#ident)
         }
     };
 
-    // Generate code for each type
-    let mut type_sections = Vec::new();
+    let header = input
+        .header
+        .as_ref()
+        .map_or_else(|| "### Types and Examples".to_string(), syn::LitStr::value);
+    let separator = input
+        .separator
+        .as_ref()
+        .map_or_else(|| "---".to_string(), syn::LitStr::value);
 
-    for ty in input.types.iter() {
-        // Extract the type name as a string
+    let type_sections = input.types.iter().map(|ty| {
         let type_name_str = quote!(#ty).to_string();
-
-        // Generate the section for this type
-        type_sections.push(quote! {
+        quote! {
             {
-                let type_name = #type_name_str;
-                let json_example = <#ty as Default>::default().to_prompt_with_mode("example_only");
-                format!("---\n#### `{}`\n{}", type_name, json_example)
+                let schema = <#ty as #crate_path::ToPrompt>::prompt_schema();
+                let example = #crate_path::ToPrompt::to_prompt_with_mode(
+                    &<#ty as Default>::default(),
+                    "example_only",
+                );
+                format!(
+                    "{}\n#### `{}`\n##### Schema\n{}\n##### Example\n{}",
+                    #separator, #type_name_str, schema, example
+                )
             }
-        });
-    }
+        }
+    });
 
-    // Build the complete examples string
     let expanded = quote! {
         {
             let mut sections = Vec::new();
-            sections.push("---".to_string());
-            sections.push("### Examples".to_string());
+            sections.push(#separator.to_string());
+            sections.push(#header.to_string());
             sections.push("".to_string());
-            sections.push("Here are examples of the data structures you should use.".to_string());
+            sections.push("Here are the data structures you should use, each with an example.".to_string());
             sections.push("".to_string());
 
             #(sections.push(#type_sections);)*
 
-            sections.push("---".to_string());
+            sections.push(#separator.to_string());
 
             sections.join("\n")
         }
@@ -3147,6 +4179,10 @@ fn parse_to_prompt_for_attribute(attrs: &[syn::Attribute]) -> (syn::Type, String
 /// - `prompt`: The prompt template (supports Jinja-style variables)
 /// - `extractor_tag`: The tag to use for extraction
 ///
+/// The enum must have at least one variant; an empty enum is a compile error.
+/// A single-variant enum is accepted, but its prompt gives the model no real
+/// choice, which the generated extractor's documentation points out.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -3199,6 +4235,16 @@ pub fn define_intent(_attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     };
 
+    // An empty enum has no value the extractor could ever return
+    if enum_data.variants.is_empty() {
+        return syn::Error::new(
+            input.ident.span(),
+            "`#[define_intent]` requires at least one variant; an empty enum has no intent to extract",
+        )
+        .to_compile_error()
+        .into();
+    }
+
     // Parse the #[intent(...)] attribute
     let mut prompt_template = None;
     let mut extractor_tag = None;
@@ -3376,6 +4422,20 @@ pub fn define_intent(_attr: TokenStream, item: TokenStream) -> TokenStream {
         proc_macro2::Span::call_site(),
     );
 
+    // A single-variant enum still gets a working extractor, but the prompt offers
+    // the model no real choice, so say so on the generated type
+    let extractor_doc = if enum_data.variants.len() == 1 {
+        let only_variant = &enum_data.variants[0].ident;
+        let note = format!(
+            " Extracts `{}` from LLM responses.\n\n Note: `{}` has a single variant (`{}`), so the generated prompt does not meaningfully constrain the model; extraction only checks that the response names `{}`.",
+            enum_name, enum_name, only_variant, only_variant
+        );
+        quote! { #[doc = #note] }
+    } else {
+        let summary = format!(" Extracts `{}` from LLM responses.", enum_name);
+        quote! { #[doc = #summary] }
+    };
+
     // Generate Expandable/Selectable implementations
     let expandable_impl =
         generate_expandable_selectable_impls(enum_name, &enum_data.variants, &crate_path);
@@ -3437,6 +4497,7 @@ pub fn define_intent(_attr: TokenStream, item: TokenStream) -> TokenStream {
         }
 
         // Generate the extractor struct
+        #extractor_doc
         pub struct #extractor_name;
 
         impl #extractor_name {
@@ -4350,9 +5411,12 @@ pub fn to_prompt_for_derive(input: TokenStream) -> TokenStream {
                 let unique_key = format!("self__{}", specific_mode);
 
                 // Replace {{ self:mode }} with {{ self__mode }} in template
-                let pattern = format!("{{{{ self:{} }}}}", specific_mode);
-                let replacement = format!("{{{{ {} }}}}", unique_key);
-                converted_template = converted_template.replace(&pattern, &replacement);
+                converted_template = replace_mode_placeholder(
+                    &converted_template,
+                    "self",
+                    specific_mode,
+                    &unique_key,
+                );
 
                 // Add to context with the specific mode
                 context_fields.push(quote! {
@@ -4408,19 +5472,46 @@ pub fn to_prompt_for_derive(input: TokenStream) -> TokenStream {
         }
     }
 
+    // Name the template after the impl so render errors point at the right derive
+    let template_name = format!(
+        "{} for {}",
+        struct_name,
+        quote!(#target_type).to_string().replace(' ', "")
+    );
+
     let expanded = quote! {
         impl #impl_generics #crate_path::prompt::ToPromptFor<#target_type> for #struct_name #ty_generics #where_clause
         where
             #target_type: serde::Serialize,
         {
             fn to_prompt_for_with_mode(&self, target: &#target_type, mode: &str) -> String {
+                // Template syntax errors are bugs and panic; render failures fall back
+                // to an error string as they always have
+                self.try_to_prompt_for_with_mode(target, mode)
+                    .unwrap_or_else(|e| {
+                        if e.source.kind() == #crate_path::minijinja::ErrorKind::SyntaxError {
+                            panic!("{}", e)
+                        }
+                        format!("Failed to render prompt: {}", e.source)
+                    })
+            }
+
+            fn try_to_prompt_for_with_mode(
+                &self,
+                target: &#target_type,
+                mode: &str,
+            ) -> Result<String, #crate_path::prompt::RenderError> {
+                let to_render_error = |source| #crate_path::prompt::RenderError {
+                    template: #template_name.to_string(),
+                    source,
+                };
+
                 // Create minijinja environment and add template
                 let mut env = #crate_path::minijinja::Environment::new();
-                env.add_template("prompt", #converted_template).unwrap_or_else(|e| {
-                    panic!("Failed to parse template: {}", e)
-                });
+                env.add_template(#template_name, #converted_template)
+                    .map_err(to_render_error)?;
 
-                let tmpl = env.get_template("prompt").unwrap();
+                let tmpl = env.get_template(#template_name).map_err(to_render_error)?;
 
                 // Build context
                 let mut context = std::collections::HashMap::new();
@@ -4437,9 +5528,7 @@ pub fn to_prompt_for_derive(input: TokenStream) -> TokenStream {
                 #(#context_fields)*
 
                 // Render template
-                tmpl.render(context).unwrap_or_else(|e| {
-                    format!("Failed to render prompt: {}", e)
-                })
+                tmpl.render(context).map_err(to_render_error)
             }
         }
     };
@@ -4462,6 +5551,11 @@ enum ExpertiseValue {
     Expr(syn::Expr),
 }
 
+/// Backend names accepted by `#[agent(backend = "...")]`.
+///
+/// `"custom"` must be paired with `default_inner = "path::To::Type"`.
+const AGENT_BACKENDS: &[&str] = &["claude", "gemini", "codex", "custom"];
+
 /// Attribute parameters for #[agent(...)]
 struct AgentAttrs {
     expertise: Option<ExpertiseValue>,
@@ -4469,6 +5563,7 @@ struct AgentAttrs {
     capabilities: Option<Vec<String>>,
     output: Option<syn::Type>,
     backend: Option<String>,
+    fallbacks: Vec<String>,
     model: Option<String>,
     inner: Option<String>,
     default_inner: Option<String>,
@@ -4477,6 +5572,16 @@ struct AgentAttrs {
     init: Option<String>,
     proxy_methods: Option<Vec<String>>,
     persona: Option<syn::Expr>,
+    validate: bool,
+    coerce: Option<CoerceHook>,
+}
+
+/// How `#[agent(coerce)]` adjusts parsed JSON before deserialization
+enum CoerceHook {
+    /// `#[agent(coerce)]`: built-in string-to-number/bool coercion
+    Builtin,
+    /// `#[agent(coerce = "path::to::fn")]`: a custom `fn(&mut serde_json::Value)`
+    Custom(syn::Path),
 }
 
 impl Parse for AgentAttrs {
@@ -4486,6 +5591,8 @@ impl Parse for AgentAttrs {
         let mut capabilities = None;
         let mut output = None;
         let mut backend = None;
+        let mut fallbacks = Vec::new();
+        let mut fallbacks_array = None;
         let mut model = None;
         let mut inner = None;
         let mut default_inner = None;
@@ -4494,6 +5601,9 @@ impl Parse for AgentAttrs {
         let mut init = None;
         let mut proxy_methods = None;
         let mut persona = None;
+        let mut validate = false;
+        let mut coerce = None;
+        let mut backend_lit = None;
 
         let pairs = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
 
@@ -4552,7 +5662,48 @@ impl Parse for AgentAttrs {
                         ..
                     }) = &nv.value
                     {
-                        backend = Some(lit_str.value());
+                        let value = lit_str.value();
+                        if !AGENT_BACKENDS.contains(&value.as_str()) {
+                            let valid = AGENT_BACKENDS
+                                .iter()
+                                .map(|b| format!("\"{}\"", b))
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            return Err(syn::Error::new_spanned(
+                                lit_str,
+                                format!(
+                                    "unknown agent backend \"{}\"; expected one of: {}",
+                                    value, valid
+                                ),
+                            ));
+                        }
+                        backend = Some(value);
+                        backend_lit = Some(lit_str.clone());
+                    }
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("fallbacks") => {
+                    if let syn::Expr::Array(array) = &nv.value {
+                        for elem in &array.elems {
+                            if let syn::Expr::Lit(syn::ExprLit {
+                                lit: syn::Lit::Str(lit_str),
+                                ..
+                            }) = elem
+                            {
+                                let value = lit_str.value();
+                                // Fallbacks are built-in backends constructed with `new()`
+                                if value == "custom" || !AGENT_BACKENDS.contains(&value.as_str()) {
+                                    return Err(syn::Error::new_spanned(
+                                        lit_str,
+                                        format!(
+                                            "unknown fallback backend \"{}\"; expected one of: \"claude\", \"gemini\", \"codex\"",
+                                            value
+                                        ),
+                                    ));
+                                }
+                                fallbacks.push(value);
+                            }
+                        }
+                        fallbacks_array = Some(array.clone());
                     }
                 }
                 Meta::NameValue(nv) if nv.path.is_ident("model") => {
@@ -4635,16 +5786,54 @@ impl Parse for AgentAttrs {
                         persona = Some(expr);
                     }
                 }
+                Meta::Path(path) if path.is_ident("validate") => {
+                    validate = true;
+                }
+                Meta::Path(path) if path.is_ident("coerce") => {
+                    coerce = Some(CoerceHook::Builtin);
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("coerce") => {
+                    if let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit_str),
+                        ..
+                    }) = &nv.value
+                    {
+                        // Parse the string as a function path (e.g., "my_coerce" or "coerce::lenient")
+                        let path: syn::Path = syn::parse_str(&lit_str.value())?;
+                        coerce = Some(CoerceHook::Custom(path));
+                    }
+                }
                 _ => {}
             }
         }
 
+        if let Some(lit_str) = &backend_lit
+            && backend.as_deref() == Some("custom")
+            && default_inner.is_none()
+        {
+            return Err(syn::Error::new_spanned(
+                lit_str,
+                "backend = \"custom\" requires default_inner = \"path::To::Type\" naming an Agent<Output = String> type",
+            ));
+        }
+
+        if let Some(array) = &fallbacks_array
+            && persona.is_some()
+            && !fallbacks.is_empty()
+        {
+            return Err(syn::Error::new_spanned(
+                array,
+                "fallbacks cannot be combined with persona",
+            ));
+        }
+
         Ok(AgentAttrs {
             expertise,
             description,
             capabilities,
             output,
             backend,
+            fallbacks,
             model,
             inner,
             default_inner,
@@ -4653,6 +5842,8 @@ impl Parse for AgentAttrs {
             init,
             proxy_methods,
             persona,
+            validate,
+            coerce,
         })
     }
 }
@@ -4671,6 +5862,7 @@ fn parse_agent_attrs(attrs: &[syn::Attribute]) -> syn::Result<AgentAttrs> {
         capabilities: None,
         output: None,
         backend: None,
+        fallbacks: Vec::new(),
         model: None,
         inner: None,
         default_inner: None,
@@ -4679,9 +5871,59 @@ fn parse_agent_attrs(attrs: &[syn::Attribute]) -> syn::Result<AgentAttrs> {
         init: None,
         proxy_methods: None,
         persona: None,
+        validate: false,
+        coerce: None,
     })
 }
 
+/// Generate the expression deserializing `json_str` into `Self::Output`.
+///
+/// With `#[agent(coerce)]`, the JSON is parsed into a `serde_json::Value` and
+/// passed through the coercion hook first. Either way the expression yields a
+/// `Result<Self::Output, serde_json::Error>`.
+fn generate_output_deserialize(
+    coerce: Option<&CoerceHook>,
+    output_type: &syn::Type,
+    crate_path: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let apply_hook = match coerce {
+        None => return quote! { serde_json::from_str::<Self::Output>(&json_str) },
+        Some(CoerceHook::Builtin) => quote! {
+            if let Some(schema) = <#output_type as #crate_path::prompt::ToPrompt>::prompt_schema_json() {
+                #crate_path::agent::coerce::coerce_to_schema(&mut value, &schema);
+            }
+        },
+        Some(CoerceHook::Custom(path)) => quote! {
+            #path(&mut value);
+        },
+    };
+
+    quote! {
+        serde_json::from_str::<serde_json::Value>(&json_str).and_then(|mut value| {
+            #apply_hook
+            serde_json::from_value::<Self::Output>(value)
+        })
+    }
+}
+
+/// Generate the `AnyAgent<String>` expressions for `#[agent(fallbacks = [...])]`
+fn generate_fallback_agents(
+    fallbacks: &[String],
+    crate_path: &proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+    fallbacks
+        .iter()
+        .map(|backend| {
+            let agent = match backend.as_str() {
+                "gemini" => quote! { #crate_path::agent::impls::GeminiAgent::new() },
+                "codex" => quote! { #crate_path::agent::impls::CodexAgent::new() },
+                _ => quote! { #crate_path::agent::impls::ClaudeCodeAgent::new() },
+            };
+            quote! { #crate_path::agent::AnyAgent::<String>::new(#agent) }
+        })
+        .collect()
+}
+
 /// Generate backend-specific convenience constructors
 fn generate_backend_constructors(
     struct_name: &syn::Ident,
@@ -4875,6 +6117,9 @@ pub fn derive_agent(input: TokenStream) -> TokenStream {
     let model = agent_attrs.model;
     let _profile = agent_attrs.profile; // Not used in simple derive macro
     let max_retries = agent_attrs.max_retries.unwrap_or(3); // Default: 3 retries
+    let validate = agent_attrs.validate;
+    let coerce = agent_attrs.coerce;
+    let fallbacks = agent_attrs.fallbacks;
 
     // Determine crate path
     let found_crate =
@@ -5056,35 +6301,51 @@ pub fn derive_agent(input: TokenStream) -> TokenStream {
     };
 
     // Generate agent initialization code based on backend
+    let default_inner_type = match agent_attrs
+        .default_inner
+        .as_deref()
+        .map(syn::parse_str::<syn::Type>)
+    {
+        Some(Ok(ty)) => Some(ty),
+        Some(Err(e)) => return e.to_compile_error().into(),
+        None => None,
+    };
     let agent_init = match backend.as_str() {
-        "gemini" => {
+        "custom" => {
+            let default_inner_type =
+                default_inner_type.expect("custom backend requires default_inner");
+            quote! {
+                let agent = <#default_inner_type as Default>::default();
+            }
+        }
+        "codex" => {
             if let Some(model_str) = model {
                 quote! {
-                    use #crate_path::agent::impls::GeminiAgent;
-                    let agent = GeminiAgent::new().with_model_str(#model_str);
+                    use #crate_path::agent::impls::CodexAgent;
+                    let agent = CodexAgent::new().with_model_str(#model_str);
                 }
             } else {
                 quote! {
-                    use #crate_path::agent::impls::GeminiAgent;
-                    let agent = GeminiAgent::new();
+                    use #crate_path::agent::impls::CodexAgent;
+                    let agent = CodexAgent::new();
                 }
             }
         }
-        "claude" => {
+        "gemini" => {
             if let Some(model_str) = model {
                 quote! {
-                    use #crate_path::agent::impls::ClaudeCodeAgent;
-                    let agent = ClaudeCodeAgent::new().with_model_str(#model_str);
+                    use #crate_path::agent::impls::GeminiAgent;
+                    let agent = GeminiAgent::new().with_model_str(#model_str);
                 }
             } else {
                 quote! {
-                    use #crate_path::agent::impls::ClaudeCodeAgent;
-                    let agent = ClaudeCodeAgent::new();
+                    use #crate_path::agent::impls::GeminiAgent;
+                    let agent = GeminiAgent::new();
                 }
             }
         }
         _ => {
-            // Default to Claude
+            // "claude" (unknown backends are rejected while parsing attributes)
             if let Some(model_str) = model {
                 quote! {
                     use #crate_path::agent::impls::ClaudeCodeAgent;
@@ -5099,6 +6360,29 @@ pub fn derive_agent(input: TokenStream) -> TokenStream {
         }
     };
 
+    // Run business-rule validation on the parsed output (opt-in via #[agent(validate)])
+    let validation = if validate && !is_string_output {
+        quote! {
+            .and_then(|output: Self::Output| {
+                #crate_path::agent::Validate::validate(&output)
+                    .map(|()| output)
+                    .map_err(|message| #crate_path::agent::AgentError::ValidationFailed { message })
+            })
+        }
+    } else {
+        quote! {}
+    };
+
+    // With validation, feed errors back to the model so it can fix the values
+    let retry_fn = if validate {
+        quote! { retry_execution_with_reprompt }
+    } else {
+        quote! { retry_execution }
+    };
+
+    let deserialize_output =
+        generate_output_deserialize(coerce.as_ref(), &output_type, &crate_path);
+
     let response_handling = if is_string_output {
         quote! {
             Ok(#crate_path::agent::normalize_string_output(&response))
@@ -5111,7 +6395,7 @@ pub fn derive_agent(input: TokenStream) -> TokenStream {
                     reason: #crate_path::agent::error::ParseErrorReason::MarkdownExtractionFailed,
                 })?;
 
-            serde_json::from_str::<Self::Output>(&json_str)
+            #deserialize_output
                 .map_err(|e| {
                     // Determine the parse error reason based on serde_json error type
                     let reason = if e.is_eof() {
@@ -5122,14 +6406,104 @@ pub fn derive_agent(input: TokenStream) -> TokenStream {
                         #crate_path::agent::error::ParseErrorReason::SchemaMismatch
                     };
 
+                    // Explain which fields deviate from the expected schema
+                    let mut message = format!("Failed to parse JSON: {}", e);
+                    if reason == #crate_path::agent::error::ParseErrorReason::SchemaMismatch {
+                        if let Some(explanation) = <#output_type as #crate_path::prompt::ToPrompt>::prompt_schema_json()
+                            .and_then(|schema| #crate_path::agent::error::explain_schema_mismatch(&json_str, &schema))
+                        {
+                            message = format!("{} ({})", message, explanation);
+                        }
+                    }
+
                     #crate_path::agent::AgentError::ParseError {
-                        message: format!("Failed to parse JSON: {}", e),
+                        message,
                         reason,
                     }
                 })
+                #validation
         }
     };
 
+    let (execute_body, is_available_body) = if fallbacks.is_empty() {
+        let execute_body = quote! {
+            // Create internal agent based on backend configuration
+            #agent_init
+
+            // Use the unified retry_execution function (DRY principle)
+            let agent_ref = &agent;
+            #crate_path::agent::retry::#retry_fn(
+                #max_retries,
+                &intent,
+                move |payload| {
+                    let payload = payload.clone();
+                    async move {
+                        // Execute and get response
+                        let response = agent_ref.execute(payload).await?;
+
+                        #response_handling
+                    }
+                }
+            ).await
+        };
+        let is_available_body = quote! {
+            // Create internal agent and check availability
+            #agent_init
+            agent.is_available().await
+        };
+        (execute_body, is_available_body)
+    } else {
+        let fallback_agents = generate_fallback_agents(&fallbacks, &crate_path);
+        let backends_init = quote! {
+            #agent_init
+            let backends: Vec<#crate_path::agent::AnyAgent<String>> = vec![
+                #crate_path::agent::AnyAgent::new(agent),
+                #(#fallback_agents),*
+            ];
+        };
+        let execute_body = quote! {
+            // Primary backend first, then each fallback on backend failure
+            #backends_init
+
+            let backends_ref = &backends;
+            let intent_ref = &intent;
+            #crate_path::agent::retry::fallback_execution(
+                backends.len(),
+                move |index| async move {
+                    let agent_ref = &backends_ref[index];
+                    #crate_path::agent::retry::#retry_fn(
+                        #max_retries,
+                        intent_ref,
+                        move |payload| {
+                            let payload = payload.clone();
+                            async move {
+                                let response = agent_ref.execute(payload).await?;
+
+                                #response_handling
+                            }
+                        }
+                    ).await
+                }
+            ).await
+        };
+        let is_available_body = quote! {
+            // Available as long as any backend is
+            #backends_init
+
+            let mut first_error = None;
+            for backend in &backends {
+                match backend.is_available().await {
+                    Ok(()) => return Ok(()),
+                    Err(e) => {
+                        first_error.get_or_insert(e);
+                    }
+                }
+            }
+            Err(first_error.expect("at least one backend"))
+        };
+        (execute_body, is_available_body)
+    };
+
     let expanded = quote! {
         #[async_trait::async_trait]
         impl #impl_generics #crate_path::agent::Agent for #struct_name #ty_generics #where_clause {
@@ -5149,30 +6523,11 @@ pub fn derive_agent(input: TokenStream) -> TokenStream {
             }
 
             async fn execute(&self, intent: #crate_path::agent::Payload) -> Result<Self::Output, #crate_path::agent::AgentError> {
-                // Create internal agent based on backend configuration
-                #agent_init
-
-                // Use the unified retry_execution function (DRY principle)
-                let agent_ref = &agent;
-                #crate_path::agent::retry::retry_execution(
-                    #max_retries,
-                    &intent,
-                    move |payload| {
-                        let payload = payload.clone();
-                        async move {
-                            // Execute and get response
-                            let response = agent_ref.execute(payload).await?;
-
-                            #response_handling
-                        }
-                    }
-                ).await
+                #execute_body
             }
 
             async fn is_available(&self) -> Result<(), #crate_path::agent::AgentError> {
-                // Create internal agent and check availability
-                #agent_init
-                agent.is_available().await
+                #is_available_body
             }
         }
     };
@@ -5220,6 +6575,9 @@ pub fn agent(attr: TokenStream, item: TokenStream) -> TokenStream {
     let model = agent_attrs.model;
     let profile = agent_attrs.profile;
     let persona = agent_attrs.persona;
+    let validate = agent_attrs.validate;
+    let coerce = agent_attrs.coerce;
+    let fallbacks = agent_attrs.fallbacks;
 
     // Check if output type is String (no JSON enforcement needed)
     let output_type_str = quote!(#output_type).to_string().replace(" ", "");
@@ -5278,6 +6636,17 @@ pub fn agent(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     });
 
+    // Fallback backends live next to the inner agent (persona + fallbacks is rejected while parsing)
+    let (fallbacks_field, fallbacks_init) = if fallbacks.is_empty() {
+        (quote! {}, quote! {})
+    } else {
+        let fallback_agents = generate_fallback_agents(&fallbacks, &crate_path);
+        (
+            quote! { fallbacks: Vec<#crate_path::agent::AnyAgent<String>>, },
+            quote! { fallbacks: vec![#(#fallback_agents),*], },
+        )
+    };
+
     // Generate struct definition - wrap with PersonaAgent if persona is specified
     let (struct_def, _actual_inner_type, uses_persona) = if let Some(ref _persona_path) = persona {
         // When persona is specified, the inner type is PersonaAgent<ActualInner>
@@ -5297,6 +6666,7 @@ pub fn agent(attr: TokenStream, item: TokenStream) -> TokenStream {
         let struct_def = quote! {
             #vis struct #struct_name<#inner_generic_ident = #default_agent_type> {
                 inner: #inner_generic_ident,
+                #fallbacks_field
                 description: String,
                 capabilities: Option<Vec<#crate_path::agent::Capability>>,
             }
@@ -5362,6 +6732,19 @@ pub fn agent(attr: TokenStream, item: TokenStream) -> TokenStream {
                 }
             }
         }
+    } else if fallbacks.is_empty() {
+        quote! {
+            impl<#inner_generic_ident> #struct_name<#inner_generic_ident> {
+                /// Create a new agent with a custom inner agent implementation
+                pub fn new(inner: #inner_generic_ident) -> Self {
+                    Self {
+                        inner,
+                        description: #description_value,
+                        capabilities: #capabilities_value
+                    }
+                }
+            }
+        }
     } else {
         quote! {
             impl<#inner_generic_ident> #struct_name<#inner_generic_ident> {
@@ -5369,10 +6752,17 @@ pub fn agent(attr: TokenStream, item: TokenStream) -> TokenStream {
                 pub fn new(inner: #inner_generic_ident) -> Self {
                     Self {
                         inner,
+                        #fallbacks_init
                         description: #description_value,
                         capabilities: #capabilities_value
                     }
                 }
+
+                /// Replace the fallback backends tried when the inner agent's backend fails
+                pub fn with_fallbacks(mut self, fallbacks: Vec<#crate_path::agent::AnyAgent<String>>) -> Self {
+                    self.fallbacks = fallbacks;
+                    self
+                }
             }
         }
     };
@@ -5527,6 +6917,7 @@ pub fn agent(attr: TokenStream, item: TokenStream) -> TokenStream {
                 fn default() -> Self {
                     Self {
                         inner: #default_inner_expr,
+                        #fallbacks_init
                         description: #description_value,
                         capabilities: #capabilities_value
                     }
@@ -5647,6 +7038,135 @@ pub fn agent(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     };
 
+    // Run business-rule validation on the parsed output (opt-in via #[agent(validate)])
+    let validation = if validate {
+        quote! {
+            .and_then(|output: Self::Output| {
+                #crate_path::agent::Validate::validate(&output)
+                    .map(|()| output)
+                    .map_err(|message| #crate_path::agent::AgentError::ValidationFailed { message })
+            })
+        }
+    } else {
+        quote! {}
+    };
+
+    let deserialize_output =
+        generate_output_deserialize(coerce.as_ref(), &output_type, &crate_path);
+
+    // Turns the inner agent's raw response into Self::Output
+    let response_handling = if is_string_output {
+        quote! {
+            Ok::<Self::Output, #crate_path::agent::AgentError>(
+                #crate_path::agent::normalize_string_output(&response)
+            )
+        }
+    } else {
+        quote! {
+            // Extract JSON from the response
+            let json_str = #crate_path::extract_json(&response)
+                .map_err(|e| #crate_path::agent::AgentError::ParseError {
+                    message: e.to_string(),
+                    reason: #crate_path::agent::error::ParseErrorReason::MarkdownExtractionFailed,
+                })?;
+
+            // Deserialize into output type
+            #deserialize_output.map_err(|e| {
+                let reason = if e.is_eof() {
+                    #crate_path::agent::error::ParseErrorReason::UnexpectedEof
+                } else if e.is_syntax() {
+                    #crate_path::agent::error::ParseErrorReason::InvalidJson
+                } else {
+                    #crate_path::agent::error::ParseErrorReason::SchemaMismatch
+                };
+
+                // Explain which fields deviate from the expected schema
+                let mut message = e.to_string();
+                if reason == #crate_path::agent::error::ParseErrorReason::SchemaMismatch {
+                    if let Some(explanation) = <#output_type as #crate_path::prompt::ToPrompt>::prompt_schema_json()
+                        .and_then(|schema| #crate_path::agent::error::explain_schema_mismatch(&json_str, &schema))
+                    {
+                        message = format!("{} ({})", message, explanation);
+                    }
+                }
+
+                #crate_path::agent::AgentError::ParseError {
+                    message,
+                    reason,
+                }
+            })
+            #validation
+        }
+    };
+
+    // Execute against the inner agent, falling back to other backends if it is unavailable
+    let (execute_body, is_available_body) = if fallbacks.is_empty() {
+        let execute_body = quote! {
+            // Use the inner agent with the enhanced payload
+            let response = self.inner.execute(enhanced_payload).await?;
+
+            #response_handling
+        };
+        let is_available_body = quote! {
+            self.inner.is_available().await
+        };
+        (execute_body, is_available_body)
+    } else {
+        let execute_body = quote! {
+            // Each backend attempt parses its own response
+            let payload_ref = &enhanced_payload;
+            #crate_path::agent::retry::fallback_execution(
+                1 + self.fallbacks.len(),
+                move |index| async move {
+                    let response = if index == 0 {
+                        self.inner.execute(payload_ref.clone()).await?
+                    } else {
+                        self.fallbacks[index - 1].execute(payload_ref.clone()).await?
+                    };
+
+                    #response_handling
+                }
+            ).await
+        };
+        let is_available_body = quote! {
+            // Available as long as any backend is
+            let primary_error = match self.inner.is_available().await {
+                Ok(()) => return Ok(()),
+                Err(e) => e,
+            };
+            for fallback in &self.fallbacks {
+                if fallback.is_available().await.is_ok() {
+                    return Ok(());
+                }
+            }
+            Err(primary_error)
+        };
+        (execute_body, is_available_body)
+    };
+
+    // Forward step-level execution profiles to the inner agent (and every fallback)
+    let fallbacks_profiled = if fallbacks.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fallbacks: self
+                .fallbacks
+                .iter()
+                .map(|fallback| #crate_path::agent::Agent::clone_with_execution_profile(fallback, profile))
+                .collect::<Option<Vec<_>>>()?,
+        }
+    };
+    let clone_with_execution_profile = quote! {
+        fn clone_with_execution_profile(&self, profile: #crate_path::agent::ExecutionProfile) -> Option<Self> {
+            Some(Self {
+                inner: #crate_path::agent::Agent::clone_with_execution_profile(&self.inner, profile)?,
+                #fallbacks_profiled
+                description: self.description.clone(),
+                capabilities: self.capabilities.clone(),
+            })
+        }
+    };
+
     // Generate Agent trait implementation
     let agent_impl = if uses_persona {
         // When using persona, simply delegate to PersonaAgent (which already implements Agent)
@@ -5679,6 +7199,8 @@ pub fn agent(attr: TokenStream, item: TokenStream) -> TokenStream {
                 async fn is_available(&self) -> Result<(), #crate_path::agent::AgentError> {
                     self.inner.is_available().await
                 }
+
+                #clone_with_execution_profile
             }
         }
     } else if is_string_output {
@@ -5706,13 +7228,14 @@ pub fn agent(attr: TokenStream, item: TokenStream) -> TokenStream {
                 #[#crate_path::tracing::instrument(name = "agent.execute", skip_all, fields(agent.name = #struct_name_str, agent.description = self.description()))]
                 async fn execute(&self, intent: #crate_path::agent::Payload) -> Result<Self::Output, #crate_path::agent::AgentError> {
                     let enhanced_payload = intent.prepend_text(self.expertise());
-                    let response = self.inner.execute(enhanced_payload).await?;
-                    Ok(#crate_path::agent::normalize_string_output(&response))
+                    #execute_body
                 }
 
                 async fn is_available(&self) -> Result<(), #crate_path::agent::AgentError> {
-                    self.inner.is_available().await
+                    #is_available_body
                 }
+
+                #clone_with_execution_profile
             }
         }
     } else {
@@ -5743,35 +7266,14 @@ pub fn agent(attr: TokenStream, item: TokenStream) -> TokenStream {
                     // Prepend expertise to the payload
                     let enhanced_payload = intent.prepend_text(self.expertise());
 
-                    // Use the inner agent with the enhanced payload
-                    let response = self.inner.execute(enhanced_payload).await?;
-
-                    // Extract JSON from the response
-                    let json_str = #crate_path::extract_json(&response)
-                        .map_err(|e| #crate_path::agent::AgentError::ParseError {
-                            message: e.to_string(),
-                            reason: #crate_path::agent::error::ParseErrorReason::MarkdownExtractionFailed,
-                        })?;
-
-                    // Deserialize into output type
-                    serde_json::from_str(&json_str).map_err(|e| {
-                        let reason = if e.is_eof() {
-                            #crate_path::agent::error::ParseErrorReason::UnexpectedEof
-                        } else if e.is_syntax() {
-                            #crate_path::agent::error::ParseErrorReason::InvalidJson
-                        } else {
-                            #crate_path::agent::error::ParseErrorReason::SchemaMismatch
-                        };
-                        #crate_path::agent::AgentError::ParseError {
-                            message: e.to_string(),
-                            reason,
-                        }
-                    })
+                    #execute_body
                 }
 
                 async fn is_available(&self) -> Result<(), #crate_path::agent::AgentError> {
-                    self.inner.is_available().await
+                    #is_available_body
                 }
+
+                #clone_with_execution_profile
             }
         }
     };
@@ -43,3 +43,15 @@ fn agent_attribute_persona_custom_inner_compile_pass() {
     let t = trybuild::TestCases::new();
     t.pass("tests/ui/agent_attribute_persona_custom_inner.rs");
 }
+
+#[test]
+fn agent_custom_backend_compile_pass() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/agent_custom_backend.rs");
+}
+
+#[test]
+fn agent_invalid_backend_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/agent_invalid_backend.rs");
+}
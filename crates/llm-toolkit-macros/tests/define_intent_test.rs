@@ -0,0 +1,13 @@
+// Compile-time checks for the define_intent attribute macro.
+
+#[test]
+fn define_intent_empty_enum_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/define_intent_empty_enum.rs");
+}
+
+#[test]
+fn define_intent_single_variant_compile_pass() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/define_intent_single_variant.rs");
+}
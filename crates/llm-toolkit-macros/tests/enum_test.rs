@@ -27,3 +27,9 @@ fn test_enum_derive() {
     // - crates/llm-toolkit/tests/derive_enum_integration.rs
     // - crates/llm-toolkit/examples/derive_prompt_enum.rs
 }
+
+#[test]
+fn to_prompt_derive_display_compile_pass() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/to_prompt_derive_display.rs");
+}
@@ -0,0 +1,82 @@
+use llm_toolkit::{ToPrompt, examples_section, schema_and_examples_section};
+use serde::Serialize;
+
+#[derive(ToPrompt, Default, Serialize)]
+#[prompt(mode = "full")]
+/// Represents a user of the system.
+struct User {
+    /// A unique identifier for the user.
+    #[prompt(example = "user-12345")]
+    id: String,
+}
+
+#[derive(ToPrompt, Default, Serialize)]
+#[prompt(mode = "full")]
+/// Defines a concept for image generation.
+struct Concept {
+    /// The main idea for the art to be generated.
+    #[prompt(example = "a futuristic city at night")]
+    prompt: String,
+}
+
+#[test]
+fn test_examples_section_default_format_is_unchanged() {
+    let section = examples_section!(User, Concept);
+
+    let expected = [
+        "---".to_string(),
+        "### Examples".to_string(),
+        String::new(),
+        "Here are examples of the data structures you should use.".to_string(),
+        String::new(),
+        format!(
+            "---\n#### `User`\n{}",
+            User::default().to_prompt_with_mode("example_only")
+        ),
+        format!(
+            "---\n#### `Concept`\n{}",
+            Concept::default().to_prompt_with_mode("example_only")
+        ),
+        "---".to_string(),
+    ]
+    .join("\n");
+
+    assert_eq!(section, expected);
+}
+
+#[test]
+fn test_examples_section_custom_header_and_separator() {
+    let section = examples_section!(
+        header = "## Reference Types",
+        separator = "===",
+        User,
+        Concept
+    );
+
+    println!("Generated section:\n{}", section);
+
+    assert!(section.starts_with("===\n## Reference Types\n"));
+    assert!(section.contains("===\n#### `User`\n"));
+    assert!(section.contains("===\n#### `Concept`\n"));
+    assert!(section.ends_with("\n==="));
+    assert!(!section.contains("### Examples"));
+    assert!(!section.contains("---"));
+}
+
+#[test]
+fn test_examples_section_header_only() {
+    let section = examples_section!(header = "## Reference Types", User);
+
+    assert!(section.starts_with("---\n## Reference Types\n"));
+    assert!(section.contains("---\n#### `User`\n"));
+}
+
+#[test]
+fn test_schema_and_examples_section_custom_header_and_separator() {
+    let section = schema_and_examples_section!(header = "## Types", separator = "***", User);
+
+    assert!(section.starts_with("***\n## Types\n"));
+    assert!(section.contains("***\n#### `User`\n##### Schema\n"));
+    assert!(section.ends_with("\n***"));
+    assert!(!section.contains("### Types and Examples"));
+}
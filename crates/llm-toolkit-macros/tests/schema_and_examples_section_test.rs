@@ -0,0 +1,56 @@
+use llm_toolkit::{ToPrompt, schema_and_examples_section};
+use serde::Serialize;
+
+#[derive(ToPrompt, Default, Serialize)]
+#[prompt(mode = "full")]
+/// Represents a user of the system.
+struct User {
+    /// A unique identifier for the user.
+    #[prompt(example = "user-12345")]
+    id: String,
+
+    /// The user's full name.
+    #[prompt(example = "Taro Yamada")]
+    name: String,
+}
+
+#[derive(ToPrompt, Default, Serialize)]
+#[prompt(mode = "full")]
+/// Defines a concept for image generation.
+struct Concept {
+    /// The main idea for the art to be generated.
+    #[prompt(example = "a futuristic city at night")]
+    prompt: String,
+
+    /// The style of the generation.
+    #[prompt(example = "anime")]
+    style: String,
+}
+
+#[test]
+fn test_schema_and_examples_section_includes_schema_and_example_per_type() {
+    let section = schema_and_examples_section!(User, Concept);
+
+    println!("Generated section:\n{}", section);
+
+    assert!(section.contains("### Types and Examples"));
+
+    let user_start = section.find("#### `User`").expect("User header");
+    let concept_start = section.find("#### `Concept`").expect("Concept header");
+    assert!(user_start < concept_start, "types keep the listed order");
+
+    let user_block = &section[user_start..concept_start];
+    assert!(user_block.contains(&User::prompt_schema()));
+    assert!(user_block.contains("type User = {"));
+    assert!(user_block.contains("##### Example"));
+    assert!(user_block.contains("user-12345"));
+
+    let concept_block = &section[concept_start..];
+    assert!(concept_block.contains(&Concept::prompt_schema()));
+    assert!(concept_block.contains("type Concept = {"));
+    assert!(concept_block.contains("##### Example"));
+    assert!(concept_block.contains("anime"));
+
+    // Schema comes before the example within each block
+    assert!(user_block.find("##### Schema").unwrap() < user_block.find("##### Example").unwrap());
+}
@@ -0,0 +1,16 @@
+// Kept in its own test binary because it mutates the process environment.
+
+#[test]
+fn template_file_resolves_from_template_dir_env() {
+    let template_dir = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/shared_templates"
+    );
+    // SAFETY: this is the only test in this binary, so nothing reads the environment concurrently.
+    unsafe {
+        std::env::set_var("LLM_TOOLKIT_TEMPLATE_DIR", template_dir);
+    }
+
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/template_dir_env.rs");
+}
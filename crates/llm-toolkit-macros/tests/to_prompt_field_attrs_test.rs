@@ -0,0 +1,7 @@
+// Compile-time checks for #[prompt(...)] field attributes of #[derive(ToPrompt)]
+
+#[test]
+fn to_prompt_order_not_integer_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/to_prompt_order_not_integer.rs");
+}
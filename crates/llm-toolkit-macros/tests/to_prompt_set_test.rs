@@ -0,0 +1,13 @@
+// Compile-time checks for #[derive(ToPromptSet)]
+
+#[test]
+fn to_prompt_set_rename_collision_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/to_prompt_set_rename_collision.rs");
+}
+
+#[test]
+fn to_prompt_set_rename_distinct_compile_pass() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/to_prompt_set_rename_distinct.rs");
+}
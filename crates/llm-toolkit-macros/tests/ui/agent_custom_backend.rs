@@ -0,0 +1,55 @@
+// Test backend = "custom" with default_inner wiring an arbitrary Agent<Output = String>
+extern crate log;
+extern crate tracing;
+
+use llm_toolkit::agent::{Agent, AgentError, Payload};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone)]
+struct LocalModelAgent;
+
+#[async_trait::async_trait]
+impl Agent for LocalModelAgent {
+    type Output = String;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "Local model agent";
+        &EXPERTISE
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<String, AgentError> {
+        Ok("{\"result\": \"local\"}".to_string())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, llm_toolkit::ToPrompt)]
+#[prompt(mode = "full")]
+pub struct TestOutput {
+    pub result: String,
+}
+
+// Attribute macro with a custom backend
+#[llm_toolkit_macros::agent(
+    expertise = "Test agent with custom backend",
+    output = "TestOutput",
+    backend = "custom",
+    default_inner = "LocalModelAgent"
+)]
+struct CustomBackendAgent;
+
+// Derive macro with a custom backend
+#[derive(llm_toolkit_macros::Agent)]
+#[agent(
+    expertise = "Derived agent with custom backend",
+    output = "TestOutput",
+    backend = "custom",
+    default_inner = "LocalModelAgent"
+)]
+pub struct DerivedCustomBackendAgent;
+
+fn main() {
+    let _agent = CustomBackendAgent::default();
+    let _agent = CustomBackendAgent::new(LocalModelAgent);
+    let _derived = DerivedCustomBackendAgent;
+}
@@ -0,0 +1,8 @@
+// Unknown backend names must be rejected instead of silently falling back to Claude
+#[llm_toolkit_macros::agent(
+    expertise = "Agent with a misspelled backend",
+    backend = "claud"
+)]
+struct TypoBackendAgent;
+
+fn main() {}
@@ -0,0 +1,8 @@
+// An empty enum has no intent to extract and must be rejected at compile time
+use llm_toolkit::define_intent;
+
+#[define_intent]
+#[intent(prompt = "Classify: {{ intents_doc }}", extractor_tag = "intent")]
+enum EmptyIntent {}
+
+fn main() {}
@@ -0,0 +1,35 @@
+// A single-variant enum without doc comments still gets a prompt builder and a working extractor
+use llm_toolkit::{IntentExtractor, define_intent};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+#[define_intent]
+#[intent(
+    prompt = "Classify the request: {{ request }}\n\n{{ intents_doc }}",
+    extractor_tag = "intent"
+)]
+enum OnlyIntent {
+    Acknowledge,
+}
+
+impl FromStr for OnlyIntent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Acknowledge" => Ok(OnlyIntent::Acknowledge),
+            _ => Err(format!("Unknown OnlyIntent variant: {}", s)),
+        }
+    }
+}
+
+fn main() {
+    let prompt = build_only_intent_prompt("hello");
+    assert!(prompt.contains("Classify the request: hello"));
+    assert!(prompt.contains("OnlyIntent:\n\nPossible values:\n- Acknowledge"));
+
+    let intent = OnlyIntentExtractor
+        .extract_intent("<intent>Acknowledge</intent>")
+        .unwrap();
+    assert_eq!(intent, OnlyIntent::Acknowledge);
+}
@@ -0,0 +1,22 @@
+// "shared_greeting.jinja" only exists under LLM_TOOLKIT_TEMPLATE_DIR, which the test harness sets.
+
+use llm_toolkit::ToPrompt;
+use serde::Serialize;
+
+#[derive(Serialize, ToPrompt)]
+#[prompt(template_file = "shared_greeting.jinja")]
+struct SharedGreeting {
+    name: String,
+    unread: u32,
+}
+
+fn main() {
+    let greeting = SharedGreeting {
+        name: "Yui".to_string(),
+        unread: 3,
+    };
+    assert_eq!(
+        greeting.to_prompt(),
+        "Hello, Yui! You have 3 unread messages."
+    );
+}
@@ -0,0 +1,33 @@
+use llm_toolkit::ToPrompt;
+use std::fmt;
+
+#[derive(ToPrompt)]
+#[prompt(derive_display)]
+pub enum Status {
+    /// Work is in progress
+    Active,
+    /// Work is finished
+    Done,
+}
+
+// Without the attribute, a hand-written Display impl must not conflict.
+#[derive(ToPrompt)]
+pub enum Priority {
+    /// Needs attention now
+    High,
+    Low,
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Priority::High => f.write_str("high"),
+            Priority::Low => f.write_str("low"),
+        }
+    }
+}
+
+fn main() {
+    let _ = Status::Active.to_string();
+    let _ = Priority::High.to_string();
+}
@@ -0,0 +1,14 @@
+// A non-integer order would otherwise be ignored and the field left unordered
+use llm_toolkit::ToPrompt;
+use serde::Serialize;
+
+#[derive(ToPrompt, Serialize)]
+struct Task {
+    #[prompt(order = "1")]
+    title: String,
+
+    #[prompt(order = -2)]
+    owner: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+// Two fields renamed to the same key for one target would emit duplicate keys
+use llm_toolkit::ToPromptSet;
+use serde::Serialize;
+
+#[derive(ToPromptSet, Serialize)]
+struct Task {
+    #[prompt_for(name = "Agent", rename = "id")]
+    task_id: u64,
+
+    #[prompt_for(name = "Agent", rename = "id")]
+    owner_id: u64,
+}
+
+fn main() {}
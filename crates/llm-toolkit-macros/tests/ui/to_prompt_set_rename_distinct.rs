@@ -0,0 +1,23 @@
+// The same key in different targets, or a rename that frees up a field's own name, is fine
+use llm_toolkit::{ToPrompt, ToPromptSet};
+use serde::Serialize;
+
+#[derive(ToPromptSet, Serialize)]
+struct Task {
+    #[prompt_for(name = "Agent", rename = "id")]
+    #[prompt_for(name = "Audit", rename = "task")]
+    task_id: u64,
+
+    #[prompt_for(name = "Audit", rename = "id")]
+    owner_id: u64,
+}
+
+fn main() {
+    let task = Task {
+        task_id: 1,
+        owner_id: 2,
+    };
+
+    assert_eq!(task.to_prompt_for("Agent").unwrap(), "id: 1");
+    assert_eq!(task.to_prompt_for("Audit").unwrap(), "task: 1\nid: 2");
+}
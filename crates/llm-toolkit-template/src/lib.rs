@@ -0,0 +1,93 @@
+//! Prompt template syntax shared by `llm-toolkit` and `llm-toolkit-macros`.
+//!
+//! `#[derive(ToPrompt)]` parses templates at compile time and
+//! `llm_toolkit::prompt::lint_template` at runtime; both use this crate so
+//! they always agree on what a placeholder is.
+
+use regex::Regex;
+use std::collections::HashSet;
+
+/// Matches `{{ field:mode }}`, allowing any whitespace inside the braces and around the colon
+/// (`{{field:mode}}`, `{{ field : mode }}`, `{{ field:mode}}`, ...).
+pub const MODE_PLACEHOLDER_PATTERN: &str = r"\{\{\s*(\w+)\s*:\s*(\w+)\s*\}\}";
+
+/// Parses `{{ field }}` and `{{ field:mode }}` placeholders from a template.
+///
+/// Returns `(field_name, optional_mode)` pairs, mode placeholders first. A field
+/// used with several modes is listed once per mode; a plain `{{ field }}` is
+/// only listed when the field has no mode placeholder.
+///
+/// # Example
+///
+/// ```
+/// use llm_toolkit_template::parse_template_placeholders_with_mode;
+///
+/// assert_eq!(
+///     parse_template_placeholders_with_mode("{{ name }} {{ profile:schema_only }}"),
+///     vec![
+///         ("profile".to_string(), Some("schema_only".to_string())),
+///         ("name".to_string(), None),
+///     ]
+/// );
+/// ```
+pub fn parse_template_placeholders_with_mode(template: &str) -> Vec<(String, Option<String>)> {
+    let mut placeholders = Vec::new();
+    let mut seen_fields = HashSet::new();
+
+    // First, find all {{ field:mode }} patterns
+    let mode_pattern = Regex::new(MODE_PLACEHOLDER_PATTERN).unwrap();
+    for cap in mode_pattern.captures_iter(template) {
+        let field_name = cap[1].to_string();
+        let mode = cap[2].to_string();
+        placeholders.push((field_name.clone(), Some(mode)));
+        seen_fields.insert(field_name);
+    }
+
+    // Then, find all standard {{ field }} patterns (without mode)
+    let standard_pattern = Regex::new(r"\{\{\s*(\w+)\s*\}\}").unwrap();
+    for cap in standard_pattern.captures_iter(template) {
+        let field_name = cap[1].to_string();
+        // Check if this field was already captured with a mode
+        if seen_fields.insert(field_name.clone()) {
+            placeholders.push((field_name, None));
+        }
+    }
+
+    placeholders
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_placeholders_tolerate_whitespace() {
+        for template in [
+            "{{ inner:schema_only }}",
+            "{{inner:schema_only}}",
+            "{{ inner : schema_only }}",
+            "{{ inner:schema_only}}",
+            "{{\tinner :schema_only  }}",
+        ] {
+            assert_eq!(
+                parse_template_placeholders_with_mode(template),
+                vec![("inner".to_string(), Some("schema_only".to_string()))],
+                "template: {:?}",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn test_field_with_several_modes_is_listed_per_mode() {
+        assert_eq!(
+            parse_template_placeholders_with_mode(
+                "{{ item:schema_only }} {{ item:example_only }} {{ item }}"
+            ),
+            vec![
+                ("item".to_string(), Some("schema_only".to_string())),
+                ("item".to_string(), Some("example_only".to_string())),
+            ]
+        );
+    }
+}
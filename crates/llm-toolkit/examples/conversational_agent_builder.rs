@@ -31,6 +31,7 @@ fn yui_persona() -> Persona {
         communication_style: "Professional, precise, and detail-oriented.".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     }
 }
 
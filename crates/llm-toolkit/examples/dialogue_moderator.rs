@@ -146,6 +146,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "Analytical and data-driven".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let engineer = Persona {
@@ -155,6 +156,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "Technical and pragmatic".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let designer = Persona {
@@ -164,6 +166,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "User-centric and visual".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     // Create mock agents for each persona
@@ -43,6 +43,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         },
         Persona {
             name: "Marcus Lee".to_string(),
@@ -55,6 +56,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         },
         Persona {
             name: "Priya Patel".to_string(),
@@ -67,6 +69,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         },
         Persona {
             name: "Kenji Tanaka".to_string(),
@@ -79,6 +82,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         },
         Persona {
             name: "Elena Rodriguez".to_string(),
@@ -91,6 +95,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         },
     ];
 
@@ -218,6 +223,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     println!("💡 Adding a guest participant:");
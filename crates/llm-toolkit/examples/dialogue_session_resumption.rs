@@ -93,6 +93,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "Strategic and user-focused".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let persona2 = Persona {
@@ -102,6 +103,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "Technical and pragmatic".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let mut session1 = Dialogue::broadcast();
@@ -89,6 +89,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "Strategic and user-focused".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let persona2 = Persona {
@@ -98,6 +99,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "Technical and pragmatic".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let agent1 = MockConversationAgent::new("Alice");
@@ -42,6 +42,7 @@ async fn main() {
         communication_style: "Concise and to the point.".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let translator_persona = Persona {
@@ -51,6 +52,7 @@ async fn main() {
         communication_style: "Formal and precise.".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let mut sequential_dialogue = Dialogue::sequential();
@@ -93,6 +95,7 @@ async fn main() {
         communication_style: "Formal and precise.".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let critic_persona = Persona {
@@ -102,6 +105,7 @@ async fn main() {
         communication_style: "Direct and questioning.".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let mut broadcast_dialogue = Dialogue::broadcast();
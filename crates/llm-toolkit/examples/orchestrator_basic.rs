@@ -82,6 +82,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("📄 Final Output:\n{}\n", output_str);
             }
         }
+        llm_toolkit::orchestrator::OrchestrationStatus::Paused => {
+            println!("⏸️  Workflow paused for approval: {:?}", result.paused);
+        }
         llm_toolkit::orchestrator::OrchestrationStatus::Failure => {
             let error_msg = result
                 .error_message
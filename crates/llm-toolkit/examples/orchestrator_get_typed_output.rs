@@ -249,6 +249,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             println!("\n🎉 All tests passed!");
         }
+        llm_toolkit::orchestrator::OrchestrationStatus::Paused => {
+            println!("⏸️  Workflow paused for approval: {:?}", result.paused);
+        }
         llm_toolkit::orchestrator::OrchestrationStatus::Failure => {
             if let Some(error) = result.error_message {
                 eprintln!("❌ Workflow failed: {}", error);
@@ -223,6 +223,9 @@ Please provide:
                 }
             }
         }
+        llm_toolkit::orchestrator::OrchestrationStatus::Paused => {
+            println!("⏸️  Workflow paused for approval: {:?}", result.paused);
+        }
         llm_toolkit::orchestrator::OrchestrationStatus::Failure => {
             if let Some(error) = result.error_message {
                 eprintln!("❌ Workflow failed: {}", error);
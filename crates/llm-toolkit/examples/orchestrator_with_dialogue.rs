@@ -77,6 +77,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "User-focused and empathetic".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let researcher_persona = Persona {
@@ -86,6 +87,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "Data-driven and methodical".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let mut design_team = Dialogue::sequential_with_order(SequentialOrder::Explicit(vec![
@@ -122,6 +124,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "Technical and systematic".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let frontend_persona = Persona {
@@ -131,6 +134,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "Pragmatic and detail-oriented".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let mut engineering_team = Dialogue::broadcast();
@@ -110,6 +110,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("\n📄 Final Output:\n{}\n", output_str);
             }
         }
+        llm_toolkit::orchestrator::OrchestrationStatus::Paused => {
+            println!("⏸️  Workflow paused for approval: {:?}", result.paused);
+        }
         llm_toolkit::orchestrator::OrchestrationStatus::Failure => {
             let error_msg = result
                 .error_message
@@ -14,6 +14,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                               responds with rhetorical questions to stimulate deeper thought. Avoids slang and contractions.".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     // 2. Create a base agent that will handle the actual LLM calls.
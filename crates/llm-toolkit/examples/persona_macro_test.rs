@@ -14,6 +14,7 @@ fn mai_persona() -> &'static Persona {
         communication_style: "Warm, encouraging, and uses emojis. Focuses on clarifying user intent.".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     })
 }
 
@@ -27,6 +28,7 @@ fn yui_persona() -> Persona {
         communication_style: "Clear, concise, and detail-oriented. Provides technical trade-offs.".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     }
 }
 
@@ -66,6 +68,7 @@ impl<A: Agent + Send + Sync> ReiAgent<A> {
                     .to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         }
     }
 }
@@ -44,6 +44,7 @@ async fn main() {
         communication_style: "Direct".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let persona_b = Persona {
@@ -53,6 +54,7 @@ async fn main() {
         communication_style: "Direct".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     dialogue
@@ -213,6 +213,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 std::process::exit(1);
             }
         }
+        llm_toolkit::orchestrator::OrchestrationStatus::Paused => {
+            println!("⏸️  Workflow paused for approval: {:?}", result.paused);
+        }
         llm_toolkit::orchestrator::OrchestrationStatus::Failure => {
             eprintln!("❌ Orchestrator failed: {:?}", result.error_message);
             eprintln!("\n💔 TEST FAILED: Orchestrator execution failed");
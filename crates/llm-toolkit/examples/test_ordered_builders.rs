@@ -71,6 +71,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "User-centered and empathetic".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let alex = Persona {
@@ -80,6 +81,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "Technical and systematic".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let sam = Persona {
@@ -89,6 +91,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         communication_style: "Strategic and metrics-driven".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     // Create mock agents
@@ -1,6 +1,6 @@
 use super::Agent;
 use crate::agent::dialogue::joining_strategy::JoiningStrategy;
-use crate::agent::history::HistoryAwareAgent;
+use crate::agent::history::{HistoryAwareAgent, HistoryLimit};
 use crate::agent::persona::{Persona, PersonaAgent};
 
 /// A builder for creating conversational agents with optional persona and history management.
@@ -48,6 +48,7 @@ pub struct Chat<A: Agent> {
     /// Identity information for history attribution (if persona is set)
     identity: Option<(String, String)>, // (name, role)
     joining_strategy: Option<JoiningStrategy>,
+    history_limit: Option<HistoryLimit>,
 }
 
 impl<A: Agent> Chat<A> {
@@ -70,6 +71,7 @@ impl<A: Agent> Chat<A> {
             with_history: true,
             identity: None,
             joining_strategy: None,
+            history_limit: None,
         }
     }
 
@@ -105,6 +107,7 @@ impl<A: Agent> Chat<A> {
             with_history: self.with_history,
             identity,
             joining_strategy: self.joining_strategy,
+            history_limit: self.history_limit,
         }
     }
 
@@ -175,6 +178,31 @@ impl<A: Agent> Chat<A> {
         self
     }
 
+    /// Caps the conversation history kept by the agent.
+    ///
+    /// With [`HistoryLimit::LastN`] older entries are dropped; with
+    /// [`HistoryLimit::SummarizeAfter`] they are condensed into a single system
+    /// note by a summarizer agent. The most recent entries are always kept
+    /// verbatim.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use llm_toolkit::agent::history::HistoryLimit;
+    ///
+    /// let chat = Chat::new(agent)
+    ///     .with_history_limit(HistoryLimit::summarize_after(20, ClaudeCodeAgent::new()))
+    ///     .build();
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This setting only takes effect when history is enabled.
+    pub fn with_history_limit(mut self, limit: HistoryLimit) -> Self {
+        self.history_limit = Some(limit);
+        self
+    }
+
     /// Finalizes the builder and returns the configured agent.
     ///
     /// This method constructs the final agent based on the builder's configuration:
@@ -202,11 +230,13 @@ impl<A: Agent> Chat<A> {
         A::Output: 'static + Send,
     {
         if self.with_history {
-            match self.identity {
-                Some((name, role)) => crate::agent::AnyAgent::boxed(
-                    HistoryAwareAgent::new_with_identity(self.agent, name, role),
-                ),
-                None => crate::agent::AnyAgent::boxed(HistoryAwareAgent::new(self.agent)),
+            let history_agent = match self.identity {
+                Some((name, role)) => HistoryAwareAgent::new_with_identity(self.agent, name, role),
+                None => HistoryAwareAgent::new(self.agent),
+            };
+            match self.history_limit {
+                Some(limit) => crate::agent::AnyAgent::boxed(history_agent.with_limit(limit)),
+                None => crate::agent::AnyAgent::boxed(history_agent),
             }
         } else {
             crate::agent::AnyAgent::boxed(self.agent)
@@ -358,6 +388,27 @@ mod tests {
         assert_eq!(calls[1], "How are you?");
     }
 
+    #[tokio::test]
+    async fn test_chat_builder_with_history_limit() {
+        let test_agent = TestAgent::new("response");
+        let chat = Chat::new(test_agent.clone())
+            .with_history_limit(HistoryLimit::LastN(2))
+            .build();
+
+        for text in ["Alpha", "Beta", "Gamma"] {
+            chat.execute(Payload::from_messages(vec![PayloadMessage::user(
+                "User", "User", text,
+            )]))
+            .await
+            .unwrap();
+        }
+
+        // Only the previous interaction survives in the third call's history
+        let calls = test_agent.get_calls().await;
+        assert!(calls[2].contains("Beta"));
+        assert!(!calls[2].contains("Alpha"));
+    }
+
     #[tokio::test]
     async fn test_chat_builder_with_persona() {
         let test_agent = TestAgent::new("response");
@@ -368,6 +419,7 @@ mod tests {
             communication_style: "Direct and clear".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let chat = Chat::new(test_agent.clone())
@@ -396,6 +448,7 @@ mod tests {
             communication_style: "Friendly".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let chat = Chat::new(test_agent.clone()).with_persona(persona).build();
@@ -447,6 +500,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let chat = Chat::new(test_agent)
@@ -470,6 +524,7 @@ mod tests {
             communication_style: "Friendly".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let config = ContextConfig {
@@ -0,0 +1,148 @@
+//! Lenient coercion of parsed agent output before deserialization.
+
+use serde_json::{Number, Value};
+
+/// Converts stringified scalars to the JSON types a schema expects.
+///
+/// Models sometimes quote values (`"42"`, `"true"`) that serde then rejects
+/// even though the intent is clear. Agents generated with `#[agent(coerce)]`
+/// run this on the parsed JSON before deserializing, using the output type's
+/// `prompt_schema_json()` as `schema`. For each top-level property:
+///
+/// - `number` fields accept strings that parse as an integer or float
+/// - `boolean` fields accept `"true"` / `"false"` (case-insensitive)
+/// - `T | null` and `T[]` fields apply the rule for `T` to the value or elements
+///
+/// Strings that don't parse, and properties of any other type, are left
+/// untouched so that deserialization reports them as usual.
+///
+/// # Examples
+///
+/// ```rust
+/// use llm_toolkit::agent::coerce::coerce_to_schema;
+/// use serde_json::json;
+///
+/// let schema = json!({
+///     "properties": {"count": {"type": "number"}, "done": {"type": "boolean"}},
+///     "required": ["count", "done"]
+/// });
+/// let mut value = json!({"count": "42", "done": "TRUE"});
+///
+/// coerce_to_schema(&mut value, &schema);
+/// assert_eq!(value, json!({"count": 42, "done": true}));
+/// ```
+pub fn coerce_to_schema(value: &mut Value, schema: &Value) {
+    let (Some(object), Some(properties)) = (
+        value.as_object_mut(),
+        schema.get("properties").and_then(|p| p.as_object()),
+    ) else {
+        return;
+    };
+
+    for (field, spec) in properties {
+        if let (Some(field_value), Some(expected_type)) = (
+            object.get_mut(field),
+            spec.get("type").and_then(|t| t.as_str()),
+        ) {
+            coerce_value(field_value, expected_type);
+        }
+    }
+}
+
+/// Coerces a single value towards a schema type name as produced by `prompt_schema()`.
+fn coerce_value(value: &mut Value, expected_type: &str) {
+    if let Some(inner) = expected_type.strip_suffix(" | null") {
+        coerce_value(value, inner);
+        return;
+    }
+
+    if let Some(element_type) = expected_type.strip_suffix("[]") {
+        if let Value::Array(items) = value {
+            for item in items {
+                coerce_value(item, element_type);
+            }
+        }
+        return;
+    }
+
+    let Value::String(text) = value else {
+        return;
+    };
+    let text = text.trim();
+
+    let coerced = match expected_type {
+        "number" => parse_number(text).map(Value::Number),
+        "boolean" if text.eq_ignore_ascii_case("true") => Some(Value::Bool(true)),
+        "boolean" if text.eq_ignore_ascii_case("false") => Some(Value::Bool(false)),
+        _ => None,
+    };
+
+    if let Some(coerced) = coerced {
+        *value = coerced;
+    }
+}
+
+fn parse_number(text: &str) -> Option<Number> {
+    if let Ok(n) = text.parse::<i64>() {
+        return Some(n.into());
+    }
+    if let Ok(n) = text.parse::<u64>() {
+        return Some(n.into());
+    }
+    text.parse::<f64>().ok().and_then(Number::from_f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema() -> Value {
+        json!({
+            "properties": {
+                "count": {"type": "number"},
+                "ratio": {"type": "number | null"},
+                "done": {"type": "boolean"},
+                "scores": {"type": "number[]"},
+                "label": {"type": "string"}
+            },
+            "required": ["count", "done", "scores", "label"]
+        })
+    }
+
+    #[test]
+    fn test_coerces_stringified_scalars() {
+        let mut value = json!({
+            "count": " 42 ",
+            "ratio": "0.5",
+            "done": "false",
+            "scores": ["1", 2, "-3"],
+            "label": "7"
+        });
+
+        coerce_to_schema(&mut value, &schema());
+
+        assert_eq!(
+            value,
+            json!({
+                "count": 42,
+                "ratio": 0.5,
+                "done": false,
+                "scores": [1, 2, -3],
+                "label": "7"
+            })
+        );
+    }
+
+    #[test]
+    fn test_leaves_unparseable_values_untouched() {
+        let mut value = json!({"count": "many", "ratio": null, "done": "yes"});
+
+        coerce_to_schema(&mut value, &schema());
+
+        assert_eq!(
+            value,
+            json!({"count": "many", "ratio": null, "done": "yes"})
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! An agent that always returns a fixed value.
+
+use super::{Agent, AgentError, Payload};
+use async_trait::async_trait;
+use serde::{Serialize, de::DeserializeOwned};
+
+/// An agent that ignores its input and returns a clone of a stored value.
+///
+/// Use it as a production placeholder for a workflow step that doesn't need a
+/// backend yet, or to inject a fixed value (a seed document, a default config)
+/// at a specific point in a strategy. It never touches the network and is
+/// always available.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use llm_toolkit::agent::{AgentAdapter, ConstAgent};
+/// use std::sync::Arc;
+///
+/// let seed = ConstAgent::new("Draft an outline for the onboarding guide".to_string())
+///     .with_name("SeedAgent");
+/// orchestrator.add_agent("SeedAgent", Arc::new(AgentAdapter::new(seed)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConstAgent<T> {
+    value: T,
+    name: String,
+    description: String,
+}
+
+impl<T> ConstAgent<T> {
+    /// Creates an agent that always returns `value`.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            name: "ConstAgent".to_string(),
+            description: "Returns a fixed value without calling any backend".to_string(),
+        }
+    }
+
+    /// Sets the agent name reported by [`Agent::name`].
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the description used for orchestrator routing.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Returns the value this agent produces.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+#[async_trait]
+impl<T> Agent for ConstAgent<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync,
+{
+    type Output = T;
+    type Expertise = String;
+
+    fn expertise(&self) -> &String {
+        &self.description
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<T, AgentError> {
+        Ok(self.value.clone())
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    async fn is_available(&self) -> Result<(), AgentError> {
+        Ok(())
+    }
+}
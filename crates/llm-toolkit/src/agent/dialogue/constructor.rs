@@ -5,11 +5,12 @@ use crate::{
     agent::{
         dialogue::{
             BroadcastOrder, Dialogue, DialogueBlueprint, DialogueContext, DialogueMessage,
-            DialogueTurn, ExecutionModel, MentionMatchStrategy, MessageId, MessageStore,
-            ReactionStrategy, SequentialOrder, Speaker, TalkStyle, format_dialogue_history_as_text,
+            DialogueTurn, ExecutionModel, HistoryWindow, MentionMatchStrategy, MessageId,
+            MessageStore, ReactionStrategy, SequentialMode, SequentialOrder, Speaker,
+            StopCondition, TalkStyle, format_dialogue_history_as_text,
             message::{self, SentAgents},
         },
-        persona::{PersonaTeam, PersonaTeamGenerationRequest},
+        persona::{Persona, PersonaTeam, PersonaTeamGenerationRequest},
     },
 };
 use std::collections::HashMap;
@@ -27,6 +28,14 @@ impl Dialogue {
             reaction_strategy: ReactionStrategy::default(),
             moderator: None,
             pending_participants: HashMap::new(),
+            summarizer: None,
+            max_turns: None,
+            stop_when: None,
+            history_window: None,
+            injected_history: Vec::new(),
+            max_concurrency: None,
+            sequential_mode: SequentialMode::default(),
+            context_block_threshold: None,
         }
     }
 
@@ -72,6 +81,28 @@ impl Dialogue {
         Self::new(ExecutionModel::OrderedSequential(order))
     }
 
+    /// Creates a new dialogue with debate execution.
+    ///
+    /// Every participant first states a position (broadcast). In each of the
+    /// remaining rounds, participants respond to the other participants'
+    /// previous-round positions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::dialogue::Dialogue;
+    ///
+    /// let mut dialogue = Dialogue::debate(2)
+    ///     .add_participant(optimist_persona, agent1)
+    ///     .add_participant(skeptic_persona, agent2);
+    ///
+    /// // Returns the turns of both rounds
+    /// let turns = dialogue.run("Should we rewrite the service in Rust?").await?;
+    /// ```
+    pub fn debate(rounds: usize) -> Self {
+        Self::new(ExecutionModel::Debate { rounds })
+    }
+
     /// Creates a dialogue with moderator-driven execution.
     ///
     /// The moderator agent determines the execution strategy for each turn
@@ -149,6 +180,34 @@ impl Dialogue {
         Self::new(ExecutionModel::Mentioned { strategy })
     }
 
+    /// Creates a dialogue where only capable participants respond.
+    ///
+    /// A participant responds only if its persona capabilities (filtered by the
+    /// context policy, if set) include every capability in `required`. If no
+    /// participant qualifies, it falls back to broadcast mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::dialogue::Dialogue;
+    ///
+    /// let mut dialogue = Dialogue::capability(["sql", "performance"])
+    ///     .add_participant(dba_persona, agent1)      // capabilities: sql, performance
+    ///     .add_participant(frontend_persona, agent2); // capabilities: css
+    ///
+    /// // Only the DBA responds
+    /// let turns = dialogue.run("Why is this query slow?").await?;
+    /// ```
+    pub fn capability<I, S>(required: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new(ExecutionModel::Capability {
+            required: required.into_iter().map(Into::into).collect(),
+        })
+    }
+
     /// Creates a dialogue with ordered sequential execution.
     ///
     /// Participants execute one by one in the specified order, with output chained as input.
@@ -337,7 +396,7 @@ impl Dialogue {
 
         // Format the history as a readable conversation log
         // Store it in the context which will be prepended to all prompts
-        let history_text = format_dialogue_history_as_text(&history);
+        let history_text = format_dialogue_history_as_text(&history, self.history_window);
 
         // Add the history as additional context that will be included
         // in the dialogue context for all participants
@@ -345,12 +404,50 @@ impl Dialogue {
             .context
             .take()
             .unwrap_or_default()
-            .with_additional_context(history_text);
+            .with_additional_context(history_text.clone());
         self.context = Some(context);
+        self.injected_history.push((history, history_text));
 
         self
     }
 
+    /// Limits how much history `with_history_as_system_prompt()` injects.
+    ///
+    /// The formatted history is trimmed from the oldest end; `System` messages are
+    /// always kept and an omission marker is inserted when trimming occurs.
+    /// History that was already injected is re-rendered, so the call order does
+    /// not matter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::dialogue::{Dialogue, HistoryWindow};
+    ///
+    /// let saved_history = Dialogue::load_history("session_123.json")?;
+    /// let mut dialogue = Dialogue::broadcast()
+    ///     .with_history_window(HistoryWindow::LastN(20))
+    ///     .with_history_as_system_prompt(saved_history);
+    /// ```
+    pub fn with_history_window(mut self, window: HistoryWindow) -> Self {
+        self.history_window = Some(window);
+
+        let Some(context) = self.context.as_mut() else {
+            return self;
+        };
+        for (history, rendered) in &mut self.injected_history {
+            let history_text = format_dialogue_history_as_text(history, self.history_window);
+            if let Some(entry) = context
+                .additional_context
+                .iter_mut()
+                .find(|entry| **entry == *rendered)
+            {
+                *entry = history_text.clone();
+            }
+            *rendered = history_text;
+        }
+        self
+    }
+
     /// Creates a Dialogue from a blueprint.
     ///
     /// If the blueprint contains pre-defined participants, they are used directly.
@@ -590,6 +687,148 @@ impl Dialogue {
         self
     }
 
+    /// Sets a summarizer that runs after each completed turn.
+    ///
+    /// After every `run()` (or once a `partial_session()` has yielded all of its
+    /// turns), the summarizer receives all agent responses of that turn and its
+    /// summary is stored in the `MessageStore` as a `System` context-info message.
+    /// Participants then receive the summary as context in the next turn.
+    ///
+    /// The summarizer is not a participant: it does not respond to the dialogue
+    /// and is not listed in `participant_names()`. This is independent of
+    /// [`with_moderator()`](Self::with_moderator), which selects execution models.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::dialogue::Dialogue;
+    ///
+    /// let mut dialogue = Dialogue::broadcast();
+    /// dialogue
+    ///     .add_participant(persona1, agent1)
+    ///     .add_participant(persona2, agent2)
+    ///     .with_summarizer(facilitator_persona, facilitator_agent);
+    ///
+    /// dialogue.run("Discuss the roadmap").await?;
+    /// ```
+    pub fn with_summarizer<T>(&mut self, persona: Persona, summarizer: T) -> &mut Self
+    where
+        T: Agent<Output = String> + 'static,
+    {
+        self.summarizer = Some(Self::create_participant(persona, summarizer, None));
+        self
+    }
+
+    /// Limits how many turns a `DialogueSession` yields.
+    ///
+    /// Once the session has yielded `max_turns` turns, `next_turn()` returns `None`
+    /// (remaining in-flight responses are discarded).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let mut dialogue = Dialogue::broadcast();
+    /// dialogue
+    ///     .add_participant(persona1, agent1)
+    ///     .add_participant(persona2, agent2)
+    ///     .with_max_turns(1);
+    ///
+    /// let mut session = dialogue.partial_session("Quick poll: yes or no?");
+    /// while let Some(turn) = session.next_turn().await {
+    ///     // Only the first response is yielded
+    /// }
+    /// ```
+    pub fn with_max_turns(&mut self, max_turns: usize) -> &mut Self {
+        self.max_turns = Some(max_turns);
+        self
+    }
+
+    /// Sets what later participants receive in sequential execution.
+    ///
+    /// With the default [`SequentialMode::Cumulative`], each participant sees all
+    /// earlier outputs of the turn plus the incoming messages. With
+    /// [`SequentialMode::PipelineOnly`], participant N receives only participant
+    /// N-1's output, so the dialogue behaves like a strict chain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::dialogue::{Dialogue, SequentialMode};
+    ///
+    /// let mut dialogue = Dialogue::sequential();
+    /// dialogue
+    ///     .add_participant(extractor_persona, extractor)
+    ///     .add_participant(translator_persona, translator)
+    ///     .with_sequential_mode(SequentialMode::PipelineOnly);
+    /// ```
+    pub fn with_sequential_mode(&mut self, mode: SequentialMode) -> &mut Self {
+        self.sequential_mode = mode;
+        self
+    }
+
+    /// Formats other participants' messages by length before they reach an agent.
+    ///
+    /// Messages of at most `chars` characters are inlined as `[Name]: text`;
+    /// longer ones are rendered as a fenced block under a `### Name (Role)`
+    /// header, which keeps long replies readable when several are stacked. By
+    /// default messages are passed through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let mut dialogue = Dialogue::broadcast();
+    /// dialogue.with_context_block_threshold(280);
+    /// ```
+    pub fn with_context_block_threshold(&mut self, chars: usize) -> &mut Self {
+        self.context_block_threshold = Some(chars);
+        self
+    }
+
+    /// Limits how many participants execute at once in broadcast turns.
+    ///
+    /// By default every participant is called simultaneously, which can trip
+    /// backend rate limits in large dialogues. With a limit, the remaining
+    /// participants wait for a free slot; turns are still yielded as each
+    /// response completes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_concurrency` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let mut dialogue = Dialogue::broadcast();
+    /// dialogue.with_max_concurrency(4); // at most 4 backend calls in flight
+    /// ```
+    pub fn with_max_concurrency(&mut self, max_concurrency: usize) -> &mut Self {
+        assert!(
+            max_concurrency > 0,
+            "max_concurrency must be greater than zero"
+        );
+        self.max_concurrency = Some(max_concurrency);
+        self
+    }
+
+    /// Sets a predicate that ends a `DialogueSession` early.
+    ///
+    /// After each yielded turn, the predicate receives all turns yielded by the
+    /// session so far. Once it returns `true`, `next_turn()` returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use std::sync::Arc;
+    ///
+    /// dialogue.with_stop_when(Arc::new(|turns: &[DialogueTurn]| {
+    ///     turns.len() >= 2 && turns.iter().all(|t| t.content.contains("I agree"))
+    /// }));
+    /// ```
+    pub fn with_stop_when(&mut self, predicate: StopCondition) -> &mut Self {
+        self.stop_when = Some(predicate);
+        self
+    }
+
     /// Sets the reaction strategy for the dialogue.
     ///
     /// This controls when agents should react to messages. By default, agents
@@ -606,10 +845,9 @@ impl Dialogue {
     /// // Don't react to System messages
     /// dialogue.with_reaction_strategy(ReactionStrategy::ExceptSystem);
     ///
-    /// // Custom logic
-    /// dialogue.with_reaction_strategy(ReactionStrategy::Custom(Arc::new(|payload, _| {
-    ///     // Only react if payload contains user messages
-    ///     payload.to_messages().iter().any(|msg| matches!(msg.speaker, Speaker::User { .. }))
+    /// // Custom logic: only react to messages mentioning "urgent"
+    /// dialogue.with_reaction_strategy(ReactionStrategy::Custom(Arc::new(|messages| {
+    ///     messages.iter().any(|msg| msg.content.contains("urgent"))
     /// })));
     /// ```
     pub fn with_reaction_strategy(&mut self, strategy: ReactionStrategy) -> &mut Self {
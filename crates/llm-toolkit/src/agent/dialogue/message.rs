@@ -43,6 +43,14 @@ impl MessageId {
     pub fn as_u64(&self) -> u64 {
         self.0
     }
+
+    /// Advances the ID counter past this ID.
+    ///
+    /// Used when restoring messages created in another process, so that
+    /// newly generated IDs never collide with restored ones.
+    pub(super) fn reserve(&self) {
+        MESSAGE_ID_COUNTER.fetch_max(self.0 + 1, Ordering::SeqCst);
+    }
 }
 
 impl Default for MessageId {
@@ -111,6 +111,7 @@ use std::{
     collections::{HashMap, HashSet},
     sync::Arc,
 };
+use tokio::sync::Semaphore;
 use tokio::task::JoinSet;
 use tracing::{debug, error, trace};
 
@@ -120,12 +121,72 @@ pub use message::{
     DialogueMessage, MessageId, MessageMetadata, MessageOrigin, Speaker, format_messages_to_prompt,
 };
 pub use session::DialogueSession;
-pub use store::MessageStore;
-pub use turn_input::{ContextMessage, ParticipantInfo, TurnInput};
+pub use store::{DialogueSessionData, MessageStore};
+pub use turn_input::{ContextMessage, ParticipantInfo, TurnInput, format_context_content};
 
 // Internal modules (not re-exported)
 use state::{BroadcastState, SessionState};
 
+/// Limits how much history is injected by `Dialogue::with_history_as_system_prompt()`.
+///
+/// History is trimmed from the oldest end. `System` messages are always kept,
+/// and a `[...earlier conversation omitted...]` marker is inserted when anything
+/// was dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryWindow {
+    /// Keep only the most recent N non-system turns.
+    LastN(usize),
+
+    /// Keep the most recent turns whose combined content fits in N characters.
+    ///
+    /// Retained `System` messages count toward the budget but are never dropped.
+    MaxChars(usize),
+}
+
+impl HistoryWindow {
+    /// Returns which turns to keep, and whether anything was omitted.
+    fn retain(&self, history: &[DialogueTurn]) -> (Vec<bool>, bool) {
+        let is_system = |turn: &DialogueTurn| matches!(turn.speaker, Speaker::System);
+        let mut keep: Vec<bool> = history.iter().map(is_system).collect();
+
+        match *self {
+            HistoryWindow::LastN(n) => {
+                for (idx, _) in history
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .filter(|(_, turn)| !is_system(turn))
+                    .take(n)
+                {
+                    keep[idx] = true;
+                }
+            }
+            HistoryWindow::MaxChars(max_chars) => {
+                let mut used: usize = history
+                    .iter()
+                    .filter(|turn| is_system(turn))
+                    .map(|turn| turn.content.chars().count())
+                    .sum();
+                for (idx, turn) in history.iter().enumerate().rev() {
+                    if is_system(turn) {
+                        continue;
+                    }
+                    let len = turn.content.chars().count();
+                    if used + len > max_chars {
+                        break;
+                    }
+                    used += len;
+                    keep[idx] = true;
+                }
+            }
+        }
+
+        let truncated = keep.iter().any(|kept| !kept);
+        (keep, truncated)
+    }
+}
+
 /// Formats dialogue history as a human-readable conversation log.
 ///
 /// Converts a vector of `DialogueTurn` into a formatted text representation
@@ -135,6 +196,7 @@ use state::{BroadcastState, SessionState};
 /// # Arguments
 ///
 /// * `history` - The conversation history to format
+/// * `window` - Optional window that trims the oldest turns (System messages are kept)
 ///
 /// # Returns
 ///
@@ -147,7 +209,7 @@ use state::{BroadcastState, SessionState};
 ///     DialogueTurn { speaker: Speaker::user("User", "User"), content: "Hello".to_string() },
 ///     DialogueTurn { speaker: Speaker::agent("Alice", "PM"), content: "Hi there!".to_string() },
 /// ];
-/// let formatted = format_dialogue_history_as_text(&history);
+/// let formatted = format_dialogue_history_as_text(&history, None);
 /// // Returns:
 /// // # Previous Conversation History
 /// //
@@ -157,12 +219,31 @@ use state::{BroadcastState, SessionState};
 /// // [Alice (PM)]
 /// // Hi there!
 /// ```
-fn format_dialogue_history_as_text(history: &[DialogueTurn]) -> String {
+fn format_dialogue_history_as_text(
+    history: &[DialogueTurn],
+    window: Option<HistoryWindow>,
+) -> String {
+    let (keep, truncated) = match window {
+        Some(window) => window.retain(history),
+        None => (vec![true; history.len()], false),
+    };
+
     let mut output = String::from("# Previous Conversation History\n\n");
     output.push_str("The following is the conversation history from previous sessions. ");
     output.push_str("Please use this context to maintain continuity in the discussion.\n\n");
 
+    let mut marker_pending = truncated;
     for (idx, turn) in history.iter().enumerate() {
+        if !keep[idx] {
+            continue;
+        }
+
+        // Mark the omission right before the first retained conversational turn
+        if marker_pending && !matches!(turn.speaker, Speaker::System) {
+            output.push_str("[...earlier conversation omitted...]\n\n");
+            marker_pending = false;
+        }
+
         // Add speaker label with appropriate formatting (includes icon if present)
         let speaker_label = match &turn.speaker {
             Speaker::System => "[System]".to_string(),
@@ -178,6 +259,10 @@ fn format_dialogue_history_as_text(history: &[DialogueTurn]) -> String {
         output.push_str("\n\n");
     }
 
+    if marker_pending {
+        output.push_str("[...earlier conversation omitted...]\n\n");
+    }
+
     output.push_str("---\n");
     output.push_str("End of previous conversation. Continue from here.\n");
 
@@ -307,6 +392,61 @@ fn extract_mentions<'a>(text: &str, participant_names: &'a [&'a str]) -> Vec<&'a
     extract_mentions_with_strategy(text, participant_names, MentionMatchStrategy::ExactWord)
 }
 
+/// Returns the raw `@token`s in `text` (without the `@`), in order of appearance.
+fn mention_tokens(text: &str) -> Vec<&str> {
+    let mention_regex =
+        Regex::new(r#"@([^\s@,.!?;:()\[\]{}<>"'`/\\|]+)"#).expect("Invalid regex pattern");
+    mention_regex
+        .captures_iter(text)
+        .filter_map(|cap| cap.get(1).map(|m| m.as_str()))
+        .collect()
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, &b_char) in b.iter().enumerate() {
+            let cost = usize::from(a_char != b_char);
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Finds the participant closest to an unmatched mention token.
+///
+/// A case-insensitive prefix match wins; otherwise the participant with the
+/// smallest edit distance is chosen, as long as the distance is within a third
+/// of the longer string's length.
+fn suggest_participant<'a>(token: &str, participant_names: &[&'a str]) -> Option<&'a str> {
+    let token_lower = token.to_lowercase();
+
+    if let Some(&name) = participant_names
+        .iter()
+        .find(|name| name.to_lowercase().starts_with(&token_lower))
+    {
+        return Some(name);
+    }
+
+    participant_names
+        .iter()
+        .map(|&name| (name, edit_distance(&token_lower, &name.to_lowercase())))
+        .filter(|(name, distance)| {
+            let longest = name.chars().count().max(token_lower.chars().count());
+            *distance <= longest.div_ceil(3)
+        })
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(name, _)| name)
+}
+
 /// Blueprint for creating a Dialogue.
 ///
 /// Provides a high-level description of the dialogue setup, including
@@ -386,6 +526,11 @@ pub struct DialogueTurn {
     pub content: String,
 }
 
+/// Predicate that ends a `DialogueSession` early.
+///
+/// Receives the turns yielded by the session so far; returning `true` stops the session.
+pub type StopCondition = Arc<dyn Fn(&[DialogueTurn]) -> bool + Send + Sync>;
+
 /// Strategy for matching @mentions in dialogue messages.
 ///
 /// Different strategies handle various naming conventions and mention patterns.
@@ -417,6 +562,44 @@ impl Default for MentionMatchStrategy {
     }
 }
 
+/// Outcome of resolving the @mentions in a message against the participants.
+///
+/// Returned by `Dialogue::resolve_mentions()` so UIs can warn about typos
+/// before a message silently falls back to broadcast.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MentionResult {
+    /// Participant names that were mentioned, in participant order.
+    pub matched: Vec<String>,
+
+    /// `@token`s (without the `@`) that did not match any participant.
+    pub unmatched: Vec<String>,
+
+    /// Closest participant for each unmatched token that has a likely candidate.
+    pub suggestions: Vec<MentionSuggestion>,
+}
+
+impl MentionResult {
+    /// Returns true if the text contained no @mentions at all.
+    pub fn is_empty(&self) -> bool {
+        self.matched.is_empty() && self.unmatched.is_empty()
+    }
+
+    /// Returns true if any @mention failed to resolve.
+    pub fn has_unmatched(&self) -> bool {
+        !self.unmatched.is_empty()
+    }
+}
+
+/// A suggested participant for an unmatched @mention.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MentionSuggestion {
+    /// The unmatched token as written (without the `@`).
+    pub mention: String,
+
+    /// The participant name that most closely matches it.
+    pub participant: String,
+}
+
 /// Represents the execution model for dialogue strategies.
 ///
 /// This enum unifies execution mode (Sequential/Broadcast/Mentioned) with
@@ -452,6 +635,26 @@ pub enum ExecutionModel {
         strategy: MentionMatchStrategy,
     },
 
+    /// Only participants with all required capabilities respond (falls back to Broadcast if none qualify).
+    ///
+    /// Capabilities are matched by name against each participant's persona
+    /// capabilities, after applying the dialogue context's policy (if any).
+    Capability {
+        /// Capability names a participant must have to respond.
+        required: Vec<String>,
+    },
+
+    /// Multi-round debate between all participants.
+    ///
+    /// Round 1 is a broadcast: every participant states a position on the input.
+    /// In each subsequent round, every participant receives the other participants'
+    /// previous-round responses as context and responds again (critique, rebuttal,
+    /// or revised position). Each round is recorded as its own turn.
+    Debate {
+        /// Total number of rounds, including the opening round (minimum 1).
+        rounds: usize,
+    },
+
     /// Moderator dynamically determines execution model.
     ///
     /// A moderator agent evaluates the current context and decides
@@ -467,7 +670,9 @@ pub enum ExecutionModel {
 /// - Slash command results that should be available as context but not trigger reactions
 /// - System notifications that provide information without requiring responses
 /// - Manual control over when agents should engage
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Custom` holds a closure and is skipped by serde; serializing it returns an error.
+#[derive(Clone, Serialize, Deserialize)]
 pub enum ReactionStrategy {
     /// Always react to all messages (default, backward compatible).
     Always,
@@ -493,14 +698,39 @@ pub enum ReactionStrategy {
     /// in history but not trigger agent responses. This strategy allows reacting
     /// to all other message types including System messages.
     ExceptContextInfo,
+
+    /// React when the predicate returns true for the incoming messages.
+    ///
+    /// Text-only payloads are passed as a single `System` message, matching how
+    /// they are stored. Payloads made up entirely of ContextInfo messages never
+    /// trigger reactions, regardless of the predicate.
+    #[serde(skip)]
+    Custom(ReactionPredicate),
 }
 
+/// Predicate used by `ReactionStrategy::Custom`.
+pub type ReactionPredicate = Arc<dyn Fn(&[PayloadMessage]) -> bool + Send + Sync>;
+
 impl Default for ReactionStrategy {
     fn default() -> Self {
         Self::Always
     }
 }
 
+impl std::fmt::Debug for ReactionStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Always => f.write_str("Always"),
+            Self::UserOnly => f.write_str("UserOnly"),
+            Self::AgentOnly => f.write_str("AgentOnly"),
+            Self::ExceptSystem => f.write_str("ExceptSystem"),
+            Self::Conversational => f.write_str("Conversational"),
+            Self::ExceptContextInfo => f.write_str("ExceptContextInfo"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
 /// Represents a participant waiting to join the dialogue (pending state).
 ///
 /// When a participant joins mid-dialogue via `join_in_progress()`, they are
@@ -525,6 +755,8 @@ pub(super) struct Participant {
     /// Tracks whether this participant has sent at least one message.
     /// Used to apply joining strategy only on the first interaction.
     pub(super) has_sent_once: bool,
+    /// Private system instruction prepended to this participant's input only.
+    pub(super) preamble: Option<String>,
 }
 
 impl Clone for Participant {
@@ -534,6 +766,7 @@ impl Clone for Participant {
             agent: Arc::clone(&self.agent),
             joining_strategy: self.joining_strategy,
             has_sent_once: self.has_sent_once,
+            preamble: self.preamble.clone(),
         }
     }
 }
@@ -557,6 +790,17 @@ impl Participant {
             None => Speaker::agent(self.persona.name.clone(), self.persona.role.clone()),
         }
     }
+
+    /// Prepends this participant's preamble (if any) as a system message.
+    ///
+    /// Applied to the payload right before execution, so the preamble never
+    /// reaches the shared `MessageStore` or other participants.
+    pub(super) fn apply_preamble(&self, payload: Payload) -> Payload {
+        match &self.preamble {
+            Some(preamble) => payload.prepend_system(preamble.clone()),
+            None => payload,
+        }
+    }
 }
 
 /// Controls the order in which broadcast responses are yielded.
@@ -569,6 +813,10 @@ pub enum BroadcastOrder {
     ParticipantOrder,
     /// Buffers responses and yields them in a custom specified order by participant name.
     Explicit(Vec<String>),
+    /// Buffers responses and yields them by `Persona::priority` (higher first).
+    ///
+    /// Participants without a priority are yielded last; ties follow participant order.
+    Priority,
 }
 
 /// Controls the execution order for sequential dialogues.
@@ -582,6 +830,19 @@ pub enum SequentialOrder {
     Explicit(Vec<String>),
 }
 
+/// Controls what each participant after the first receives in sequential dialogues.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SequentialMode {
+    /// Later participants see every earlier output of the turn plus the incoming
+    /// messages (default behavior).
+    #[default]
+    Cumulative,
+    /// Each participant sees only the immediately preceding participant's output,
+    /// like a classic pipeline. The first participant receives the incoming payload.
+    PipelineOnly,
+}
+
 /// A dialogue manager for multi-agent conversations.
 ///
 /// The dialogue maintains a list of participants and a conversation history,
@@ -641,6 +902,34 @@ pub struct Dialogue {
     /// mid-dialogue via `join_in_progress()`, they are placed here until their
     /// first turn completes, after which they transition to regular participant status.
     pub(super) pending_participants: HashMap<String, PendingParticipant>,
+
+    /// Optional summarizer that runs after each completed turn.
+    ///
+    /// Kept separate from `participants`: it never responds to the dialogue
+    /// itself and is not listed in `participant_names()`.
+    pub(super) summarizer: Option<Participant>,
+
+    /// Maximum number of turns a `DialogueSession` yields before completing.
+    pub(super) max_turns: Option<usize>,
+
+    /// Predicate that completes a `DialogueSession` early.
+    pub(super) stop_when: Option<StopCondition>,
+
+    /// Window applied when injecting history via `with_history_as_system_prompt()`.
+    pub(super) history_window: Option<HistoryWindow>,
+
+    /// History injected via `with_history_as_system_prompt()`, paired with its
+    /// rendered context entry so a later `with_history_window()` can re-render it.
+    pub(super) injected_history: Vec<(Vec<DialogueTurn>, String)>,
+
+    /// Maximum number of broadcast participants executing at once (`None` = unlimited).
+    pub(super) max_concurrency: Option<usize>,
+
+    /// What later participants receive in sequential execution.
+    pub(super) sequential_mode: SequentialMode,
+
+    /// Character limit above which other participants' messages are fenced (`None` = verbatim).
+    pub(super) context_block_threshold: Option<usize>,
 }
 
 /// Prepared context for broadcast-based execution models.
@@ -688,6 +977,7 @@ impl Dialogue {
             agent: Arc::new(*chat_agent),
             joining_strategy,
             has_sent_once: false,
+            preamble: None,
         }
     }
 
@@ -797,6 +1087,37 @@ impl Dialogue {
         self
     }
 
+    /// Adds a participant with a private preamble.
+    ///
+    /// `preamble` is prepended as a system message to this participant's input
+    /// on every execution (e.g. "Stay in character and be terse"). It is not
+    /// stored in the dialogue's message store, so other participants never see it.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// dialogue.add_participant_with_preamble(
+    ///     critic,
+    ///     llm_agent.clone(),
+    ///     "Stay in character. Answer in at most two sentences.".to_string(),
+    /// );
+    /// ```
+    pub fn add_participant_with_preamble<T>(
+        &mut self,
+        persona: Persona,
+        llm_agent: T,
+        preamble: String,
+    ) -> &mut Self
+    where
+        T: Agent<Output = String> + 'static,
+    {
+        let mut participant = Self::create_participant(persona, llm_agent, None);
+        participant.preamble = Some(preamble);
+        self.participants.push(participant);
+
+        self
+    }
+
     /// Adds a participant to an ongoing dialogue with custom joining strategy.
     ///
     /// This method is designed for mid-dialogue participation scenarios where
@@ -918,6 +1239,7 @@ impl Dialogue {
             agent: Arc::new(*chat_agent),
             joining_strategy: None,
             has_sent_once: false,
+            preamble: None,
         });
 
         self
@@ -951,6 +1273,64 @@ impl Dialogue {
         self.participants.iter().map(|p| p.name()).collect()
     }
 
+    /// Resolves the @mentions in `text` against the current participants.
+    ///
+    /// Uses the `Mentioned` execution model's matching strategy (or `ExactWord`
+    /// for other models). Tokens that match nobody are reported in `unmatched`,
+    /// with a suggested participant when one is close by case-insensitive prefix
+    /// or edit distance. This is a read-only check; `run()` still falls back to
+    /// broadcast when nothing matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let result = dialogue.resolve_mentions("@Alic what do you think?");
+    /// assert!(result.matched.is_empty());
+    /// assert_eq!(result.unmatched, vec!["Alic"]);
+    /// assert_eq!(result.suggestions[0].participant, "Alice");
+    /// ```
+    pub fn resolve_mentions(&self, text: &str) -> MentionResult {
+        let strategy = match self.execution_model {
+            ExecutionModel::Mentioned { strategy } => strategy,
+            _ => MentionMatchStrategy::default(),
+        };
+        let participant_names = self.participant_names();
+        let mentioned = extract_mentions_with_strategy(text, &participant_names, strategy);
+
+        let matched: Vec<String> = participant_names
+            .iter()
+            .filter(|name| mentioned.contains(name))
+            .map(|name| name.to_string())
+            .collect();
+
+        let mut result = MentionResult {
+            matched,
+            ..Default::default()
+        };
+
+        for token in mention_tokens(text) {
+            let resolved = mentioned.iter().any(|name| match strategy {
+                MentionMatchStrategy::ExactWord => *name == token,
+                MentionMatchStrategy::Name | MentionMatchStrategy::Partial => {
+                    name.starts_with(token)
+                }
+            });
+            if resolved || result.unmatched.iter().any(|seen| seen == token) {
+                continue;
+            }
+
+            result.unmatched.push(token.to_string());
+            if let Some(participant) = suggest_participant(token, &participant_names) {
+                result.suggestions.push(MentionSuggestion {
+                    mention: token.to_string(),
+                    participant: participant.to_string(),
+                });
+            }
+        }
+
+        result
+    }
+
     /// Removes a participant from the dialogue by name.
     ///
     /// This is useful for guest participants who are only needed for specific
@@ -1052,6 +1432,11 @@ impl Dialogue {
 
         // If no messages (e.g., text-only payload), check based on strategy defaults
         if messages.is_empty() {
+            // Text-only payloads are stored as System messages, so evaluate them as such
+            if let ReactionStrategy::Custom(predicate) = &self.reaction_strategy {
+                return predicate(&[PayloadMessage::new(Speaker::System, payload.to_text())]);
+            }
+
             // Text-only payloads should trigger reactions for most strategies
             return !matches!(self.reaction_strategy, ReactionStrategy::AgentOnly);
         }
@@ -1106,6 +1491,7 @@ impl Dialogue {
                 // React to all messages except ContextInfo (already filtered above)
                 true
             }
+            ReactionStrategy::Custom(predicate) => predicate(&messages),
         }
     }
 
@@ -1328,7 +1714,7 @@ impl Dialogue {
 
         // Use new implementation for both modes
         // Note: no_react_messages will be prepended by each execution mode
-        match self.execution_model.clone() {
+        let turns = match self.execution_model.clone() {
             ExecutionModel::Sequential => {
                 // Sequential with default AsAdded order
                 self.run_sequential(current_turn, &SequentialOrder::AsAdded)
@@ -1348,11 +1734,75 @@ impl Dialogue {
             ExecutionModel::Mentioned { strategy } => {
                 self.run_mentioned(current_turn, strategy).await
             }
+            ExecutionModel::Capability { required } => {
+                self.run_capability(current_turn, &required).await
+            }
+            ExecutionModel::Debate { rounds } => self.run_debate(current_turn, rounds).await,
             ExecutionModel::Moderator => {
                 // Consult moderator for execution strategy
                 self.run_with_moderator(current_turn, payload).await
             }
+        }?;
+
+        self.summarize_turn(self.message_store.latest_turn())
+            .await?;
+
+        Ok(turns)
+    }
+
+    /// Runs the summarizer (if configured) over the agent responses of `turn`.
+    ///
+    /// The summary is stored as a `System` context-info message in the same turn,
+    /// so participants receive it as context in the next turn. Does nothing if no
+    /// summarizer is set or no agent responded in the turn.
+    pub(super) async fn summarize_turn(&mut self, turn: usize) -> Result<(), AgentError> {
+        let Some(summarizer) = self.summarizer.clone() else {
+            return Ok(());
+        };
+
+        let responses: Vec<PayloadMessage> = self
+            .message_store
+            .messages_for_turn(turn)
+            .into_iter()
+            .filter(|msg| {
+                matches!(msg.speaker, Speaker::Agent { .. })
+                    && msg.metadata.origin() == Some(MessageOrigin::AgentGenerated)
+            })
+            .map(PayloadMessage::from)
+            .collect();
+
+        if responses.is_empty() {
+            return Ok(());
+        }
+
+        debug!(
+            target = "llm_toolkit::dialogue",
+            turn,
+            summarizer = %summarizer.name(),
+            response_count = responses.len(),
+            "Summarizing dialogue turn"
+        );
+
+        let mut messages = responses;
+        messages.push(PayloadMessage::system(
+            "Summarize the participants' responses above for the next turn: key points, agreements, and open disagreements.",
+        ));
+        let mut payload =
+            Payload::from_messages(messages).with_participants(self.get_participants_info());
+        if let Some(ref context) = self.context {
+            payload = payload.with_context(context.to_prompt());
         }
+
+        let summary = summarizer.agent.execute(payload).await?;
+
+        let metadata = MessageMetadata::new()
+            .with_origin(MessageOrigin::IncomingPayload)
+            .with_type(message::MessageType::ContextInfo);
+        let summary_message =
+            DialogueMessage::new(turn, Speaker::System, summary).with_metadata(&metadata);
+        self.message_store.push(summary_message);
+
+        Ok(())
     }
 
     /// Moderator-driven execution.
@@ -1413,6 +1863,10 @@ impl Dialogue {
             ExecutionModel::Mentioned { strategy } => {
                 self.run_mentioned(current_turn, strategy).await
             }
+            ExecutionModel::Capability { required } => {
+                self.run_capability(current_turn, &required).await
+            }
+            ExecutionModel::Debate { rounds } => self.run_debate(current_turn, rounds).await,
             ExecutionModel::Moderator => {
                 // Prevent infinite recursion
                 Err(AgentError::ExecutionFailed(
@@ -1562,7 +2016,7 @@ impl Dialogue {
 
         let sequence_indices = self.resolve_sequential_indices(order)?;
         // Execute participants sequentially
-        let mut final_turn = None;
+        let mut final_turn: Option<DialogueTurn> = None;
 
         for (sequence_idx, participant_idx) in sequence_indices.iter().enumerate() {
             let participant_idx = *participant_idx;
@@ -1624,6 +2078,27 @@ impl Dialogue {
 
                 // Don't mark incoming messages as sent yet - subsequent agents need to see them
                 (messages, metadata_messages, vec![])
+            } else if self.sequential_mode == SequentialMode::PipelineOnly {
+                // Pipeline: only the immediately preceding agent's output
+                let previous_output: Vec<PayloadMessage> = final_turn
+                    .iter()
+                    .map(|turn| PayloadMessage::new(turn.speaker.clone(), turn.content.clone()))
+                    .collect();
+
+                let mut messages: Vec<PayloadMessage> = joining_history_context.unwrap_or_default();
+                messages.extend(previous_output.clone());
+
+                trace!(
+                    target = "llm_toolkit::dialogue",
+                    turn = current_turn,
+                    agent_idx = participant_idx,
+                    sequence_idx,
+                    agent_name = %agent_name,
+                    "Sequential mode (pipeline): Agent {} receiving the previous agent's output",
+                    agent_name
+                );
+
+                (messages, previous_output, vec![])
             } else {
                 // Subsequent agents: get ALL previous agents' messages from current turn
                 // (not just unsent, as they may have been marked sent by earlier agents in the chain)
@@ -1669,7 +2144,8 @@ impl Dialogue {
                 vec![], // context is integrated into messages
                 participants_info.clone(),
                 agent_name.clone(),
-            );
+            )
+            .with_context_block_threshold(self.context_block_threshold);
 
             let messages = turn_input.to_messages();
             let mut input_payload = Payload::from_messages(messages);
@@ -1685,6 +2161,7 @@ impl Dialogue {
 
             // Add participants info
             input_payload = input_payload.with_participants(participants_info.clone());
+            input_payload = participant.apply_preamble(input_payload);
 
             // Execute agent
             let response = agent.execute(input_payload).await?;
@@ -1755,8 +2232,42 @@ impl Dialogue {
         );
 
         // Spawn tasks for mentioned participants (or all if no mentions)
-        let mut pending = self.spawn_mentioned_tasks(current_turn, strategy);
+        let pending = self.spawn_mentioned_tasks(current_turn, strategy);
+
+        self.collect_selected_responses(current_turn, pending).await
+    }
+
+    /// Capability-based implementation using MessageStore and TurnInput.
+    ///
+    /// Only participants whose (policy-filtered) capabilities include every required
+    /// capability respond. If no participant qualifies, it falls back to broadcast mode
+    /// (all participants respond).
+    async fn run_capability(
+        &mut self,
+        current_turn: usize,
+        required: &[String],
+    ) -> Result<Vec<DialogueTurn>, AgentError> {
+        debug!(
+            target = "llm_toolkit::dialogue",
+            turn = current_turn,
+            execution_model = "capability",
+            required = ?required,
+            participant_count = self.participants.len(),
+            has_context = self.context.is_some(),
+            "Starting dialogue.run() in capability mode"
+        );
 
+        let pending = self.spawn_capability_tasks(current_turn, required);
+
+        self.collect_selected_responses(current_turn, pending).await
+    }
+
+    /// Awaits the tasks spawned for a subset of participants and stores their responses.
+    async fn collect_selected_responses(
+        &mut self,
+        current_turn: usize,
+        mut pending: JoinSet<(usize, String, Result<String, AgentError>)>,
+    ) -> Result<Vec<DialogueTurn>, AgentError> {
         // Collect responses and create message entities
         let mut dialogue_turns = Vec::new();
 
@@ -1782,6 +2293,73 @@ impl Dialogue {
         Ok(dialogue_turns)
     }
 
+    /// Debate implementation built on the broadcast machinery.
+    ///
+    /// Round 1 broadcasts the incoming payload. Before each later round, a system
+    /// instruction is stored for the next turn; `spawn_broadcast_tasks` then delivers
+    /// it together with the other participants' unsent (previous-round) responses.
+    /// Returns the turns of all rounds in order.
+    async fn run_debate(
+        &mut self,
+        current_turn: usize,
+        rounds: usize,
+    ) -> Result<Vec<DialogueTurn>, AgentError> {
+        let rounds = rounds.max(1);
+        debug!(
+            target = "llm_toolkit::dialogue",
+            turn = current_turn,
+            execution_model = "debate",
+            rounds,
+            participant_count = self.participants.len(),
+            has_context = self.context.is_some(),
+            "Starting dialogue.run() in debate mode"
+        );
+
+        let mut dialogue_turns = Vec::new();
+
+        for round in 1..=rounds {
+            let round_turn = current_turn + round - 1;
+
+            if round > 1 {
+                let metadata = MessageMetadata::new().with_origin(MessageOrigin::IncomingPayload);
+                let instruction = DialogueMessage::new(
+                    round_turn,
+                    Speaker::System,
+                    format!(
+                        "Debate round {} of {}: critique the other participants' positions above, then defend or revise your own.",
+                        round, rounds
+                    ),
+                )
+                .with_metadata(&metadata);
+                self.message_store.push(instruction);
+            }
+
+            trace!(
+                target = "llm_toolkit::dialogue",
+                turn = round_turn,
+                round,
+                rounds,
+                "Starting debate round"
+            );
+
+            let mut pending = self.spawn_broadcast_tasks(round_turn);
+
+            while let Some(Ok((idx, _name, result))) = pending.join_next().await {
+                let content = result?;
+                let speaker = self.participants[idx].to_speaker();
+                let metadata = MessageMetadata::new().with_origin(MessageOrigin::AgentGenerated);
+                let response_message =
+                    DialogueMessage::new(round_turn, speaker.clone(), content.clone())
+                        .with_metadata(&metadata);
+                self.message_store.push(response_message);
+
+                dialogue_turns.push(DialogueTurn { speaker, content });
+            }
+        }
+
+        Ok(dialogue_turns)
+    }
+
     /// Begins a dialogue session that yields turns incrementally.
     ///
     /// This method accepts any type that can be converted into a `Payload`, including:
@@ -1869,6 +2447,7 @@ impl Dialogue {
                 dialogue: self,
                 state: SessionState::Completed,
                 model,
+                yielded: Vec::new(),
             };
         }
 
@@ -1997,6 +2576,27 @@ impl Dialogue {
                     current_turn,
                 ))
             }
+            ExecutionModel::Capability { required } => {
+                // Like Mentioned, but participants are selected by capability
+                let pending = self.spawn_capability_tasks(current_turn, required);
+
+                SessionState::Broadcast(BroadcastState::new(
+                    pending,
+                    BroadcastOrder::Completion,
+                    self.participants.len(),
+                    current_turn,
+                ))
+            }
+            ExecutionModel::Debate { .. } => {
+                // Debate rounds depend on each other, so they are not streamed yet
+                error!(
+                    target = "llm_toolkit::dialogue",
+                    "Debate mode is not supported in partial_session, use run() instead"
+                );
+                SessionState::Failed(Some(AgentError::ExecutionFailed(
+                    "Debate mode requires run() method, not partial_session()".to_string(),
+                )))
+            }
             ExecutionModel::Moderator => {
                 // For Moderator mode, we need to consult the moderator first
                 // This is not supported in partial_session yet - use run() instead
@@ -2014,6 +2614,7 @@ impl Dialogue {
             dialogue: self,
             state,
             model,
+            yielded: Vec::new(),
         }
     }
 
@@ -2029,6 +2630,12 @@ impl Dialogue {
 
         let mut pending = JoinSet::new();
 
+        // Every task is spawned up front; the semaphore only gates the backend call,
+        // so results still arrive in completion order
+        let semaphore = self
+            .max_concurrency
+            .map(|limit| Arc::new(Semaphore::new(limit)));
+
         for idx in 0..self.participants.len() {
             let participant: &Participant = &self.participants[idx];
             let joining_history_context = ctx.joining_history_contexts.get(idx);
@@ -2041,25 +2648,21 @@ impl Dialogue {
             }
 
             // Regular participant: use unsent_from_agent
-            let unsent_payload_messages: Vec<PayloadMessage> = ctx
-                .unsent_from_agent
-                .iter()
-                .filter(|msg| msg.speaker.name() != participant_name)
-                .cloned()
-                .collect();
-
-            current_messages.extend(unsent_payload_messages);
+            current_messages.extend(ctx.unsent_from_agent.iter().cloned());
 
             current_messages.extend(ctx.unsent_incoming.clone());
-            let messages_with_metadata = current_messages.clone();
 
-            // Create payload with turn input formatting
+            // Create payload with turn input formatting; the participant's own replies
+            // stay in its managed history and are not echoed back as dialogue context
             let turn_input = TurnInput::with_messages_and_context(
                 current_messages,
                 vec![],
                 ctx.participants_info.clone(),
                 participant_name.clone(),
-            );
+            )
+            .without_own_messages()
+            .with_context_block_threshold(self.context_block_threshold);
+            let messages_with_metadata = turn_input.current_messages.clone();
 
             let messages = turn_input.to_messages();
             let mut payload = Payload::from_messages(messages);
@@ -2070,9 +2673,20 @@ impl Dialogue {
             }
 
             payload = Self::apply_metadata_attachments(payload, &messages_with_metadata);
-            let input_payload = payload.with_participants(ctx.participants_info.clone());
+            let input_payload = participant
+                .apply_preamble(payload.with_participants(ctx.participants_info.clone()));
 
+            let semaphore = semaphore.clone();
             pending.spawn(async move {
+                let _permit = match semaphore {
+                    Some(semaphore) => Some(
+                        semaphore
+                            .acquire_owned()
+                            .await
+                            .expect("broadcast semaphore is never closed"),
+                    ),
+                    None => None,
+                };
                 let result = agent.execute(input_payload).await;
                 (idx, participant_name, result)
             });
@@ -2112,14 +2726,6 @@ impl Dialogue {
             .map(PayloadMessage::from)
             .collect();
 
-        // Collect incoming message IDs to mark as sent later
-        let incoming_message_ids: Vec<_> = self
-            .message_store
-            .unsent_messages_with_origin(MessageOrigin::IncomingPayload)
-            .iter()
-            .map(|msg| msg.id)
-            .collect();
-
         // Get unsent agent-generated messages (for mention extraction and context)
         let unsent_messages_from_agent: Vec<PayloadMessage> = self
             .message_store
@@ -2128,14 +2734,6 @@ impl Dialogue {
             .map(PayloadMessage::from)
             .collect();
 
-        // Collect agent message IDs to mark as sent later
-        let agent_message_ids: Vec<_> = self
-            .message_store
-            .unsent_messages_with_origin(MessageOrigin::AgentGenerated)
-            .iter()
-            .map(|msg| msg.id)
-            .collect();
-
         trace!(
             target = "llm_toolkit::dialogue",
             turn = current_turn,
@@ -2203,6 +2801,100 @@ impl Dialogue {
             mentioned_names
         };
 
+        // Collect indices of mentioned participants
+        let mentioned_indices: Vec<usize> = self
+            .participants
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| target_participants.contains(&p.name()))
+            .map(|(idx, _)| idx)
+            .collect();
+
+        self.spawn_selected_tasks(current_turn, &mentioned_indices)
+    }
+
+    /// Helper method to spawn tasks for participants whose (policy-filtered)
+    /// capabilities include every required capability.
+    ///
+    /// If no participant qualifies, falls back to spawning tasks for all participants (broadcast).
+    ///
+    /// Returns a JoinSet with pending agent executions.
+    pub(super) fn spawn_capability_tasks(
+        &mut self,
+        current_turn: usize,
+        required: &[String],
+    ) -> JoinSet<(usize, String, Result<String, AgentError>)> {
+        // Use the same policy-aware capabilities that participants see in their context
+        let capable_indices: Vec<usize> = self
+            .get_participants_info()
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| {
+                let capabilities = info.capabilities.as_deref().unwrap_or_default();
+                required
+                    .iter()
+                    .all(|tag| capabilities.iter().any(|cap| &cap.name == tag))
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+
+        let selected_indices: Vec<usize> = if capable_indices.is_empty() {
+            debug!(
+                target = "llm_toolkit::dialogue",
+                turn = current_turn,
+                required = ?required,
+                "No participant has the required capabilities, falling back to broadcast mode"
+            );
+            (0..self.participants.len()).collect()
+        } else {
+            trace!(
+                target = "llm_toolkit::dialogue",
+                turn = current_turn,
+                required = ?required,
+                capable_count = capable_indices.len(),
+                "Capable participants found - executing selective participants"
+            );
+            capable_indices
+        };
+
+        self.spawn_selected_tasks(current_turn, &selected_indices)
+    }
+
+    /// Helper method to spawn tasks for the participants at `selected_indices`.
+    ///
+    /// Each selected participant receives the unsent incoming messages (plus its joining
+    /// history, if any). All unsent messages are marked as sent afterwards.
+    ///
+    /// Returns a JoinSet with pending agent executions.
+    fn spawn_selected_tasks(
+        &mut self,
+        current_turn: usize,
+        selected_indices: &[usize],
+    ) -> JoinSet<(usize, String, Result<String, AgentError>)> {
+        // Get unsent incoming messages (agent input)
+        let unsent_messages_incoming: Vec<PayloadMessage> = self
+            .message_store
+            .unsent_messages_with_origin(MessageOrigin::IncomingPayload)
+            .into_iter()
+            .map(PayloadMessage::from)
+            .collect();
+
+        // Collect incoming message IDs to mark as sent later
+        let incoming_message_ids: Vec<_> = self
+            .message_store
+            .unsent_messages_with_origin(MessageOrigin::IncomingPayload)
+            .iter()
+            .map(|msg| msg.id)
+            .collect();
+
+        // Collect agent message IDs to mark as sent later
+        let agent_message_ids: Vec<_> = self
+            .message_store
+            .unsent_messages_with_origin(MessageOrigin::AgentGenerated)
+            .iter()
+            .map(|msg| msg.id)
+            .collect();
+
         // Build participant list
         let participants_info = self.get_participants_info();
 
@@ -2210,15 +2902,17 @@ impl Dialogue {
         let executing_participants: Vec<_> = self
             .participants
             .iter()
-            .filter(|p| target_participants.contains(&p.name()))
-            .map(|p| p.name())
+            .enumerate()
+            .filter(|(idx, _)| selected_indices.contains(idx))
+            .map(|(_, p)| p.name())
             .collect();
 
         let skipped_participants: Vec<_> = self
             .participants
             .iter()
-            .filter(|p| !target_participants.contains(&p.name()))
-            .map(|p| p.name())
+            .enumerate()
+            .filter(|(idx, _)| !selected_indices.contains(idx))
+            .map(|(_, p)| p.name())
             .collect();
 
         trace!(
@@ -2228,21 +2922,12 @@ impl Dialogue {
             executing_participants = ?executing_participants,
             skipped_count = skipped_participants.len(),
             skipped_participants = ?skipped_participants,
-            "Mention-based execution plan determined"
+            "Selective execution plan determined"
         );
 
-        // Collect indices of mentioned participants
-        let mentioned_indices: Vec<usize> = self
-            .participants
-            .iter()
-            .enumerate()
-            .filter(|(_, p)| target_participants.contains(&p.name()))
-            .map(|(idx, _)| idx)
-            .collect();
-
-        // Prepare joining history contexts for mentioned participants only
+        // Prepare joining history contexts for selected participants only
         let mut joining_history_contexts = vec![];
-        for &idx in &mentioned_indices {
+        for &idx in selected_indices {
             let participant = &self.participants[idx];
             let speaker = participant.to_speaker();
             let joining_history_context = self.join_pending_participant(speaker, current_turn);
@@ -2251,8 +2936,8 @@ impl Dialogue {
 
         let mut pending = JoinSet::new();
 
-        // Spawn tasks for mentioned participants only
-        for (i, &idx) in mentioned_indices.iter().enumerate() {
+        // Spawn tasks for selected participants only
+        for (i, &idx) in selected_indices.iter().enumerate() {
             let participant = &self.participants[idx];
             let participant_name = participant.name().to_string();
             let agent = Arc::clone(&participant.agent);
@@ -2266,14 +2951,16 @@ impl Dialogue {
             }
 
             current_messages.extend(unsent_messages_incoming.clone());
-            let messages_with_metadata = current_messages.clone();
 
             let turn_input = TurnInput::with_messages_and_context(
-                current_messages.clone(),
+                current_messages,
                 vec![],
                 participants_info.clone(),
                 participant_name.clone(),
-            );
+            )
+            .without_own_messages()
+            .with_context_block_threshold(self.context_block_threshold);
+            let messages_with_metadata = turn_input.current_messages.clone();
 
             let messages = turn_input.to_messages();
             let mut payload = Payload::from_messages(messages);
@@ -2283,13 +2970,14 @@ impl Dialogue {
             }
 
             payload = Self::apply_metadata_attachments(payload, &messages_with_metadata);
-            let input_payload = payload.with_participants(participants_info.clone());
+            let input_payload =
+                participant.apply_preamble(payload.with_participants(participants_info.clone()));
 
             trace!(
                 target = "llm_toolkit::dialogue",
                 turn = current_turn,
                 participant = %participant_name,
-                "Spawning task for mentioned participant"
+                "Spawning task for selected participant"
             );
 
             pending.spawn(async move {
@@ -2437,6 +3125,11 @@ impl Dialogue {
         &self.message_store
     }
 
+    /// Returns the number of the last completed turn (0 before the first turn).
+    pub fn current_turn(&self) -> usize {
+        self.message_store.latest_turn()
+    }
+
     /// Returns references to the personas of all participants.
     ///
     /// This provides access to participant information such as names, roles,
@@ -2542,6 +3235,179 @@ impl Dialogue {
             AgentError::ExecutionFailed(format!("Failed to deserialize history: {}", e))
         })
     }
+
+    /// Exports the full message store as a serializable session snapshot.
+    ///
+    /// Unlike `save_history()`, which keeps only speaker and content, the
+    /// snapshot preserves message IDs, turn numbers, metadata, and context
+    /// delivery tracking, so `import_session()` can resume the dialogue exactly
+    /// where it stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let session = dialogue.export_session();
+    /// std::fs::write("session_123.json", serde_json::to_string(&session)?)?;
+    ///
+    /// // Later, with the same participants:
+    /// let session: DialogueSessionData =
+    ///     serde_json::from_str(&std::fs::read_to_string("session_123.json")?)?;
+    /// let mut dialogue = Dialogue::broadcast()
+    ///     .add_participant(persona1, agent1);
+    /// dialogue.import_session(session);
+    /// ```
+    pub fn export_session(&self) -> DialogueSessionData {
+        DialogueSessionData {
+            messages: self
+                .message_store
+                .all_messages()
+                .into_iter()
+                .cloned()
+                .collect(),
+            current_turn: self.current_turn(),
+        }
+    }
+
+    /// Replaces the message store with a snapshot created by `export_session()`.
+    ///
+    /// Participants are not part of the snapshot; register them as usual.
+    /// The next turn continues from the restored turn numbers, and messages
+    /// that were not yet delivered as context are delivered on the next turn.
+    pub fn import_session(&mut self, data: DialogueSessionData) {
+        self.message_store = MessageStore::from_messages(data.messages);
+    }
+
+    /// Renders the conversation as a Mermaid `sequenceDiagram`.
+    ///
+    /// Registered participants (and any users who spoke) are declared first.
+    /// Each turn is a shaded block headed by a `Turn N` note. Every user or
+    /// agent message becomes one arrow labeled with a short content preview,
+    /// pointing at the previous non-system speaker (the first message points
+    /// at the next declared participant). System messages, such as the prompt
+    /// of a `run()` call, are shown as notes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// dialogue.run("Discuss the API design").await?;
+    /// std::fs::write("dialogue.mmd", dialogue.to_mermaid_sequence())?;
+    /// ```
+    pub fn to_mermaid_sequence(&self) -> String {
+        let messages = self.message_store.all_messages();
+
+        // Participant declarations: registered personas first, then other speakers
+        let mut actors: Vec<(String, String, bool)> = self
+            .participants
+            .iter()
+            .map(|p| {
+                let label = format!("{} ({})", p.persona.name, p.persona.role);
+                (p.persona.name.clone(), label, false)
+            })
+            .collect();
+        for msg in &messages {
+            let is_user = matches!(msg.speaker, Speaker::User { .. });
+            let name = msg.speaker_name();
+            if matches!(msg.speaker, Speaker::System) || actors.iter().any(|(n, ..)| n == name) {
+                continue;
+            }
+            let label = match msg.speaker_role() {
+                Some(role) => format!("{} ({})", name, role),
+                None => name.to_string(),
+            };
+            actors.push((name.to_string(), label, is_user));
+        }
+        let actor_id = |name: &str| {
+            actors
+                .iter()
+                .position(|(n, ..)| n == name)
+                .map(|i| format!("p{}", i))
+        };
+
+        let mut lines = vec!["sequenceDiagram".to_string()];
+        for (i, (_, label, is_user)) in actors.iter().enumerate() {
+            let keyword = if *is_user { "actor" } else { "participant" };
+            lines.push(format!(
+                "    {} p{} as {}",
+                keyword,
+                i,
+                mermaid_text(label, usize::MAX)
+            ));
+        }
+
+        let note_span = match actors.len() {
+            0 => None,
+            1 => Some("p0".to_string()),
+            n => Some(format!("p0,p{}", n - 1)),
+        };
+        let mut previous_speaker: Option<String> = None;
+        let mut current_turn = None;
+
+        for msg in &messages {
+            if current_turn != Some(msg.turn) {
+                if current_turn.is_some() {
+                    lines.push("    end".to_string());
+                }
+                current_turn = Some(msg.turn);
+                lines.push("    rect rgb(245, 245, 245)".to_string());
+                if let Some(span) = &note_span {
+                    lines.push(format!("    Note over {}: Turn {}", span, msg.turn));
+                }
+            }
+
+            let preview = mermaid_text(&msg.content, MERMAID_PREVIEW_CHARS);
+            if matches!(msg.speaker, Speaker::System) {
+                if let Some(span) = &note_span {
+                    lines.push(format!("    Note over {}: System: {}", span, preview));
+                }
+                continue;
+            }
+            let Some(from) = actor_id(msg.speaker_name()) else {
+                continue;
+            };
+
+            let to = previous_speaker
+                .as_deref()
+                .filter(|name| *name != msg.speaker_name())
+                .and_then(actor_id)
+                .or_else(|| {
+                    actors
+                        .iter()
+                        .find(|(name, ..)| name != msg.speaker_name())
+                        .and_then(|(name, ..)| actor_id(name))
+                })
+                .unwrap_or_else(|| from.clone());
+            lines.push(format!("    {}->>{}: {}", from, to, preview));
+            previous_speaker = Some(msg.speaker_name().to_string());
+        }
+        if current_turn.is_some() {
+            lines.push("    end".to_string());
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Maximum number of characters of a message shown in a Mermaid arrow label.
+const MERMAID_PREVIEW_CHARS: usize = 40;
+
+/// Makes `text` safe for a single-line Mermaid label, truncated to `max_chars`.
+///
+/// Whitespace (including newlines) is collapsed, and `#` and `;`, which Mermaid
+/// treats specially, are written as entity codes.
+fn mermaid_text(text: &str, max_chars: usize) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut preview: String = collapsed.chars().take(max_chars).collect();
+    if collapsed.chars().count() > max_chars {
+        preview.push('…');
+    }
+    preview
+        .chars()
+        .map(|c| match c {
+            '#' => "#35;".to_string(),
+            ';' => "#59;".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
 }
 
 // ============================================================================
@@ -2603,6 +3469,8 @@ impl Agent for Dialogue {
                 ExecutionModel::Broadcast => "Broadcast",
                 ExecutionModel::OrderedBroadcast(_) => "Broadcast",
                 ExecutionModel::Mentioned { .. } => "Mentioned",
+                ExecutionModel::Capability { .. } => "Capability",
+                ExecutionModel::Debate { .. } => "Debate",
                 ExecutionModel::Moderator => "Moderator",
             };
 
@@ -2704,6 +3572,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -2713,6 +3582,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -2741,6 +3611,226 @@ mod tests {
         assert_eq!(dialogue.history()[2].speaker.name(), "Agent2");
     }
 
+    #[tokio::test]
+    async fn test_export_import_session_round_trip() {
+        use crate::agent::persona::Persona;
+
+        let persona = |name: &str| Persona {
+            name: name.to_string(),
+            role: "Tester".to_string(),
+            background: "Test agent".to_string(),
+            communication_style: "Direct".to_string(),
+            visual_identity: None,
+            capabilities: None,
+            priority: None,
+        };
+
+        let mut dialogue = Dialogue::broadcast();
+        dialogue
+            .add_participant(
+                persona("Agent1"),
+                MockAgent::new("Agent1", vec!["Response 1".to_string()]),
+            )
+            .add_participant(
+                persona("Agent2"),
+                MockAgent::new("Agent2", vec!["Response 2".to_string()]),
+            );
+        dialogue.run("Initial prompt".to_string()).await.unwrap();
+
+        let json = serde_json::to_string(&dialogue.export_session()).unwrap();
+        let data: DialogueSessionData = serde_json::from_str(&json).unwrap();
+        assert_eq!(data.current_turn, 1);
+
+        let mut restored = Dialogue::broadcast();
+        restored
+            .add_participant(
+                persona("Agent1"),
+                MockAgent::new("Agent1", vec!["Response 1".to_string()]),
+            )
+            .add_participant(
+                persona("Agent2"),
+                MockAgent::new("Agent2", vec!["Response 2".to_string()]),
+            );
+        restored.import_session(data);
+
+        assert_eq!(restored.current_turn(), dialogue.current_turn());
+
+        let summarize = |store: &MessageStore| -> Vec<_> {
+            store
+                .all_messages()
+                .into_iter()
+                .map(|msg| {
+                    (
+                        msg.id,
+                        msg.turn,
+                        msg.content.clone(),
+                        msg.metadata.origin(),
+                        msg.sent_agents.clone(),
+                    )
+                })
+                .collect()
+        };
+        assert_eq!(
+            summarize(restored.message_store()),
+            summarize(dialogue.message_store())
+        );
+
+        let unsent_ids = |store: &MessageStore| -> Vec<MessageId> {
+            store.unsent_messages().iter().map(|msg| msg.id).collect()
+        };
+        assert!(!unsent_ids(dialogue.message_store()).is_empty());
+        assert_eq!(
+            unsent_ids(restored.message_store()),
+            unsent_ids(dialogue.message_store())
+        );
+
+        // New messages continue from the restored turn with fresh IDs.
+        restored.run("Follow-up".to_string()).await.unwrap();
+        assert_eq!(restored.current_turn(), 2);
+        let ids: std::collections::HashSet<_> = restored
+            .message_store()
+            .all_messages()
+            .iter()
+            .map(|msg| msg.id)
+            .collect();
+        assert_eq!(ids.len(), restored.message_store().len());
+    }
+
+    #[tokio::test]
+    async fn test_summarizer_runs_once_per_turn() {
+        use crate::agent::persona::Persona;
+
+        let persona = |name: &str| Persona {
+            name: name.to_string(),
+            role: "Tester".to_string(),
+            background: "Test agent".to_string(),
+            communication_style: "Direct".to_string(),
+            visual_identity: None,
+            capabilities: None,
+            priority: None,
+        };
+
+        let agent1 = MockAgent::new("Agent1", vec!["Response 1".to_string()]);
+        let summarizer = MockAgent::new(
+            "Facilitator",
+            vec![
+                "Summary of turn 1".to_string(),
+                "Summary of turn 2".to_string(),
+            ],
+        );
+
+        let mut dialogue = Dialogue::broadcast();
+        dialogue
+            .add_participant(persona("Agent1"), agent1.clone())
+            .add_participant(
+                persona("Agent2"),
+                MockAgent::new("Agent2", vec!["Response 2".to_string()]),
+            )
+            .with_summarizer(persona("Facilitator"), summarizer.clone());
+
+        assert_eq!(dialogue.participant_names(), vec!["Agent1", "Agent2"]);
+
+        let contents = |payload: &Payload| -> Vec<String> {
+            payload
+                .to_messages()
+                .into_iter()
+                .map(|msg| msg.content)
+                .collect()
+        };
+
+        let turns = dialogue.run("First topic".to_string()).await.unwrap();
+        assert_eq!(turns.len(), 2);
+        assert_eq!(summarizer.get_call_count(), 1);
+
+        // The summarizer saw both responses of the turn
+        let summarizer_input = contents(&summarizer.get_payloads()[0]);
+        assert!(summarizer_input.contains(&"Response 1".to_string()));
+        assert!(summarizer_input.contains(&"Response 2".to_string()));
+
+        let summaries = |dialogue: &Dialogue, turn: usize| -> Vec<String> {
+            dialogue
+                .message_store()
+                .messages_for_turn(turn)
+                .into_iter()
+                .filter(|msg| {
+                    matches!(msg.speaker, Speaker::System) && msg.metadata.is_context_only()
+                })
+                .map(|msg| msg.content.clone())
+                .collect()
+        };
+        assert_eq!(summaries(&dialogue, 1), vec!["Summary of turn 1"]);
+
+        dialogue.run("Second topic".to_string()).await.unwrap();
+        assert_eq!(summarizer.get_call_count(), 2);
+        assert_eq!(summaries(&dialogue, 2), vec!["Summary of turn 2"]);
+
+        // Participants receive the previous turn's summary as context
+        let agent1_turn2 = contents(&agent1.get_payloads()[1]);
+        assert!(agent1_turn2.contains(&"Summary of turn 1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_debate_second_round_receives_first_round_positions() {
+        use crate::agent::persona::Persona;
+
+        let persona = |name: &str| Persona {
+            name: name.to_string(),
+            role: "Debater".to_string(),
+            background: "Test debater".to_string(),
+            communication_style: "Direct".to_string(),
+            visual_identity: None,
+            capabilities: None,
+            priority: None,
+        };
+
+        let alice = MockAgent::new(
+            "Alice",
+            vec!["Alice position".to_string(), "Alice rebuttal".to_string()],
+        );
+        let bob = MockAgent::new(
+            "Bob",
+            vec!["Bob position".to_string(), "Bob rebuttal".to_string()],
+        );
+
+        let mut dialogue = Dialogue::debate(2);
+        dialogue
+            .add_participant(persona("Alice"), alice.clone())
+            .add_participant(persona("Bob"), bob.clone());
+
+        let turns = dialogue.run("Tabs or spaces?".to_string()).await.unwrap();
+
+        assert_eq!(turns.len(), 4);
+        assert_eq!(alice.get_call_count(), 2);
+        assert_eq!(bob.get_call_count(), 2);
+        assert_eq!(dialogue.current_turn(), 2);
+
+        let contents = |payload: &Payload| -> Vec<(String, String)> {
+            payload
+                .to_messages()
+                .into_iter()
+                .map(|msg| (msg.speaker.name().to_string(), msg.content))
+                .collect()
+        };
+
+        // Round 1: only the topic, no positions yet
+        let alice_round1 = contents(&alice.get_payloads()[0]);
+        assert!(
+            alice_round1
+                .iter()
+                .any(|(_, c)| c.contains("Tabs or spaces?"))
+        );
+        assert!(!alice_round1.iter().any(|(name, _)| name == "Bob"));
+
+        // Round 2: each debater sees the other's round-1 position, not their own
+        let alice_round2 = contents(&alice.get_payloads()[1]);
+        assert!(alice_round2.contains(&("Bob".to_string(), "Bob position".to_string())));
+        assert!(!alice_round2.iter().any(|(name, _)| name == "Alice"));
+
+        let bob_round2 = contents(&bob.get_payloads()[1]);
+        assert!(bob_round2.contains(&("Alice".to_string(), "Alice position".to_string())));
+        assert!(!bob_round2.iter().any(|(name, _)| name == "Bob"));
+    }
+
     #[tokio::test]
     async fn test_dialogue_with_no_participants() {
         let mut dialogue = Dialogue::broadcast();
@@ -2765,6 +3855,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue.add_participant(persona, MockAgent::new("Agent1", vec!["Hello".to_string()]));
@@ -2789,6 +3880,7 @@ mod tests {
             communication_style: "Concise".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -2798,6 +3890,7 @@ mod tests {
             communication_style: "Formal".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona3 = Persona {
@@ -2807,6 +3900,7 @@ mod tests {
             communication_style: "Professional".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         // Create agents with distinct responses so we can track the chain
@@ -2865,6 +3959,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -2874,6 +3969,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -2900,12 +3996,119 @@ mod tests {
         assert_eq!(second.speaker.name(), "Step2");
         assert_eq!(second.content, "S2 output");
 
-        assert!(session.next_turn().await.is_none());
+        assert!(session.next_turn().await.is_none());
+
+        assert_eq!(dialogue.history().len(), 3);
+        assert_eq!(dialogue.history()[0].speaker.name(), "System");
+        assert_eq!(dialogue.history()[1].speaker.name(), "Step1");
+        assert_eq!(dialogue.history()[2].speaker.name(), "Step2");
+    }
+
+    fn stage_persona(name: &str) -> crate::agent::persona::Persona {
+        crate::agent::persona::Persona {
+            name: name.to_string(),
+            role: "Stage".to_string(),
+            background: "Pipeline stage".to_string(),
+            communication_style: "Direct".to_string(),
+            visual_identity: None,
+            capabilities: None,
+            priority: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_partial_session_stops_at_max_turns() {
+        let third = MockAgent::new("Step3", vec!["S3 output".to_string()]);
+
+        let mut dialogue = Dialogue::sequential();
+        dialogue
+            .add_participant(
+                stage_persona("Step1"),
+                MockAgent::new("Step1", vec!["S1 output".to_string()]),
+            )
+            .add_participant(
+                stage_persona("Step2"),
+                MockAgent::new("Step2", vec!["S2 output".to_string()]),
+            )
+            .add_participant(stage_persona("Step3"), third.clone())
+            .with_max_turns(2);
+
+        let mut session = dialogue.partial_session("Initial".to_string());
+        let mut yielded = Vec::new();
+        while let Some(turn) = session.next_turn().await {
+            yielded.push(turn.unwrap().content);
+        }
+
+        assert_eq!(yielded, vec!["S1 output", "S2 output"]);
+        assert!(session.next_turn().await.is_none());
+        assert_eq!(third.get_call_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sequential_pipeline_only_passes_previous_output() {
+        let contents = |payload: &Payload| -> Vec<String> {
+            payload
+                .to_messages()
+                .into_iter()
+                .map(|msg| msg.content)
+                .collect()
+        };
+
+        for use_session in [false, true] {
+            let first = MockAgent::new("Step1", vec!["S1 output".to_string()]);
+            let second = MockAgent::new("Step2", vec!["S2 output".to_string()]);
+            let third = MockAgent::new("Step3", vec!["S3 output".to_string()]);
+
+            let mut dialogue = Dialogue::sequential();
+            dialogue
+                .add_participant(stage_persona("Step1"), first.clone())
+                .add_participant(stage_persona("Step2"), second.clone())
+                .add_participant(stage_persona("Step3"), third.clone())
+                .with_sequential_mode(SequentialMode::PipelineOnly);
+
+            if use_session {
+                let mut session = dialogue.partial_session("Initial".to_string());
+                while let Some(turn) = session.next_turn().await {
+                    turn.unwrap();
+                }
+            } else {
+                let turns = dialogue.run("Initial".to_string()).await.unwrap();
+                assert_eq!(turns[0].content, "S3 output");
+            }
+
+            assert!(first.get_payloads()[0].to_text().contains("Initial"));
+            assert_eq!(contents(&second.get_payloads()[0]), vec!["S1 output"]);
+            assert_eq!(contents(&third.get_payloads()[0]), vec!["S2 output"]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_partial_session_stops_when_predicate_matches() {
+        let third = MockAgent::new("Step3", vec!["I disagree".to_string()]);
 
-        assert_eq!(dialogue.history().len(), 3);
-        assert_eq!(dialogue.history()[0].speaker.name(), "System");
-        assert_eq!(dialogue.history()[1].speaker.name(), "Step1");
-        assert_eq!(dialogue.history()[2].speaker.name(), "Step2");
+        let mut dialogue = Dialogue::sequential();
+        dialogue
+            .add_participant(
+                stage_persona("Step1"),
+                MockAgent::new("Step1", vec!["I agree".to_string()]),
+            )
+            .add_participant(
+                stage_persona("Step2"),
+                MockAgent::new("Step2", vec!["I agree too".to_string()]),
+            )
+            .add_participant(stage_persona("Step3"), third.clone())
+            .with_stop_when(Arc::new(|turns: &[DialogueTurn]| {
+                turns.len() >= 2 && turns.iter().all(|t| t.content.contains("I agree"))
+            }));
+
+        let mut session = dialogue.partial_session("Do we agree?".to_string());
+        let mut yielded = Vec::new();
+        while let Some(turn) = session.next_turn().await {
+            yielded.push(turn.unwrap().content);
+        }
+
+        assert_eq!(yielded, vec!["I agree", "I agree too"]);
+        assert_eq!(third.get_call_count(), 0);
     }
 
     #[derive(Clone)]
@@ -2939,6 +4142,58 @@ mod tests {
         }
     }
 
+    /// Mock agent that tracks how many agents sharing its gauge run at once
+    #[derive(Clone)]
+    struct GaugeAgent {
+        in_flight: Arc<std::sync::atomic::AtomicUsize>,
+        peak: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Agent for GaugeAgent {
+        type Output = String;
+        type Expertise = &'static str;
+
+        fn expertise(&self) -> &&'static str {
+            const EXPERTISE: &str = "Agent that records concurrent executions";
+            &EXPERTISE
+        }
+
+        async fn execute(&self, _intent: Payload) -> Result<Self::Output, AgentError> {
+            use std::sync::atomic::Ordering;
+
+            let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(running, Ordering::SeqCst);
+            sleep(Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok("done".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_respects_max_concurrency() {
+        let gauge = GaugeAgent {
+            in_flight: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+            peak: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+        };
+
+        let mut dialogue = Dialogue::broadcast();
+        for name in ["A", "B", "C", "D"] {
+            dialogue.add_participant(stage_persona(name), gauge.clone());
+        }
+        dialogue.with_max_concurrency(2);
+
+        let mut session = dialogue.partial_session("Go".to_string());
+        let mut yielded = 0;
+        while let Some(turn) = session.next_turn().await {
+            turn.unwrap();
+            yielded += 1;
+        }
+
+        assert_eq!(yielded, 4);
+        assert_eq!(gauge.peak.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
     #[tokio::test]
     async fn test_partial_session_broadcast_streams_responses() {
         use crate::agent::persona::Persona;
@@ -2952,6 +4207,7 @@ mod tests {
             communication_style: "Snappy".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let slow = Persona {
@@ -2961,6 +4217,7 @@ mod tests {
             communication_style: "Measured".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -2997,6 +4254,7 @@ mod tests {
             communication_style: "Measured".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let fast = Persona {
@@ -3006,6 +4264,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -3026,6 +4285,49 @@ mod tests {
         assert!(session.next_turn().await.is_none());
     }
 
+    #[tokio::test]
+    async fn test_partial_session_broadcast_priority_order() {
+        use crate::agent::persona::Persona;
+
+        let mut dialogue = Dialogue::broadcast();
+        dialogue
+            // Finishes first, but has no priority
+            .add_participant(
+                Persona::new("Member", "Team member"),
+                DelayAgent::new("Member", 10),
+            )
+            // Finishes second with a low priority
+            .add_participant(
+                Persona::new("Reviewer", "Reviewer").with_priority(1),
+                DelayAgent::new("Reviewer", 30),
+            )
+            // Finishes last, but leads
+            .add_participant(
+                Persona::new("Lead", "Tech lead").with_priority(10),
+                DelayAgent::new("Lead", 60),
+            );
+
+        let mut session =
+            dialogue.partial_session_with_order("Hello".to_string(), BroadcastOrder::Priority);
+
+        let mut speakers = Vec::new();
+        while let Some(turn) = session.next_turn().await {
+            speakers.push(turn.unwrap().speaker.name().to_string());
+        }
+
+        assert_eq!(speakers, vec!["Lead", "Reviewer", "Member"]);
+
+        // The store records responses in yield order
+        let stored: Vec<_> = dialogue
+            .message_store()
+            .all_messages()
+            .into_iter()
+            .filter(|msg| matches!(msg.speaker, Speaker::Agent { .. }))
+            .map(|msg| msg.speaker.name().to_string())
+            .collect();
+        assert_eq!(stored, vec!["Lead", "Reviewer", "Member"]);
+    }
+
     #[tokio::test]
     async fn test_from_persona_team_broadcast() {
         use crate::agent::persona::{Persona, PersonaTeam};
@@ -3038,6 +4340,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         });
         team.add_persona(Persona {
             name: "Bob".to_string(),
@@ -3046,6 +4349,7 @@ mod tests {
             communication_style: "User-focused".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         });
         team.execution_strategy =
             Some(ExecutionModel::OrderedBroadcast(BroadcastOrder::Completion));
@@ -3074,6 +4378,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         });
         team.add_persona(Persona {
             name: "Second".to_string(),
@@ -3082,6 +4387,7 @@ mod tests {
             communication_style: "Creative".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         });
         team.execution_strategy = Some(ExecutionModel::OrderedSequential(SequentialOrder::AsAdded));
 
@@ -3106,6 +4412,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
         dialogue.add_participant(
             initial_persona,
@@ -3121,6 +4428,7 @@ mod tests {
             communication_style: "Authoritative".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let llm = MockAgent::new("ExpertLLM", vec!["Expert response".to_string()]);
@@ -3149,6 +4457,7 @@ mod tests {
             communication_style: "Thorough".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         });
 
         // Save
@@ -3178,6 +4487,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
         let persona2 = Persona {
             name: "Agent2".to_string(),
@@ -3186,6 +4496,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
         let persona3 = Persona {
             name: "Agent3".to_string(),
@@ -3194,6 +4505,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         // Add 3 participants
@@ -3226,6 +4538,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
         dialogue.add_participant(persona, MockAgent::new("Agent1", vec!["R1".to_string()]));
 
@@ -3251,6 +4564,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -3260,6 +4574,7 @@ mod tests {
             communication_style: "User-focused".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let blueprint = DialogueBlueprint {
@@ -3321,6 +4636,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
         let core2 = Persona {
             name: "CoreMember2".to_string(),
@@ -3329,6 +4645,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue.add_participant(
@@ -3349,6 +4666,7 @@ mod tests {
             communication_style: "Expert".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
         let guest_llm = MockAgent::new("Guest", vec!["Guest insight".to_string()]);
         dialogue.add_participant(guest, guest_llm);
@@ -3390,6 +4708,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
         dialogue.add_participant(
             persona1.clone(),
@@ -3404,6 +4723,7 @@ mod tests {
             communication_style: "User-focused".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
         dialogue.add_participant(
             persona2.clone(),
@@ -3418,6 +4738,7 @@ mod tests {
             communication_style: "Strategic".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
         dialogue.add_participant(
             persona3.clone(),
@@ -3470,6 +4791,7 @@ mod tests {
             communication_style: "Technical and precise".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue.add_participant(
@@ -3530,6 +4852,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue.add_participant(
@@ -3558,6 +4881,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -3567,6 +4891,7 @@ mod tests {
             communication_style: "Concise".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -3647,6 +4972,7 @@ mod tests {
             communication_style: "Fast".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -3656,6 +4982,7 @@ mod tests {
             communication_style: "Thorough".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -3691,6 +5018,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue.add_participant(
@@ -3726,6 +5054,106 @@ mod tests {
         assert_eq!(dialogue2.history().len(), 4);
     }
 
+    fn windowed_history() -> Vec<DialogueTurn> {
+        vec![
+            DialogueTurn {
+                speaker: Speaker::System,
+                content: "Project kickoff".to_string(),
+            },
+            DialogueTurn {
+                speaker: Speaker::agent("Alice", "PM"),
+                content: "First idea".to_string(),
+            },
+            DialogueTurn {
+                speaker: Speaker::agent("Bob", "Engineer"),
+                content: "Second idea".to_string(),
+            },
+            DialogueTurn {
+                speaker: Speaker::System,
+                content: "Round two".to_string(),
+            },
+            DialogueTurn {
+                speaker: Speaker::agent("Alice", "PM"),
+                content: "Third idea".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_history_window_last_n_keeps_system_messages() {
+        let formatted =
+            format_dialogue_history_as_text(&windowed_history(), Some(HistoryWindow::LastN(1)));
+
+        assert!(formatted.contains("Project kickoff"));
+        assert!(formatted.contains("Round two"));
+        assert!(formatted.contains("Third idea"));
+        assert!(!formatted.contains("First idea"));
+        assert!(!formatted.contains("Second idea"));
+        assert_eq!(
+            formatted
+                .matches("[...earlier conversation omitted...]")
+                .count(),
+            1
+        );
+
+        // The marker sits right before the first retained conversational turn
+        let marker = formatted
+            .find("[...earlier conversation omitted...]")
+            .unwrap();
+        assert!(formatted.find("Round two").unwrap() < marker);
+        assert!(marker < formatted.find("Third idea").unwrap());
+    }
+
+    #[test]
+    fn test_history_window_max_chars_keeps_system_messages() {
+        // System messages use 24 chars; each idea is 10-11 chars, so two fit in 48.
+        let formatted =
+            format_dialogue_history_as_text(&windowed_history(), Some(HistoryWindow::MaxChars(48)));
+
+        assert!(formatted.contains("Project kickoff"));
+        assert!(formatted.contains("Round two"));
+        assert!(formatted.contains("Second idea"));
+        assert!(formatted.contains("Third idea"));
+        assert!(!formatted.contains("First idea"));
+        assert!(formatted.contains("[...earlier conversation omitted...]"));
+
+        // A budget smaller than the System messages still keeps them
+        let formatted =
+            format_dialogue_history_as_text(&windowed_history(), Some(HistoryWindow::MaxChars(5)));
+        assert!(formatted.contains("Project kickoff"));
+        assert!(formatted.contains("Round two"));
+        assert!(!formatted.contains("idea"));
+        assert!(formatted.contains("[...earlier conversation omitted...]"));
+    }
+
+    #[test]
+    fn test_history_window_no_truncation_omits_marker() {
+        let formatted =
+            format_dialogue_history_as_text(&windowed_history(), Some(HistoryWindow::LastN(10)));
+        assert!(formatted.contains("First idea"));
+        assert!(!formatted.contains("omitted"));
+
+        let unbounded = format_dialogue_history_as_text(&windowed_history(), None);
+        assert_eq!(formatted, unbounded);
+    }
+
+    #[test]
+    fn test_history_window_applies_in_either_builder_order() {
+        let window_first = Dialogue::broadcast()
+            .with_history_window(HistoryWindow::LastN(1))
+            .with_history_as_system_prompt(windowed_history());
+        let window_last = Dialogue::broadcast()
+            .with_history_as_system_prompt(windowed_history())
+            .with_history_window(HistoryWindow::LastN(1));
+
+        let first = &window_first.context.as_ref().unwrap().additional_context;
+        let last = &window_last.context.as_ref().unwrap().additional_context;
+        assert_eq!(first, last);
+        assert_eq!(last.len(), 1);
+        assert!(!last[0].contains("First idea"));
+        assert!(last[0].contains("[...earlier conversation omitted...]"));
+    }
+
     #[tokio::test]
     async fn test_save_and_load_history() {
         use crate::agent::persona::Persona;
@@ -3740,6 +5168,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue.add_participant(
@@ -3780,6 +5209,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         session1.add_participant(
@@ -3894,6 +5324,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona_b = Persona {
@@ -3903,6 +5334,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -4056,6 +5488,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona_b = Persona {
@@ -4065,6 +5498,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         // Create Chat agents WITH history (as Dialogue does)
@@ -4087,6 +5521,7 @@ mod tests {
             agent: Arc::new(*chat_a),
             joining_strategy: None,
             has_sent_once: false,
+            preamble: None,
         });
 
         dialogue.participants.push(Participant {
@@ -4094,6 +5529,7 @@ mod tests {
             agent: Arc::new(*chat_b),
             joining_strategy: None,
             has_sent_once: false,
+            preamble: None,
         });
 
         // Turn 1
@@ -4154,6 +5590,7 @@ mod tests {
                 communication_style: "Direct".to_string(),
                 visual_identity: None,
                 capabilities: None,
+                priority: None,
             },
             MockAgent::new("Agent1", vec!["Response from Agent1".to_string()]),
         );
@@ -4165,6 +5602,7 @@ mod tests {
                 communication_style: "Direct".to_string(),
                 visual_identity: None,
                 capabilities: None,
+                priority: None,
             },
             MockAgent::new("Agent2", vec!["Response from Agent2".to_string()]),
         );
@@ -4217,6 +5655,7 @@ mod tests {
                 communication_style: "Analytical".to_string(),
                 visual_identity: None,
                 capabilities: None,
+                priority: None,
             },
             MockAgent::new("Agent1", vec!["Analysis result".to_string()]),
         );
@@ -4228,6 +5667,7 @@ mod tests {
                 communication_style: "Critical".to_string(),
                 visual_identity: None,
                 capabilities: None,
+                priority: None,
             },
             MockAgent::new("Agent2", vec!["Review complete".to_string()]),
         );
@@ -4298,6 +5738,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue.add_participant(
@@ -4398,6 +5839,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona_b = Persona {
@@ -4407,6 +5849,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -4524,6 +5967,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         // Test with run()
@@ -4637,6 +6081,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona_b = Persona {
@@ -4646,6 +6091,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_a_responses = Arc::new(Mutex::new(Vec::new()));
@@ -4810,6 +6256,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona_b = Persona {
@@ -4819,6 +6266,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_a_responses = Arc::new(Mutex::new(Vec::new()));
@@ -4985,6 +6433,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona_b = Persona {
@@ -4994,6 +6443,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona_c = Persona {
@@ -5003,6 +6453,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_a_responses = Arc::new(Mutex::new(Vec::new()));
@@ -5179,6 +6630,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona_b = Persona {
@@ -5188,6 +6640,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona_c = Persona {
@@ -5197,6 +6650,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_a_responses = Arc::new(Mutex::new(Vec::new()));
@@ -5405,6 +6859,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona_b = Persona {
@@ -5414,6 +6869,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let payloads = Arc::new(Mutex::new(Vec::new()));
@@ -5489,6 +6945,7 @@ mod tests {
                 communication_style: "Direct".to_string(),
                 visual_identity: None,
                 capabilities: None,
+                priority: None,
             },
             Persona {
                 name: "AgentB".to_string(),
@@ -5497,6 +6954,7 @@ mod tests {
                 communication_style: "Direct".to_string(),
                 visual_identity: None,
                 capabilities: None,
+                priority: None,
             },
             Persona {
                 name: "AgentC".to_string(),
@@ -5505,6 +6963,7 @@ mod tests {
                 communication_style: "Direct".to_string(),
                 visual_identity: None,
                 capabilities: None,
+                priority: None,
             },
         ];
 
@@ -5569,6 +7028,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let mut dialogue =
@@ -5640,6 +7100,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let received_payloads = Arc::new(Mutex::new(Vec::new()));
@@ -5876,6 +7337,66 @@ mod tests {
         assert!(mentions.contains(&"太郎 山田"));
     }
 
+    fn mention_dialogue() -> Dialogue {
+        let mut dialogue = Dialogue::mentioned();
+        for name in ["Alice", "Bob", "Charlie"] {
+            dialogue.add_participant(
+                stage_persona(name),
+                MockAgent::new(name, vec![format!("{}'s response", name)]),
+            );
+        }
+        dialogue
+    }
+
+    #[test]
+    fn test_resolve_mentions_exact_matches() {
+        let dialogue = mention_dialogue();
+
+        let result = dialogue.resolve_mentions("@Bob @Alice what do you think? @Bob?");
+
+        assert_eq!(result.matched, vec!["Alice", "Bob"]);
+        assert!(result.unmatched.is_empty());
+        assert!(result.suggestions.is_empty());
+        assert!(!result.has_unmatched());
+    }
+
+    #[test]
+    fn test_resolve_mentions_unknown_with_suggestion() {
+        let dialogue = mention_dialogue();
+
+        let result = dialogue.resolve_mentions("@Alic @charlie @Chralie @Zed please review");
+
+        assert!(result.matched.is_empty());
+        assert_eq!(result.unmatched, vec!["Alic", "charlie", "Chralie", "Zed"]);
+        assert_eq!(
+            result.suggestions,
+            vec![
+                MentionSuggestion {
+                    mention: "Alic".to_string(),
+                    participant: "Alice".to_string(),
+                },
+                MentionSuggestion {
+                    mention: "charlie".to_string(),
+                    participant: "Charlie".to_string(),
+                },
+                MentionSuggestion {
+                    mention: "Chralie".to_string(),
+                    participant: "Charlie".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_mentions_no_mentions() {
+        let dialogue = mention_dialogue();
+
+        let result = dialogue.resolve_mentions("What does everyone think?");
+
+        assert!(result.is_empty());
+        assert_eq!(result, MentionResult::default());
+    }
+
     #[tokio::test]
     async fn test_participants_method() {
         use crate::agent::persona::Persona;
@@ -5889,6 +7410,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -5898,6 +7420,7 @@ mod tests {
             communication_style: "Visual".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -5923,6 +7446,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -5932,6 +7456,7 @@ mod tests {
             communication_style: "Visual".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona3 = Persona {
@@ -5941,6 +7466,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -5984,6 +7510,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -5993,6 +7520,7 @@ mod tests {
             communication_style: "Visual".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -6018,6 +7546,98 @@ mod tests {
         assert!(responders.contains(&"Bob"));
     }
 
+    fn capable_persona(name: &str, capabilities: &[&str]) -> crate::agent::persona::Persona {
+        crate::agent::persona::Persona {
+            name: name.to_string(),
+            role: "Engineer".to_string(),
+            background: "Software engineer".to_string(),
+            communication_style: "Technical".to_string(),
+            visual_identity: None,
+            capabilities: Some(
+                capabilities
+                    .iter()
+                    .map(|cap| crate::agent::Capability::new(*cap))
+                    .collect(),
+            ),
+            priority: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_capability_mode_only_capable_participants_respond() {
+        let mut dialogue = Dialogue::capability(["sql", "performance"]);
+
+        let dba = MockAgent::new("Dana", vec!["Add an index".to_string()]);
+        let backend = MockAgent::new("Ben", vec!["Rewrite the query".to_string()]);
+        let frontend = MockAgent::new("Fay", vec!["Looks fine to me".to_string()]);
+        let frontend_handle = frontend.clone();
+
+        dialogue
+            .add_participant(capable_persona("Dana", &["sql", "performance"]), dba)
+            .add_participant(
+                capable_persona("Ben", &["rust", "performance", "sql"]),
+                backend,
+            )
+            .add_participant(capable_persona("Fay", &["css", "performance"]), frontend);
+
+        let turns = dialogue.run("Why is this query slow?").await.unwrap();
+
+        // Fay lacks "sql", so only Dana and Ben respond
+        assert_eq!(turns.len(), 2);
+        let responders: Vec<&str> = turns.iter().map(|t| t.speaker.name()).collect();
+        assert!(responders.contains(&"Dana"));
+        assert!(responders.contains(&"Ben"));
+        assert_eq!(frontend_handle.get_call_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_capability_mode_fallback_to_broadcast() {
+        let mut dialogue = Dialogue::capability(["kubernetes"]);
+
+        dialogue
+            .add_participant(
+                capable_persona("Dana", &["sql"]),
+                MockAgent::new("Dana", vec!["Dana's response".to_string()]),
+            )
+            .add_participant(
+                capable_persona("Fay", &["css"]),
+                MockAgent::new("Fay", vec!["Fay's response".to_string()]),
+            );
+
+        // Nobody has the capability, so everyone responds
+        let turns = dialogue.run("How should we deploy this?").await.unwrap();
+
+        assert_eq!(turns.len(), 2);
+        let responders: Vec<&str> = turns.iter().map(|t| t.speaker.name()).collect();
+        assert!(responders.contains(&"Dana"));
+        assert!(responders.contains(&"Fay"));
+    }
+
+    #[tokio::test]
+    async fn test_capability_mode_respects_policy() {
+        use crate::agent::Capability;
+
+        let mut dialogue = Dialogue::capability(["sql"]);
+        dialogue
+            .with_context(DialogueContext::new().with_policy("Ben", vec![Capability::new("rust")]));
+
+        dialogue
+            .add_participant(
+                capable_persona("Dana", &["sql"]),
+                MockAgent::new("Dana", vec!["Dana's response".to_string()]),
+            )
+            .add_participant(
+                capable_persona("Ben", &["rust", "sql"]),
+                MockAgent::new("Ben", vec!["Ben's response".to_string()]),
+            );
+
+        // Ben's persona has "sql", but the policy only allows "rust"
+        let turns = dialogue.run("Review this migration").await.unwrap();
+
+        assert_eq!(turns.len(), 1);
+        assert_eq!(turns[0].speaker.name(), "Dana");
+    }
+
     #[tokio::test]
     async fn test_mentioned_mode_single_mention() {
         use crate::agent::persona::Persona;
@@ -6031,6 +7651,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -6040,6 +7661,7 @@ mod tests {
             communication_style: "Visual".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -6074,6 +7696,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -6083,6 +7706,7 @@ mod tests {
             communication_style: "Visual".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona3 = Persona {
@@ -6092,6 +7716,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -6172,6 +7797,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -6181,6 +7807,7 @@ mod tests {
             communication_style: "Visual".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -6220,6 +7847,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -6229,6 +7857,7 @@ mod tests {
             communication_style: "Visual".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona3 = Persona {
@@ -6238,6 +7867,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -6281,6 +7911,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -6290,6 +7921,7 @@ mod tests {
             communication_style: "Visual".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona3 = Persona {
@@ -6299,6 +7931,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -6366,6 +7999,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue.add_participant(
@@ -6423,6 +8057,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let bob_persona = Persona {
@@ -6432,6 +8067,7 @@ mod tests {
             communication_style: "Visual".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let charlie_persona = Persona {
@@ -6441,6 +8077,7 @@ mod tests {
             communication_style: "Detail-oriented".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         dialogue
@@ -6540,6 +8177,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent = RecordingAgent::new("Agent1", "I can help with that");
@@ -6617,6 +8255,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -6626,6 +8265,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent1 = RecordingAgent::new("Agent1", "Analysis done");
@@ -6696,6 +8336,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -6705,6 +8346,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent1 = RecordingAgent::new("Alice", "I'll handle it");
@@ -6762,6 +8404,207 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_broadcast_does_not_echo_own_messages() {
+        use crate::agent::persona::Persona;
+
+        let persona = |name: &str| Persona {
+            name: name.to_string(),
+            role: "Engineer".to_string(),
+            background: "Test agent".to_string(),
+            communication_style: "Direct".to_string(),
+            visual_identity: None,
+            capabilities: None,
+            priority: None,
+        };
+
+        let alice = RecordingAgent::new("Alice", "Alice turn output");
+        let bob = RecordingAgent::new("Bob", "Bob turn output");
+
+        let mut dialogue = Dialogue::broadcast();
+        dialogue
+            .add_participant(persona("Alice"), alice.clone())
+            .add_participant(persona("Bob"), bob.clone());
+
+        dialogue.run("Turn 1 topic").await.unwrap();
+        dialogue.run("Turn 2 topic").await.unwrap();
+
+        let alice_received = alice.get_received_payloads();
+        assert_eq!(alice_received.len(), 2);
+
+        let turn2_messages = alice_received[1].to_messages();
+        assert!(
+            turn2_messages
+                .iter()
+                .any(|m| m.speaker.name() == "Bob" && m.content == "Bob turn output"),
+            "Alice should see Bob's turn 1 message"
+        );
+        assert!(
+            !turn2_messages
+                .iter()
+                .any(|m| m.speaker.name() == "Alice" || m.content == "Alice turn output"),
+            "Alice should not be sent her own turn 1 message"
+        );
+        assert!(turn2_messages.iter().any(|m| m.content == "Turn 2 topic"));
+    }
+
+    #[tokio::test]
+    async fn test_to_mermaid_sequence_lists_participants_and_messages() {
+        let alice = RecordingAgent::new("Alice", "Alice says hi; see #1");
+        let bob = RecordingAgent::new("Bob", "Bob output");
+
+        let mut dialogue = Dialogue::broadcast();
+        dialogue
+            .add_participant(stage_persona("Alice"), alice)
+            .add_participant(stage_persona("Bob"), bob);
+
+        dialogue.run("Turn 1 topic").await.unwrap();
+        dialogue.run("Turn 2 topic").await.unwrap();
+
+        let diagram = dialogue.to_mermaid_sequence();
+        let lines: Vec<&str> = diagram.lines().collect();
+
+        assert_eq!(lines[0], "sequenceDiagram");
+        assert!(lines.contains(&"    participant p0 as Alice (Stage)"));
+        assert!(lines.contains(&"    participant p1 as Bob (Stage)"));
+        assert_eq!(lines.iter().filter(|l| l.contains("->>")).count(), 4);
+        assert!(lines.contains(&"    Note over p0,p1: Turn 2"));
+        assert!(lines.contains(&"    Note over p0,p1: System: Turn 1 topic"));
+        assert!(
+            diagram.contains("Alice says hi#59; see #35;1"),
+            "special characters should be escaped: {}",
+            diagram
+        );
+        assert_eq!(lines.iter().filter(|l| l.trim() == "end").count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_participant_preamble_is_private() {
+        let alice = RecordingAgent::new("Alice", "Alice output");
+        let bob = RecordingAgent::new("Bob", "Bob output");
+        let preamble = "Stay in character and be terse.";
+
+        let mut dialogue = Dialogue::broadcast();
+        dialogue
+            .add_participant_with_preamble(
+                stage_persona("Alice"),
+                alice.clone(),
+                preamble.to_string(),
+            )
+            .add_participant(stage_persona("Bob"), bob.clone());
+
+        dialogue.run("Discuss the plan").await.unwrap();
+
+        let alice_messages = alice.get_received_payloads()[0].to_messages();
+        assert!(
+            alice_messages
+                .iter()
+                .any(|m| m.speaker == Speaker::System && m.content == preamble),
+            "Alice should receive her preamble as a system message"
+        );
+
+        let bob_payload = &bob.get_received_payloads()[0];
+        assert!(
+            !bob_payload
+                .to_messages()
+                .iter()
+                .any(|m| m.content.contains(preamble)),
+            "Bob should not receive Alice's preamble"
+        );
+        assert!(!bob_payload.to_text().contains(preamble));
+
+        assert!(
+            !dialogue
+                .message_store
+                .all_messages()
+                .iter()
+                .any(|m| m.content.contains(preamble)),
+            "The preamble should not be stored as a shared message"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_context_block_threshold_formats_other_participants_messages() {
+        let alice = RecordingAgent::new("Alice", "Alice output");
+        let bob = RecordingAgent::new("Bob", "Bob output");
+
+        let mut dialogue = Dialogue::broadcast();
+        dialogue
+            .add_participant(stage_persona("Alice"), alice.clone())
+            .add_participant(stage_persona("Bob"), bob.clone())
+            .with_context_block_threshold(5);
+
+        dialogue.run("Turn 1 topic").await.unwrap();
+        dialogue.run("Turn 2 topic").await.unwrap();
+
+        let turn2_messages = alice.get_received_payloads()[1].to_messages();
+        assert!(
+            turn2_messages
+                .iter()
+                .any(|m| m.content == "### Bob (Stage)\n```\nBob output\n```"),
+            "Bob's reply exceeds the threshold and should be fenced"
+        );
+        assert!(turn2_messages.iter().any(|m| m.content == "Turn 2 topic"));
+        assert!(
+            dialogue.history().iter().any(|m| m.content == "Bob output"),
+            "Stored history keeps the original content"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reaction_strategy_custom_predicate() {
+        let agent = RecordingAgent::new("Agent1", "On it");
+
+        let mut dialogue = Dialogue::broadcast();
+        dialogue
+            .add_participant(stage_persona("Agent1"), agent.clone())
+            .with_reaction_strategy(ReactionStrategy::Custom(Arc::new(|messages| {
+                messages
+                    .iter()
+                    .any(|msg| msg.content.to_lowercase().contains("urgent"))
+            })));
+
+        // Turn 1: no keyword, so the message is only stored as context
+        let fyi_payload = Payload::from_messages(vec![PayloadMessage::new(
+            Speaker::user("Alice", "User"),
+            "FYI: the deploy window moved to Friday",
+        )]);
+        let turns = dialogue.run(fyi_payload).await.unwrap();
+        assert_eq!(turns.len(), 0, "Predicate should suppress the reaction");
+        assert_eq!(dialogue.history().len(), 1);
+        assert!(agent.get_received_payloads().is_empty());
+
+        // Turn 2: keyword present, so agents react and see the earlier context
+        let urgent_payload = Payload::from_messages(vec![PayloadMessage::new(
+            Speaker::user("Alice", "User"),
+            "Urgent: production is down",
+        )]);
+        let turns = dialogue.run(urgent_payload).await.unwrap();
+        assert_eq!(turns.len(), 1, "Predicate should trigger the reaction");
+        assert_eq!(turns[0].content, "On it");
+
+        let received = agent.get_received_payloads();
+        assert_eq!(received.len(), 1);
+        let contents: Vec<String> = received[0]
+            .to_messages()
+            .into_iter()
+            .map(|msg| msg.content)
+            .collect();
+        assert_eq!(
+            contents,
+            vec![
+                "FYI: the deploy window moved to Friday".to_string(),
+                "Urgent: production is down".to_string(),
+            ]
+        );
+
+        // Text-only payloads are evaluated as well
+        let turns = dialogue.run("nothing urgent here").await.unwrap();
+        assert_eq!(turns.len(), 1);
+        let turns = dialogue.run("just a note").await.unwrap();
+        assert_eq!(turns.len(), 0);
+    }
+
     #[tokio::test]
     async fn test_reaction_strategy_partial_session() {
         use crate::agent::dialogue::message::{MessageMetadata, MessageType};
@@ -6774,6 +8617,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent = RecordingAgent::new("Agent1", "Understood");
@@ -6834,6 +8678,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent = RecordingAgent::new("Agent1", "Got it");
@@ -6896,6 +8741,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         // Configure PersonaAgent before adding to dialogue
@@ -6940,6 +8786,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -6949,6 +8796,7 @@ mod tests {
             communication_style: "Creative".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent1 = MockAgent::new("Alice", vec!["Technical perspective".to_string()]);
@@ -6985,6 +8833,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -6994,6 +8843,7 @@ mod tests {
             communication_style: "Clear and concise".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent1 = MockAgent::new("Analyzer", vec!["Data shows trend X".to_string()]);
@@ -7027,6 +8877,7 @@ mod tests {
             communication_style: "Friendly".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent = MockAgent::new("Agent", vec!["Response 1".to_string()]);
@@ -7087,6 +8938,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let persona2 = Persona {
@@ -7096,6 +8948,7 @@ mod tests {
             communication_style: "Creative".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent1 = MockAgent::new("Alice", vec!["Alice's response".to_string()]);
@@ -7125,6 +8978,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent = MockAgent::new("Alice", vec!["Response".to_string()]);
@@ -7179,6 +9033,7 @@ mod tests {
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent = MockAgent::new("Alice", vec!["Response".to_string()]);
@@ -7214,6 +9069,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let bob = Persona {
@@ -7223,6 +9079,7 @@ mod tests {
             communication_style: "Creative".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -7252,6 +9109,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_carol = MockAgent::new("Carol", vec!["Carol's fresh perspective".to_string()]);
@@ -7345,6 +9203,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -7379,6 +9238,7 @@ mod tests {
             communication_style: "Curious".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_bob = MockAgent::new(
@@ -7473,6 +9333,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -7512,6 +9373,7 @@ mod tests {
             communication_style: "Focused".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_bob = MockAgent::new(
@@ -7619,6 +9481,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let bob = Persona {
@@ -7628,6 +9491,7 @@ mod tests {
             communication_style: "Creative".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -7669,6 +9533,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_carol = MockAgent::new(
@@ -7773,6 +9638,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let bob = Persona {
@@ -7782,6 +9648,7 @@ mod tests {
             communication_style: "Critical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -7831,6 +9698,7 @@ mod tests {
             communication_style: "Concise".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_carol = MockAgent::new(
@@ -7966,6 +9834,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let bob = Persona {
@@ -7975,6 +9844,7 @@ mod tests {
             communication_style: "Critical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -8027,6 +9897,7 @@ mod tests {
             communication_style: "Concise".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_carol = MockAgent::new(
@@ -8130,6 +10001,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let bob = Persona {
@@ -8139,6 +10011,7 @@ mod tests {
             communication_style: "Creative".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -8191,6 +10064,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_carol = MockAgent::new(
@@ -8311,6 +10185,7 @@ mod tests {
             communication_style: "Direct".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let bob = Persona {
@@ -8320,6 +10195,7 @@ mod tests {
             communication_style: "Creative".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let dave = Persona {
@@ -8329,6 +10205,7 @@ mod tests {
             communication_style: "Meticulous".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -8375,6 +10252,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_carol = MockAgent::new(
@@ -8525,6 +10403,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let bob = Persona {
@@ -8534,6 +10413,7 @@ mod tests {
             communication_style: "Critical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -8581,6 +10461,7 @@ mod tests {
             communication_style: "Concise".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_carol = MockAgent::new(
@@ -8718,6 +10599,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let bob = Persona {
@@ -8727,6 +10609,7 @@ mod tests {
             communication_style: "Critical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -8785,6 +10668,7 @@ mod tests {
             communication_style: "Concise".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_carol = MockAgent::new(
@@ -8942,6 +10826,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let bob = Persona {
@@ -8951,6 +10836,7 @@ mod tests {
             communication_style: "Critical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -9002,6 +10888,7 @@ mod tests {
             communication_style: "Concise".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_carol = MockAgent::new(
@@ -9119,6 +11006,7 @@ mod tests {
             communication_style: "Analytical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let bob = Persona {
@@ -9128,6 +11016,7 @@ mod tests {
             communication_style: "Critical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_alice = MockAgent::new(
@@ -9182,6 +11071,7 @@ mod tests {
             communication_style: "Concise".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let agent_carol = MockAgent::new(
@@ -7,7 +7,9 @@
 use super::super::{Agent, AgentError, Payload, PayloadMessage};
 use super::message::{DialogueMessage, MessageMetadata, MessageOrigin, Speaker};
 use super::state::SessionState;
-use super::{BroadcastOrder, Dialogue, DialogueTurn, ExecutionModel, ParticipantInfo};
+use super::{
+    BroadcastOrder, Dialogue, DialogueTurn, ExecutionModel, ParticipantInfo, SequentialMode,
+};
 use crate::prompt::ToPrompt;
 use tracing::{error, info};
 
@@ -16,6 +18,8 @@ pub struct DialogueSession<'a> {
     pub(super) dialogue: &'a mut Dialogue,
     pub(super) state: SessionState,
     pub(super) model: ExecutionModel,
+    /// Turns yielded so far, checked against the dialogue's stop conditions.
+    pub(super) yielded: Vec<DialogueTurn>,
 }
 
 impl<'a> DialogueSession<'a> {
@@ -26,8 +30,47 @@ impl<'a> DialogueSession<'a> {
 
     /// Retrieves the next available dialogue turn.
     ///
-    /// Returns `None` when the session is complete.
+    /// Returns `None` when the session is complete, or once a stop condition
+    /// configured via `Dialogue::with_max_turns()` or `Dialogue::with_stop_when()`
+    /// has fired.
     pub async fn next_turn(&mut self) -> Option<Result<DialogueTurn, AgentError>> {
+        if self.stop_condition_met() {
+            let turn = match &self.state {
+                SessionState::Broadcast(state) => state.current_turn,
+                SessionState::Sequential { current_turn, .. } => *current_turn,
+                SessionState::Failed(_) | SessionState::Completed => return None,
+            };
+            info!(
+                target = "llm_toolkit::dialogue",
+                mode = ?self.model,
+                turn,
+                yielded_turns = self.yielded.len(),
+                event = "dialogue_session_stopped"
+            );
+            return self.complete(turn).await;
+        }
+
+        let result = self.next_turn_inner().await;
+        if let Some(Ok(turn)) = &result {
+            self.yielded.push(turn.clone());
+        }
+        result
+    }
+
+    /// Returns true if the turn cap is reached or the stop predicate fires.
+    fn stop_condition_met(&self) -> bool {
+        if let Some(max_turns) = self.dialogue.max_turns
+            && self.yielded.len() >= max_turns
+        {
+            return true;
+        }
+        match &self.dialogue.stop_when {
+            Some(predicate) => !self.yielded.is_empty() && predicate(&self.yielded),
+            None => false,
+        }
+    }
+
+    async fn next_turn_inner(&mut self) -> Option<Result<DialogueTurn, AgentError>> {
         let participant_total = self.dialogue.participants.len();
 
         loop {
@@ -85,7 +128,7 @@ impl<'a> DialogueSession<'a> {
                                     state.record_result(idx, participant_name, result);
                                     continue;
                                 }
-                                BroadcastOrder::ParticipantOrder => {
+                                BroadcastOrder::ParticipantOrder | BroadcastOrder::Priority => {
                                     match &result {
                                         Ok(_) => {
                                             info!(
@@ -177,8 +220,7 @@ impl<'a> DialogueSession<'a> {
                             if let Some(result) = state.try_emit(self.dialogue) {
                                 return Some(result);
                             }
-                            self.state = SessionState::Completed;
-                            return None;
+                            return self.complete(current_turn).await;
                         }
                     }
                 }
@@ -192,8 +234,8 @@ impl<'a> DialogueSession<'a> {
                     participants_info,
                 } => {
                     if sequence.is_empty() || *next_index >= sequence.len() {
-                        self.state = SessionState::Completed;
-                        return None;
+                        let turn = *current_turn;
+                        return self.complete(turn).await;
                     }
 
                     let sequence_position = *next_index;
@@ -238,8 +280,11 @@ impl<'a> DialogueSession<'a> {
                         self.dialogue.message_store.mark_as_sent_all_for(speaker);
                     }
 
+                    let mode = self.dialogue.sequential_mode;
                     let mut prev_messages = joining_history_context;
-                    prev_messages.append(prev_agent_outputs);
+                    if mode == SequentialMode::Cumulative || sequence_position == 0 {
+                        prev_messages.append(prev_agent_outputs);
+                    }
 
                     let mut response_payload = build_sequential_payload(
                         payload,
@@ -247,6 +292,7 @@ impl<'a> DialogueSession<'a> {
                         current_turn_outputs.as_slice(), // Always include current turn outputs (sequential chain requirement)
                         participants_info.as_slice(),
                         sequence_position,
+                        mode,
                     );
 
                     // Attach context if exists
@@ -256,7 +302,10 @@ impl<'a> DialogueSession<'a> {
 
                     let response_result = {
                         let participant = &self.dialogue.participants[participant_idx];
-                        participant.agent.execute(response_payload).await
+                        participant
+                            .agent
+                            .execute(participant.apply_preamble(response_payload))
+                            .await
                     };
 
                     return match response_result {
@@ -317,6 +366,26 @@ impl<'a> DialogueSession<'a> {
             }
         }
     }
+
+    /// Marks the session as completed and runs the dialogue's summarizer, if any.
+    ///
+    /// Returns `Some(Err(_))` only if the summarizer fails.
+    async fn complete(&mut self, turn: usize) -> Option<Result<DialogueTurn, AgentError>> {
+        self.state = SessionState::Completed;
+        match self.dialogue.summarize_turn(turn).await {
+            Ok(()) => None,
+            Err(err) => {
+                error!(
+                    target = "llm_toolkit::dialogue",
+                    mode = ?self.model,
+                    turn,
+                    error = %err,
+                    event = "dialogue_summary_failed"
+                );
+                Some(Err(err))
+            }
+        }
+    }
 }
 
 fn build_sequential_payload(
@@ -325,8 +394,14 @@ fn build_sequential_payload(
     current_turn_outputs: &[PayloadMessage],
     participants_info: &[ParticipantInfo],
     idx: usize,
+    mode: SequentialMode,
 ) -> Payload {
-    if idx == 0 {
+    if idx > 0 && mode == SequentialMode::PipelineOnly {
+        // Only the immediately preceding output (plus any joining history)
+        let mut messages = prev_agent_outputs.to_vec();
+        messages.extend(current_turn_outputs.last().cloned());
+        Payload::from_messages(messages).with_participants(participants_info.to_vec())
+    } else if idx == 0 {
         let mut payload = base_payload.clone();
 
         if !prev_agent_outputs.is_empty() {
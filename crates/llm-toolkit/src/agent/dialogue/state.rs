@@ -35,6 +35,9 @@ impl BroadcastState {
             BroadcastOrder::Explicit(_) => std::iter::repeat_with(|| None)
                 .take(participant_count)
                 .collect::<Vec<Option<Result<String, AgentError>>>>(),
+            BroadcastOrder::Priority => std::iter::repeat_with(|| None)
+                .take(participant_count)
+                .collect::<Vec<Option<Result<String, AgentError>>>>(),
         };
 
         Self {
@@ -100,6 +103,21 @@ impl BroadcastState {
                     self.buffered[idx] = Some(result);
                 }
             }
+            BroadcastOrder::Priority => {
+                if idx < self.buffered.len() {
+                    let content_len = result.as_ref().map(|s| s.len()).unwrap_or(0);
+                    trace!(
+                        target = "llm_toolkit::dialogue",
+                        participant = %participant_name,
+                        participant_index = idx,
+                        content_length = content_len,
+                        is_error = result.is_err(),
+                        turn = self.current_turn,
+                        "Recording result to buffered (Priority mode)"
+                    );
+                    self.buffered[idx] = Some(result);
+                }
+            }
         }
     }
 
@@ -144,130 +162,101 @@ impl BroadcastState {
                 }
             }
             BroadcastOrder::ParticipantOrder => {
-                let participant_total = dialogue.participants.len();
-
-                if self.next_emit >= participant_total {
+                if self.next_emit >= dialogue.participants.len() {
                     return None;
                 }
-
                 let idx = self.next_emit;
-                let slot_ready = self
-                    .buffered
-                    .get(idx)
-                    .and_then(|slot| slot.as_ref())
-                    .is_some();
-
-                if !slot_ready {
-                    return None;
-                }
-
-                let result = self.buffered[idx].take().expect("checked is_some");
-                self.next_emit += 1;
-
-                match result {
-                    Ok(content) => {
-                        let participant = &dialogue.participants[idx];
-                        let participant_name = participant.name().to_string();
-
-                        // Store in MessageStore
-                        let metadata =
-                            MessageMetadata::new().with_origin(MessageOrigin::AgentGenerated);
-                        let message = DialogueMessage::new(
-                            self.current_turn,
-                            Speaker::agent(
-                                participant_name.clone(),
-                                participant.persona.role.clone(),
-                            ),
-                            content.clone(),
-                        )
-                        .with_metadata(&metadata);
-                        dialogue.message_store.push(message);
-
-                        let turn = DialogueTurn {
-                            speaker: Speaker::agent(
-                                participant_name.clone(),
-                                participant.persona.role.clone(),
-                            ),
-                            content: content.clone(),
-                        };
-                        info!(
-                            target = "llm_toolkit::dialogue",
-                            mode = "broadcast_participant_order",
-                            participant = %participant_name,
-                            participant_index = idx,
-                            total_participants = participant_total,
-                            event = "dialogue_turn_emitted"
-                        );
-                        Some(Ok(turn))
-                    }
-                    Err(err) => Some(Err(err)),
-                }
+                self.emit_slot(dialogue, idx, "broadcast_participant_order")
             }
             BroadcastOrder::Explicit(_) => {
                 // For Explicit order mode, use participant order approach for now
-                let participant_total = dialogue.participants.len();
-
-                if self.next_emit >= participant_total {
+                if self.next_emit >= dialogue.participants.len() {
                     return None;
                 }
-
                 let idx = self.next_emit;
-                let slot_ready = self
-                    .buffered
-                    .get(idx)
-                    .and_then(|slot| slot.as_ref())
-                    .is_some();
+                self.emit_slot(dialogue, idx, "broadcast_explicit_order")
+            }
+            BroadcastOrder::Priority => {
+                let idx = *priority_order(dialogue).get(self.next_emit)?;
+                self.emit_slot(dialogue, idx, "broadcast_priority_order")
+            }
+        }
+    }
 
-                if !slot_ready {
-                    return None;
-                }
+    /// Emits the buffered result of participant `idx`, or `None` if it has not arrived yet.
+    ///
+    /// Successful responses are stored in the MessageStore at emit time, so the
+    /// store reflects the yield order.
+    fn emit_slot(
+        &mut self,
+        dialogue: &mut Dialogue,
+        idx: usize,
+        mode: &'static str,
+    ) -> Option<Result<DialogueTurn, AgentError>> {
+        let slot_ready = self
+            .buffered
+            .get(idx)
+            .and_then(|slot| slot.as_ref())
+            .is_some();
 
-                let result = self.buffered[idx].take().expect("checked is_some");
-                self.next_emit += 1;
+        if !slot_ready {
+            return None;
+        }
 
-                match result {
-                    Ok(content) => {
-                        let participant = &dialogue.participants[idx];
-                        let participant_name = participant.name().to_string();
+        let result = self.buffered[idx].take().expect("checked is_some");
+        self.next_emit += 1;
 
-                        // Store in MessageStore
-                        let metadata =
-                            MessageMetadata::new().with_origin(MessageOrigin::AgentGenerated);
-                        let message = DialogueMessage::new(
-                            self.current_turn,
-                            Speaker::agent(
-                                participant_name.clone(),
-                                participant.persona.role.clone(),
-                            ),
-                            content.clone(),
-                        )
-                        .with_metadata(&metadata);
-                        dialogue.message_store.push(message);
+        match result {
+            Ok(content) => {
+                let participant = &dialogue.participants[idx];
+                let participant_name = participant.name().to_string();
 
-                        let turn = DialogueTurn {
-                            speaker: Speaker::agent(
-                                participant_name.clone(),
-                                participant.persona.role.clone(),
-                            ),
-                            content: content.clone(),
-                        };
-                        info!(
-                            target = "llm_toolkit::dialogue",
-                            mode = "broadcast_explicit_order",
-                            participant = %participant_name,
-                            participant_index = idx,
-                            total_participants = participant_total,
-                            event = "dialogue_turn_emitted"
-                        );
-                        Some(Ok(turn))
-                    }
-                    Err(err) => Some(Err(err)),
-                }
+                // Store in MessageStore
+                let metadata = MessageMetadata::new().with_origin(MessageOrigin::AgentGenerated);
+                let message = DialogueMessage::new(
+                    self.current_turn,
+                    Speaker::agent(participant_name.clone(), participant.persona.role.clone()),
+                    content.clone(),
+                )
+                .with_metadata(&metadata);
+                dialogue.message_store.push(message);
+
+                let turn = DialogueTurn {
+                    speaker: Speaker::agent(
+                        participant_name.clone(),
+                        participant.persona.role.clone(),
+                    ),
+                    content: content.clone(),
+                };
+                info!(
+                    target = "llm_toolkit::dialogue",
+                    mode,
+                    participant = %participant_name,
+                    participant_index = idx,
+                    total_participants = dialogue.participants.len(),
+                    event = "dialogue_turn_emitted"
+                );
+                Some(Ok(turn))
             }
+            Err(err) => Some(Err(err)),
         }
     }
 }
 
+/// Returns participant indices ordered by persona priority (higher first).
+///
+/// Participants without a priority come last; ties keep participant order.
+fn priority_order(dialogue: &Dialogue) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..dialogue.participants.len()).collect();
+    order.sort_by_key(|&idx| {
+        (
+            std::cmp::Reverse(dialogue.participants[idx].persona.priority),
+            idx,
+        )
+    });
+    order
+}
+
 /// Session state enum for tracking execution progress.
 pub(super) enum SessionState {
     Broadcast(BroadcastState),
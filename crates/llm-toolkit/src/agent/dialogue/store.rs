@@ -6,8 +6,26 @@
 use crate::agent::dialogue::message::SentAgents;
 
 use super::message::{DialogueMessage, MessageId, MessageOrigin, Speaker};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Serializable snapshot of a dialogue's message store.
+///
+/// Unlike the `DialogueTurn` list returned by `Dialogue::history()`, this keeps
+/// every `DialogueMessage` intact: IDs, turn numbers, metadata (including
+/// origins), and which agents have already received each message as context.
+///
+/// Created by [`Dialogue::export_session()`](super::Dialogue::export_session) and
+/// restored with [`Dialogue::import_session()`](super::Dialogue::import_session).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DialogueSessionData {
+    /// All messages in chronological order.
+    pub messages: Vec<DialogueMessage>,
+
+    /// The last completed turn at export time.
+    pub current_turn: usize,
+}
+
 /// Central message repository within a Dialogue.
 ///
 /// # Responsibility
@@ -40,6 +58,19 @@ impl MessageStore {
         }
     }
 
+    /// Rebuilds a store from messages in chronological order.
+    ///
+    /// Message IDs are preserved, and the ID counter is advanced past them so
+    /// that messages added afterwards get fresh IDs.
+    pub fn from_messages(messages: Vec<DialogueMessage>) -> Self {
+        let mut store = Self::new();
+        for message in messages {
+            message.id.reserve();
+            store.push(message);
+        }
+        store
+    }
+
     /// Adds a new message to the store.
     ///
     /// The message will be appended to the chronological order.
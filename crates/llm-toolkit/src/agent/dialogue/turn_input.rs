@@ -87,6 +87,14 @@ pub struct TurnInput {
     /// The name of the current participant (the one receiving this input)
     #[serde(default)]
     pub current_participant: String,
+
+    /// Character limit for inlining messages from other participants.
+    ///
+    /// When set, `to_messages` renders shorter messages as `[Name]: text` and
+    /// longer ones as a fenced block under a `### Name (Role)` header. When
+    /// `None`, content is passed through unchanged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_block_threshold: Option<usize>,
 }
 
 impl TurnInput {
@@ -98,6 +106,7 @@ impl TurnInput {
             context: Vec::new(),
             participants: Vec::new(),
             current_participant: String::new(),
+            context_block_threshold: None,
         }
     }
 
@@ -109,6 +118,7 @@ impl TurnInput {
             context,
             participants: Vec::new(),
             current_participant: String::new(),
+            context_block_threshold: None,
         }
     }
 
@@ -125,6 +135,7 @@ impl TurnInput {
             context,
             participants,
             current_participant: current_participant.into(),
+            context_block_threshold: None,
         }
     }
 
@@ -160,7 +171,38 @@ impl TurnInput {
             context,
             participants,
             current_participant: current_participant.into(),
+            context_block_threshold: None,
+        }
+    }
+
+    /// Removes messages authored by `current_participant` from this input.
+    ///
+    /// Used when distributing a turn to several participants at once: each agent
+    /// already keeps its own replies in its managed history, so echoing them back
+    /// as part of the dialogue context only wastes tokens and invites the agent to
+    /// quote itself. Messages from other agents, users, and the system are kept.
+    ///
+    /// Does nothing when `current_participant` is empty.
+    pub fn without_own_messages(mut self) -> Self {
+        if self.current_participant.is_empty() {
+            return self;
         }
+
+        let own_name = self.current_participant.as_str();
+        self.context
+            .retain(|ctx| ctx.speaker_role == "System" || ctx.speaker_name != own_name);
+        self.current_messages.retain(|msg| {
+            !matches!(&msg.speaker, crate::agent::dialogue::Speaker::Agent { name, .. } if name == own_name)
+        });
+        self
+    }
+
+    /// Sets the character limit for inlining messages from other participants.
+    ///
+    /// See [`TurnInput::context_block_threshold`]; `None` disables formatting.
+    pub fn with_context_block_threshold(mut self, threshold: Option<usize>) -> Self {
+        self.context_block_threshold = threshold;
+        self
     }
 
     /// Converts this TurnInput into a vector of Messages for structured dialogue.
@@ -169,6 +211,9 @@ impl TurnInput {
     /// - Context messages from other participants (converted to Speaker + content)
     /// - Current messages (if `current_messages` is non-empty, it takes precedence)
     /// - Otherwise, `user_prompt` as a single System message (legacy)
+    ///
+    /// With a `context_block_threshold`, context messages and agent messages in
+    /// `current_messages` are formatted by length (see [`format_context_content`]).
     pub fn to_messages(&self) -> Vec<PayloadMessage> {
         use crate::agent::dialogue::Speaker;
 
@@ -181,13 +226,31 @@ impl TurnInput {
             } else {
                 Speaker::agent(&ctx.speaker_name, &ctx.speaker_role)
             };
-            messages.push(PayloadMessage::new(speaker, ctx.content.clone()));
+            let content = match self.context_block_threshold {
+                Some(threshold) => format_context_content(
+                    &ctx.speaker_name,
+                    &ctx.speaker_role,
+                    &ctx.content,
+                    threshold,
+                ),
+                None => ctx.content.clone(),
+            };
+            messages.push(PayloadMessage::new(speaker, content));
         }
 
         // Add current messages or user_prompt
         if !self.current_messages.is_empty() {
             // New: use structured messages
-            messages.extend(self.current_messages.clone());
+            messages.extend(self.current_messages.iter().map(|msg| {
+                match (&msg.speaker, self.context_block_threshold) {
+                    (Speaker::Agent { name, role, .. }, Some(threshold)) => {
+                        let mut msg = msg.clone();
+                        msg.content = format_context_content(name, role, &msg.content, threshold);
+                        msg
+                    }
+                    _ => msg.clone(),
+                }
+            }));
         } else if !self.user_prompt.is_empty() {
             // Legacy: use single user_prompt as System message
             messages.push(PayloadMessage::system(self.user_prompt.clone()));
@@ -197,6 +260,22 @@ impl TurnInput {
     }
 }
 
+/// Formats a message from another participant according to its length.
+///
+/// Content of at most `threshold` characters is inlined as `[Name]: text`.
+/// Longer content goes in a fenced block under a `### Name (Role)` header; the
+/// fence is made longer than any backtick run in the content so it cannot be
+/// closed early.
+pub fn format_context_content(name: &str, role: &str, content: &str, threshold: usize) -> String {
+    if content.chars().count() <= threshold {
+        return format!("[{}]: {}", name, content);
+    }
+
+    let longest_backtick_run = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_backtick_run.max(2) + 1);
+    format!("### {} ({})\n{}\n{}\n{}", name, role, fence, content, fence)
+}
+
 /// Context message from another participant in the dialogue.
 ///
 /// This represents what other agents said in recent turns,
@@ -351,4 +430,64 @@ mod tests {
         assert_eq!(msg.turn, 5);
         assert_eq!(msg.timestamp, 1699000000);
     }
+
+    #[test]
+    fn test_without_own_messages_drops_current_participant_messages() {
+        let turn_input = TurnInput::with_messages_and_context(
+            vec![
+                PayloadMessage::new(Speaker::agent("Alice", "Engineer"), "Alice turn 1"),
+                PayloadMessage::new(Speaker::agent("Bob", "Designer"), "Bob turn 1"),
+                PayloadMessage::system("Next topic"),
+            ],
+            vec![
+                ContextMessage::new("Alice", "Engineer", "Alice earlier"),
+                ContextMessage::new("Bob", "Designer", "Bob earlier"),
+            ],
+            vec![],
+            "Alice",
+        )
+        .without_own_messages();
+
+        let contents: Vec<String> = turn_input
+            .to_messages()
+            .into_iter()
+            .map(|msg| msg.content)
+            .collect();
+        assert_eq!(contents, vec!["Bob earlier", "Bob turn 1", "Next topic"]);
+    }
+
+    #[test]
+    fn test_context_block_threshold_inlines_short_and_fences_long_messages() {
+        let long = "x".repeat(30);
+        let turn_input = TurnInput::with_messages_and_context(
+            vec![
+                PayloadMessage::new(Speaker::agent("Bob", "Designer"), long.clone()),
+                PayloadMessage::system("Next topic"),
+            ],
+            vec![ContextMessage::new("Alice", "Engineer", "Use Rust")],
+            vec![],
+            "Carol",
+        )
+        .with_context_block_threshold(Some(20));
+
+        let messages = turn_input.to_messages();
+        assert_eq!(messages[0].content, "[Alice]: Use Rust");
+        assert_eq!(
+            messages[1].content,
+            format!("### Bob (Designer)\n```\n{}\n```", long)
+        );
+        assert_eq!(messages[1].speaker, Speaker::agent("Bob", "Designer"));
+        assert_eq!(messages[2].content, "Next topic");
+    }
+
+    #[test]
+    fn test_format_context_content_fence_outlasts_content_backticks() {
+        let content = "See:\n```rust\nfn main() {}\n```";
+        let formatted = format_context_content("Bob", "Engineer", content, 5);
+
+        assert_eq!(
+            formatted,
+            format!("### Bob (Engineer)\n````\n{}\n````", content)
+        );
+    }
 }
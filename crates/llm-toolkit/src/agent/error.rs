@@ -126,6 +126,13 @@ pub enum AgentError {
         reason: ParseErrorReason,
     },
 
+    /// The output was parsed but rejected by its [`Validate`](super::Validate) implementation.
+    ///
+    /// The model produced well-formed output with semantically wrong values, so
+    /// asking again (ideally with the validation message as feedback) may help.
+    #[error("Output validation failed: {message}")]
+    ValidationFailed { message: String },
+
     /// The agent process spawning or communication failed.
     ///
     /// This variant is structured to allow better retry decisions based on
@@ -143,6 +150,13 @@ pub enum AgentError {
         retry_after: Option<Duration>,
     },
 
+    /// The agent's backend (e.g. its CLI binary) is not installed or not found.
+    ///
+    /// Not retryable: the environment has to be fixed first. `hint` names what
+    /// is missing and how to install it.
+    #[error("{backend} backend is unavailable: {hint}")]
+    BackendUnavailable { backend: &'static str, hint: String },
+
     /// I/O error occurred during agent execution.
     #[error("I/O error: {0}")]
     IoError(#[from] std::io::Error),
@@ -263,6 +277,7 @@ impl AgentError {
     ///
     /// Returns `true` for errors that are likely transient and may succeed on retry:
     /// - `ParseError`: Only certain parse error types (UnexpectedEof, MarkdownExtractionFailed)
+    /// - `ValidationFailed`: The model may produce valid values on the next attempt
     /// - `ProcessError`: Based on status code and is_retryable flag
     /// - `IoError`: Temporary I/O failures
     ///
@@ -290,6 +305,8 @@ impl AgentError {
                 reason,
                 ParseErrorReason::UnexpectedEof | ParseErrorReason::MarkdownExtractionFailed
             ),
+            // Validation failures: the model may get the values right next time
+            AgentError::ValidationFailed { .. } => true,
             // I/O errors are generally transient
             AgentError::IoError(_) => true,
             // Rich variants: same logic as simple variants
@@ -308,6 +325,24 @@ impl AgentError {
         }
     }
 
+    /// Check if this error means the backend itself failed, so a different backend may succeed.
+    ///
+    /// Returns `true` when the backend is not installed (`BackendUnavailable`) or
+    /// the request timed out (an `IoError` of kind `TimedOut`, or a 408/504
+    /// `ProcessError`). Errors about the model's output, such as parse or
+    /// validation failures, are not backend failures.
+    pub fn is_backend_failure(&self) -> bool {
+        match self {
+            AgentError::BackendUnavailable { .. } => true,
+            AgentError::IoError(e) => e.kind() == std::io::ErrorKind::TimedOut,
+            AgentError::ProcessError { status_code, .. }
+            | AgentError::ProcessErrorRich { status_code, .. } => {
+                matches!(status_code, Some(408) | Some(504))
+            }
+            _ => false,
+        }
+    }
+
     /// Calculate the delay before the next retry attempt.
     ///
     /// This implements different backoff strategies based on the error type and
@@ -595,6 +630,14 @@ impl AgentError {
                     "Parse error (no metadata)"
                 );
             }
+            AgentError::ValidationFailed { message } => {
+                tracing::error!(
+                    target: "llm_toolkit::agent::error",
+                    error_type = "ValidationFailed",
+                    error_message = %message,
+                    "Output validation failed"
+                );
+            }
             AgentError::ProcessError {
                 status_code,
                 message,
@@ -611,6 +654,15 @@ impl AgentError {
                     "Process error (no metadata)"
                 );
             }
+            AgentError::BackendUnavailable { backend, hint } => {
+                tracing::error!(
+                    target: "llm_toolkit::agent::error",
+                    error_type = "BackendUnavailable",
+                    backend = %backend,
+                    error_message = %hint,
+                    "Agent backend unavailable"
+                );
+            }
             AgentError::IoError(err) => {
                 tracing::error!(
                     target: "llm_toolkit::agent::error",
@@ -724,6 +776,102 @@ impl RichErrorBuilder {
     }
 }
 
+/// Explains how a JSON document deviates from a type's expected shape.
+///
+/// `expected` is the value returned by `ToPrompt::prompt_schema_json()`, of the form
+/// `{"properties": {"field": {"type": "string"}}, "required": ["field"]}`.
+/// The explanation lists missing required fields, fields not present in the
+/// schema, and fields whose JSON type doesn't match the expected type.
+///
+/// Returns `None` if the JSON is not an object or no differences are found.
+pub fn explain_schema_mismatch(json_str: &str, expected: &serde_json::Value) -> Option<String> {
+    let actual = serde_json::from_str::<serde_json::Value>(json_str).ok()?;
+    let actual = actual.as_object()?;
+    let properties = expected.get("properties")?.as_object()?;
+    let required: Vec<&str> = expected
+        .get("required")
+        .and_then(|r| r.as_array())
+        .map(|r| r.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default();
+
+    let missing: Vec<&str> = required
+        .iter()
+        .copied()
+        .filter(|field| !actual.contains_key(*field))
+        .collect();
+
+    let unexpected: Vec<&str> = actual
+        .keys()
+        .map(|k| k.as_str())
+        .filter(|key| !properties.contains_key(*key))
+        .collect();
+
+    let mistyped: Vec<String> = properties
+        .iter()
+        .filter_map(|(field, spec)| {
+            let expected_type = spec.get("type")?.as_str()?;
+            let value = actual.get(field)?;
+            match json_matches_schema_type(value, expected_type) {
+                Some(false) => Some(format!(
+                    "{} (expected {}, got {})",
+                    field,
+                    expected_type,
+                    json_type_name(value)
+                )),
+                _ => None,
+            }
+        })
+        .collect();
+
+    let mut sections = Vec::new();
+    if !missing.is_empty() {
+        sections.push(format!("missing fields: [{}]", missing.join(", ")));
+    }
+    if !unexpected.is_empty() {
+        sections.push(format!("unexpected fields: [{}]", unexpected.join(", ")));
+    }
+    if !mistyped.is_empty() {
+        sections.push(format!("mistyped fields: [{}]", mistyped.join(", ")));
+    }
+
+    if sections.is_empty() {
+        None
+    } else {
+        Some(sections.join("; "))
+    }
+}
+
+/// Checks a JSON value against a schema type name as produced by `prompt_schema()`.
+///
+/// Returns `None` for custom types that can't be checked structurally.
+fn json_matches_schema_type(value: &serde_json::Value, expected_type: &str) -> Option<bool> {
+    if let Some(inner) = expected_type.strip_suffix(" | null") {
+        if value.is_null() {
+            return Some(true);
+        }
+        return json_matches_schema_type(value, inner);
+    }
+
+    match expected_type {
+        "string" => Some(value.is_string()),
+        "number" => Some(value.is_number()),
+        "boolean" => Some(value.is_boolean()),
+        t if t.ends_with("[]") || t == "array" => Some(value.is_array()),
+        _ => None,
+    }
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -776,6 +924,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_retryable_validation_failed() {
+        let err = AgentError::ValidationFailed {
+            message: "percent must be at most 100".to_string(),
+        };
+        assert!(err.is_retryable(), "ValidationFailed should be retryable");
+        assert!(!err.is_transient());
+    }
+
     #[test]
     fn test_is_retryable_process_error_with_flag() {
         let err = AgentError::ProcessError {
@@ -869,6 +1026,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_backend_failure() {
+        let unavailable = AgentError::BackendUnavailable {
+            backend: "claude",
+            hint: "install it".to_string(),
+        };
+        assert!(unavailable.is_backend_failure());
+
+        let timed_out =
+            AgentError::IoError(std::io::Error::new(std::io::ErrorKind::TimedOut, "timeout"));
+        assert!(timed_out.is_backend_failure());
+        assert!(AgentError::process_error(504, "gateway timeout", true).is_backend_failure());
+
+        let parse_err = AgentError::ParseError {
+            message: "bad json".to_string(),
+            reason: ParseErrorReason::InvalidJson,
+        };
+        assert!(!parse_err.is_backend_failure());
+        assert!(!AgentError::process_error(429, "rate limited", true).is_backend_failure());
+    }
+
     #[test]
     fn test_is_transient_backward_compatibility() {
         // is_transient() should still work for backward compatibility
@@ -1200,4 +1378,44 @@ mod tests {
         };
         process_err.trace_error(); // Should not panic
     }
+
+    fn user_schema() -> serde_json::Value {
+        serde_json::json!({
+            "properties": {
+                "name": { "type": "string" },
+                "age": { "type": "number" },
+                "email": { "type": "string | null" }
+            },
+            "required": ["name", "age"]
+        })
+    }
+
+    #[test]
+    fn test_explain_schema_mismatch_missing_field() {
+        let explanation =
+            explain_schema_mismatch(r#"{"name": "Mai", "nickname": "m"}"#, &user_schema()).unwrap();
+        assert_eq!(
+            explanation,
+            "missing fields: [age]; unexpected fields: [nickname]"
+        );
+    }
+
+    #[test]
+    fn test_explain_schema_mismatch_wrong_type() {
+        let explanation = explain_schema_mismatch(
+            r#"{"name": "Mai", "age": "thirty", "email": null}"#,
+            &user_schema(),
+        )
+        .unwrap();
+        assert_eq!(
+            explanation,
+            "mistyped fields: [age (expected number, got string)]"
+        );
+    }
+
+    #[test]
+    fn test_explain_schema_mismatch_no_difference() {
+        assert!(explain_schema_mismatch(r#"{"name": "Mai", "age": 30}"#, &user_schema()).is_none());
+        assert!(explain_schema_mismatch("[1, 2]", &user_schema()).is_none());
+    }
 }
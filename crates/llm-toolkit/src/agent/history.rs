@@ -2,7 +2,7 @@ use crate::ToPrompt;
 use crate::agent::payload_message::format_messages_with_relation;
 
 use super::payload_message::PayloadMessage;
-use super::{Agent, AgentError, Payload};
+use super::{Agent, AgentError, AnyAgent, Payload};
 use async_trait::async_trait;
 use serde::Serialize;
 use std::sync::Arc;
@@ -23,6 +23,103 @@ struct HistoryPromptDto {
     history: String,
 }
 
+/// Caps how much dialogue history a [`HistoryAwareAgent`] keeps.
+///
+/// Limits are counted in history entries: each incoming message and each
+/// response is one entry. The most recent entries are always kept verbatim.
+#[derive(Clone)]
+pub enum HistoryLimit {
+    /// Keep only the last `n` entries, dropping older ones.
+    LastN(usize),
+    /// Once history grows past `max_messages` entries, condense everything but
+    /// the last `keep_recent` entries into a single system note written by
+    /// `summarizer`.
+    SummarizeAfter {
+        max_messages: usize,
+        keep_recent: usize,
+        summarizer: Arc<AnyAgent<String>>,
+    },
+}
+
+impl HistoryLimit {
+    /// Summarizes older entries with `summarizer` once history exceeds
+    /// `max_messages`, keeping the newest half verbatim.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use llm_toolkit::agent::history::HistoryLimit;
+    ///
+    /// let limit = HistoryLimit::summarize_after(20, ClaudeCodeAgent::new());
+    /// ```
+    pub fn summarize_after(
+        max_messages: usize,
+        summarizer: impl Agent<Output = String> + 'static,
+    ) -> Self {
+        HistoryLimit::SummarizeAfter {
+            max_messages,
+            keep_recent: (max_messages / 2).max(1),
+            summarizer: AnyAgent::arc(summarizer),
+        }
+    }
+
+    /// Applies this limit to `history` in place.
+    ///
+    /// If the summarizer fails, history is left untouched and summarization
+    /// is retried after the next interaction.
+    async fn apply(&self, history: &mut Vec<PayloadMessage>) {
+        match self {
+            HistoryLimit::LastN(n) => {
+                let excess = history.len().saturating_sub(*n);
+                history.drain(..excess);
+            }
+            HistoryLimit::SummarizeAfter {
+                max_messages,
+                keep_recent,
+                summarizer,
+            } => {
+                if history.len() <= *max_messages {
+                    return;
+                }
+                let split = history.len().saturating_sub(*keep_recent);
+                if split == 0 {
+                    return;
+                }
+
+                let transcript = history[..split]
+                    .iter()
+                    .map(|m| format!("[{}]: {}", m.speaker.name(), m.content))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let request = Payload::text(format!(
+                    "Summarize the following conversation concisely. Preserve decisions, \
+                     facts, and open questions.\n\n{}",
+                    transcript
+                ));
+
+                match summarizer.execute(request).await {
+                    Ok(summary) => {
+                        history.splice(
+                            ..split,
+                            [PayloadMessage::system(format!(
+                                "Summary of earlier conversation: {}",
+                                summary.trim()
+                            ))],
+                        );
+                    }
+                    Err(e) => {
+                        crate::tracing::warn!(
+                            target: "llm_toolkit::agent::history",
+                            error = %e,
+                            "Failed to summarize history; keeping it verbatim"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// An agent wrapper that maintains dialogue history across multiple executions.
 ///
 /// This agent wraps any inner agent and automatically maintains a history of all
@@ -54,6 +151,8 @@ pub struct HistoryAwareAgent<T: Agent> {
     self_name: Option<String>,
     /// Role of this agent (for attributing responses in history)
     self_role: Option<String>,
+    /// Optional cap on the stored history
+    limit: Option<HistoryLimit>,
 }
 
 impl<T: Agent> HistoryAwareAgent<T> {
@@ -72,6 +171,7 @@ impl<T: Agent> HistoryAwareAgent<T> {
             dialogue_history: Arc::new(Mutex::new(Vec::new())),
             self_name: None,
             self_role: None,
+            limit: None,
         }
     }
 
@@ -107,8 +207,17 @@ impl<T: Agent> HistoryAwareAgent<T> {
             dialogue_history: Arc::new(Mutex::new(Vec::new())),
             self_name: Some(name.into()),
             self_role: Some(role.into()),
+            limit: None,
         }
     }
+
+    /// Caps the stored history with the given limit.
+    ///
+    /// The limit is applied after each interaction is recorded.
+    pub fn with_limit(mut self, limit: HistoryLimit) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 #[async_trait]
@@ -201,6 +310,9 @@ where
             }
         };
         history.push(response_entry);
+        if let Some(limit) = &self.limit {
+            limit.apply(&mut history).await;
+        }
         crate::tracing::debug!(
             target: "llm_toolkit::agent::history",
             description = self.inner_agent.description(),
@@ -316,6 +428,7 @@ mod tests {
             dialogue_history: history_agent.dialogue_history.clone(),
             self_name: None,
             self_role: None,
+            limit: None,
         };
 
         let payload2 =
@@ -386,6 +499,54 @@ mod tests {
         assert_eq!(received_messages[0].content, "Hello");
     }
 
+    #[tokio::test]
+    async fn test_last_n_limit_trims_oldest_entries() {
+        let base_agent = RecordingAgent::new(String::from("ok"));
+        let history_agent =
+            HistoryAwareAgent::new(base_agent.clone()).with_limit(HistoryLimit::LastN(3));
+
+        for text in ["first", "second", "third"] {
+            let payload = Payload::from_messages(vec![PayloadMessage::user("User", "User", text)]);
+            history_agent.execute(payload).await.unwrap();
+        }
+
+        let history = history_agent.dialogue_history.lock().await;
+        let contents: Vec<&str> = history.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, vec!["\"ok\"", "third", "\"ok\""]);
+    }
+
+    #[tokio::test]
+    async fn test_summarize_after_condenses_older_entries() {
+        let summarizer = RecordingAgent::new(String::from("User greeted twice."));
+        let base_agent = RecordingAgent::new(String::from("ok"));
+        let history_agent = HistoryAwareAgent::new(base_agent.clone())
+            .with_limit(HistoryLimit::summarize_after(4, summarizer.clone()));
+
+        for text in ["hello", "hello again", "what now?"] {
+            let payload = Payload::from_messages(vec![PayloadMessage::user("User", "User", text)]);
+            history_agent.execute(payload).await.unwrap();
+        }
+
+        // Summarizer only ran once history exceeded four entries
+        let summary_calls = summarizer.get_calls().await;
+        assert_eq!(summary_calls.len(), 1);
+        let request = summary_calls[0].to_text();
+        assert!(request.contains("[User]: hello"));
+        assert!(request.contains("[User]: hello again"));
+        assert!(!request.contains("what now?"));
+
+        let history = history_agent.dialogue_history.lock().await;
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].speaker, Speaker::System);
+        assert_eq!(
+            history[0].content,
+            "Summary of earlier conversation: User greeted twice."
+        );
+        // The latest interaction is kept verbatim
+        assert_eq!(history[1].content, "what now?");
+        assert_eq!(history[2].content, "\"ok\"");
+    }
+
     #[tokio::test]
     async fn test_expertise_delegation() {
         let base_agent = RecordingAgent::new(String::from("response"));
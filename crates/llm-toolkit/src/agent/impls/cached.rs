@@ -0,0 +1,340 @@
+//! Response caching wrapper for agents.
+//!
+//! This module provides `Cached`, a decorator that stores successful outputs
+//! keyed by the payload, so repeated identical requests skip the backend.
+
+use crate::agent::{Agent, AgentError, Capability, ExecutionProfile, Payload};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+/// A cached agent output along with the time it was stored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheEntry {
+    /// The output, serialized as JSON
+    pub value: serde_json::Value,
+    /// When the entry was stored (used for TTL expiry)
+    pub created_at: SystemTime,
+}
+
+/// Storage backend for [`Cached`].
+///
+/// Keys combine the agent name with [`Payload::canonical_key`], so they are
+/// stable across processes and Rust versions. Implement this trait to persist
+/// entries elsewhere (e.g. one JSON file per key on disk, named by a digest of
+/// the key).
+pub trait CacheStore: Send + Sync {
+    /// Returns the entry stored under `key`, if any.
+    fn get(&self, key: &str) -> Option<CacheEntry>;
+
+    /// Stores `entry` under `key`, replacing any previous entry.
+    fn insert(&self, key: &str, entry: CacheEntry);
+
+    /// Removes the entry stored under `key`, if any.
+    fn remove(&self, key: &str);
+}
+
+/// The default [`CacheStore`]: a `HashMap` kept in memory for the process lifetime.
+#[derive(Debug, Default)]
+pub struct InMemoryCacheStore {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl InMemoryCacheStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of stored entries, including expired ones not yet evicted.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl CacheStore for InMemoryCacheStore {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    fn insert(&self, key: &str, entry: CacheEntry) {
+        self.entries.lock().unwrap().insert(key.to_string(), entry);
+    }
+
+    fn remove(&self, key: &str) {
+        self.entries.lock().unwrap().remove(key);
+    }
+}
+
+/// A wrapper agent that caches successful outputs of the underlying agent.
+///
+/// Requests are keyed by the inner agent's name and the payload's
+/// [`canonical_key`](Payload::canonical_key) (text plus attachment identities).
+/// On a hit, the stored output is returned without calling the inner agent.
+/// Only successful outputs are cached; errors always pass through, so the
+/// next identical request tries the backend again.
+///
+/// Local file attachments are keyed by path, not contents: after editing an
+/// attached file in place, the stale output is still served until it expires.
+/// Use [`with_ttl`](Self::with_ttl) or a fresh store when files change.
+///
+/// Intended for development loops that re-run the same deterministic prompts.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use llm_toolkit::agent::impls::{Cached, ClaudeCodeAgent};
+/// use std::time::Duration;
+///
+/// let agent = Cached::new(ClaudeCodeAgent::new()).with_ttl(Duration::from_secs(3600));
+///
+/// let first = agent.execute("Summarize README.md".into()).await?; // calls Claude
+/// let second = agent.execute("Summarize README.md".into()).await?; // served from cache
+/// ```
+pub struct Cached<A: Agent> {
+    inner: A,
+    store: Arc<dyn CacheStore>,
+    ttl: Option<Duration>,
+}
+
+impl<A: Agent> Cached<A> {
+    /// Wraps `inner` with an in-memory cache whose entries never expire.
+    pub fn new(inner: A) -> Self {
+        Self::with_store(inner, Arc::new(InMemoryCacheStore::new()))
+    }
+
+    /// Wraps `inner` with a custom (possibly shared or persistent) store.
+    pub fn with_store(inner: A, store: Arc<dyn CacheStore>) -> Self {
+        Self {
+            inner,
+            store,
+            ttl: None,
+        }
+    }
+
+    /// Expires entries older than `ttl`.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Returns a reference to the inner agent.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    fn cache_key(&self, payload: &Payload) -> String {
+        // Length-prefixed like the canonical key itself, so names can't bleed into content
+        let name = self.inner.name();
+        format!("agent:{}:{}\n{}", name.len(), name, payload.canonical_key())
+    }
+
+    fn is_expired(&self, entry: &CacheEntry) -> bool {
+        match self.ttl {
+            // A clock that went backwards counts as fresh
+            Some(ttl) => entry
+                .created_at
+                .elapsed()
+                .map(|age| age > ttl)
+                .unwrap_or(false),
+            None => false,
+        }
+    }
+}
+
+#[async_trait]
+impl<A: Agent> Agent for Cached<A>
+where
+    A::Output: Send,
+{
+    type Output = A::Output;
+    type Expertise = A::Expertise;
+
+    fn expertise(&self) -> &Self::Expertise {
+        self.inner.expertise()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn capabilities(&self) -> Option<Vec<Capability>> {
+        self.inner.capabilities()
+    }
+
+    fn name(&self) -> String {
+        // Transparent decorator: keep the inner name for orchestrator lookup
+        self.inner.name()
+    }
+
+    async fn execute(&self, payload: Payload) -> Result<Self::Output, AgentError> {
+        let key = self.cache_key(&payload);
+
+        if let Some(entry) = self.store.get(&key) {
+            if self.is_expired(&entry) {
+                self.store.remove(&key);
+            } else {
+                match serde_json::from_value(entry.value) {
+                    Ok(output) => {
+                        log::debug!("💾 Cache hit for {}", self.name());
+                        return Ok(output);
+                    }
+                    Err(e) => {
+                        // Stale format (e.g. the output type changed): drop it and refetch
+                        log::warn!(
+                            "Discarding unreadable cache entry for {}: {}",
+                            self.name(),
+                            e
+                        );
+                        self.store.remove(&key);
+                    }
+                }
+            }
+        }
+
+        let output = self.inner.execute(payload).await?;
+
+        match serde_json::to_value(&output) {
+            Ok(value) => self.store.insert(
+                &key,
+                CacheEntry {
+                    value,
+                    created_at: SystemTime::now(),
+                },
+            ),
+            Err(e) => log::warn!("Not caching output that failed to serialize: {}", e),
+        }
+
+        Ok(output)
+    }
+
+    async fn is_available(&self) -> Result<(), AgentError> {
+        self.inner.is_available().await
+    }
+
+    fn clone_with_execution_profile(&self, profile: ExecutionProfile) -> Option<Self> {
+        Some(Self {
+            inner: self.inner.clone_with_execution_profile(profile)?,
+            store: Arc::clone(&self.store),
+            ttl: self.ttl,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attachment::Attachment;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Mock agent that counts calls and fails when asked to
+    struct CountingAgent {
+        calls: AtomicU32,
+    }
+
+    #[async_trait]
+    impl Agent for CountingAgent {
+        type Output = String;
+        type Expertise = &'static str;
+
+        fn expertise(&self) -> &&'static str {
+            const EXPERTISE: &str = "Test agent that counts calls";
+            &EXPERTISE
+        }
+
+        async fn execute(&self, payload: Payload) -> Result<String, AgentError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            let text = payload.to_text();
+            if text == "fail" {
+                return Err(AgentError::ExecutionFailed("requested failure".to_string()));
+            }
+            Ok(format!("{} #{}", text, call))
+        }
+    }
+
+    fn counting_agent() -> CountingAgent {
+        CountingAgent {
+            calls: AtomicU32::new(0),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_identical_payload_hits_cache() {
+        let agent = Cached::new(counting_agent());
+
+        let first = agent.execute(Payload::text("summarize")).await.unwrap();
+        let second = agent.execute(Payload::text("summarize")).await.unwrap();
+
+        assert_eq!(first, "summarize #1");
+        assert_eq!(second, "summarize #1");
+        assert_eq!(agent.inner().calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_different_payloads_miss_cache() {
+        let agent = Cached::new(counting_agent());
+
+        agent.execute(Payload::text("summarize")).await.unwrap();
+        agent.execute(Payload::text("translate")).await.unwrap();
+        agent
+            .execute(
+                Payload::text("summarize").with_attachment(Attachment::in_memory(vec![1, 2, 3])),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(agent.inner().calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_store_is_keyed_by_agent_name_and_canonical_key() {
+        let store = Arc::new(InMemoryCacheStore::new());
+        let agent = Cached::with_store(counting_agent(), store.clone());
+        agent.execute(Payload::text("summarize")).await.unwrap();
+
+        let name = agent.name();
+        let key = format!("agent:{}:{}\ntext:9:summarize\n", name.len(), name);
+        assert!(store.get(&key).is_some());
+
+        // A fresh wrapper over the same store (e.g. after a restart) reuses the entry
+        let restarted = Cached::with_store(counting_agent(), store);
+        let output = restarted.execute(Payload::text("summarize")).await.unwrap();
+        assert_eq!(output, "summarize #1");
+        assert_eq!(restarted.inner().calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_not_cached() {
+        let store = Arc::new(InMemoryCacheStore::new());
+        let agent = Cached::with_store(counting_agent(), store.clone());
+
+        assert!(agent.execute(Payload::text("fail")).await.is_err());
+        assert!(agent.execute(Payload::text("fail")).await.is_err());
+
+        assert_eq!(agent.inner().calls.load(Ordering::SeqCst), 2);
+        assert!(store.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_refetched() {
+        let store = Arc::new(InMemoryCacheStore::new());
+        let agent =
+            Cached::with_store(counting_agent(), store.clone()).with_ttl(Duration::from_secs(60));
+
+        agent.execute(Payload::text("summarize")).await.unwrap();
+
+        // Age the stored entry past the TTL
+        let key = agent.cache_key(&Payload::text("summarize"));
+        let mut entry = store.get(&key).unwrap();
+        entry.created_at -= Duration::from_secs(120);
+        store.insert(&key, entry);
+
+        let refreshed = agent.execute(Payload::text("summarize")).await.unwrap();
+        assert_eq!(refreshed, "summarize #2");
+        assert_eq!(agent.inner().calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -12,7 +12,16 @@ use std::path::{Path, PathBuf};
 use tokio::process::Command;
 use tracing::{debug, error, info, instrument};
 
-use super::cli_agent::{CliAgent, CliAgentConfig};
+use super::cli_agent::{
+    CliAgent, CliAgentConfig, backend_unavailable, check_cli_available, describe_command,
+    record_execution, reserved_flag_conflict,
+};
+
+/// Flags set by the agent itself, which extra arguments may not override.
+const RESERVED_FLAGS: &[&str] = &["-p", "--print", "--model"];
+
+/// Installation instructions for the `claude` CLI.
+const INSTALL_DOCS: &str = "https://docs.anthropic.com/en/docs/claude-code";
 
 /// A general-purpose agent that executes tasks using the Claude CLI.
 ///
@@ -41,6 +50,7 @@ use super::cli_agent::{CliAgent, CliAgentConfig};
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone)]
 pub struct ClaudeCodeAgent {
     /// Path to the `claude` executable. If None, searches in PATH.
     claude_path: Option<PathBuf>,
@@ -68,12 +78,10 @@ impl ClaudeCodeAgent {
     }
 
     /// Creates a new ClaudeCodeAgent with a custom path to the claude executable.
+    ///
+    /// Equivalent to `ClaudeCodeAgent::new().with_command(path)`.
     pub fn with_path(path: PathBuf) -> Self {
-        Self {
-            claude_path: Some(path),
-            model: None,
-            config: CliAgentConfig::new(),
-        }
+        Self::new().with_command(path)
     }
 
     /// Sets the model to use.
@@ -192,6 +200,40 @@ impl ClaudeCodeAgent {
         self
     }
 
+    /// Sets the command to spawn instead of `claude` from PATH.
+    ///
+    /// Useful for pointing the agent at a wrapper script.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let agent = ClaudeCodeAgent::new().with_command("/opt/bin/claude-wrapper");
+    /// ```
+    pub fn with_command(mut self, path: impl Into<PathBuf>) -> Self {
+        self.claude_path = Some(path.into());
+        self
+    }
+
+    /// Adds CLI arguments that are passed after the agent's own flags.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an argument would override a flag the agent sets itself
+    /// (`-p`, `--print`, `--model`), in either `--flag` or `--flag=value` form. Arguments
+    /// added with `with_arg`/`with_args` are checked when the agent executes
+    /// instead, failing with [`AgentError::ExecutionFailed`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let agent = ClaudeCodeAgent::new()
+    ///     .with_extra_args(vec!["--dangerously-skip-permissions".to_string()]);
+    /// ```
+    pub fn with_extra_args(self, args: Vec<String>) -> Self {
+        if let Some(message) = reserved_flag_conflict(&args, RESERVED_FLAGS) {
+            panic!("{}", message);
+        }
+        self.with_args(args)
+    }
+
     /// Sets the directory where attachment files will be written.
     ///
     /// If not specified, falls back to `working_dir` or system temp directory.
@@ -239,29 +281,81 @@ impl ClaudeCodeAgent {
     }
 
     /// Checks availability using tokio (async version for trait implementation).
-    async fn check_available() -> Result<(), AgentError> {
-        #[cfg(unix)]
-        let check_cmd = "which";
-        #[cfg(windows)]
-        let check_cmd = "where";
+    ///
+    /// Honors a path set with `with_path`; a missing binary is reported as
+    /// [`AgentError::BackendUnavailable`].
+    async fn check_available(&self) -> Result<(), AgentError> {
+        check_cli_available("claude", &self.cli_program(), INSTALL_DOCS).await
+    }
 
-        let output = Command::new(check_cmd)
-            .arg("claude")
-            .output()
-            .await
-            .map_err(|e| AgentError::ProcessError {
+    /// Runs the claude CLI for one payload.
+    async fn run_command(&self, payload: Payload) -> Result<String, AgentError> {
+        // Process attachments using shared config method
+        let (final_prompt, _temp_dir) = self.config.process_payload_attachments(&payload).await?;
+
+        debug!(
+            target: "llm_toolkit::agent::claude_code",
+            "Building claude command with prompt length: {}", final_prompt.len()
+        );
+
+        crate::tracing::trace!(
+            target: "llm_toolkit::agent::claude_code",
+            "\n========== CLAUDE CODE PROMPT ==========\n{}\n====================================",
+            final_prompt
+        );
+
+        let mut cmd = self.build_command(&final_prompt)?;
+
+        debug!(
+            target: "llm_toolkit::agent::claude_code",
+            "Executing claude command: {}",
+            describe_command(&cmd)
+        );
+
+        let output = cmd.output().await.map_err(|e| {
+            error!(
+                target: "llm_toolkit::agent::claude_code",
+                "Failed to execute claude command: {}", e
+            );
+            if self.config.is_missing_program(&e) {
+                return backend_unavailable("claude", &self.cli_program(), INSTALL_DOCS);
+            }
+            AgentError::ProcessError {
                 status_code: None,
-                message: format!("Failed to check claude availability: {}", e),
+                message: format!(
+                    "Failed to spawn claude process: {}. \
+                     Make sure 'claude' is installed and in PATH.",
+                    e
+                ),
                 is_retryable: true,
                 retry_after: None,
-            })?;
+            }
+        })?;
 
         if output.status.success() {
-            Ok(())
+            let stdout = String::from_utf8(output.stdout).map_err(|e| {
+                error!(
+                    target: "llm_toolkit::agent::claude_code",
+                    "Failed to parse stdout as UTF-8: {}", e
+                );
+                AgentError::Other(format!("Failed to parse claude output as UTF-8: {}", e))
+            })?;
+
+            info!(
+                target: "llm_toolkit::agent::claude_code",
+                "Claude command completed successfully, response length: {}", stdout.len()
+            );
+            Ok(stdout)
         } else {
-            Err(AgentError::ExecutionFailed(
-                "claude CLI not found in PATH. Please install Claude CLI.".to_string(),
-            ))
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            error!(
+                target: "llm_toolkit::agent::claude_code",
+                "Claude command failed with stderr: {}", stderr
+            );
+            Err(AgentError::ExecutionFailed(format!(
+                "Claude command failed with status {}: {}",
+                output.status, stderr
+            )))
         }
     }
 }
@@ -290,6 +384,8 @@ impl CliAgent for ClaudeCodeAgent {
     }
 
     fn build_command(&self, prompt: &str) -> Result<Command, AgentError> {
+        self.config.check_extra_args(RESERVED_FLAGS)?;
+
         let cmd_name = self
             .claude_path
             .as_ref()
@@ -338,77 +434,16 @@ impl Agent for ClaudeCodeAgent {
     }
 
     #[instrument(skip(self, intent), fields(
+        backend = "claude_code",
         model = ?self.model,
         working_dir = ?self.config.working_dir,
-        has_attachments = intent.has_attachments(),
-        prompt_length = intent.to_text().len()
+        prompt_length = intent.to_text().len(),
+        attachment_count = intent.attachments().len(),
+        response_length = tracing::field::Empty,
+        elapsed_ms = tracing::field::Empty
     ))]
     async fn execute(&self, intent: Payload) -> Result<Self::Output, AgentError> {
-        let payload = intent;
-
-        // Process attachments using shared config method
-        let (final_prompt, _temp_dir) = self.config.process_payload_attachments(&payload).await?;
-
-        debug!(
-            target: "llm_toolkit::agent::claude_code",
-            "Building claude command with prompt length: {}", final_prompt.len()
-        );
-
-        crate::tracing::trace!(
-            target: "llm_toolkit::agent::claude_code",
-            "\n========== CLAUDE CODE PROMPT ==========\n{}\n====================================",
-            final_prompt
-        );
-
-        let mut cmd = self.build_command(&final_prompt)?;
-
-        debug!(
-            target: "llm_toolkit::agent::claude_code",
-            "Executing claude command: {:?}", cmd
-        );
-
-        let output = cmd.output().await.map_err(|e| {
-            error!(
-                target: "llm_toolkit::agent::claude_code",
-                "Failed to execute claude command: {}", e
-            );
-            AgentError::ProcessError {
-                status_code: None,
-                message: format!(
-                    "Failed to spawn claude process: {}. \
-                     Make sure 'claude' is installed and in PATH.",
-                    e
-                ),
-                is_retryable: true,
-                retry_after: None,
-            }
-        })?;
-
-        if output.status.success() {
-            let stdout = String::from_utf8(output.stdout).map_err(|e| {
-                error!(
-                    target: "llm_toolkit::agent::claude_code",
-                    "Failed to parse stdout as UTF-8: {}", e
-                );
-                AgentError::Other(format!("Failed to parse claude output as UTF-8: {}", e))
-            })?;
-
-            info!(
-                target: "llm_toolkit::agent::claude_code",
-                "Claude command completed successfully, response length: {}", stdout.len()
-            );
-            Ok(stdout)
-        } else {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            error!(
-                target: "llm_toolkit::agent::claude_code",
-                "Claude command failed with stderr: {}", stderr
-            );
-            Err(AgentError::ExecutionFailed(format!(
-                "Claude command failed with status {}: {}",
-                output.status, stderr
-            )))
-        }
+        record_execution(self.run_command(intent)).await
     }
 
     fn name(&self) -> String {
@@ -416,7 +451,14 @@ impl Agent for ClaudeCodeAgent {
     }
 
     async fn is_available(&self) -> Result<(), AgentError> {
-        Self::check_available().await
+        self.check_available().await
+    }
+
+    fn clone_with_execution_profile(
+        &self,
+        profile: crate::agent::ExecutionProfile,
+    ) -> Option<Self> {
+        Some(self.clone().with_execution_profile(profile))
     }
 }
 
@@ -528,6 +570,20 @@ where
         self
     }
 
+    /// Sets the command to spawn instead of `claude` from PATH.
+    pub fn with_command(mut self, path: impl Into<PathBuf>) -> Self {
+        self.inner = self.inner.with_command(path);
+        self
+    }
+
+    /// Adds CLI arguments that are passed after the agent's own flags.
+    ///
+    /// Panics on reserved flags, like [`ClaudeCodeAgent::with_extra_args`].
+    pub fn with_extra_args(mut self, args: Vec<String>) -> Self {
+        self.inner = self.inner.with_extra_args(args);
+        self
+    }
+
     /// Sets the directory where attachment files will be written.
     pub fn with_attachment_dir(mut self, path: impl Into<PathBuf>) -> Self {
         self.inner = self.inner.with_attachment_dir(path);
@@ -747,4 +803,86 @@ mod tests {
         assert_eq!(agent.config.extra_args.len(), 1);
         assert_eq!(agent.config.extra_args[0], "--experimental");
     }
+
+    #[tokio::test]
+    async fn test_missing_binary_reports_backend_unavailable() {
+        let bogus = PathBuf::from("/nonexistent/llm-toolkit-test/claude");
+        let agent = ClaudeCodeAgent::with_path(bogus);
+
+        for error in [
+            agent.execute(Payload::text("hello")).await.unwrap_err(),
+            Agent::is_available(&agent).await.unwrap_err(),
+        ] {
+            match error {
+                AgentError::BackendUnavailable { backend, hint } => {
+                    assert_eq!(backend, "claude");
+                    assert!(
+                        hint.contains("/nonexistent/llm-toolkit-test/claude"),
+                        "{}",
+                        hint
+                    );
+                    assert!(hint.contains(INSTALL_DOCS), "{}", hint);
+                    assert!(hint.contains("with_path"), "{}", hint);
+                }
+                other => panic!("expected BackendUnavailable, got {:?}", other),
+            }
+        }
+    }
+
+    /// Writes an executable script that prints each of its arguments on a line.
+    #[cfg(unix)]
+    fn write_echo_stub(dir: &Path) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.join("stub");
+        std::fs::write(&path, "#!/bin/sh\nprintf '%s\\n' \"$@\"\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_custom_command_and_extra_args_are_forwarded() {
+        let dir = tempfile::tempdir().unwrap();
+        let agent = ClaudeCodeAgent::new()
+            .with_command(write_echo_stub(dir.path()))
+            .with_model(ClaudeModel::Opus4)
+            .with_extra_args(vec!["--dangerously-skip-permissions".to_string()]);
+
+        let output = agent.execute(Payload::text("hello")).await.unwrap();
+
+        let args: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            args,
+            vec![
+                "-p",
+                "hello",
+                "--model",
+                ClaudeModel::Opus4.as_cli_name(),
+                "--dangerously-skip-permissions"
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "extra argument `--model=opus` conflicts")]
+    fn test_with_extra_args_rejects_reserved_flags() {
+        let _ = ClaudeCodeAgent::new().with_extra_args(vec!["--model=opus".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_args_cannot_override_reserved_flags() {
+        for arg in ["--model=opus", "-p"] {
+            let agent = ClaudeCodeAgent::new()
+                .with_command("/nonexistent/llm-toolkit-test/stub")
+                .with_arg(arg);
+
+            match agent.execute(Payload::text("hello")).await {
+                Err(AgentError::ExecutionFailed(message)) => {
+                    assert!(message.contains(arg), "{}", message)
+                }
+                other => panic!("expected ExecutionFailed for {}, got {:?}", arg, other),
+            }
+        }
+    }
 }
@@ -8,6 +8,7 @@
 use crate::agent::{AgentError, ExecutionProfile, Payload};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 use tokio::process::Command;
 use tracing::debug;
 
@@ -104,6 +105,25 @@ impl CliAgentConfig {
         self
     }
 
+    /// Rejects extra arguments that would override flags the agent sets itself.
+    ///
+    /// See [`reserved_flag_conflict`] for which arguments conflict.
+    pub(crate) fn check_extra_args(&self, reserved: &[&str]) -> Result<(), AgentError> {
+        match reserved_flag_conflict(&self.extra_args, reserved) {
+            Some(message) => Err(AgentError::ExecutionFailed(message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns true if a spawn `error` means the program itself was not found.
+    ///
+    /// A missing working directory also surfaces as `NotFound`, so that case
+    /// is excluded.
+    pub(crate) fn is_missing_program(&self, error: &std::io::Error) -> bool {
+        error.kind() == std::io::ErrorKind::NotFound
+            && self.working_dir.as_ref().is_none_or(|dir| dir.is_dir())
+    }
+
     /// Applies configuration to a Command.
     ///
     /// Sets working directory and environment variables on the command.
@@ -119,9 +139,10 @@ impl CliAgentConfig {
 
         // Apply environment variables
         for (key, value) in &self.env_vars {
+            // Values often hold API keys, so only the name is logged
             debug!(
                 target: "llm_toolkit::agent::cli_agent",
-                "Setting environment variable: {}={}", key, value
+                "Setting environment variable: {}", key
             );
             cmd.env(key, value);
         }
@@ -184,6 +205,111 @@ impl CliAgentConfig {
     }
 }
 
+/// Describes the first of `args` that would override a `reserved` flag, if any.
+///
+/// Both `--flag` and `--flag=value` forms of a reserved flag conflict.
+pub(crate) fn reserved_flag_conflict(args: &[String], reserved: &[&str]) -> Option<String> {
+    args.iter()
+        .find(|arg| reserved.contains(&arg.split_once('=').map_or(arg.as_str(), |(flag, _)| flag)))
+        .map(|arg| {
+            format!(
+                "extra argument `{}` conflicts with a flag set by the agent (reserved: {})",
+                arg,
+                reserved.join(", ")
+            )
+        })
+}
+
+/// Describes a command for logging: the program and its arguments.
+///
+/// Unlike the `Debug` output of [`Command`], this leaves out environment
+/// variables, which may contain API keys.
+pub(crate) fn describe_command(cmd: &Command) -> String {
+    let cmd = cmd.as_std();
+    std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|part| format!("{:?}", part))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Builds the error returned when a CLI backend's executable cannot be found.
+///
+/// `program` is the command or path that was tried; the hint points to
+/// `install_docs` and to `with_path` for non-standard locations.
+pub(crate) fn backend_unavailable(
+    backend: &'static str,
+    program: &str,
+    install_docs: &str,
+) -> AgentError {
+    AgentError::BackendUnavailable {
+        backend,
+        hint: format!(
+            "`{}` was not found. Install the {} CLI ({}) and make sure it is in PATH, \
+             or pass its location with `with_path`.",
+            program, backend, install_docs
+        ),
+    }
+}
+
+/// Checks that `program` can be run: an existing file for paths, a PATH hit
+/// (via `which`/`where`) for bare command names.
+pub(crate) async fn check_cli_available(
+    backend: &'static str,
+    program: &str,
+    install_docs: &str,
+) -> Result<(), AgentError> {
+    let path = Path::new(program);
+    let found = if path.components().count() > 1 {
+        tokio::fs::metadata(path)
+            .await
+            .map(|metadata| metadata.is_file())
+            .unwrap_or(false)
+    } else {
+        #[cfg(unix)]
+        let check_cmd = "which";
+        #[cfg(windows)]
+        let check_cmd = "where";
+
+        Command::new(check_cmd)
+            .arg(program)
+            .output()
+            .await
+            .map_err(|e| AgentError::ProcessError {
+                status_code: None,
+                message: format!("Failed to check {} availability: {}", program, e),
+                is_retryable: true,
+                retry_after: None,
+            })?
+            .status
+            .success()
+    };
+
+    if found {
+        Ok(())
+    } else {
+        Err(backend_unavailable(backend, program, install_docs))
+    }
+}
+
+/// Awaits a CLI execution and records its outcome on the current span.
+///
+/// Records `elapsed_ms` always and `response_length` on success; the calling
+/// `execute` must declare both fields as `tracing::field::Empty`.
+pub(crate) async fn record_execution(
+    execution: impl Future<Output = Result<String, AgentError>>,
+) -> Result<String, AgentError> {
+    let started = Instant::now();
+    let result = execution.await;
+
+    let span = tracing::Span::current();
+    span.record("elapsed_ms", started.elapsed().as_millis() as u64);
+    if let Ok(response) = &result {
+        span.record("response_length", response.len());
+    }
+    result
+}
+
 /// Trait for CLI-based agents.
 ///
 /// This trait provides common functionality for agents that execute
@@ -202,6 +328,13 @@ pub trait CliAgent {
     /// Returns the name of the CLI command (e.g., "gemini", "claude").
     fn cli_command_name(&self) -> &str;
 
+    /// Returns the program to run: the configured path, or the command name.
+    fn cli_program(&self) -> String {
+        self.cli_path()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.cli_command_name().to_string())
+    }
+
     /// Builds a Command for execution with the given prompt.
     ///
     /// This method should:
@@ -292,4 +425,17 @@ mod tests {
         assert_eq!(config.extra_args[0], "--flag1");
         assert_eq!(config.extra_args[1], "--flag2");
     }
+
+    #[test]
+    fn test_describe_command_omits_env_values() {
+        let mut cmd = Command::new("claude");
+        CliAgentConfig::new()
+            .with_env("ANTHROPIC_API_KEY", "sk-secret")
+            .apply_to_command(&mut cmd);
+        cmd.arg("-p").arg("hello");
+
+        let described = describe_command(&cmd);
+        assert_eq!(described, r#""claude" "-p" "hello""#);
+        assert!(!described.contains("sk-secret"));
+    }
 }
@@ -10,7 +10,7 @@ use std::path::{Path, PathBuf};
 use tokio::process::Command;
 use tracing::{debug, error, info, instrument};
 
-use super::cli_agent::{CliAgent, CliAgentConfig};
+use super::cli_agent::{CliAgent, CliAgentConfig, describe_command};
 
 /// Type alias for backward compatibility.
 /// Use [`OpenAIModel`] directly for new code.
@@ -45,6 +45,7 @@ pub type CodexModel = OpenAIModel;
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone)]
 pub struct CodexAgent {
     /// Path to the `codex` executable. If None, searches in PATH.
     codex_path: Option<PathBuf>,
@@ -429,7 +430,8 @@ impl Agent for CodexAgent {
 
         debug!(
             target: "llm_toolkit::agent::codex",
-            "Executing codex command: {:?}", cmd
+            "Executing codex command: {}",
+            describe_command(&cmd)
         );
 
         let output = cmd.output().await.map_err(|e| {
@@ -477,6 +479,13 @@ impl Agent for CodexAgent {
     async fn is_available(&self) -> Result<(), AgentError> {
         Self::check_available().await
     }
+
+    fn clone_with_execution_profile(
+        &self,
+        profile: crate::agent::ExecutionProfile,
+    ) -> Option<Self> {
+        Some(self.clone().with_execution_profile(profile))
+    }
 }
 
 #[cfg(test)]
@@ -10,7 +10,16 @@ use std::path::{Path, PathBuf};
 use tokio::process::Command;
 use tracing::{debug, error, info, instrument};
 
-use super::cli_agent::{CliAgent, CliAgentConfig};
+use super::cli_agent::{
+    CliAgent, CliAgentConfig, backend_unavailable, check_cli_available, describe_command,
+    record_execution, reserved_flag_conflict,
+};
+
+/// Flags set by the agent itself, which extra arguments may not override.
+const RESERVED_FLAGS: &[&str] = &["--model", "-m"];
+
+/// Installation instructions for the `gemini` CLI.
+const INSTALL_DOCS: &str = "https://github.com/google-gemini/gemini-cli";
 
 /// A general-purpose agent that executes tasks using the Gemini CLI.
 ///
@@ -41,6 +50,7 @@ use super::cli_agent::{CliAgent, CliAgentConfig};
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone)]
 pub struct GeminiAgent {
     /// Path to the `gemini` executable. If None, searches in PATH.
     gemini_path: Option<PathBuf>,
@@ -68,12 +78,10 @@ impl GeminiAgent {
     }
 
     /// Creates a new GeminiAgent with a custom path to the gemini executable.
+    ///
+    /// Equivalent to `GeminiAgent::new().with_command(path)`.
     pub fn with_path(path: PathBuf) -> Self {
-        Self {
-            gemini_path: Some(path),
-            model: GeminiModel::default(),
-            config: CliAgentConfig::new(),
-        }
+        Self::new().with_command(path)
     }
 
     /// Sets the model to use.
@@ -93,6 +101,10 @@ impl GeminiAgent {
 
     /// Sets the execution profile.
     ///
+    /// The `gemini` CLI has no flags for temperature, top-p or seed, so the
+    /// profile's [`sampling`](crate::agent::ExecutionProfile::sampling) values
+    /// are not passed to it. Use `GeminiApiAgent` when they must take effect.
+    ///
     /// # Example
     /// ```rust,ignore
     /// use llm_toolkit::agent::ExecutionProfile;
@@ -192,6 +204,40 @@ impl GeminiAgent {
         self
     }
 
+    /// Sets the command to spawn instead of `gemini` from PATH.
+    ///
+    /// Useful for pointing the agent at a wrapper script.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let agent = GeminiAgent::new().with_command("/opt/bin/gemini-wrapper");
+    /// ```
+    pub fn with_command(mut self, path: impl Into<PathBuf>) -> Self {
+        self.gemini_path = Some(path.into());
+        self
+    }
+
+    /// Adds CLI arguments that are passed after the agent's own flags.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an argument would override a flag the agent sets itself
+    /// (`--model`, `-m`), in either `--flag` or `--flag=value` form. Arguments
+    /// added with `with_arg`/`with_args` are checked when the agent executes
+    /// instead, failing with [`AgentError::ExecutionFailed`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let agent = GeminiAgent::new()
+    ///     .with_extra_args(vec!["--yolo".to_string()]);
+    /// ```
+    pub fn with_extra_args(self, args: Vec<String>) -> Self {
+        if let Some(message) = reserved_flag_conflict(&args, RESERVED_FLAGS) {
+            panic!("{}", message);
+        }
+        self.with_args(args)
+    }
+
     /// Sets the directory where attachment files will be written.
     ///
     /// If not specified, falls back to `working_dir` or system temp directory.
@@ -238,29 +284,77 @@ impl GeminiAgent {
     }
 
     /// Checks availability using tokio (async version for trait implementation).
-    async fn check_available() -> Result<(), AgentError> {
-        #[cfg(unix)]
-        let check_cmd = "which";
-        #[cfg(windows)]
-        let check_cmd = "where";
+    ///
+    /// Honors a path set with `with_path`; a missing binary is reported as
+    /// [`AgentError::BackendUnavailable`].
+    async fn check_available(&self) -> Result<(), AgentError> {
+        check_cli_available("gemini", &self.cli_program(), INSTALL_DOCS).await
+    }
 
-        let output = Command::new(check_cmd)
-            .arg("gemini")
-            .output()
-            .await
-            .map_err(|e| AgentError::ProcessError {
-                status_code: None,
-                message: format!("Failed to check gemini availability: {}", e),
-                is_retryable: true,
-                retry_after: None,
-            })?;
+    /// Runs the gemini CLI for one payload.
+    async fn run_command(&self, payload: Payload) -> Result<String, AgentError> {
+        // Process attachments using shared config method
+        let (final_prompt, _temp_dir) = self.config.process_payload_attachments(&payload).await?;
+
+        debug!(
+            target = "llm_toolkit::agent::gemini",
+            "Building gemini command with prompt length: {}",
+            final_prompt.len()
+        );
+
+        crate::tracing::trace!(
+            target: "llm_toolkit::agent::gemini",
+            "\n========== GEMINI PROMPT ==========\n{}\n====================================",
+            final_prompt
+        );
+
+        let mut cmd = self.build_command(&final_prompt)?;
+
+        debug!(
+            target = "llm_toolkit::agent::gemini",
+            "Executing gemini command: {}",
+            describe_command(&cmd)
+        );
+
+        let output = cmd.output().await.map_err(|e| {
+            error!(
+                target = "llm_toolkit::agent::gemini",
+                "Failed to execute gemini command: {}", e
+            );
+            if self.config.is_missing_program(&e) {
+                return backend_unavailable("gemini", &self.cli_program(), INSTALL_DOCS);
+            }
+            AgentError::ExecutionFailed(format!("Failed to execute gemini command: {}", e))
+        })?;
 
         if output.status.success() {
-            Ok(())
+            let response = String::from_utf8(output.stdout).map_err(|e| {
+                error!(
+                    target = "llm_toolkit::agent::gemini",
+                    "Failed to parse stdout as UTF-8: {}", e
+                );
+                AgentError::ParseError {
+                    message: format!("Failed to parse gemini stdout: {}", e),
+                    reason: crate::agent::error::ParseErrorReason::UnexpectedEof,
+                }
+            })?;
+
+            info!(
+                target = "llm_toolkit::agent::gemini",
+                "Gemini command completed successfully, response length: {}",
+                response.len()
+            );
+            Ok(response.trim().to_string())
         } else {
-            Err(AgentError::ExecutionFailed(
-                "gemini CLI not found in PATH. Please install Gemini CLI.".to_string(),
-            ))
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            error!(
+                target = "llm_toolkit::agent::gemini",
+                "Gemini command failed with stderr: {}", stderr
+            );
+            Err(AgentError::ExecutionFailed(format!(
+                "Gemini command failed: {}",
+                stderr
+            )))
         }
     }
 }
@@ -289,6 +383,8 @@ impl CliAgent for GeminiAgent {
     }
 
     fn build_command(&self, prompt: &str) -> Result<Command, AgentError> {
+        self.config.check_extra_args(RESERVED_FLAGS)?;
+
         let cmd_name = self
             .gemini_path
             .as_ref()
@@ -329,73 +425,16 @@ impl Agent for GeminiAgent {
     }
 
     #[instrument(skip(self, intent), fields(
+        backend = "gemini",
         model = ?self.model,
         working_dir = ?self.config.working_dir,
-        has_attachments = intent.has_attachments(),
-        prompt_length = intent.to_text().len()
+        prompt_length = intent.to_text().len(),
+        attachment_count = intent.attachments().len(),
+        response_length = tracing::field::Empty,
+        elapsed_ms = tracing::field::Empty
     ))]
     async fn execute(&self, intent: Payload) -> Result<Self::Output, AgentError> {
-        let payload = intent;
-
-        // Process attachments using shared config method
-        let (final_prompt, _temp_dir) = self.config.process_payload_attachments(&payload).await?;
-
-        debug!(
-            target = "llm_toolkit::agent::gemini",
-            "Building gemini command with prompt length: {}",
-            final_prompt.len()
-        );
-
-        crate::tracing::trace!(
-            target: "llm_toolkit::agent::gemini",
-            "\n========== GEMINI PROMPT ==========\n{}\n====================================",
-            final_prompt
-        );
-
-        let mut cmd = self.build_command(&final_prompt)?;
-
-        debug!(
-            target = "llm_toolkit::agent::gemini",
-            "Executing gemini command: {:?}", cmd
-        );
-
-        let output = cmd.output().await.map_err(|e| {
-            error!(
-                target = "llm_toolkit::agent::gemini",
-                "Failed to execute gemini command: {}", e
-            );
-            AgentError::ExecutionFailed(format!("Failed to execute gemini command: {}", e))
-        })?;
-
-        if output.status.success() {
-            let response = String::from_utf8(output.stdout).map_err(|e| {
-                error!(
-                    target = "llm_toolkit::agent::gemini",
-                    "Failed to parse stdout as UTF-8: {}", e
-                );
-                AgentError::ParseError {
-                    message: format!("Failed to parse gemini stdout: {}", e),
-                    reason: crate::agent::error::ParseErrorReason::UnexpectedEof,
-                }
-            })?;
-
-            info!(
-                target = "llm_toolkit::agent::gemini",
-                "Gemini command completed successfully, response length: {}",
-                response.len()
-            );
-            Ok(response.trim().to_string())
-        } else {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            error!(
-                target = "llm_toolkit::agent::gemini",
-                "Gemini command failed with stderr: {}", stderr
-            );
-            Err(AgentError::ExecutionFailed(format!(
-                "Gemini command failed: {}",
-                stderr
-            )))
-        }
+        record_execution(self.run_command(intent)).await
     }
 
     fn name(&self) -> String {
@@ -403,7 +442,14 @@ impl Agent for GeminiAgent {
     }
 
     async fn is_available(&self) -> Result<(), AgentError> {
-        Self::check_available().await
+        self.check_available().await
+    }
+
+    fn clone_with_execution_profile(
+        &self,
+        profile: crate::agent::ExecutionProfile,
+    ) -> Option<Self> {
+        Some(self.clone().with_execution_profile(profile))
     }
 }
 
@@ -544,4 +590,80 @@ mod tests {
         assert_eq!(agent.config.extra_args.len(), 1);
         assert_eq!(agent.config.extra_args[0], "--experimental");
     }
+
+    #[tokio::test]
+    async fn test_missing_binary_reports_backend_unavailable() {
+        let bogus = PathBuf::from("/nonexistent/llm-toolkit-test/gemini");
+        let agent = GeminiAgent::with_path(bogus);
+
+        for error in [
+            agent.execute(Payload::text("hello")).await.unwrap_err(),
+            Agent::is_available(&agent).await.unwrap_err(),
+        ] {
+            match error {
+                AgentError::BackendUnavailable { backend, hint } => {
+                    assert_eq!(backend, "gemini");
+                    assert!(
+                        hint.contains("/nonexistent/llm-toolkit-test/gemini"),
+                        "{}",
+                        hint
+                    );
+                    assert!(hint.contains(INSTALL_DOCS), "{}", hint);
+                    assert!(hint.contains("with_path"), "{}", hint);
+                }
+                other => panic!("expected BackendUnavailable, got {:?}", other),
+            }
+        }
+    }
+
+    /// Writes an executable script that prints each of its arguments on a line.
+    #[cfg(unix)]
+    fn write_echo_stub(dir: &Path) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.join("stub");
+        std::fs::write(&path, "#!/bin/sh\nprintf '%s\\n' \"$@\"\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_custom_command_and_extra_args_are_forwarded() {
+        let dir = tempfile::tempdir().unwrap();
+        let agent = GeminiAgent::new()
+            .with_command(write_echo_stub(dir.path()))
+            .with_model(GeminiModel::Pro25)
+            .with_extra_args(vec!["--yolo".to_string()]);
+
+        let output = agent.execute(Payload::text("hello")).await.unwrap();
+
+        let args: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            args,
+            vec!["--model", GeminiModel::Pro25.as_api_id(), "--yolo", "hello"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "extra argument `-m` conflicts")]
+    fn test_with_extra_args_rejects_reserved_flags() {
+        let _ = GeminiAgent::new().with_extra_args(vec!["--yolo".to_string(), "-m".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_args_cannot_override_reserved_flags() {
+        for arg in ["--model=gemini-2.5-pro", "-m"] {
+            let agent = GeminiAgent::new()
+                .with_command("/nonexistent/llm-toolkit-test/stub")
+                .with_arg(arg);
+
+            match agent.execute(Payload::text("hello")).await {
+                Err(AgentError::ExecutionFailed(message)) => {
+                    assert!(message.contains(arg), "{}", message)
+                }
+                other => panic!("expected ExecutionFailed for {}, got {:?}", arg, other),
+            }
+        }
+    }
 }
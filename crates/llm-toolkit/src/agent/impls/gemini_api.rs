@@ -29,7 +29,7 @@
 //! # }
 //! ```
 
-use crate::agent::{Agent, AgentError, Payload};
+use crate::agent::{Agent, AgentError, ExecutionProfile, Payload};
 use crate::attachment::Attachment;
 use crate::models::GeminiModel;
 use async_trait::async_trait;
@@ -50,6 +50,7 @@ pub struct GeminiApiAgent {
     system_instruction: Option<String>,
     thinking_level: Option<String>,
     enable_google_search: bool,
+    execution_profile: Option<ExecutionProfile>,
 }
 
 impl GeminiApiAgent {
@@ -62,6 +63,7 @@ impl GeminiApiAgent {
             system_instruction: None,
             thinking_level: None,
             enable_google_search: false,
+            execution_profile: None,
         }
     }
 
@@ -139,6 +141,52 @@ impl GeminiApiAgent {
         self
     }
 
+    /// Sends the profile's sampling parameters (`temperature`, `topP`, `seed`)
+    /// in `generationConfig`. Without a profile, the API defaults are used.
+    pub fn with_execution_profile(mut self, profile: ExecutionProfile) -> Self {
+        self.execution_profile = Some(profile);
+        self
+    }
+
+    async fn build_request(&self, payload: &Payload) -> Result<GenerateContentRequest, AgentError> {
+        let contents = vec![Content {
+            role: "user".to_string(),
+            parts: self.build_parts(payload).await?,
+        }];
+
+        let system_instruction = self.system_instruction.as_ref().map(|text| Content {
+            role: "system".to_string(),
+            parts: vec![Part::Text {
+                text: text.to_string(),
+            }],
+        });
+
+        let thinking_config = self.thinking_level.as_ref().map(|level| ThinkingConfig {
+            thinking_level: level.to_string(),
+        });
+        let sampling = self.execution_profile.map(|profile| profile.sampling());
+        let generation_config =
+            (thinking_config.is_some() || sampling.is_some()).then(|| GenerationConfig {
+                thinking_config,
+                temperature: sampling.map(|s| s.temperature),
+                top_p: sampling.and_then(|s| s.top_p),
+                seed: sampling.and_then(|s| s.seed),
+            });
+
+        let tools = if self.enable_google_search {
+            Some(vec![Tool::GoogleSearch(GoogleSearchTool {})])
+        } else {
+            None
+        };
+
+        Ok(GenerateContentRequest {
+            contents,
+            system_instruction,
+            generation_config,
+            tools,
+        })
+    }
+
     async fn build_parts(&self, payload: &Payload) -> Result<Vec<Part>, AgentError> {
         let mut parts = Vec::new();
         let text = payload.to_text();
@@ -246,38 +294,13 @@ impl Agent for GeminiApiAgent {
     }
 
     async fn execute(&self, payload: Payload) -> Result<Self::Output, AgentError> {
-        let contents = vec![Content {
-            role: "user".to_string(),
-            parts: self.build_parts(&payload).await?,
-        }];
-
-        let system_instruction = self.system_instruction.as_ref().map(|text| Content {
-            role: "system".to_string(),
-            parts: vec![Part::Text {
-                text: text.to_string(),
-            }],
-        });
-
-        let generation_config = self.thinking_level.as_ref().map(|level| GenerationConfig {
-            thinking_config: ThinkingConfig {
-                thinking_level: level.to_string(),
-            },
-        });
-
-        let tools = if self.enable_google_search {
-            Some(vec![Tool::GoogleSearch(GoogleSearchTool {})])
-        } else {
-            None
-        };
-
-        let request = GenerateContentRequest {
-            contents,
-            system_instruction,
-            generation_config,
-            tools,
-        };
+        let request = self.build_request(&payload).await?;
         self.send_request(&request).await
     }
+
+    fn clone_with_execution_profile(&self, profile: ExecutionProfile) -> Option<Self> {
+        Some(self.clone().with_execution_profile(profile))
+    }
 }
 
 #[derive(Serialize)]
@@ -295,7 +318,14 @@ struct GenerateContentRequest {
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct GenerationConfig {
-    thinking_config: ThinkingConfig,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thinking_config: Option<ThinkingConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
 }
 
 #[derive(Serialize)]
@@ -487,9 +517,12 @@ mod tests {
             }],
             system_instruction: None,
             generation_config: Some(GenerationConfig {
-                thinking_config: ThinkingConfig {
+                thinking_config: Some(ThinkingConfig {
                     thinking_level: "HIGH".to_string(),
-                },
+                }),
+                temperature: None,
+                top_p: None,
+                seed: None,
             }),
             tools: None,
         };
@@ -516,6 +549,36 @@ mod tests {
         assert!(json.contains("googleSearch"));
     }
 
+    #[tokio::test]
+    async fn test_custom_profile_sets_generation_config() {
+        let agent = GeminiApiAgent::new("test-key", "gemini-2.5-flash").with_execution_profile(
+            ExecutionProfile::Custom {
+                temperature: 0.2,
+                top_p: Some(0.9),
+                seed: Some(42),
+            },
+        );
+
+        let request = agent.build_request(&Payload::text("Hello")).await.unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+        let config = &json["generationConfig"];
+
+        assert_eq!(config["temperature"], serde_json::json!(0.2f32));
+        assert_eq!(config["topP"], serde_json::json!(0.9f32));
+        assert_eq!(config["seed"], 42);
+        assert!(config.get("thinkingConfig").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_generation_config_omitted_without_profile() {
+        let agent = GeminiApiAgent::new("test-key", "gemini-2.5-flash");
+
+        let request = agent.build_request(&Payload::text("Hello")).await.unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert!(json.get("generationConfig").is_none());
+    }
+
     #[test]
     fn test_response_parsing() {
         let json = r#"{
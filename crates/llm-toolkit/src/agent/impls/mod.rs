@@ -1,12 +1,16 @@
 //! Built-in agent implementations.
 
+pub mod cached;
 pub mod claude_code;
 pub mod cli_agent;
 mod cli_attachment;
 pub mod codex_agent;
 pub mod gemini;
 pub mod inner_validator;
+pub mod pipe;
+pub mod rate_limited;
 pub mod retry;
+pub mod with_middleware;
 
 // API client implementations (direct HTTP API calls)
 #[cfg(feature = "anthropic-api")]
@@ -22,11 +26,15 @@ pub mod ollama_api;
 #[cfg(feature = "openai-api")]
 pub mod openai_api;
 
+pub use cached::{CacheEntry, CacheStore, Cached, InMemoryCacheStore};
 pub use claude_code::{ClaudeCodeAgent, ClaudeCodeJsonAgent};
 pub use codex_agent::CodexAgent;
 pub use gemini::GeminiAgent;
 pub use inner_validator::InnerValidatorAgent;
+pub use pipe::Pipe;
+pub use rate_limited::{RateLimited, RateLimiter};
 pub use retry::RetryAgent;
+pub use with_middleware::WithMiddleware;
 
 // Re-export model types from the models module for backward compatibility
 pub use crate::models::{ClaudeModel, GeminiModel, OpenAIModel};
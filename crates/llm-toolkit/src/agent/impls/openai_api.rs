@@ -24,7 +24,7 @@
 //! # }
 //! ```
 
-use crate::agent::{Agent, AgentError, Payload};
+use crate::agent::{Agent, AgentError, ExecutionProfile, Payload};
 use crate::attachment::Attachment;
 use crate::models::OpenAIModel;
 use async_trait::async_trait;
@@ -44,6 +44,7 @@ pub struct OpenAIApiAgent {
     api_key: String,
     model: String,
     max_tokens: Option<u32>,
+    execution_profile: Option<ExecutionProfile>,
 }
 
 impl OpenAIApiAgent {
@@ -54,6 +55,7 @@ impl OpenAIApiAgent {
             api_key: api_key.into(),
             model: model.into(),
             max_tokens: None,
+            execution_profile: None,
         }
     }
 
@@ -97,6 +99,26 @@ impl OpenAIApiAgent {
         self
     }
 
+    /// Sends the profile's sampling parameters (`temperature`, `top_p`, `seed`)
+    /// with every request. Without a profile, the API defaults are used.
+    pub fn with_execution_profile(mut self, profile: ExecutionProfile) -> Self {
+        self.execution_profile = Some(profile);
+        self
+    }
+
+    async fn build_request(&self, payload: &Payload) -> Result<ChatCompletionRequest, AgentError> {
+        let sampling = self.execution_profile.map(|profile| profile.sampling());
+
+        Ok(ChatCompletionRequest {
+            model: self.model.clone(),
+            messages: self.build_messages(payload).await?,
+            max_tokens: self.max_tokens,
+            temperature: sampling.map(|s| s.temperature),
+            top_p: sampling.and_then(|s| s.top_p),
+            seed: sampling.and_then(|s| s.seed),
+        })
+    }
+
     async fn build_messages(&self, payload: &Payload) -> Result<Vec<ChatMessage>, AgentError> {
         let mut content_parts = Vec::new();
 
@@ -198,16 +220,13 @@ impl Agent for OpenAIApiAgent {
     }
 
     async fn execute(&self, payload: Payload) -> Result<Self::Output, AgentError> {
-        let messages = self.build_messages(&payload).await?;
-
-        let request = ChatCompletionRequest {
-            model: self.model.clone(),
-            messages,
-            max_tokens: self.max_tokens,
-        };
-
+        let request = self.build_request(&payload).await?;
         self.send_request(&request).await
     }
+
+    fn clone_with_execution_profile(&self, profile: ExecutionProfile) -> Option<Self> {
+        Some(self.clone().with_execution_profile(profile))
+    }
 }
 
 #[derive(Serialize)]
@@ -216,6 +235,12 @@ struct ChatCompletionRequest {
     messages: Vec<ChatMessage>,
     #[serde(skip_serializing_if = "Option::is_none")]
     max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
 }
 
 #[derive(Serialize)]
@@ -364,6 +389,9 @@ mod tests {
                 }],
             }],
             max_tokens: Some(1000),
+            temperature: None,
+            top_p: None,
+            seed: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -391,6 +419,9 @@ mod tests {
                 ],
             }],
             max_tokens: None,
+            temperature: None,
+            top_p: None,
+            seed: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -398,6 +429,45 @@ mod tests {
         assert!(json.contains("https://example.com/image.png"));
     }
 
+    #[tokio::test]
+    async fn test_custom_profile_sets_sampling_parameters() {
+        let agent = OpenAIApiAgent::new("test-key", "gpt-5").with_execution_profile(
+            ExecutionProfile::Custom {
+                temperature: 0.2,
+                top_p: Some(0.9),
+                seed: Some(42),
+            },
+        );
+
+        let request = agent.build_request(&Payload::text("Hello")).await.unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["temperature"], serde_json::json!(0.2f32));
+        assert_eq!(json["top_p"], serde_json::json!(0.9f32));
+        assert_eq!(json["seed"], 42);
+    }
+
+    #[tokio::test]
+    async fn test_named_profile_sets_temperature_only() {
+        let default_request = OpenAIApiAgent::new("test-key", "gpt-5")
+            .build_request(&Payload::text("Hello"))
+            .await
+            .unwrap();
+        let json = serde_json::to_value(&default_request).unwrap();
+        assert!(json.get("temperature").is_none());
+
+        let request = OpenAIApiAgent::new("test-key", "gpt-5")
+            .with_execution_profile(ExecutionProfile::Deterministic)
+            .build_request(&Payload::text("Hello"))
+            .await
+            .unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["temperature"], 0.0);
+        assert!(json.get("top_p").is_none());
+        assert!(json.get("seed").is_none());
+    }
+
     #[test]
     fn test_response_parsing() {
         let json = r#"{
@@ -0,0 +1,201 @@
+//! Agent composition.
+//!
+//! This module provides `Pipe`, a combinator that runs one agent and feeds its
+//! structured output to a second agent as the prompt.
+
+use crate::agent::{Agent, AgentError, Capability, ExecutionProfile, Payload};
+use crate::prompt::ToPrompt;
+use async_trait::async_trait;
+
+/// Runs `first`, renders its output with [`ToPrompt`], and executes `second` on it.
+///
+/// The output of `first` is converted with [`ToPrompt::to_prompt_parts`] into a
+/// new [`Payload`] (see [`Payload::from_prompt_parts`]); nothing else from the
+/// original payload is forwarded. The pipe's output is `second`'s output.
+///
+/// The pipe presents `first`'s expertise and capabilities, since it accepts the
+/// same input as `first`. Usually built with [`Agent::pipe`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use llm_toolkit::agent::Agent;
+///
+/// // OutlineAgent::Output implements ToPrompt
+/// let pipeline = OutlineAgent::new().pipe(WriterAgent::new());
+/// let article = pipeline.execute("Rust async".into()).await?;
+/// ```
+pub struct Pipe<A: Agent, B: Agent> {
+    first: A,
+    second: B,
+}
+
+impl<A: Agent, B: Agent> Pipe<A, B> {
+    /// Chains `first` into `second`.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Returns a reference to the first agent.
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Returns a reference to the second agent.
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+#[async_trait]
+impl<A: Agent, B: Agent> Agent for Pipe<A, B>
+where
+    A::Output: ToPrompt + Send,
+    B::Output: Send,
+{
+    type Output = B::Output;
+    type Expertise = A::Expertise;
+
+    fn expertise(&self) -> &Self::Expertise {
+        self.first.expertise()
+    }
+
+    fn description(&self) -> &str {
+        self.first.description()
+    }
+
+    fn capabilities(&self) -> Option<Vec<Capability>> {
+        self.first.capabilities()
+    }
+
+    fn name(&self) -> String {
+        format!("{} | {}", self.first.name(), self.second.name())
+    }
+
+    async fn execute(&self, payload: Payload) -> Result<Self::Output, AgentError> {
+        let intermediate = self.first.execute(payload).await?;
+        let next = Payload::from_prompt_parts(intermediate.to_prompt_parts());
+        self.second.execute(next).await
+    }
+
+    async fn is_available(&self) -> Result<(), AgentError> {
+        self.first.is_available().await?;
+        self.second.is_available().await
+    }
+
+    fn clone_with_execution_profile(&self, profile: ExecutionProfile) -> Option<Self> {
+        // Both stages must accept the profile, otherwise half the pipeline would ignore it
+        Some(Self::new(
+            self.first.clone_with_execution_profile(profile)?,
+            self.second.clone_with_execution_profile(profile)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prompt::PromptPart;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Outline {
+        title: String,
+        points: Vec<String>,
+    }
+
+    impl ToPrompt for Outline {
+        fn to_prompt_parts(&self) -> Vec<PromptPart> {
+            vec![PromptPart::Text(format!(
+                "Title: {}\nPoints: {}",
+                self.title,
+                self.points.join(", ")
+            ))]
+        }
+    }
+
+    /// Mock first stage producing a structured outline from the topic
+    struct OutlineAgent;
+
+    #[async_trait]
+    impl Agent for OutlineAgent {
+        type Output = Outline;
+        type Expertise = &'static str;
+
+        fn expertise(&self) -> &&'static str {
+            const EXPERTISE: &str = "Outlines articles";
+            &EXPERTISE
+        }
+
+        fn name(&self) -> String {
+            "OutlineAgent".to_string()
+        }
+
+        async fn execute(&self, payload: Payload) -> Result<Outline, AgentError> {
+            Ok(Outline {
+                title: payload.to_text(),
+                points: vec!["intro".to_string(), "summary".to_string()],
+            })
+        }
+    }
+
+    /// Mock second stage echoing the prompt it received
+    struct EchoAgent;
+
+    #[async_trait]
+    impl Agent for EchoAgent {
+        type Output = String;
+        type Expertise = &'static str;
+
+        fn expertise(&self) -> &&'static str {
+            const EXPERTISE: &str = "Echoes its prompt";
+            &EXPERTISE
+        }
+
+        fn name(&self) -> String {
+            "EchoAgent".to_string()
+        }
+
+        async fn execute(&self, payload: Payload) -> Result<String, AgentError> {
+            Ok(payload.to_text())
+        }
+    }
+
+    /// Mock agent that always fails
+    struct FailingAgent;
+
+    #[async_trait]
+    impl Agent for FailingAgent {
+        type Output = Outline;
+        type Expertise = &'static str;
+
+        fn expertise(&self) -> &&'static str {
+            const EXPERTISE: &str = "Always fails";
+            &EXPERTISE
+        }
+
+        async fn execute(&self, _payload: Payload) -> Result<Outline, AgentError> {
+            Err(AgentError::ExecutionFailed("outline failed".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipe_feeds_rendered_output_to_next_agent() {
+        let pipeline = OutlineAgent.pipe(EchoAgent);
+
+        let output = pipeline.execute(Payload::text("Rust async")).await.unwrap();
+
+        assert_eq!(output, "Title: Rust async\nPoints: intro, summary");
+        assert_eq!(pipeline.name(), "OutlineAgent | EchoAgent");
+        assert_eq!(pipeline.expertise(), &"Outlines articles");
+    }
+
+    #[tokio::test]
+    async fn test_pipe_stops_at_first_error() {
+        let pipeline = FailingAgent.pipe(EchoAgent);
+
+        let result = pipeline.execute(Payload::text("anything")).await;
+
+        assert!(matches!(result, Err(AgentError::ExecutionFailed(msg)) if msg == "outline failed"));
+    }
+}
@@ -0,0 +1,256 @@
+//! Rate limiting wrapper for agents.
+//!
+//! This module provides `RateLimited`, a decorator that keeps calls to the
+//! underlying agent under a maximum number of requests per time window.
+
+use crate::agent::{Agent, AgentError, Capability, ExecutionProfile, Payload};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::{Instant, sleep};
+
+/// A token bucket allowing `max_requests` per `per` duration.
+///
+/// The bucket starts full, so up to `max_requests` calls may run immediately;
+/// after that, tokens refill continuously at `max_requests / per`. Waiters are
+/// served in the order they arrive.
+///
+/// Share one limiter between several [`RateLimited`] agents (via
+/// [`RateLimited::with_limiter`]) when they use the same backend API key.
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_sec: f64,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing `max_requests` per `per` duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_requests` is zero or `per` is zero.
+    pub fn new(max_requests: u32, per: Duration) -> Self {
+        assert!(max_requests > 0, "max_requests must be greater than zero");
+        assert!(
+            !per.is_zero(),
+            "rate limit duration must be greater than zero"
+        );
+
+        let capacity = f64::from(max_requests);
+        Self {
+            capacity,
+            refill_per_sec: capacity / per.as_secs_f64(),
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a request may be made, then consumes one token.
+    pub async fn acquire(&self) {
+        // Holding the lock while sleeping queues later callers behind this one
+        let mut state = self.state.lock().await;
+
+        loop {
+            let now = Instant::now();
+            let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+            state.tokens = (state.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+            state.last_refill = now;
+
+            if state.tokens >= 1.0 {
+                state.tokens -= 1.0;
+                return;
+            }
+
+            let wait = Duration::from_secs_f64((1.0 - state.tokens) / self.refill_per_sec);
+            log::debug!("⏳ Rate limit reached, waiting {:?}", wait);
+            sleep(wait).await;
+        }
+    }
+}
+
+/// A wrapper agent that limits how often the underlying agent is called.
+///
+/// Calls to [`execute`](Agent::execute) wait for the shared [`RateLimiter`]
+/// before reaching the inner agent, so concurrent callers are spaced out
+/// instead of hitting the backend's rate limit. Output, expertise, and
+/// availability are forwarded unchanged.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use llm_toolkit::agent::impls::{ClaudeCodeAgent, RateLimited, RateLimiter};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// // At most 10 requests per minute for a single agent
+/// let agent = RateLimited::new(ClaudeCodeAgent::new(), 10, Duration::from_secs(60));
+///
+/// // Two agents sharing one API key share one budget
+/// let limiter = Arc::new(RateLimiter::new(10, Duration::from_secs(60)));
+/// let writer = RateLimited::with_limiter(ClaudeCodeAgent::new(), limiter.clone());
+/// let reviewer = RateLimited::with_limiter(ClaudeCodeAgent::new(), limiter);
+/// ```
+pub struct RateLimited<A: Agent> {
+    inner: A,
+    limiter: Arc<RateLimiter>,
+}
+
+impl<A: Agent> RateLimited<A> {
+    /// Wraps `inner` with its own limit of `max_requests` per `per` duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_requests` is zero or `per` is zero.
+    pub fn new(inner: A, max_requests: u32, per: Duration) -> Self {
+        Self::with_limiter(inner, Arc::new(RateLimiter::new(max_requests, per)))
+    }
+
+    /// Wraps `inner` with an existing (possibly shared) limiter.
+    pub fn with_limiter(inner: A, limiter: Arc<RateLimiter>) -> Self {
+        Self { inner, limiter }
+    }
+
+    /// Returns a reference to the inner agent.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Returns the limiter, e.g. to share it with another agent.
+    pub fn limiter(&self) -> Arc<RateLimiter> {
+        Arc::clone(&self.limiter)
+    }
+}
+
+#[async_trait]
+impl<A: Agent> Agent for RateLimited<A>
+where
+    A::Output: Send,
+{
+    type Output = A::Output;
+    type Expertise = A::Expertise;
+
+    fn expertise(&self) -> &Self::Expertise {
+        self.inner.expertise()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn capabilities(&self) -> Option<Vec<Capability>> {
+        self.inner.capabilities()
+    }
+
+    fn name(&self) -> String {
+        // Transparent decorator: keep the inner name for orchestrator lookup
+        self.inner.name()
+    }
+
+    async fn execute(&self, payload: Payload) -> Result<Self::Output, AgentError> {
+        self.limiter.acquire().await;
+        self.inner.execute(payload).await
+    }
+
+    async fn is_available(&self) -> Result<(), AgentError> {
+        // Availability checks don't count against the limit
+        self.inner.is_available().await
+    }
+
+    fn clone_with_execution_profile(&self, profile: ExecutionProfile) -> Option<Self> {
+        // The copy draws from the same bucket as the original
+        Some(Self::with_limiter(
+            self.inner.clone_with_execution_profile(profile)?,
+            Arc::clone(&self.limiter),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mock agent that records when each call reaches it
+    struct TimestampAgent {
+        calls: std::sync::Mutex<Vec<Instant>>,
+    }
+
+    #[async_trait]
+    impl Agent for TimestampAgent {
+        type Output = String;
+        type Expertise = &'static str;
+
+        fn expertise(&self) -> &&'static str {
+            const EXPERTISE: &str = "Test agent that records call times";
+            &EXPERTISE
+        }
+
+        async fn execute(&self, payload: Payload) -> Result<String, AgentError> {
+            self.calls.lock().unwrap().push(Instant::now());
+            Ok(payload.to_text())
+        }
+    }
+
+    fn timestamp_agent() -> TimestampAgent {
+        TimestampAgent {
+            calls: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_concurrent_calls_are_spaced_by_the_limit() {
+        let agent = RateLimited::new(timestamp_agent(), 1, Duration::from_secs(2));
+
+        let (first, second) = tokio::join!(
+            agent.execute(Payload::text("first")),
+            agent.execute(Payload::text("second"))
+        );
+        assert_eq!(first.unwrap(), "first");
+        assert_eq!(second.unwrap(), "second");
+
+        let calls = agent.inner().calls.lock().unwrap().clone();
+        assert_eq!(calls.len(), 2);
+        assert!(
+            calls[1].duration_since(calls[0]) >= Duration::from_secs(2),
+            "second call should wait for a refill, got {:?}",
+            calls[1].duration_since(calls[0])
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_burst_up_to_capacity_is_not_delayed() {
+        let agent = RateLimited::new(timestamp_agent(), 3, Duration::from_secs(60));
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            agent.execute(Payload::text("burst")).await.unwrap();
+        }
+        assert_eq!(Instant::now(), start);
+
+        // The fourth call waits for one token: 60s / 3 requests
+        agent.execute(Payload::text("over")).await.unwrap();
+        assert!(Instant::now().duration_since(start) >= Duration::from_secs(20));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_shared_limiter_applies_across_agents() {
+        let limiter = Arc::new(RateLimiter::new(1, Duration::from_secs(1)));
+        let writer = RateLimited::with_limiter(timestamp_agent(), limiter.clone());
+        let reviewer = RateLimited::with_limiter(timestamp_agent(), limiter);
+        let start = Instant::now();
+
+        writer.execute(Payload::text("draft")).await.unwrap();
+        reviewer.execute(Payload::text("review")).await.unwrap();
+
+        assert!(Instant::now().duration_since(start) >= Duration::from_secs(1));
+    }
+}
@@ -3,7 +3,10 @@
 //! This module provides `RetryAgent`, a decorator that adds retry functionality
 //! to any agent implementation.
 
-use crate::agent::{Agent, AgentError, Payload, retry::retry_execution};
+use crate::agent::{
+    Agent, AgentError, ExecutionProfile, Payload,
+    retry::{retry_execution, retry_execution_with_reprompt},
+};
 use async_trait::async_trait;
 
 /// A wrapper agent that adds retry logic to any underlying agent.
@@ -21,9 +24,17 @@ use async_trait::async_trait;
 ///
 /// let result = retry_agent.execute("Generate a summary".into()).await?;
 /// ```
+///
+/// To tell the model why its previous output was rejected, enable
+/// [`with_reprompt_on_parse_error`](Self::with_reprompt_on_parse_error):
+///
+/// ```rust,ignore
+/// let retry_agent = RetryAgent::new(json_agent, 3).with_reprompt_on_parse_error(true);
+/// ```
 pub struct RetryAgent<T: Agent> {
     inner: T,
     max_retries: u32,
+    reprompt_on_parse_error: bool,
 }
 
 impl<T: Agent> RetryAgent<T> {
@@ -34,7 +45,23 @@ impl<T: Agent> RetryAgent<T> {
     /// * `inner` - The agent to wrap with retry logic
     /// * `max_retries` - Maximum number of retry attempts (not including the first attempt)
     pub fn new(inner: T, max_retries: u32) -> Self {
-        Self { inner, max_retries }
+        Self {
+            inner,
+            max_retries,
+            reprompt_on_parse_error: false,
+        }
+    }
+
+    /// Appends the parse error to the payload of the next attempt.
+    ///
+    /// When enabled, an attempt that fails with a parse error is retried with
+    /// the original payload plus a note such as "Your previous response could
+    /// not be parsed: ... Please return valid JSON matching the schema."
+    /// Validation failures from `#[agent(validate)]` agents are fed back the
+    /// same way. See [`retry_execution_with_reprompt`].
+    pub fn with_reprompt_on_parse_error(mut self, enabled: bool) -> Self {
+        self.reprompt_on_parse_error = enabled;
+        self
     }
 
     /// Returns a reference to the inner agent.
@@ -70,16 +97,28 @@ where
     async fn execute(&self, payload: Payload) -> Result<Self::Output, AgentError> {
         // Use the unified retry_execution function
         let inner = &self.inner;
-        retry_execution(self.max_retries, &payload, move |p| {
+        let operation = move |p: &Payload| {
             let p = p.clone();
             async move { inner.execute(p).await }
-        })
-        .await
+        };
+        if self.reprompt_on_parse_error {
+            retry_execution_with_reprompt(self.max_retries, &payload, operation).await
+        } else {
+            retry_execution(self.max_retries, &payload, operation).await
+        }
     }
 
     async fn is_available(&self) -> Result<(), AgentError> {
         self.inner.is_available().await
     }
+
+    fn clone_with_execution_profile(&self, profile: ExecutionProfile) -> Option<Self> {
+        Some(Self {
+            inner: self.inner.clone_with_execution_profile(profile)?,
+            max_retries: self.max_retries,
+            reprompt_on_parse_error: self.reprompt_on_parse_error,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -169,6 +208,77 @@ mod tests {
         assert_eq!(retry_agent.inner().total_calls(), 3); // 1 initial + 2 retries
     }
 
+    /// Mock agent that parses canned responses as JSON, like a structured agent
+    struct JsonResponseAgent {
+        responses: Vec<&'static str>,
+        received: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Agent for JsonResponseAgent {
+        type Output = serde_json::Value;
+        type Expertise = &'static str;
+
+        fn expertise(&self) -> &&'static str {
+            const EXPERTISE: &str = "Test agent that returns canned JSON responses";
+            &EXPERTISE
+        }
+
+        async fn execute(&self, payload: Payload) -> Result<serde_json::Value, AgentError> {
+            let attempt = {
+                let mut received = self.received.lock().unwrap();
+                received.push(payload.to_text());
+                received.len() - 1
+            };
+            serde_json::from_str(self.responses[attempt]).map_err(|e| AgentError::ParseError {
+                message: e.to_string(),
+                reason: ParseErrorReason::InvalidJson,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_agent_reprompts_with_parse_error() {
+        let base = JsonResponseAgent {
+            responses: vec![r#"{"status": "ok""#, r#"{"status": "ok"}"#],
+            received: std::sync::Mutex::new(Vec::new()),
+        };
+        let retry_agent = RetryAgent::new(base, 2).with_reprompt_on_parse_error(true);
+
+        let result = retry_agent
+            .execute(Payload::text("Report the status"))
+            .await
+            .unwrap();
+
+        assert_eq!(result, serde_json::json!({"status": "ok"}));
+        let received = retry_agent.inner().received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[0], "Report the status");
+        assert!(received[1].starts_with("Report the status\n"));
+        assert!(
+            received[1].contains("Your previous response could not be parsed: EOF while parsing"),
+            "Second attempt should carry the parse error: {}",
+            received[1]
+        );
+        assert!(received[1].contains("Please return valid JSON matching the schema."));
+    }
+
+    #[tokio::test]
+    async fn test_retry_agent_without_reprompt_does_not_retry_invalid_json() {
+        let base = JsonResponseAgent {
+            responses: vec![r#"{"status": "ok""#, r#"{"status": "ok"}"#],
+            received: std::sync::Mutex::new(Vec::new()),
+        };
+        let retry_agent = RetryAgent::new(base, 2);
+
+        let result = retry_agent
+            .execute(Payload::text("Report the status"))
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(retry_agent.inner().received.lock().unwrap().len(), 1);
+    }
+
     #[tokio::test]
     async fn test_retry_agent_name() {
         let base = FailingAgent::new(0);
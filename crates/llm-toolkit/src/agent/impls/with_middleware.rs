@@ -0,0 +1,154 @@
+//! Prompt middleware wrapper for agents.
+//!
+//! This module provides `WithMiddleware`, a decorator that runs a chain of
+//! [`PromptMiddleware`] over each payload before the underlying agent sees it.
+
+use crate::agent::middleware::PromptMiddleware;
+use crate::agent::{Agent, AgentError, Capability, ExecutionProfile, Payload};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// A wrapper agent that rewrites payloads with middleware before delegating.
+///
+/// Middleware runs in the order it was added: the output of one is the input
+/// of the next. Output, expertise, and availability are forwarded unchanged.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use llm_toolkit::agent::impls::{ClaudeCodeAgent, WithMiddleware};
+/// use llm_toolkit::agent::middleware::{RedactMiddleware, TimestampMiddleware};
+///
+/// let agent = WithMiddleware::new(ClaudeCodeAgent::new())
+///     .with_middleware(TimestampMiddleware::new())
+///     .with_middleware(RedactMiddleware::new(r"sk-[A-Za-z0-9]{20,}")?);
+/// ```
+pub struct WithMiddleware<A: Agent> {
+    inner: A,
+    middleware: Vec<Arc<dyn PromptMiddleware>>,
+}
+
+impl<A: Agent> WithMiddleware<A> {
+    /// Wraps `inner` with an empty middleware chain.
+    pub fn new(inner: A) -> Self {
+        Self {
+            inner,
+            middleware: Vec::new(),
+        }
+    }
+
+    /// Appends `middleware` to the end of the chain.
+    pub fn with_middleware(mut self, middleware: impl PromptMiddleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Returns a reference to the inner agent.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Runs the middleware chain over `payload`, as done before each call.
+    pub fn process(&self, payload: Payload) -> Payload {
+        self.middleware
+            .iter()
+            .fold(payload, |payload, middleware| middleware.process(payload))
+    }
+}
+
+#[async_trait]
+impl<A: Agent> Agent for WithMiddleware<A>
+where
+    A::Output: Send,
+{
+    type Output = A::Output;
+    type Expertise = A::Expertise;
+
+    fn expertise(&self) -> &Self::Expertise {
+        self.inner.expertise()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn capabilities(&self) -> Option<Vec<Capability>> {
+        self.inner.capabilities()
+    }
+
+    fn name(&self) -> String {
+        // Transparent decorator: keep the inner name for orchestrator lookup
+        self.inner.name()
+    }
+
+    async fn execute(&self, payload: Payload) -> Result<Self::Output, AgentError> {
+        self.inner.execute(self.process(payload)).await
+    }
+
+    async fn is_available(&self) -> Result<(), AgentError> {
+        self.inner.is_available().await
+    }
+
+    fn clone_with_execution_profile(&self, profile: ExecutionProfile) -> Option<Self> {
+        Some(Self {
+            inner: self.inner.clone_with_execution_profile(profile)?,
+            middleware: self.middleware.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::middleware::RedactMiddleware;
+    use std::sync::Mutex;
+
+    /// Mock agent that records the text of every payload it receives
+    #[derive(Default)]
+    struct RecordingAgent {
+        received: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Agent for RecordingAgent {
+        type Output = String;
+        type Expertise = &'static str;
+
+        fn expertise(&self) -> &&'static str {
+            const EXPERTISE: &str = "Test agent that records payloads";
+            &EXPERTISE
+        }
+
+        async fn execute(&self, payload: Payload) -> Result<String, AgentError> {
+            let text = payload.to_text();
+            self.received.lock().unwrap().push(text.clone());
+            Ok(text)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middleware_runs_in_order() {
+        let agent = WithMiddleware::new(RecordingAgent::default())
+            .with_middleware(|payload: Payload| payload.with_text("first"))
+            .with_middleware(|payload: Payload| payload.with_text("second"))
+            .with_middleware(|payload: Payload| payload.map_text(|text| text.to_uppercase()));
+
+        let output = agent.execute(Payload::text("start")).await.unwrap();
+
+        assert_eq!(output, "START\nFIRST\nSECOND");
+    }
+
+    #[tokio::test]
+    async fn test_redaction_happens_before_inner_agent() {
+        let agent = WithMiddleware::new(RecordingAgent::default())
+            .with_middleware(RedactMiddleware::new(r"password=\S+").unwrap());
+
+        agent
+            .execute(Payload::text("Connect with password=hunter2 please"))
+            .await
+            .unwrap();
+
+        let received = agent.inner().received.lock().unwrap();
+        assert_eq!(*received, vec!["Connect with [REDACTED] please"]);
+    }
+}
@@ -0,0 +1,200 @@
+//! Prompt middleware: payload rewrites applied before an agent is called.
+//!
+//! A [`PromptMiddleware`] turns one payload into another, e.g. to inject global
+//! context or to redact secrets. Wrap an agent with
+//! [`WithMiddleware`](super::impls::WithMiddleware) to run a chain of them on
+//! every call.
+
+use super::Payload;
+use regex::Regex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Rewrites a payload before it is sent to an agent.
+///
+/// Closures `Fn(Payload) -> Payload` implement this trait, so one-off
+/// middleware does not need its own type.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use llm_toolkit::agent::middleware::PromptMiddleware;
+/// use llm_toolkit::agent::Payload;
+///
+/// struct Locale(&'static str);
+///
+/// impl PromptMiddleware for Locale {
+///     fn process(&self, payload: Payload) -> Payload {
+///         payload.prepend_text(format!("Answer in the {} locale.", self.0))
+///     }
+/// }
+/// ```
+pub trait PromptMiddleware: Send + Sync {
+    /// Returns the payload to send in place of `payload`.
+    fn process(&self, payload: Payload) -> Payload;
+}
+
+impl<F> PromptMiddleware for F
+where
+    F: Fn(Payload) -> Payload + Send + Sync,
+{
+    fn process(&self, payload: Payload) -> Payload {
+        self(payload)
+    }
+}
+
+/// Prepends the current UTC time, e.g. `Current time: 2025-01-31T09:30:00Z`.
+///
+/// Models have no clock of their own, so this grounds relative dates such as
+/// "tomorrow" or "last week".
+#[derive(Debug, Clone, Default)]
+pub struct TimestampMiddleware {
+    fixed: Option<SystemTime>,
+}
+
+impl TimestampMiddleware {
+    /// Stamps each payload with the time it is processed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stamps each payload with `time` instead of the current time.
+    pub fn fixed(time: SystemTime) -> Self {
+        Self { fixed: Some(time) }
+    }
+}
+
+impl PromptMiddleware for TimestampMiddleware {
+    fn process(&self, payload: Payload) -> Payload {
+        let time = self.fixed.unwrap_or_else(SystemTime::now);
+        payload.prepend_text(format!("Current time: {}", format_utc(time)))
+    }
+}
+
+/// Formats `time` as an RFC 3339 UTC timestamp with second precision.
+fn format_utc(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/// Replaces every match of a regex in the payload's text with a placeholder.
+///
+/// Text, dialogue messages, context, and documents are all redacted (see
+/// [`Payload::map_text`]). Attachments are not inspected.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use llm_toolkit::agent::middleware::RedactMiddleware;
+///
+/// let redact = RedactMiddleware::new(r"sk-[A-Za-z0-9]{20,}")?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct RedactMiddleware {
+    pattern: Regex,
+    replacement: String,
+}
+
+impl RedactMiddleware {
+    /// Redacts matches of `pattern`, replacing them with `[REDACTED]`.
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            pattern: Regex::new(pattern)?,
+            replacement: "[REDACTED]".to_string(),
+        })
+    }
+
+    /// Uses `replacement` instead of `[REDACTED]`.
+    ///
+    /// The replacement is inserted literally; `$` group references are not expanded.
+    pub fn with_replacement(mut self, replacement: impl Into<String>) -> Self {
+        self.replacement = replacement.into();
+        self
+    }
+}
+
+impl PromptMiddleware for RedactMiddleware {
+    fn process(&self, payload: Payload) -> Payload {
+        payload.map_text(|text| {
+            self.pattern
+                .replace_all(text, regex::NoExpand(&self.replacement))
+                .into_owned()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_format_utc() {
+        assert_eq!(format_utc(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        // 2024-02-29 (leap day) 13:45:30 UTC
+        let time = UNIX_EPOCH + Duration::from_secs(1_709_214_330);
+        assert_eq!(format_utc(time), "2024-02-29T13:45:30Z");
+    }
+
+    #[test]
+    fn test_timestamp_middleware_prepends_time() {
+        let payload =
+            TimestampMiddleware::fixed(UNIX_EPOCH).process(Payload::text("What day is it?"));
+
+        assert_eq!(
+            payload.to_text(),
+            "Current time: 1970-01-01T00:00:00Z\nWhat day is it?"
+        );
+    }
+
+    #[test]
+    fn test_redact_middleware_removes_matches() {
+        let redact = RedactMiddleware::new(r"sk-[A-Za-z0-9]{8,}").unwrap();
+
+        let payload = redact.process(
+            Payload::text("Use key sk-abcdef123456 to call the API")
+                .with_text("Backup: sk-ZYXWVU987654"),
+        );
+
+        let text = payload.to_text();
+        assert!(!text.contains("sk-"), "{}", text);
+        assert_eq!(
+            text,
+            "Use key [REDACTED] to call the API\nBackup: [REDACTED]"
+        );
+    }
+
+    #[test]
+    fn test_redact_middleware_custom_replacement_is_literal() {
+        let redact = RedactMiddleware::new(r"\d{4}-\d{4}")
+            .unwrap()
+            .with_replacement("$0-hidden");
+
+        let payload = redact.process(Payload::text("card 1234-5678"));
+
+        assert_eq!(payload.to_text(), "card $0-hidden");
+    }
+}
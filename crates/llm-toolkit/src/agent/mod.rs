@@ -214,8 +214,10 @@
 //! This pattern is recommended for building your own custom agents.
 
 pub mod capability;
+pub mod coerce;
 pub mod error;
 pub mod payload;
+pub mod validate;
 
 #[cfg(feature = "agent")]
 pub mod impls;
@@ -229,6 +231,9 @@ pub mod persona;
 #[cfg(feature = "agent")]
 pub mod history;
 
+#[cfg(feature = "agent")]
+pub mod middleware;
+
 #[cfg(feature = "agent")]
 pub mod retrieval;
 
@@ -265,19 +270,125 @@ pub mod rule_based_detector;
 #[cfg(feature = "agent")]
 pub mod agent_based_detector;
 
+#[cfg(feature = "agent")]
+pub mod const_agent;
+
 /// Defines the execution profile for an agent, controlling its behavior.
 ///
-/// This enum provides a semantic way to configure agents for different tasks
-/// without exposing model-specific parameters like temperature directly.
-#[derive(Debug, Clone, Copy, Default)]
+/// The named profiles provide a semantic way to configure agents for different
+/// tasks without exposing model-specific parameters. They map to fixed sampling
+/// temperatures (see [`sampling`](Self::sampling)); use [`Custom`](Self::Custom)
+/// for explicit control, e.g. a seed for reproducible runs.
+///
+/// API agents such as `OpenAIApiAgent` and `GeminiApiAgent` send these values
+/// in the request body. CLI agents have no sampling flags and ignore them.
+///
+/// Profiles are `Eq`: sampling values compare by value, with `0.0` equal to
+/// `-0.0`. NaN is not a meaningful sampling value; build custom profiles with
+/// [`ExecutionProfile::custom`], which rejects it.
+#[derive(Debug, Clone, Copy, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ExecutionProfile {
-    /// For tasks requiring creativity and diverse outputs.
+    /// For tasks requiring creativity and diverse outputs (temperature `1.0`).
     Creative,
-    /// A balanced profile for general use cases.
+    /// A balanced profile for general use cases (temperature `0.7`).
     #[default]
     Balanced,
-    /// For tasks requiring precision, consistency, and predictable outputs.
+    /// For tasks requiring precision, consistency, and predictable outputs
+    /// (temperature `0.0`).
     Deterministic,
+    /// Explicit sampling parameters; see [`ExecutionProfile::custom`].
+    Custom {
+        /// Sampling temperature.
+        temperature: f32,
+        /// Nucleus sampling cutoff, if any.
+        top_p: Option<f32>,
+        /// Seed for reproducible sampling, on backends that support it.
+        seed: Option<u64>,
+    },
+}
+
+impl PartialEq for ExecutionProfile {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                ExecutionProfile::Custom {
+                    temperature,
+                    top_p,
+                    seed,
+                },
+                ExecutionProfile::Custom {
+                    temperature: other_temperature,
+                    top_p: other_top_p,
+                    seed: other_seed,
+                },
+            ) => {
+                sampling_bits(*temperature) == sampling_bits(*other_temperature)
+                    && top_p.map(sampling_bits) == other_top_p.map(sampling_bits)
+                    && seed == other_seed
+            }
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+// Bit equality after folding -0.0 into 0.0 is reflexive and transitive
+impl Eq for ExecutionProfile {}
+
+/// Bit pattern of a sampling value, with `-0.0` folded into `0.0`.
+fn sampling_bits(value: f32) -> u32 {
+    if value == 0.0 {
+        0.0f32.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
+
+impl ExecutionProfile {
+    /// Creates a [`Custom`](Self::Custom) profile with explicit sampling parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `temperature` or `top_p` is NaN.
+    pub fn custom(temperature: f32, top_p: Option<f32>, seed: Option<u64>) -> Self {
+        assert!(!temperature.is_nan(), "temperature must not be NaN");
+        assert!(!top_p.is_some_and(f32::is_nan), "top_p must not be NaN");
+        ExecutionProfile::Custom {
+            temperature,
+            top_p,
+            seed,
+        }
+    }
+
+    /// Returns the sampling parameters this profile maps to.
+    pub fn sampling(&self) -> SamplingParams {
+        let (temperature, top_p, seed) = match *self {
+            ExecutionProfile::Creative => (1.0, None, None),
+            ExecutionProfile::Balanced => (0.7, None, None),
+            ExecutionProfile::Deterministic => (0.0, None, None),
+            ExecutionProfile::Custom {
+                temperature,
+                top_p,
+                seed,
+            } => (temperature, top_p, seed),
+        };
+        SamplingParams {
+            temperature,
+            top_p,
+            seed,
+        }
+    }
+}
+
+/// Backend sampling parameters derived from an [`ExecutionProfile`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SamplingParams {
+    /// Sampling temperature.
+    pub temperature: f32,
+    /// Nucleus sampling cutoff, if any.
+    pub top_p: Option<f32>,
+    /// Seed for reproducible sampling, if any.
+    pub seed: Option<u64>,
 }
 
 pub use capability::Capability;
@@ -290,6 +401,7 @@ pub use payload_message::{
     PayloadMessage, RelatedParticipant, RelatedPayloadMessage, SpeakerRelation,
     participant_relation,
 };
+pub use validate::Validate;
 
 #[cfg(feature = "agent")]
 pub use env_context::{EnvContext, JournalSummary, StepInfo};
@@ -309,6 +421,9 @@ pub use rule_based_detector::RuleBasedDetector;
 #[cfg(feature = "agent")]
 pub use agent_based_detector::AgentBasedDetector;
 
+#[cfg(feature = "agent")]
+pub use const_agent::ConstAgent;
+
 use crate::prompt::ToPrompt;
 use async_trait::async_trait;
 use serde::{Serialize, de::DeserializeOwned};
@@ -484,6 +599,40 @@ pub trait Agent: Send + Sync {
     /// A `Result` containing the structured output on success, or an `AgentError` on failure.
     async fn execute(&self, intent: Payload) -> Result<Self::Output, AgentError>;
 
+    /// Executes the agent over many intents, running at most `concurrency` at once.
+    ///
+    /// Results are returned in the same order as `intents`. A failing input does not
+    /// abort the batch; its error is returned in its slot. A `concurrency` of `0` is
+    /// treated as `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let intents = documents.iter().map(|doc| Payload::text(doc)).collect();
+    /// for result in agent.execute_batch(intents, 4).await {
+    ///     match result {
+    ///         Ok(summary) => println!("{summary}"),
+    ///         Err(e) => eprintln!("failed: {e}"),
+    ///     }
+    /// }
+    /// ```
+    async fn execute_batch(
+        &self,
+        intents: Vec<Payload>,
+        concurrency: usize,
+    ) -> Vec<Result<Self::Output, AgentError>>
+    where
+        Self::Output: Send,
+    {
+        use futures::stream::{self, StreamExt};
+
+        stream::iter(intents)
+            .map(|intent| self.execute(intent))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
     /// Returns the name of this agent.
     ///
     /// By default, this returns the type name. Can be overridden for custom naming.
@@ -549,6 +698,47 @@ pub trait Agent: Send + Sync {
         let caps = self.expertise().capabilities();
         if caps.is_empty() { None } else { Some(caps) }
     }
+
+    /// Returns a copy of this agent configured with `profile`.
+    ///
+    /// Used by the orchestrator to apply a step's `execution_profile` without
+    /// registering the same backend twice. The default returns `None`, meaning
+    /// the agent has no notion of execution profiles and is used unchanged.
+    /// Agents with a `with_execution_profile` builder should override this.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// fn clone_with_execution_profile(&self, profile: ExecutionProfile) -> Option<Self> {
+    ///     Some(self.clone().with_execution_profile(profile))
+    /// }
+    /// ```
+    fn clone_with_execution_profile(&self, _profile: ExecutionProfile) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Chains this agent into `next`, feeding this agent's output to it as a prompt.
+    ///
+    /// The output is rendered with [`ToPrompt::to_prompt_parts`] and passed to
+    /// `next` as a fresh [`Payload`]. See [`impls::Pipe`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let pipeline = outline_agent.pipe(writer_agent);
+    /// let article = pipeline.execute("Rust async".into()).await?;
+    /// ```
+    #[cfg(feature = "agent")]
+    fn pipe<B: Agent>(self, next: B) -> impls::Pipe<Self, B>
+    where
+        Self: Sized,
+        Self::Output: ToPrompt,
+    {
+        impls::Pipe::new(self, next)
+    }
 }
 
 /// A type-erased agent wrapper for easy dynamic dispatch.
@@ -628,6 +818,15 @@ impl<T: Serialize + DeserializeOwned> Agent for AnyAgent<T> {
     async fn is_available(&self) -> Result<(), AgentError> {
         self.inner.is_available().await
     }
+
+    fn clone_with_execution_profile(&self, profile: ExecutionProfile) -> Option<Self> {
+        let inner = self.inner.clone_with_execution_profile(profile)?;
+        Some(Self {
+            inner,
+            description: self.description.clone(),
+            capabilities: self.capabilities.clone(),
+        })
+    }
 }
 
 /// A boxed agent trait object for dynamic dispatch.
@@ -719,10 +918,28 @@ pub trait DynamicAgent: Send + Sync {
     fn try_to_prompt(&self, _json: &serde_json::Value) -> Option<String> {
         None
     }
+
+    /// Returns a copy of this agent configured with `profile`, if it supports execution profiles.
+    ///
+    /// This is the type-erased version of `Agent::clone_with_execution_profile()`.
+    fn clone_with_execution_profile(
+        &self,
+        _profile: ExecutionProfile,
+    ) -> Option<Box<dyn DynamicAgent>> {
+        None
+    }
+
+    /// Returns the short name of the agent's output type (e.g. `"ReviewResponse"`), if known.
+    ///
+    /// For `TypeMarker` types this matches the `__type` marker, which lets the
+    /// orchestrator check type references without executing the agent.
+    fn output_type_name(&self) -> Option<String> {
+        None
+    }
 }
 
 /// Type alias for the ToPrompt conversion function.
-type ToPromptFn = Box<dyn Fn(&serde_json::Value) -> Option<String> + Send + Sync>;
+type ToPromptFn = Arc<dyn Fn(&serde_json::Value) -> Option<String> + Send + Sync>;
 
 /// Adapter that wraps any `Agent<Output = T>` to implement `DynamicAgent`.
 ///
@@ -746,6 +963,10 @@ trait DynamicAgentInternal<T>: Send + Sync {
     fn description(&self) -> &str;
     async fn is_available(&self) -> Result<(), AgentError>;
     fn capabilities(&self) -> Option<Vec<Capability>>;
+    fn clone_with_execution_profile(
+        &self,
+        profile: ExecutionProfile,
+    ) -> Option<Box<dyn DynamicAgentInternal<T>>>;
 }
 
 /// Blanket implementation for all Agent types
@@ -753,7 +974,7 @@ trait DynamicAgentInternal<T>: Send + Sync {
 impl<T, A> DynamicAgentInternal<T> for A
 where
     T: Serialize + DeserializeOwned,
-    A: Agent<Output = T> + Send + Sync,
+    A: Agent<Output = T> + Send + Sync + 'static,
 {
     async fn execute(&self, intent: Payload) -> Result<T, AgentError> {
         Agent::execute(self, intent).await
@@ -774,6 +995,14 @@ where
     fn capabilities(&self) -> Option<Vec<Capability>> {
         Agent::capabilities(self)
     }
+
+    fn clone_with_execution_profile(
+        &self,
+        profile: ExecutionProfile,
+    ) -> Option<Box<dyn DynamicAgentInternal<T>>> {
+        Agent::clone_with_execution_profile(self, profile)
+            .map(|agent| Box::new(agent) as Box<dyn DynamicAgentInternal<T>>)
+    }
 }
 
 impl<T: Serialize + DeserializeOwned> AgentAdapter<T> {
@@ -795,7 +1024,7 @@ impl<T: Serialize + DeserializeOwned> AgentAdapter<T> {
     ) -> Self {
         Self {
             inner: Box::new(agent),
-            try_to_prompt_fn: Some(Box::new(move |json| {
+            try_to_prompt_fn: Some(Arc::new(move |json| {
                 serde_json::from_value::<T>(json.clone())
                     .ok()
                     .map(|output| to_prompt_fn(&output))
@@ -805,7 +1034,7 @@ impl<T: Serialize + DeserializeOwned> AgentAdapter<T> {
 }
 
 #[async_trait]
-impl<T: Serialize + DeserializeOwned> DynamicAgent for AgentAdapter<T> {
+impl<T: Serialize + DeserializeOwned + 'static> DynamicAgent for AgentAdapter<T> {
     async fn execute_dynamic(&self, intent: Payload) -> Result<AgentOutput, AgentError> {
         let output = self.inner.execute(intent).await?;
         let json_value = serde_json::to_value(output)
@@ -832,6 +1061,21 @@ impl<T: Serialize + DeserializeOwned> DynamicAgent for AgentAdapter<T> {
     fn try_to_prompt(&self, json: &serde_json::Value) -> Option<String> {
         self.try_to_prompt_fn.as_ref().and_then(|f| f(json))
     }
+
+    fn clone_with_execution_profile(
+        &self,
+        profile: ExecutionProfile,
+    ) -> Option<Box<dyn DynamicAgent>> {
+        let inner = self.inner.clone_with_execution_profile(profile)?;
+        Some(Box::new(AgentAdapter {
+            inner,
+            try_to_prompt_fn: self.try_to_prompt_fn.clone(),
+        }))
+    }
+
+    fn output_type_name(&self) -> Option<String> {
+        Some(crate::prompt::short_type_name(std::any::type_name::<T>()))
+    }
 }
 
 // Agent implementations for smart pointers (Box, Arc)
@@ -94,6 +94,14 @@ struct PayloadInner {
     contexts: Vec<ExecutionContext>,
 }
 
+/// One line of the JSONL form written by [`Payload::to_jsonl`].
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum JsonlEntry {
+    Message(PayloadMessage),
+    Attachment { attachment: Attachment },
+}
+
 /// A multi-modal payload that can contain multiple content items.
 ///
 /// This structure uses `Arc` internally to make cloning efficient,
@@ -211,6 +219,52 @@ impl Payload {
         })
     }
 
+    /// Estimates the number of tokens this payload will cost.
+    ///
+    /// This is an approximation, not a real tokenizer; see
+    /// [`estimate_tokens`](crate::prompt::tokens::estimate_tokens). Every
+    /// attachment is charged [`DEFAULT_TOKENS_PER_IMAGE`](crate::prompt::tokens::DEFAULT_TOKENS_PER_IMAGE).
+    pub fn estimated_tokens(&self) -> usize {
+        self.estimated_tokens_with_image_cost(crate::prompt::tokens::DEFAULT_TOKENS_PER_IMAGE)
+    }
+
+    /// Estimates the number of tokens this payload will cost, charging
+    /// `tokens_per_image` for every attachment.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::Payload;
+    /// use llm_toolkit::attachment::Attachment;
+    ///
+    /// let payload = Payload::text("Describe this image.")
+    ///     .with_attachment(Attachment::local("photo.png"));
+    /// assert!(payload.estimated_tokens_with_image_cost(1600) > 1600);
+    /// ```
+    pub fn estimated_tokens_with_image_cost(&self, tokens_per_image: usize) -> usize {
+        use crate::prompt::tokens::estimate_tokens;
+
+        self.inner
+            .contents
+            .iter()
+            .map(|content| match content {
+                PayloadContent::Text(text) => estimate_tokens(text),
+                PayloadContent::Message { content, .. } => estimate_tokens(content),
+                PayloadContent::Attachment(_) => tokens_per_image,
+                PayloadContent::Document(doc) => estimate_tokens(&doc.content),
+                PayloadContent::Participants(participants) => participants
+                    .iter()
+                    .map(|p| {
+                        estimate_tokens(&p.name)
+                            + estimate_tokens(&p.role)
+                            + estimate_tokens(&p.description)
+                    })
+                    .sum(),
+                PayloadContent::Context(ctx) => estimate_tokens(ctx),
+            })
+            .sum()
+    }
+
     /// Helper: Creates a new PayloadInner from contents while preserving context timeline
     fn create_inner(&self, contents: Vec<PayloadContent>) -> PayloadInner {
         PayloadInner {
@@ -239,6 +293,49 @@ impl Payload {
         }
     }
 
+    /// Rewrites every piece of text in this payload with `f`.
+    ///
+    /// Applies to text, dialogue message, context, and document contents.
+    /// Attachments and participant information are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::Payload;
+    ///
+    /// let payload = Payload::text("hello").map_text(|text| text.to_uppercase());
+    /// assert_eq!(payload.to_text(), "HELLO");
+    /// ```
+    pub fn map_text(self, f: impl Fn(&str) -> String) -> Self {
+        let new_contents = self
+            .inner
+            .contents
+            .iter()
+            .map(|content| match content {
+                PayloadContent::Text(text) => PayloadContent::Text(f(text)),
+                PayloadContent::Message {
+                    speaker,
+                    content,
+                    metadata,
+                } => PayloadContent::Message {
+                    speaker: speaker.clone(),
+                    content: f(content),
+                    metadata: metadata.clone(),
+                },
+                PayloadContent::Context(context) => PayloadContent::Context(f(context)),
+                PayloadContent::Document(document) => {
+                    let mut document = document.clone();
+                    document.content = f(&document.content);
+                    PayloadContent::Document(document)
+                }
+                other => other.clone(),
+            })
+            .collect();
+        Self {
+            inner: Arc::new(self.create_inner(new_contents)),
+        }
+    }
+
     /// Adds an attachment to this payload.
     ///
     /// # Examples
@@ -432,6 +529,89 @@ impl Payload {
         self.prepend_message(crate::agent::dialogue::Speaker::System, instruction)
     }
 
+    /// Adds a system instruction, merging it into a leading system message.
+    ///
+    /// If the payload starts with a system message, `instruction` is appended
+    /// to it on a new line; otherwise a new system message is prepended. Calling
+    /// this repeatedly yields a single system message, for backends that don't
+    /// merge consecutive system messages themselves. Use
+    /// [`prepend_system`](Self::prepend_system) when separate messages are wanted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::Payload;
+    ///
+    /// let payload = Payload::text("User question")
+    ///     .with_merged_system("You are a reviewer.")
+    ///     .with_merged_system("Keep responses concise.");
+    ///
+    /// // One system message: "You are a reviewer.\nKeep responses concise."
+    /// ```
+    pub fn with_merged_system(self, instruction: impl Into<String>) -> Self {
+        let instruction = instruction.into();
+        let mut new_contents = self.inner.contents.clone();
+        if let Some(PayloadContent::Message {
+            speaker: crate::agent::dialogue::Speaker::System,
+            content,
+            ..
+        }) = new_contents.first_mut()
+        {
+            content.push('\n');
+            content.push_str(&instruction);
+        } else {
+            new_contents.insert(
+                0,
+                PayloadContent::Message {
+                    speaker: crate::agent::dialogue::Speaker::System,
+                    content: instruction,
+                    metadata: MessageMetadata::default(),
+                },
+            );
+        }
+        Self {
+            inner: Arc::new(self.create_inner(new_contents)),
+        }
+    }
+
+    /// Adds a system instruction, merging it into a trailing system message.
+    ///
+    /// The counterpart of [`with_merged_system`](Self::with_merged_system) for the
+    /// end of the payload: if the last content is a system message, `instruction`
+    /// is appended to it on a new line; otherwise a new system message is added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::Payload;
+    ///
+    /// let payload = Payload::text("User question")
+    ///     .append_system("Answer in Japanese.")
+    ///     .append_system("Cite your sources.");
+    /// ```
+    pub fn append_system(self, instruction: impl Into<String>) -> Self {
+        let instruction = instruction.into();
+        let mut new_contents = self.inner.contents.clone();
+        if let Some(PayloadContent::Message {
+            speaker: crate::agent::dialogue::Speaker::System,
+            content,
+            ..
+        }) = new_contents.last_mut()
+        {
+            content.push('\n');
+            content.push_str(&instruction);
+        } else {
+            new_contents.push(PayloadContent::Message {
+                speaker: crate::agent::dialogue::Speaker::System,
+                content: instruction,
+                metadata: MessageMetadata::default(),
+            });
+        }
+        Self {
+            inner: Arc::new(self.create_inner(new_contents)),
+        }
+    }
+
     /// Merges another payload's contents into this one.
     ///
     /// This appends all content items from the other payload to the end of this payload's
@@ -518,6 +698,41 @@ impl Payload {
         }
     }
 
+    /// Creates a payload from rendered prompt parts.
+    ///
+    /// Text parts become text content and images become in-memory
+    /// attachments carrying their media type, in the original order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::Payload;
+    /// use llm_toolkit::ToPrompt;
+    ///
+    /// let payload = Payload::from_prompt_parts(report.to_prompt_parts());
+    /// ```
+    pub fn from_prompt_parts(parts: Vec<crate::prompt::PromptPart>) -> Self {
+        use crate::prompt::PromptPart;
+
+        let contents = parts
+            .into_iter()
+            .map(|part| match part {
+                PromptPart::Text(text) => PayloadContent::Text(text),
+                PromptPart::Image { media_type, data } => PayloadContent::Attachment(
+                    Attachment::in_memory_with_meta(data, None, Some(media_type)),
+                ),
+            })
+            .collect();
+
+        Self {
+            inner: Arc::new(PayloadInner {
+                contents,
+                #[cfg(feature = "agent")]
+                contexts: Vec::new(),
+            }),
+        }
+    }
+
     /// Adds participants information to this payload.
     ///
     /// This provides context about all participants in a dialogue, which can be
@@ -596,6 +811,10 @@ impl Payload {
     /// This is useful for agents that need to understand the message structure
     /// and maintain proper conversation history.
     ///
+    /// Messages are returned in insertion order with speaker and metadata intact,
+    /// so `Payload::from_messages(payload.to_messages())` rebuilds the same
+    /// message list. Use [`Payload::to_jsonl`] to persist attachments as well.
+    ///
     /// # Examples
     ///
     /// ```rust,ignore
@@ -635,6 +854,89 @@ impl Payload {
             .collect()
     }
 
+    /// Serializes the messages and attachments of this payload to JSONL.
+    ///
+    /// Each line is one JSON object, either a `"message"` entry carrying the
+    /// full `PayloadMessage` (speaker, content, metadata) or an `"attachment"`
+    /// entry. Entries keep their order in the payload. Attachments are written
+    /// by reference: local files stay paths and remote ones stay URLs, so
+    /// nothing is read or inlined. Other content kinds (text, context,
+    /// documents, participants) are not included.
+    ///
+    /// Use [`Payload::from_jsonl`] to reconstruct the payload.
+    ///
+    /// # Errors
+    ///
+    /// Fails if an entry cannot be serialized, e.g. a local attachment whose
+    /// path is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::{Payload, PayloadMessage};
+    /// use llm_toolkit::attachment::Attachment;
+    ///
+    /// let payload = Payload::from_messages(vec![PayloadMessage::system("Be brief")])
+    ///     .with_attachment(Attachment::local("/tmp/report.pdf"));
+    ///
+    /// let restored = Payload::from_jsonl(&payload.to_jsonl().unwrap()).unwrap();
+    /// assert_eq!(restored, payload);
+    /// ```
+    pub fn to_jsonl(&self) -> Result<String, serde_json::Error> {
+        let mut jsonl = String::new();
+        for content in &self.inner.contents {
+            let entry = match content {
+                PayloadContent::Message {
+                    speaker,
+                    content,
+                    metadata,
+                } => JsonlEntry::Message(PayloadMessage {
+                    speaker: speaker.clone(),
+                    content: content.clone(),
+                    metadata: metadata.clone(),
+                }),
+                PayloadContent::Attachment(attachment) => JsonlEntry::Attachment {
+                    attachment: attachment.clone(),
+                },
+                PayloadContent::Text(_)
+                | PayloadContent::Document(_)
+                | PayloadContent::Participants(_)
+                | PayloadContent::Context(_) => continue,
+            };
+            jsonl.push_str(&serde_json::to_string(&entry)?);
+            jsonl.push('\n');
+        }
+        Ok(jsonl)
+    }
+
+    /// Reconstructs a payload from JSONL produced by [`Payload::to_jsonl`].
+    ///
+    /// Blank lines are ignored. Returns the first parse error encountered.
+    pub fn from_jsonl(jsonl: &str) -> Result<Self, serde_json::Error> {
+        let contents = jsonl
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str::<JsonlEntry>(line).map(|entry| match entry {
+                    JsonlEntry::Message(message) => PayloadContent::Message {
+                        speaker: message.speaker,
+                        content: message.content,
+                        metadata: message.metadata,
+                    },
+                    JsonlEntry::Attachment { attachment } => PayloadContent::Attachment(attachment),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            inner: Arc::new(PayloadInner {
+                contents,
+                #[cfg(feature = "agent")]
+                contexts: Vec::new(),
+            }),
+        })
+    }
+
     /// Returns true if this payload contains only text.
     pub fn is_text_only(&self) -> bool {
         self.inner
@@ -686,6 +988,181 @@ impl Payload {
             .collect()
     }
 
+    /// Returns a copy of this payload with duplicate attachments removed.
+    ///
+    /// In-memory attachments are compared by content, local files by their
+    /// resolved path, and remote attachments by URL. The first occurrence of
+    /// each attachment keeps its position; all other contents are untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::Payload;
+    /// use llm_toolkit::attachment::Attachment;
+    ///
+    /// let image = Attachment::in_memory(vec![1, 2, 3]);
+    /// let payload = Payload::text("Compare")
+    ///     .with_attachment(image.clone())
+    ///     .with_attachment(image)
+    ///     .deduplicated();
+    /// assert_eq!(payload.attachments().len(), 1);
+    /// ```
+    pub fn deduplicated(&self) -> Self {
+        let mut seen = std::collections::HashSet::new();
+        let new_contents = self
+            .inner
+            .contents
+            .iter()
+            .filter(|c| match c {
+                PayloadContent::Attachment(a) => seen.insert(AttachmentKey::of(a)),
+                _ => true,
+            })
+            .cloned()
+            .collect();
+        Self {
+            inner: Arc::new(self.create_inner(new_contents)),
+        }
+    }
+
+    /// Returns a hash identifying what this payload asks for.
+    ///
+    /// Covers the text-bearing contents (text, messages with their speaker,
+    /// documents, contexts, participants with their role and description) in
+    /// order, plus the identity of each attachment: local files by resolved
+    /// path, remote attachments by URL, and in-memory attachments by their
+    /// bytes. Execution contexts are not included. Two payloads that would
+    /// produce the same prompt hash equally.
+    ///
+    /// Local files are not read, so editing a file in place does not change
+    /// the hash.
+    ///
+    /// The hash is stable within one build of the crate, but not guaranteed to
+    /// be stable across Rust or crate versions, so persisted caches keyed by it
+    /// should be treated as disposable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::Payload;
+    ///
+    /// assert_eq!(
+    ///     Payload::text("Summarize").content_hash(),
+    ///     Payload::text("Summarize").content_hash()
+    /// );
+    /// assert_ne!(
+    ///     Payload::text("Summarize").content_hash(),
+    ///     Payload::text("Translate").content_hash()
+    /// );
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        for content in &self.inner.contents {
+            // Tag each variant so "a" as text and "a" as context differ
+            match content {
+                PayloadContent::Text(text) => (0u8, text).hash(&mut hasher),
+                PayloadContent::Attachment(attachment) => {
+                    (1u8, AttachmentKey::of(attachment)).hash(&mut hasher)
+                }
+                PayloadContent::Message {
+                    speaker, content, ..
+                } => (2u8, speaker.name(), content).hash(&mut hasher),
+                PayloadContent::Participants(participants) => {
+                    3u8.hash(&mut hasher);
+                    for participant in participants {
+                        (
+                            &participant.name,
+                            &participant.role,
+                            &participant.description,
+                        )
+                            .hash(&mut hasher);
+                    }
+                }
+                PayloadContent::Document(document) => {
+                    (4u8, &document.content, &document.source).hash(&mut hasher)
+                }
+                PayloadContent::Context(context) => (5u8, context).hash(&mut hasher),
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Returns a deterministic string describing what this payload asks for.
+    ///
+    /// Covers the same contents as [`content_hash`](Self::content_hash), as
+    /// readable text: text-bearing contents (text, messages with their speaker,
+    /// documents, contexts, participants with their role and description) in
+    /// order, followed by the attachment identities sorted (local files by
+    /// resolved path, remote attachments by URL, in-memory attachments by a
+    /// digest of their bytes).
+    /// Message metadata and execution contexts are excluded.
+    ///
+    /// Two payloads equal in content produce equal keys, regardless of
+    /// attachment order or message metadata. Unlike `content_hash`, the key
+    /// does not depend on the Rust version, so it can be hashed with any
+    /// algorithm or stored as-is to key persistent caches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::Payload;
+    ///
+    /// let key = Payload::text("Summarize").with_context("Be concise").canonical_key();
+    /// assert_eq!(key, "text:9:Summarize\ncontext:10:Be concise\n");
+    /// ```
+    pub fn canonical_key(&self) -> String {
+        use std::fmt::Write;
+
+        // Length-prefixed so content containing separators can't collide
+        fn push(key: &mut String, tag: &str, value: &str) {
+            let _ = writeln!(key, "{}:{}:{}", tag, value.len(), value);
+        }
+
+        let mut key = String::new();
+        let mut attachments = Vec::new();
+        for content in &self.inner.contents {
+            match content {
+                PayloadContent::Text(text) => push(&mut key, "text", text),
+                PayloadContent::Attachment(attachment) => {
+                    attachments.push(match AttachmentKey::of(attachment) {
+                        AttachmentKey::Local(path) => format!("file:{}", path.display()),
+                        AttachmentKey::Remote(url) => format!("url:{}", url),
+                        AttachmentKey::InMemory(bytes) => {
+                            format!("bytes:{}:{:016x}", bytes.len(), fnv1a(bytes))
+                        }
+                    })
+                }
+                PayloadContent::Message {
+                    speaker, content, ..
+                } => {
+                    push(&mut key, "speaker", speaker.name());
+                    push(&mut key, "message", content);
+                }
+                PayloadContent::Participants(participants) => {
+                    for participant in participants {
+                        push(&mut key, "participant", &participant.name);
+                        push(&mut key, "role", &participant.role);
+                        push(&mut key, "description", &participant.description);
+                    }
+                }
+                PayloadContent::Document(document) => {
+                    push(&mut key, "document", &document.content);
+                    if let Some(source) = &document.source {
+                        push(&mut key, "source", source);
+                    }
+                }
+                PayloadContent::Context(context) => push(&mut key, "context", context),
+            }
+        }
+
+        attachments.sort();
+        for attachment in &attachments {
+            push(&mut key, "attachment", attachment);
+        }
+        key
+    }
+
     /// Returns a vector of references to all documents in this payload.
     ///
     /// Documents are typically added by retriever agents for RAG use cases.
@@ -1158,6 +1635,34 @@ impl fmt::Display for Payload {
     }
 }
 
+/// Identity of an attachment for deduplication and hashing purposes.
+#[derive(PartialEq, Eq, Hash)]
+enum AttachmentKey<'a> {
+    Local(std::path::PathBuf),
+    Remote(&'a str),
+    InMemory(&'a [u8]),
+}
+
+impl<'a> AttachmentKey<'a> {
+    fn of(attachment: &'a Attachment) -> Self {
+        match attachment {
+            // Fall back to the path as given when it can't be resolved (e.g. missing file)
+            Attachment::Local(path) => {
+                Self::Local(std::fs::canonicalize(path).unwrap_or_else(|_| path.clone()))
+            }
+            Attachment::Remote(url) => Self::Remote(url.as_str()),
+            Attachment::InMemory { bytes, .. } => Self::InMemory(bytes),
+        }
+    }
+}
+
+/// 64-bit FNV-1a, used where a digest must not change across Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1213,6 +1718,227 @@ mod tests {
         assert!(!attachments.is_empty());
     }
 
+    #[test]
+    fn test_payload_deduplicated_removes_identical_attachments() {
+        let image = Attachment::in_memory(vec![1, 2, 3]);
+        let payload = Payload::text("First")
+            .with_attachment(image.clone())
+            .with_text("Second")
+            .with_attachment(Attachment::in_memory(vec![4, 5]))
+            .with_attachment(image)
+            .with_text("Second")
+            .deduplicated();
+
+        assert_eq!(
+            payload.contents(),
+            &[
+                PayloadContent::Text("First".to_string()),
+                PayloadContent::Attachment(Attachment::in_memory(vec![1, 2, 3])),
+                PayloadContent::Text("Second".to_string()),
+                PayloadContent::Attachment(Attachment::in_memory(vec![4, 5])),
+                PayloadContent::Text("Second".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_payload_deduplicated_compares_resolved_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("image.png");
+        std::fs::write(&image, [0u8; 4]).unwrap();
+
+        let payload = Payload::text("Look")
+            .with_attachment(Attachment::local(&image))
+            .with_attachment(Attachment::local(dir.path().join(".").join("image.png")))
+            .with_attachment(Attachment::remote("https://example.com/image.png"))
+            .with_attachment(Attachment::remote("https://example.com/image.png"))
+            .deduplicated();
+
+        assert_eq!(
+            payload.attachments(),
+            vec![
+                &Attachment::local(&image),
+                &Attachment::remote("https://example.com/image.png"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_payload_content_hash() {
+        let image = Attachment::in_memory(vec![1, 2, 3]);
+        let base = Payload::text("Describe").with_attachment(image.clone());
+
+        assert_eq!(
+            base.content_hash(),
+            Payload::text("Describe")
+                .with_attachment(image)
+                .content_hash()
+        );
+        assert_ne!(
+            base.content_hash(),
+            Payload::text("Describe")
+                .with_attachment(Attachment::in_memory(vec![4, 5, 6]))
+                .content_hash()
+        );
+        assert_ne!(
+            base.content_hash(),
+            Payload::text("Describe").content_hash()
+        );
+        assert_ne!(
+            Payload::text("Be concise").content_hash(),
+            Payload::new().with_context("Be concise").content_hash()
+        );
+
+        // Participant role and description are part of the prompt
+        use crate::agent::dialogue::ParticipantInfo;
+        let reviewer = |role: &str| {
+            Payload::new().with_participants(vec![ParticipantInfo::new("Alice", role, "Senior")])
+        };
+        assert_ne!(
+            reviewer("Reviewer").content_hash(),
+            reviewer("Author").content_hash()
+        );
+        assert_ne!(
+            reviewer("Reviewer").canonical_key(),
+            reviewer("Author").canonical_key()
+        );
+    }
+
+    #[test]
+    fn test_with_merged_system_combines_leading_system_messages() {
+        use crate::agent::dialogue::Speaker;
+
+        let payload = Payload::text("User question")
+            .with_merged_system("You are a reviewer.")
+            .with_merged_system("Keep responses concise.");
+
+        let contents = payload.contents();
+        assert_eq!(contents.len(), 2);
+        assert!(matches!(
+            &contents[0],
+            PayloadContent::Message { speaker: Speaker::System, content, .. }
+                if content == "You are a reviewer.\nKeep responses concise."
+        ));
+        assert!(matches!(&contents[1], PayloadContent::Text(t) if t == "User question"));
+
+        // prepend_system still creates separate messages
+        let separate = Payload::text("User question")
+            .prepend_system("First")
+            .prepend_system("Second");
+        assert_eq!(separate.contents().len(), 3);
+    }
+
+    #[test]
+    fn test_append_system_combines_trailing_system_messages() {
+        use crate::agent::dialogue::Speaker;
+
+        let payload = Payload::text("User question")
+            .append_system("Answer in Japanese.")
+            .append_system("Cite your sources.");
+
+        let contents = payload.contents();
+        assert_eq!(contents.len(), 2);
+        assert!(matches!(&contents[0], PayloadContent::Text(t) if t == "User question"));
+        assert!(matches!(
+            &contents[1],
+            PayloadContent::Message { speaker: Speaker::System, content, .. }
+                if content == "Answer in Japanese.\nCite your sources."
+        ));
+
+        // Only a system message at the end is merged into
+        let user_last = Payload::new()
+            .append_system("Be brief.")
+            .with_message(Speaker::user("Alice", "User"), "Hi")
+            .append_system("Be polite.");
+        assert_eq!(user_last.contents().len(), 3);
+    }
+
+    #[test]
+    fn test_payload_canonical_key_ignores_metadata_and_attachment_order() {
+        use crate::agent::dialogue::Speaker;
+        use crate::agent::dialogue::message::MessageType;
+
+        let first = Attachment::in_memory(vec![1, 2, 3]);
+        let second = Attachment::remote("https://example.com/image.png");
+
+        let plain = Payload::new()
+            .add_message_with_metadata(
+                Speaker::user("Alice", "User"),
+                "Review this",
+                MessageMetadata::new()
+                    .with_custom("request_id", "r-1")
+                    .with_custom("attempt", 1),
+            )
+            .with_attachment(first.clone())
+            .with_attachment(second.clone());
+        let reordered = Payload::new()
+            .add_message_with_metadata(
+                Speaker::user("Alice", "User"),
+                "Review this",
+                MessageMetadata::new()
+                    .with_type(MessageType::ContextInfo)
+                    .with_custom("attempt", 2)
+                    .with_custom("request_id", "r-2"),
+            )
+            .with_attachment(second)
+            .with_attachment(first);
+        let bare = Payload::new()
+            .with_message(Speaker::user("Alice", "User"), "Review this")
+            .with_attachment(Attachment::remote("https://example.com/image.png"))
+            .with_attachment(Attachment::in_memory(vec![1, 2, 3]));
+
+        assert_eq!(plain.canonical_key(), reordered.canonical_key());
+        assert_eq!(plain.canonical_key(), bare.canonical_key());
+        assert_eq!(
+            Payload::text("Summarize")
+                .with_context("Be concise")
+                .canonical_key(),
+            "text:9:Summarize\ncontext:10:Be concise\n"
+        );
+    }
+
+    #[test]
+    fn test_payload_canonical_key_distinguishes_content() {
+        assert_ne!(
+            Payload::text("Summarize").canonical_key(),
+            Payload::text("Translate").canonical_key()
+        );
+        assert_ne!(
+            Payload::text("Be concise").canonical_key(),
+            Payload::new().with_context("Be concise").canonical_key()
+        );
+        assert_ne!(
+            Payload::text("a\ntext:1:b").canonical_key(),
+            Payload::text("a").with_text("b").canonical_key()
+        );
+        assert_ne!(
+            Payload::attachment(Attachment::in_memory(vec![1, 2, 3])).canonical_key(),
+            Payload::attachment(Attachment::in_memory(vec![3, 2, 1])).canonical_key()
+        );
+    }
+
+    #[test]
+    fn test_payload_estimated_tokens() {
+        use crate::prompt::tokens::{DEFAULT_TOKENS_PER_IMAGE, estimate_tokens};
+
+        let text = Payload::text("Summarize this document.").with_context("Be concise.");
+        let text_tokens =
+            estimate_tokens("Summarize this document.") + estimate_tokens("Be concise.");
+        assert_eq!(text.estimated_tokens(), text_tokens);
+
+        let with_images = text
+            .with_attachment(Attachment::in_memory(vec![1, 2, 3]))
+            .with_attachment(Attachment::local("/test.png"));
+        assert_eq!(
+            with_images.estimated_tokens(),
+            text_tokens + 2 * DEFAULT_TOKENS_PER_IMAGE
+        );
+        assert_eq!(
+            with_images.estimated_tokens_with_image_cost(85),
+            text_tokens + 2 * 85
+        );
+    }
+
     #[test]
     fn test_payload_is_text_only() {
         use crate::attachment::Attachment;
@@ -1686,4 +2412,56 @@ mod tests {
         // "Hello" (5) + "World" (5) = 10
         assert_eq!(payload.total_content_count(), 10);
     }
+
+    #[test]
+    fn test_jsonl_round_trip_preserves_messages_and_attachments() {
+        use crate::agent::dialogue::Speaker;
+        use crate::agent::dialogue::message::MessageType;
+
+        let mut metadata = MessageMetadata::new().with_type(MessageType::Conversational);
+        metadata
+            .custom
+            .insert("turn".to_string(), serde_json::json!(2));
+
+        let payload = Payload::from_messages(vec![
+            PayloadMessage::system("You are a reviewer"),
+            PayloadMessage::user("Alice", "PM", "Please review the report"),
+            PayloadMessage {
+                speaker: Speaker::agent("Reviewer", "QA"),
+                content: "Looks good".to_string(),
+                metadata,
+            },
+        ])
+        .with_attachment(Attachment::local("/tmp/reports/q3.pdf"));
+
+        let jsonl = payload.to_jsonl().unwrap();
+        assert_eq!(jsonl.lines().count(), 4);
+        assert!(jsonl.contains("/tmp/reports/q3.pdf"));
+
+        let restored = Payload::from_jsonl(&jsonl).unwrap();
+        assert_eq!(restored, payload);
+        assert_eq!(restored.to_messages(), payload.to_messages());
+        assert_eq!(
+            restored.attachments(),
+            vec![&Attachment::local("/tmp/reports/q3.pdf")]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_to_jsonl_rejects_non_utf8_path() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = OsStr::from_bytes(b"/tmp/report\xff.pdf");
+        let payload = Payload::text("Summarize").with_attachment(Attachment::local(path));
+
+        assert!(payload.to_jsonl().is_err());
+    }
+
+    #[test]
+    fn test_from_jsonl_rejects_malformed_line() {
+        assert!(Payload::from_jsonl("{\"type\":\"message\"}\n").is_err());
+        assert_eq!(Payload::from_jsonl("\n\n").unwrap(), Payload::new());
+    }
 }
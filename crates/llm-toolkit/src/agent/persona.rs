@@ -135,9 +135,34 @@ pub struct Persona {
     /// Dialogue for participant coordination.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub capabilities: Option<Vec<super::Capability>>,
+
+    /// Yield priority in `BroadcastOrder::Priority` dialogues (higher first).
+    ///
+    /// `None` sorts after every explicit priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
 }
 
 impl Persona {
+    /// Starts a validated [`PersonaBuilder`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use llm_toolkit::agent::persona::Persona;
+    ///
+    /// let persona = Persona::builder()
+    ///     .name("Alice")
+    ///     .role("UI/UX Designer")
+    ///     .background("10 years of experience in user-centered design")
+    ///     .capability("design:review")
+    ///     .priority(10)
+    ///     .build()?;
+    /// ```
+    pub fn builder() -> PersonaBuilder {
+        PersonaBuilder::default()
+    }
+
     /// Creates a new persona with the given name and role.
     ///
     /// # Examples
@@ -157,6 +182,7 @@ impl Persona {
             communication_style: String::new(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         }
     }
 
@@ -172,6 +198,12 @@ impl Persona {
         self
     }
 
+    /// Sets the yield priority used by `BroadcastOrder::Priority` (higher first).
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
     /// Sets the visual identity for this persona.
     ///
     /// # Examples
@@ -254,6 +286,97 @@ impl Persona {
     }
 }
 
+/// Errors returned by [`PersonaBuilder::build`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PersonaError {
+    #[error("Persona name must not be empty")]
+    EmptyName,
+
+    /// Mentions match `@\w+`, so a name with whitespace could never be addressed
+    #[error("Persona name {0:?} must not contain whitespace (@mentions match a single word)")]
+    NameContainsWhitespace(String),
+}
+
+/// Builder for [`Persona`] that validates the name on [`build`](Self::build).
+///
+/// Created with [`Persona::builder`]. Unset text fields default to empty.
+#[derive(Debug, Clone, Default)]
+pub struct PersonaBuilder {
+    name: String,
+    role: String,
+    background: String,
+    communication_style: String,
+    visual_identity: Option<VisualIdentity>,
+    capabilities: Vec<super::Capability>,
+    priority: Option<i32>,
+}
+
+impl PersonaBuilder {
+    /// Sets the name used for display and `@mention` matching.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the role.
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.role = role.into();
+        self
+    }
+
+    /// Sets the background.
+    pub fn background(mut self, background: impl Into<String>) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the communication style.
+    pub fn communication_style(mut self, style: impl Into<String>) -> Self {
+        self.communication_style = style.into();
+        self
+    }
+
+    /// Adds one capability; call repeatedly to add several.
+    pub fn capability(mut self, capability: impl Into<super::Capability>) -> Self {
+        self.capabilities.push(capability.into());
+        self
+    }
+
+    /// Sets the visual identity.
+    pub fn visual_identity(mut self, identity: VisualIdentity) -> Self {
+        self.visual_identity = Some(identity);
+        self
+    }
+
+    /// Sets the yield priority used by `BroadcastOrder::Priority` (higher first).
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Validates the name and builds the persona.
+    ///
+    /// Fails if the name is empty or contains whitespace.
+    pub fn build(self) -> Result<Persona, PersonaError> {
+        if self.name.is_empty() {
+            return Err(PersonaError::EmptyName);
+        }
+        if self.name.chars().any(char::is_whitespace) {
+            return Err(PersonaError::NameContainsWhitespace(self.name));
+        }
+
+        Ok(Persona {
+            name: self.name,
+            role: self.role,
+            background: self.background,
+            communication_style: self.communication_style,
+            visual_identity: self.visual_identity,
+            capabilities: (!self.capabilities.is_empty()).then_some(self.capabilities),
+            priority: self.priority,
+        })
+    }
+}
+
 /// A team of personas for multi-agent dialogue scenarios.
 ///
 /// PersonaTeam can be generated from a BluePrint description or loaded from JSON.
@@ -784,6 +907,14 @@ where
         );
         self.inner_agent.execute(final_payload).await
     }
+
+    fn clone_with_execution_profile(&self, profile: super::ExecutionProfile) -> Option<Self> {
+        Some(Self {
+            inner_agent: self.inner_agent.clone_with_execution_profile(profile)?,
+            persona: self.persona.clone(),
+            context_config: self.context_config.clone(),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -797,6 +928,48 @@ mod tests {
     use std::sync::Arc;
     use tokio::sync::Mutex;
 
+    #[test]
+    fn test_persona_builder_builds_valid_persona() {
+        let persona = Persona::builder()
+            .name("Alice")
+            .role("Designer")
+            .background("Design systems")
+            .capability("design:review")
+            .capability(("design:mockup", "Create mockups"))
+            .visual_identity(VisualIdentity::new("🎨"))
+            .priority(5)
+            .build()
+            .unwrap();
+
+        assert_eq!(persona.name, "Alice");
+        assert_eq!(persona.role, "Designer");
+        assert_eq!(persona.background, "Design systems");
+        assert_eq!(persona.icon(), Some("🎨"));
+        assert_eq!(persona.priority, Some(5));
+        let capabilities = persona.capabilities.unwrap();
+        assert_eq!(capabilities.len(), 2);
+        assert_eq!(capabilities[0].name, "design:review");
+
+        let minimal = Persona::builder().name("Bob").build().unwrap();
+        assert_eq!(minimal.capabilities, None);
+    }
+
+    #[test]
+    fn test_persona_builder_rejects_invalid_names() {
+        assert_eq!(
+            Persona::builder().role("Designer").build().unwrap_err(),
+            PersonaError::EmptyName
+        );
+        assert_eq!(
+            Persona::builder().name("Alice Smith").build().unwrap_err(),
+            PersonaError::NameContainsWhitespace("Alice Smith".to_string())
+        );
+        assert!(matches!(
+            Persona::builder().name("Alice\t").build(),
+            Err(PersonaError::NameContainsWhitespace(_))
+        ));
+    }
+
     #[derive(Clone)]
     struct RecordingAgent<T: Clone + Serialize + DeserializeOwned + Send + Sync + 'static> {
         calls: Arc<Mutex<Vec<Payload>>>,
@@ -844,6 +1017,7 @@ mod tests {
             communication_style: "Direct and concise.".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let base_agent = RecordingAgent::new(String::from("ok"));
@@ -873,6 +1047,7 @@ mod tests {
             communication_style: "Direct and clear".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let base_agent = RecordingAgent::new(String::from("response"));
@@ -908,6 +1083,7 @@ mod tests {
             communication_style: "Direct and clear".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let prompt = persona.to_prompt();
@@ -969,6 +1145,7 @@ mod tests {
             communication_style: "Direct and clear".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let prompt_struct = PersonaAgentPrompt {
@@ -1054,6 +1231,7 @@ Please review the code
             communication_style: "Technical".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         });
 
         team.add_persona(Persona {
@@ -1063,6 +1241,7 @@ Please review the code
             communication_style: "User-focused".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         });
 
         // Serialize
@@ -1091,6 +1270,7 @@ Please review the code
             communication_style: "Strategic".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         });
 
         // Save to temp file
@@ -1131,6 +1311,7 @@ Please review the code
             communication_style: "Strategic".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let participants = vec![
@@ -1172,6 +1353,7 @@ Please review the code
             communication_style: "Friendly".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let base_agent = RecordingAgent::new("response".to_string());
@@ -1203,6 +1385,7 @@ Please review the code
             communication_style: "Friendly".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let base_agent = RecordingAgent::new("response".to_string());
@@ -1239,6 +1422,7 @@ Please review the code
             communication_style: "Strategic and data-driven".to_string(),
             visual_identity: None,
             capabilities: None,
+            priority: None,
         };
 
         let participants = vec![
@@ -1314,6 +1498,7 @@ Please review the code
                 Capability::new("file:write").with_description("Write content to a file"),
                 Capability::new("api:call").with_description("Make HTTP API calls"),
             ]),
+            priority: None,
         };
 
         let prompt = persona.to_prompt();
@@ -48,17 +48,131 @@ pub async fn retry_execution<F, Fut, T>(
     payload: &Payload,
     operation: F,
 ) -> Result<T, AgentError>
+where
+    F: Fn(&Payload) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<T, AgentError>> + Send,
+    T: Send,
+{
+    retry_loop(max_retries, payload, false, operation).await
+}
+
+/// Executes an operation with retry logic, feeding parse errors back to the model.
+///
+/// Behaves like [`retry_execution`], except that when an attempt fails with a
+/// parse or validation error, the next attempt receives the original payload
+/// plus a note describing what went wrong. Because the model is told how to fix
+/// its output, every parse error is retried, including those that
+/// [`AgentError::is_retryable`] considers hopeless for a plain re-run.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use llm_toolkit::agent::retry::retry_execution_with_reprompt;
+///
+/// let result = retry_execution_with_reprompt(3, &payload, |p| {
+///     let p = p.clone();
+///     async move { agent.execute(p).await }
+/// })
+/// .await;
+/// ```
+pub async fn retry_execution_with_reprompt<F, Fut, T>(
+    max_retries: u32,
+    payload: &Payload,
+    operation: F,
+) -> Result<T, AgentError>
+where
+    F: Fn(&Payload) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<T, AgentError>> + Send,
+    T: Send,
+{
+    retry_loop(max_retries, payload, true, operation).await
+}
+
+/// Runs an operation against a primary backend and, if needed, its fallbacks.
+///
+/// `operation(0)` targets the primary backend and `operation(i)` the `i`-th
+/// fallback. The next backend is only tried when the current one fails with a
+/// backend failure (see [`AgentError::is_backend_failure`]); any other result,
+/// including parse errors, is returned as is. Each operation should wrap its own
+/// retry and parsing logic.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use llm_toolkit::agent::retry::fallback_execution;
+///
+/// let backends = [claude, gemini];
+/// let result = fallback_execution(backends.len(), |index| {
+///     let payload = payload.clone();
+///     let backend = &backends[index];
+///     async move { backend.execute(payload).await }
+/// })
+/// .await;
+/// ```
+pub async fn fallback_execution<F, Fut, T>(backends: usize, operation: F) -> Result<T, AgentError>
+where
+    F: Fn(usize) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<T, AgentError>> + Send,
+    T: Send,
+{
+    let mut index = 0;
+    loop {
+        match operation(index).await {
+            Err(e) if e.is_backend_failure() && index + 1 < backends => {
+                log::warn!(
+                    "⚠️ Backend {}/{} failed: {}. Falling back to the next backend...",
+                    index + 1,
+                    backends,
+                    e
+                );
+                index += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Builds the feedback appended to the payload after a parse or validation error.
+fn parse_error_feedback(error: &AgentError) -> Option<String> {
+    match error {
+        AgentError::ParseError { message, .. } | AgentError::ParseErrorRich { message, .. } => {
+            Some(format!(
+                "Your previous response could not be parsed: {}. Please return valid JSON matching the schema.",
+                message
+            ))
+        }
+        AgentError::ValidationFailed { message } => Some(format!(
+            "Your previous response was rejected by validation: {}. Please return corrected JSON matching the schema.",
+            message
+        )),
+        _ => None,
+    }
+}
+
+async fn retry_loop<F, Fut, T>(
+    max_retries: u32,
+    payload: &Payload,
+    reprompt_on_parse_error: bool,
+    operation: F,
+) -> Result<T, AgentError>
 where
     F: Fn(&Payload) -> Fut + Send + Sync,
     Fut: Future<Output = Result<T, AgentError>> + Send,
     T: Send,
 {
     let mut attempts = 0;
+    let mut current = payload.clone();
 
     loop {
         attempts += 1;
 
-        match operation(payload).await {
+        let result = operation(&current).await;
+        let feedback = match &result {
+            Err(e) if reprompt_on_parse_error => parse_error_feedback(e),
+            _ => None,
+        };
+
+        match result {
             Ok(output) => {
                 if attempts > 1 {
                     log::info!(
@@ -69,7 +183,7 @@ where
                 }
                 return Ok(output);
             }
-            Err(e) if e.is_retryable() && attempts <= max_retries => {
+            Err(e) if (e.is_retryable() || feedback.is_some()) && attempts <= max_retries => {
                 let delay = e.retry_delay(attempts);
                 log::warn!(
                     "⚠️ Operation failed (attempt {}/{}): {}. Retrying in {:?}...",
@@ -78,11 +192,15 @@ where
                     e,
                     delay
                 );
+                // Always build on the original payload so feedback doesn't pile up
+                if let Some(feedback) = feedback {
+                    current = payload.clone().with_text(feedback);
+                }
                 tokio::time::sleep(delay).await;
                 continue;
             }
             Err(e) => {
-                if e.is_retryable() {
+                if e.is_retryable() || feedback.is_some() {
                     log::error!(
                         "❌ Operation failed after {} attempts (max retries exhausted): {}",
                         attempts,
@@ -198,4 +316,75 @@ mod tests {
             "Should try once + 2 retries"
         );
     }
+
+    #[tokio::test]
+    async fn test_retry_execution_with_reprompt_feeds_back_parse_error() {
+        let payload = Payload::text("Return a JSON object");
+        let received = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let received_clone = received.clone();
+
+        let operation = move |payload: &Payload| {
+            let received = received_clone.clone();
+            let text = payload.to_text();
+            async move {
+                let mut received = received.lock().unwrap();
+                received.push(text);
+                if received.len() == 1 {
+                    // InvalidJson is not retryable on its own
+                    Err(AgentError::ParseError {
+                        message: "expected value at line 1 column 1".to_string(),
+                        reason: ParseErrorReason::InvalidJson,
+                    })
+                } else {
+                    Ok("success".to_string())
+                }
+            }
+        };
+
+        let result = retry_execution_with_reprompt(3, &payload, operation).await;
+
+        assert_eq!(result.unwrap(), "success");
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[0], "Return a JSON object");
+        assert!(received[1].starts_with("Return a JSON object\n"));
+        assert!(received[1].contains(
+            "Your previous response could not be parsed: expected value at line 1 column 1."
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fallback_execution_moves_to_next_backend_on_backend_failure() {
+        let calls = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let calls_clone = calls.clone();
+
+        let result = fallback_execution(3, move |index| {
+            let calls = calls_clone.clone();
+            async move {
+                calls.lock().unwrap().push(index);
+                if index == 0 {
+                    Err(AgentError::BackendUnavailable {
+                        backend: "claude",
+                        hint: "not installed".to_string(),
+                    })
+                } else {
+                    Ok(format!("backend {}", index))
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), "backend 1");
+        assert_eq!(*calls.lock().unwrap(), vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_execution_returns_other_errors_immediately() {
+        let result: Result<String, _> = fallback_execution(2, |_index| async {
+            Err(AgentError::ExecutionFailed("bad request".to_string()))
+        })
+        .await;
+
+        assert!(matches!(result, Err(AgentError::ExecutionFailed(_))));
+    }
 }
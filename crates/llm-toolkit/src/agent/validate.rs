@@ -0,0 +1,40 @@
+//! Business-rule validation for parsed agent output.
+
+/// Checks parsed output against rules that a JSON schema can't express.
+///
+/// Deserialization only guarantees the shape of the output; the values can
+/// still be wrong (a percentage over 100, an end date before the start date).
+/// Agents generated with `#[agent(validate)]` call [`Validate::validate`]
+/// right after deserialization and turn an `Err` into a retryable
+/// [`AgentError::ValidationFailed`](super::AgentError::ValidationFailed).
+/// With reprompting enabled, the message is sent back to the model so it can
+/// correct the values.
+///
+/// # Examples
+///
+/// ```rust
+/// use llm_toolkit::agent::Validate;
+///
+/// struct Progress {
+///     percent: u32,
+/// }
+///
+/// impl Validate for Progress {
+///     fn validate(&self) -> Result<(), String> {
+///         if self.percent > 100 {
+///             return Err(format!("percent must be at most 100, got {}", self.percent));
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// assert!(Progress { percent: 42 }.validate().is_ok());
+/// assert!(Progress { percent: 150 }.validate().is_err());
+/// ```
+pub trait Validate {
+    /// Returns `Err` with a human-readable explanation if the value is invalid.
+    ///
+    /// The message is shown to the model on reprompt, so say what is wrong
+    /// and what a valid value looks like.
+    fn validate(&self) -> Result<(), String>;
+}
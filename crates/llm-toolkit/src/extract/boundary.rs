@@ -0,0 +1,205 @@
+use std::ops::Range;
+
+/// Incrementally detects the first complete top-level JSON value in a stream.
+///
+/// Feed chunks as they arrive (e.g. from `execute_stream`). The scanner tracks
+/// bracket nesting and string/escape state across chunk boundaries, so a value
+/// can be parsed as soon as its closing bracket arrives instead of waiting for
+/// the whole response.
+///
+/// Text before the first `{` or `[` is skipped. Ranges are byte offsets into the
+/// concatenation of every chunk pushed so far.
+///
+/// # Examples
+///
+/// ```rust
+/// use llm_toolkit::extract::JsonBoundaryScanner;
+///
+/// let mut scanner = JsonBoundaryScanner::new();
+/// let mut buffer = String::new();
+///
+/// for chunk in ["Sure: {\"status\": ", "\"ok\"}", " anything else"] {
+///     buffer.push_str(chunk);
+///     if let Some(range) = scanner.push(chunk) {
+///         assert_eq!(&buffer[range], r#"{"status": "ok"}"#);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct JsonBoundaryScanner {
+    /// Bytes consumed so far
+    offset: usize,
+    /// Byte offset of the opening bracket of the value being scanned
+    start: Option<usize>,
+    /// Expected closing brackets, innermost last
+    closers: Vec<char>,
+    in_string: bool,
+    escape_next: bool,
+    /// Range of the completed value, once found
+    complete: Option<Range<usize>>,
+}
+
+impl JsonBoundaryScanner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the next chunk.
+    ///
+    /// Returns the byte range of the first complete top-level value when it
+    /// closes within this chunk; returns `None` otherwise, including for every
+    /// chunk after the value has been found.
+    pub fn push(&mut self, chunk: &str) -> Option<Range<usize>> {
+        if self.complete.is_some() {
+            self.offset += chunk.len();
+            return None;
+        }
+
+        let mut found = None;
+        for (i, ch) in chunk.char_indices() {
+            let pos = self.offset + i;
+
+            if self.start.is_none() {
+                if let Some(closer) = closer_for(ch) {
+                    self.start = Some(pos);
+                    self.closers.push(closer);
+                }
+                continue;
+            }
+
+            if self.escape_next {
+                self.escape_next = false;
+                continue;
+            }
+
+            match ch {
+                '\\' if self.in_string => self.escape_next = true,
+                '"' => self.in_string = !self.in_string,
+                _ if self.in_string => {}
+                '{' | '[' => self.closers.extend(closer_for(ch)),
+                '}' | ']' => {
+                    if self.closers.last() != Some(&ch) {
+                        // Mismatched bracket: not a valid value, start over
+                        self.start = None;
+                        self.closers.clear();
+                        continue;
+                    }
+                    self.closers.pop();
+                    if self.closers.is_empty()
+                        && let Some(start) = self.start
+                    {
+                        found = Some(start..pos + ch.len_utf8());
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.offset += chunk.len();
+        self.complete.clone_from(&found);
+        found
+    }
+
+    /// Returns the range of the completed value, if one has been found.
+    pub fn complete(&self) -> Option<Range<usize>> {
+        self.complete.clone()
+    }
+
+    /// Returns true while a value has started but not yet closed.
+    pub fn in_progress(&self) -> bool {
+        self.start.is_some() && self.complete.is_none()
+    }
+
+    /// Clears all state so the scanner can be reused for a new stream.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+fn closer_for(ch: char) -> Option<char> {
+    match ch {
+        '{' => Some('}'),
+        '[' => Some(']'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pushes `chunks` in order and returns the extracted value, if any.
+    fn scan(chunks: &[&str]) -> Option<String> {
+        let mut scanner = JsonBoundaryScanner::new();
+        let mut buffer = String::new();
+        let mut result = None;
+        for chunk in chunks {
+            buffer.push_str(chunk);
+            if let Some(range) = scanner.push(chunk) {
+                assert!(result.is_none(), "boundary reported twice");
+                result = Some(buffer[range].to_string());
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_single_chunk() {
+        assert_eq!(
+            scan(&[r#"Result: {"a": 1} trailing"#]),
+            Some(r#"{"a": 1}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_every_split_point() {
+        let prefix = "Here you go: ";
+        let json = r#"{"text": "braces } and ] and \"quotes\" {", "items": [1, {"b": [2]}]}"#;
+        let full = format!("{}{} and more text", prefix, json);
+
+        for split in 1..full.len() {
+            let (first, second) = full.split_at(split);
+            assert_eq!(
+                scan(&[first, second]),
+                Some(json.to_string()),
+                "split at {}",
+                split
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_inside_string_and_escape() {
+        // Split right after the backslash so the escape spans chunks
+        let chunks = [r#"{"quote": "say \"#, r#""hi\"", "#, r#""done": true}"#];
+        assert_eq!(
+            scan(&chunks),
+            Some(r#"{"quote": "say \"hi\"", "done": true}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_top_level_array_and_multibyte() {
+        assert_eq!(
+            scan(&["前置き [\"こん", "にちは\", 1]", " 後"]),
+            Some("[\"こんにちは\", 1]".to_string())
+        );
+    }
+
+    #[test]
+    fn test_incomplete_value() {
+        let mut scanner = JsonBoundaryScanner::new();
+        assert_eq!(scanner.push(r#"{"a": [1, 2"#), None);
+        assert!(scanner.in_progress());
+        assert_eq!(scanner.complete(), None);
+
+        assert_eq!(scanner.push("]}"), Some(0..13));
+        assert_eq!(scanner.push(r#" {"b": 2}"#), None);
+        assert_eq!(scanner.complete(), Some(0..13));
+
+        scanner.reset();
+        assert!(!scanner.in_progress());
+        assert_eq!(scanner.push("[]"), Some(0..2));
+    }
+}
@@ -0,0 +1,227 @@
+use super::core::ExtractionStrategy;
+use super::error::ParseError;
+use super::extractors::{FlexibleExtractor, MarkdownCodeBlockExtractor, extract_tagged};
+
+/// A single way of pulling content out of an LLM response.
+///
+/// Implement this for custom extraction logic and combine strategies with
+/// [`StrategyChain`].
+pub trait Strategy: Send + Sync {
+    /// Short label used in error reports
+    fn name(&self) -> String;
+
+    /// Extract content from `text`, or explain why nothing was found
+    fn extract(&self, text: &str) -> Result<String, ParseError>;
+}
+
+impl Strategy for ExtractionStrategy {
+    fn name(&self) -> String {
+        self.label()
+    }
+
+    fn extract(&self, text: &str) -> Result<String, ParseError> {
+        match self {
+            // Keep the more specific tag error
+            ExtractionStrategy::TaggedContent(tag) => extract_tagged(text, tag),
+            _ => FlexibleExtractor::new()
+                .extract_with_strategy(text, self)
+                .ok_or_else(|| ParseError::InvalidFormat(format!("Strategy {:?} failed", self))),
+        }
+    }
+}
+
+impl Strategy for MarkdownCodeBlockExtractor {
+    fn name(&self) -> String {
+        match &self.language {
+            Some(lang) => format!("fence:{}", lang),
+            None => "fence".to_string(),
+        }
+    }
+
+    fn extract(&self, text: &str) -> Result<String, ParseError> {
+        MarkdownCodeBlockExtractor::extract(self, text)
+    }
+}
+
+/// Runs extraction strategies in a user-defined order.
+///
+/// Unlike [`FlexibleExtractor`], which tries a fixed set of strategies, a chain
+/// contains exactly the strategies you add, in the order you add them. The
+/// first success wins; if every strategy fails, the error is
+/// [`ParseError::NoContentFound`] listing each strategy's name in order, with
+/// its error message in `failures`.
+///
+/// # Examples
+///
+/// ```rust
+/// use llm_toolkit::extract::{
+///     ExtractionStrategy, MarkdownCodeBlockExtractor, StrategyChain,
+/// };
+///
+/// let chain = StrategyChain::new()
+///     .with_strategy(ExtractionStrategy::TaggedContent("result".to_string()))
+///     .with_strategy(MarkdownCodeBlockExtractor::with_language("json".to_string()))
+///     .with_strategy(ExtractionStrategy::JsonBrackets);
+///
+/// let response = "Done.\n```json\n{\"ok\": true}\n```";
+/// assert_eq!(chain.extract(response).unwrap(), r#"{"ok": true}"#);
+/// ```
+#[derive(Default)]
+pub struct StrategyChain {
+    strategies: Vec<Box<dyn Strategy>>,
+}
+
+impl StrategyChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a strategy to the end of the chain
+    pub fn with_strategy(self, strategy: impl Strategy + 'static) -> Self {
+        self.with_boxed_strategy(Box::new(strategy))
+    }
+
+    /// Append an already boxed strategy to the end of the chain
+    pub fn with_boxed_strategy(mut self, strategy: Box<dyn Strategy>) -> Self {
+        self.strategies.push(strategy);
+        self
+    }
+
+    /// Names of the strategies in the order they are tried
+    pub fn strategy_names(&self) -> Vec<String> {
+        self.strategies.iter().map(|s| s.name()).collect()
+    }
+
+    /// Try each strategy in order and return the first success
+    pub fn extract(&self, text: &str) -> Result<String, ParseError> {
+        let mut attempted_strategies = Vec::new();
+        let mut failures = Vec::new();
+
+        for strategy in &self.strategies {
+            match strategy.extract(text) {
+                Ok(result) => return Ok(result),
+                Err(e) => {
+                    attempted_strategies.push(strategy.name());
+                    failures.push((strategy.name(), e.to_string()));
+                }
+            }
+        }
+
+        Err(ParseError::NoContentFound {
+            attempted_strategies,
+            failures,
+        })
+    }
+}
+
+impl std::fmt::Debug for StrategyChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StrategyChain")
+            .field("strategies", &self.strategy_names())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESPONSE: &str = "<result>from tag</result>\n\
+                            ```json\n{\"from\": \"fence\"}\n```\n\
+                            trailing {\"from\": \"braces\"}";
+
+    #[test]
+    fn test_first_strategy_in_order_wins() {
+        let tag_first = StrategyChain::new()
+            .with_strategy(ExtractionStrategy::TaggedContent("result".to_string()))
+            .with_strategy(MarkdownCodeBlockExtractor::with_language(
+                "json".to_string(),
+            ))
+            .with_strategy(ExtractionStrategy::JsonBrackets);
+        assert_eq!(tag_first.extract(RESPONSE).unwrap(), "from tag");
+
+        let fence_first = StrategyChain::new()
+            .with_strategy(MarkdownCodeBlockExtractor::with_language(
+                "json".to_string(),
+            ))
+            .with_strategy(ExtractionStrategy::TaggedContent("result".to_string()));
+        assert_eq!(
+            fence_first.extract(RESPONSE).unwrap(),
+            r#"{"from": "fence"}"#
+        );
+
+        // A missing tag falls through to the next strategy
+        let fallback = StrategyChain::new()
+            .with_strategy(ExtractionStrategy::TaggedContent("answer".to_string()))
+            .with_strategy(ExtractionStrategy::JsonBrackets);
+        assert_eq!(fallback.extract(RESPONSE).unwrap(), r#"{"from": "fence"}"#);
+    }
+
+    #[test]
+    fn test_boxed_custom_strategy() {
+        struct LastLine;
+
+        impl Strategy for LastLine {
+            fn name(&self) -> String {
+                "LastLine".to_string()
+            }
+
+            fn extract(&self, text: &str) -> Result<String, ParseError> {
+                text.lines()
+                    .last()
+                    .map(str::to_string)
+                    .ok_or_else(|| ParseError::InvalidFormat("empty text".to_string()))
+            }
+        }
+
+        let chain = StrategyChain::new()
+            .with_boxed_strategy(Box::new(ExtractionStrategy::TaggedContent(
+                "missing".to_string(),
+            )))
+            .with_boxed_strategy(Box::new(LastLine));
+        assert_eq!(
+            chain.extract(RESPONSE).unwrap(),
+            r#"trailing {"from": "braces"}"#
+        );
+    }
+
+    #[test]
+    fn test_error_lists_every_attempted_strategy_in_order() {
+        let chain = StrategyChain::new()
+            .with_strategy(ExtractionStrategy::TaggedContent("result".to_string()))
+            .with_strategy(MarkdownCodeBlockExtractor::with_language(
+                "json".to_string(),
+            ))
+            .with_strategy(ExtractionStrategy::JsonBrackets);
+
+        let err = chain.extract("no structured content here").unwrap_err();
+        assert!(matches!(err, ParseError::NoContentFound { .. }));
+        assert_eq!(
+            err.attempted_strategies(),
+            ["tagged:result", "fence:json", "json-brackets"]
+        );
+
+        // Each strategy's own failure message is kept alongside its name
+        let failures = err.strategy_failures();
+        assert_eq!(failures.len(), 3);
+        assert_eq!(failures[0].0, "tagged:result");
+        assert!(failures[0].1.contains("No <result> tag found"));
+        assert_eq!(failures[1].0, "fence:json");
+        assert!(failures[1].1.contains("with language 'json'"));
+        assert_eq!(failures[2].0, "json-brackets");
+
+        let message = err.to_string();
+        assert!(message.contains("tagged:result ("));
+        assert!(message.contains("No <result> tag found"));
+    }
+
+    #[test]
+    fn test_empty_chain_fails() {
+        let chain = StrategyChain::new();
+        assert!(matches!(
+            chain.extract("{}"),
+            Err(ParseError::NoContentFound { attempted_strategies, failures })
+                if attempted_strategies.is_empty() && failures.is_empty()
+        ));
+    }
+}
@@ -47,6 +47,20 @@ pub enum ExtractionStrategy {
     OriginalText,
 }
 
+impl ExtractionStrategy {
+    /// Short name used in error reports (e.g. `"json-brackets"`, `"tagged:answer"`)
+    pub fn label(&self) -> String {
+        match self {
+            ExtractionStrategy::TaggedContent(tag) => format!("tagged:{}", tag),
+            ExtractionStrategy::JsonBrackets => "json-brackets".to_string(),
+            ExtractionStrategy::FirstJsonObject => "first-json-object".to_string(),
+            ExtractionStrategy::KeywordSearch(_) => "keywords".to_string(),
+            ExtractionStrategy::RegexPattern(_) => "regex".to_string(),
+            ExtractionStrategy::OriginalText => "original-text".to_string(),
+        }
+    }
+}
+
 /// Configuration for response parsing
 #[derive(Debug, Clone)]
 pub struct ParsingConfig {
@@ -0,0 +1,122 @@
+use serde::de::DeserializeOwned;
+
+use super::error::ParseError;
+use crate::prompt::ToPrompt;
+
+/// Extract a unit enum variant named somewhere in free text.
+///
+/// Classification responses often name the answer in prose ("I'd classify
+/// this as Urgent") rather than as JSON. The candidate names are the unit
+/// variants listed in `T::prompt_schema()`, so serde `rename` / `rename_all`
+/// (and `#[prompt(rename)]`) are honored and `#[prompt(skip)]` variants are
+/// never matched. Names match case-insensitively as whole words, and a name
+/// found only inside a longer matching name (`High` in `Very-High`) does not
+/// count.
+///
+/// Exactly one distinct variant must be named. If none is,
+/// [`ParseError::NoVariantMatched`] is returned; if several are,
+/// [`ParseError::AmbiguousVariant`] lists them.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use llm_toolkit::{ToPrompt, extract_enum};
+/// use serde::Deserialize;
+///
+/// #[derive(ToPrompt, Deserialize, Debug, PartialEq)]
+/// enum Priority {
+///     Low,
+///     Urgent,
+/// }
+///
+/// let priority: Priority = extract_enum("I'd classify this as urgent.").unwrap();
+/// assert_eq!(priority, Priority::Urgent);
+/// ```
+pub fn extract_enum<T: DeserializeOwned + ToPrompt>(text: &str) -> Result<T, ParseError> {
+    let candidates = unit_variant_names(&T::prompt_schema());
+    let text = text.to_lowercase();
+
+    // Byte spans of every whole-word occurrence, per candidate
+    let occurrences: Vec<(&str, Vec<(usize, usize)>)> = candidates
+        .iter()
+        .map(|name| (name.as_str(), word_occurrences(&text, &name.to_lowercase())))
+        .filter(|(_, spans)| !spans.is_empty())
+        .collect();
+
+    let matched: Vec<&str> = occurrences
+        .iter()
+        .filter(|(name, spans)| {
+            // Drop names that only appear as part of a longer matched name
+            spans.iter().any(|&(start, end)| {
+                !occurrences.iter().any(|(other, other_spans)| {
+                    other.len() > name.len()
+                        && other_spans.iter().any(|&(s, e)| s <= start && end <= e)
+                })
+            })
+        })
+        .map(|(name, _)| *name)
+        .collect();
+
+    match matched.as_slice() {
+        [] => Err(ParseError::NoVariantMatched {
+            candidates: candidates.clone(),
+        }),
+        [name] => serde_json::from_value(serde_json::Value::String(name.to_string()))
+            .map_err(|e| ParseError::JsonParsingFailed(format!("variant `{}`: {}", name, e))),
+        names => Err(ParseError::AmbiguousVariant {
+            matches: names.iter().map(|name| name.to_string()).collect(),
+        }),
+    }
+}
+
+/// Collect the quoted unit variant names from an enum's `prompt_schema()`.
+fn unit_variant_names(schema: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for line in schema.lines() {
+        let Some(rest) = line.trim_start().strip_prefix("| \"") else {
+            continue;
+        };
+        if let Some(end) = rest.find('"') {
+            let name = rest[..end].to_string();
+            if !name.is_empty() && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+/// Find `needle` in `haystack` where it isn't adjacent to other word characters.
+fn word_occurrences(haystack: &str, needle: &str) -> Vec<(usize, usize)> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    haystack
+        .match_indices(needle)
+        .map(|(start, _)| (start, start + needle.len()))
+        .filter(|&(start, end)| {
+            !haystack[..start].chars().next_back().is_some_and(is_word)
+                && !haystack[end..].chars().next().is_some_and(is_word)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_variant_names_from_schema() {
+        let schema = "/**\n * Priority\n */\ntype Priority =\n  | \"low\"  // Not urgent\n  | \"VeryHigh\"\n  | { type: \"Custom\", level: number }\n  | \"low\";\n\nExample value: \"low\"";
+        assert_eq!(unit_variant_names(schema), vec!["low", "VeryHigh"]);
+    }
+
+    #[test]
+    fn test_word_occurrences_respects_boundaries() {
+        assert_eq!(word_occurrences("high, higher", "high"), vec![(0, 4)]);
+        assert_eq!(word_occurrences("very-high", "high"), vec![(5, 9)]);
+        assert!(word_occurrences("very_high", "high").is_empty());
+        assert_eq!(
+            word_occurrences("優先度: high です", "high"),
+            vec![(11, 15)]
+        );
+    }
+}
@@ -1,3 +1,6 @@
+// The derived impls still name the deprecated `AllStrategiesFailed` variant
+#![allow(deprecated)]
+
 /// Response parsing errors
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum ParseError {
@@ -7,9 +10,37 @@ pub enum ParseError {
     #[error("Failed to extract metadata from response: {0}")]
     JsonParsingFailed(String),
 
+    /// No longer produced by this crate; extraction failures are reported as
+    /// [`ParseError::NoContentFound`].
+    #[deprecated(note = "no longer produced; match ParseError::NoContentFound instead")]
     #[error("Failed to extract content from response: {0:?}")]
     AllStrategiesFailed(Vec<String>),
 
+    /// No extraction strategy found content; lists the strategies tried, in order.
+    ///
+    /// `failures` pairs each strategy name with its error message when the
+    /// strategies report one (as [`StrategyChain`](super::StrategyChain) does),
+    /// and is empty otherwise.
+    #[error(
+        "No content could be extracted from response (tried: {})",
+        describe_attempts(.attempted_strategies, .failures)
+    )]
+    NoContentFound {
+        attempted_strategies: Vec<String>,
+        failures: Vec<(String, String)>,
+    },
+
+    /// None of the enum's variant names appear in the response
+    #[error(
+        "No enum variant found in response (expected one of: {})",
+        .candidates.join(", ")
+    )]
+    NoVariantMatched { candidates: Vec<String> },
+
+    /// More than one of the enum's variant names appear in the response
+    #[error("Response names several enum variants: {}", .matches.join(", "))]
+    AmbiguousVariant { matches: Vec<String> },
+
     #[error("Missing required field: {0}")]
     MissingRequiredField(String),
 
@@ -19,3 +50,36 @@ pub enum ParseError {
     #[error("Failed to process response: {0}")]
     ProcessingFailed(String),
 }
+
+/// Lists each attempt with its failure message when one was recorded
+fn describe_attempts(attempted_strategies: &[String], failures: &[(String, String)]) -> String {
+    if failures.is_empty() {
+        return attempted_strategies.join(", ");
+    }
+    failures
+        .iter()
+        .map(|(name, message)| format!("{} ({})", name, message))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl ParseError {
+    /// Strategies attempted before giving up, for errors that record them
+    pub fn attempted_strategies(&self) -> &[String] {
+        match self {
+            ParseError::NoContentFound {
+                attempted_strategies,
+                ..
+            } => attempted_strategies,
+            _ => &[],
+        }
+    }
+
+    /// Each strategy's failure as `(name, message)`, for errors that record them
+    pub fn strategy_failures(&self) -> &[(String, String)] {
+        match self {
+            ParseError::NoContentFound { failures, .. } => failures,
+            _ => &[],
+        }
+    }
+}
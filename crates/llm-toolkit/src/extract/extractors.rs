@@ -15,14 +15,166 @@ macro_rules! debug_log {
     ($($arg:tt)*) => {};
 }
 
+/// Extract the trimmed inner text of the first `<tag>...</tag>` element.
+///
+/// Attributes on the opening tag are allowed, e.g. `<answer lang="en">...</answer>`.
+pub fn extract_tagged(text: &str, tag: &str) -> Result<String, ParseError> {
+    let pattern = format!(
+        r"(?s)<{tag}(?:\s[^>]*)?>(.*?)</{tag}\s*>",
+        tag = regex::escape(tag)
+    );
+    let regex = Regex::new(&pattern)
+        .map_err(|e| ParseError::InvalidFormat(format!("Failed to compile regex: {}", e)))?;
+
+    regex
+        .captures(text)
+        .and_then(|captures| captures.get(1))
+        .map(|content| content.as_str().trim().to_string())
+        .ok_or_else(|| ParseError::TagExtractionFailed(format!("No <{}> tag found", tag)))
+}
+
+/// Remove every `<tag>...</tag>` block for the given tag names.
+///
+/// Use this to drop chain-of-thought such as `<think>...</think>` before
+/// extracting JSON, since reasoning often contains stray braces. Blocks nested
+/// inside a removed block go with it. Two malformed cases are handled:
+/// - An opening tag that is never closed removes the rest of the text.
+/// - A closing tag with no opening tag removes everything before it. Some
+///   reasoning models omit the opening `<think>`.
+///
+/// Tag names are matched case-sensitively. Attributes on the opening tag are
+/// allowed.
+pub fn strip_tagged_blocks(text: &str, tags: &[String]) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut open: Vec<&str> = Vec::new();
+    let mut copied_to = 0;
+    let mut pos = 0;
+
+    while let Some(offset) = text[pos..].find('<') {
+        let start = pos + offset;
+        let Some((name, end, kind)) = match_strip_tag(text, start, tags) else {
+            pos = start + 1;
+            continue;
+        };
+        pos = end;
+
+        match kind {
+            TagKind::SelfClosing => {
+                if open.is_empty() {
+                    output.push_str(&text[copied_to..start]);
+                    copied_to = end;
+                }
+            }
+            TagKind::Open => {
+                if open.is_empty() {
+                    output.push_str(&text[copied_to..start]);
+                }
+                open.push(name);
+            }
+            TagKind::Close => {
+                if let Some(depth) = open.iter().rposition(|&tag| tag == name) {
+                    open.truncate(depth);
+                    if open.is_empty() {
+                        copied_to = end;
+                    }
+                } else if open.is_empty() {
+                    // Closing tag without an opening one: everything before it was inside
+                    output.clear();
+                    copied_to = end;
+                }
+            }
+        }
+    }
+
+    // An unclosed block runs to the end of the text
+    if open.is_empty() {
+        output.push_str(&text[copied_to..]);
+    }
+    output
+}
+
+enum TagKind {
+    Open,
+    Close,
+    SelfClosing,
+}
+
+/// Match one of `tags` as an XML-style tag starting at `text[start]` (a `<`).
+///
+/// Returns the tag name, the byte offset just past the tag, and its kind.
+fn match_strip_tag<'a>(
+    text: &str,
+    start: usize,
+    tags: &'a [String],
+) -> Option<(&'a str, usize, TagKind)> {
+    let rest = &text[start + 1..];
+    let (is_close, rest) = match rest.strip_prefix('/') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+
+    let name = tags.iter().find(|tag| {
+        rest.strip_prefix(tag.as_str()).is_some_and(|after| {
+            after.starts_with('>')
+                || after.starts_with('/')
+                || after.starts_with(char::is_whitespace)
+        })
+    })?;
+
+    let after_name = &rest[name.len()..];
+    let close = after_name.find('>')?;
+    let end = text.len() - after_name.len() + close + 1;
+
+    let kind = if is_close {
+        TagKind::Close
+    } else if after_name[..close].trim_end().ends_with('/') {
+        TagKind::SelfClosing
+    } else {
+        TagKind::Open
+    };
+    Some((name.as_str(), end, kind))
+}
+
+/// Parse JSON Lines (NDJSON) output: one JSON value per line.
+///
+/// Blank lines are always skipped. With `strict`, every other line must be
+/// valid JSON and each failure is returned as an error. Without it, lines that
+/// don't start with `{` or `[` (e.g. log output interleaved with the stream)
+/// are skipped, while lines that look like JSON but fail to parse are still
+/// reported. Results keep the order of the input lines.
+pub fn extract_ndjson(text: &str, strict: bool) -> Vec<Result<serde_json::Value, ParseError>> {
+    text.lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let line = line.trim();
+            if line.is_empty() || (!strict && !line.starts_with(['{', '['])) {
+                return None;
+            }
+            Some(
+                serde_json::from_str(line).map_err(|e| {
+                    ParseError::JsonParsingFailed(format!("line {}: {}", index + 1, e))
+                }),
+            )
+        })
+        .collect()
+}
+
 /// Flexible content extractor with multiple strategies
 pub struct FlexibleExtractor {
     debug_mode: bool,
+    /// Tags tried, in order, before the standard strategies
+    tags: Vec<String>,
+    /// Tags whose blocks are removed before any strategy runs
+    strip_tags: Vec<String>,
 }
 
 impl FlexibleExtractor {
     pub fn new() -> Self {
-        Self { debug_mode: false }
+        Self {
+            debug_mode: false,
+            tags: Vec::new(),
+            strip_tags: Vec::new(),
+        }
     }
 
     pub fn with_debug(mut self) -> Self {
@@ -30,6 +182,22 @@ impl FlexibleExtractor {
         self
     }
 
+    /// Try `<tag>...</tag>` content before the standard strategies in `extract()`
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Remove `<tag>...</tag>` blocks for these tags before extracting
+    ///
+    /// Useful for reasoning models that emit `<think>` blocks, whose stray braces
+    /// would otherwise be picked up as JSON. See [`strip_tagged_blocks`].
+    pub fn with_strip_tags(mut self, tags: &[&str]) -> Self {
+        self.strip_tags
+            .extend(tags.iter().map(|tag| tag.to_string()));
+        self
+    }
+
     pub fn standard_extraction_strategies() -> Vec<ExtractionStrategy> {
         vec![
             ExtractionStrategy::TaggedContent("answer".to_string()),
@@ -43,7 +211,13 @@ impl FlexibleExtractor {
         if self.debug_mode {
             debug_log!("Extracting content from text: {}", text);
         }
-        self.extract_with_strategies(text, &Self::standard_extraction_strategies())
+        let strategies: Vec<ExtractionStrategy> = self
+            .tags
+            .iter()
+            .map(|tag| ExtractionStrategy::TaggedContent(tag.clone()))
+            .chain(Self::standard_extraction_strategies())
+            .collect();
+        self.extract_with_strategies(text, &strategies)
     }
 
     /// Extract content using specified strategy
@@ -67,12 +241,25 @@ impl FlexibleExtractor {
     }
 
     /// Try multiple extraction strategies in order
+    ///
+    /// Blocks for tags set with [`with_strip_tags`](Self::with_strip_tags) are
+    /// removed first.
+    ///
+    /// When none succeeds, the error is [`ParseError::NoContentFound`] listing
+    /// each strategy's [`label`](ExtractionStrategy::label) in the order tried.
     pub fn extract_with_strategies(
         &self,
         text: &str,
         strategies: &[ExtractionStrategy],
     ) -> Result<String, ParseError> {
-        let mut errors = Vec::new();
+        let stripped;
+        let text = if self.strip_tags.is_empty() {
+            text
+        } else {
+            stripped = strip_tagged_blocks(text, &self.strip_tags);
+            stripped.as_str()
+        };
+        let mut attempted_strategies = Vec::new();
 
         for strategy in strategies {
             if let Some(result) = self.extract_with_strategy(text, strategy) {
@@ -80,12 +267,14 @@ impl FlexibleExtractor {
                     debug_log!("Successfully extracted with strategy: {:?}", strategy);
                 }
                 return Ok(result);
-            } else {
-                errors.push(format!("Strategy {:?} failed", strategy));
             }
+            attempted_strategies.push(strategy.label());
         }
 
-        Err(ParseError::AllStrategiesFailed(errors))
+        Err(ParseError::NoContentFound {
+            attempted_strategies,
+            failures: Vec::new(),
+        })
     }
 
     /// Extract first complete JSON entity (object or array) from text
@@ -122,7 +311,8 @@ impl FlexibleExtractor {
                         let is_valid =
                             (opening == '{' && ch == '}') || (opening == '[' && ch == ']');
                         if is_valid {
-                            return Some(text[p..=i].to_string());
+                            // Both ends come from char_indices, so the slice is on char boundaries
+                            return Some(text[p..i + ch.len_utf8()].to_string());
                         }
                     }
                 }
@@ -162,21 +352,13 @@ impl Default for FlexibleExtractor {
 
 impl ContentExtractor for FlexibleExtractor {
     fn extract_tagged(&self, text: &str, tag: &str) -> Option<String> {
-        // Create regex pattern for XML-like tags
-        let pattern = format!(r"(?s)<{tag}>(.*?)</{tag}>", tag = regex::escape(tag));
-
-        if let Ok(regex) = Regex::new(&pattern)
-            && let Some(captures) = regex.captures(text)
-            && let Some(content) = captures.get(1)
-        {
-            return Some(content.as_str().trim().to_string());
-        }
+        let result = extract_tagged(text, tag).ok();
 
-        if self.debug_mode {
+        if result.is_none() && self.debug_mode {
             debug_log!("Failed to extract tagged content with tag: {}", tag);
         }
 
-        None
+        result
     }
 
     fn extract_json_like(&self, text: &str) -> Option<String> {
@@ -289,6 +471,98 @@ mod tests {
         assert_eq!(result, Some("Hello World".to_string()));
     }
 
+    #[test]
+    fn test_extract_tagged_fn_plain_tag() {
+        let text = "Reasoning first.\n<answer>\n  42  \n</answer>\nDone.";
+        assert_eq!(extract_tagged(text, "answer").unwrap(), "42");
+    }
+
+    #[test]
+    fn test_extract_tagged_fn_with_attributes() {
+        let text = r#"<answer lang="en" confidence='0.9'>Paris</answer>"#;
+        assert_eq!(extract_tagged(text, "answer").unwrap(), "Paris");
+
+        // A longer tag name sharing the prefix must not match
+        let text = "<answers>wrong</answers><answer>right</answer>";
+        assert_eq!(extract_tagged(text, "answer").unwrap(), "right");
+    }
+
+    #[test]
+    fn test_extract_tagged_fn_missing_tag() {
+        let result = extract_tagged("<reply>Paris</reply>", "answer");
+        assert!(matches!(result, Err(ParseError::TagExtractionFailed(_))));
+    }
+
+    #[test]
+    fn test_extract_ndjson_clean() {
+        let text = "{\"id\": 1}\n\n{\"id\": 2}\n[3]\n";
+        let values: Vec<_> = extract_ndjson(text, true)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                serde_json::json!({"id": 1}),
+                serde_json::json!({"id": 2}),
+                serde_json::json!([3])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_ndjson_lenient_skips_log_lines() {
+        let text = "INFO starting stream\n{\"event\": \"start\"}\n\
+                    2024-01-01T00:00:00Z progress 50%\n{\"event\": \"end\"}";
+        let values: Vec<_> = extract_ndjson(text, false)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                serde_json::json!({"event": "start"}),
+                serde_json::json!({"event": "end"})
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_ndjson_strict_reports_malformed_line() {
+        let text = "{\"id\": 1}\nnot json\n{\"id\": 3}";
+        let results = extract_ndjson(text, true);
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        match &results[1] {
+            Err(ParseError::JsonParsingFailed(message)) => assert!(message.starts_with("line 2:")),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert!(results[2].is_ok());
+
+        // Lines that look like JSON are reported even when lenient
+        let results = extract_ndjson("{\"id\": 1,", false);
+        assert!(matches!(
+            results.as_slice(),
+            [Err(ParseError::JsonParsingFailed(_))]
+        ));
+    }
+
+    #[test]
+    fn test_with_tag_strategy() {
+        let extractor = FlexibleExtractor::new().with_tag("result");
+
+        let text = r#"<result id="1">{"status": "ok"}</result> {"status": "ignored"}"#;
+        assert_eq!(extractor.extract(text).unwrap(), r#"{"status": "ok"}"#);
+
+        // Falls back to the standard strategies when the tag is absent
+        let text = r#"No tag here: {"status": "fallback"}"#;
+        assert_eq!(
+            extractor.extract(text).unwrap(),
+            r#"{"status": "fallback"}"#
+        );
+    }
+
     #[test]
     fn test_extract_json_like() {
         let extractor = FlexibleExtractor::new();
@@ -346,6 +620,29 @@ mod tests {
         assert_eq!(result.unwrap(), "{\"type\": \"success\"}");
     }
 
+    #[test]
+    fn test_extract_error_lists_attempted_strategies() {
+        let err = FlexibleExtractor::new()
+            .with_tag("result")
+            .extract("No structured data here.")
+            .unwrap_err();
+
+        assert_eq!(
+            err.attempted_strategies(),
+            [
+                "tagged:result",
+                "tagged:answer",
+                "json-brackets",
+                "first-json-object"
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            "No content could be extracted from response \
+             (tried: tagged:result, tagged:answer, json-brackets, first-json-object)"
+        );
+    }
+
     #[test]
     fn test_clean_json_trailing_commas_object() {
         let extractor = FlexibleExtractor::new();
@@ -437,4 +734,66 @@ mod tests {
             Some(r#"{"result": "success", "code": 200}"#.to_string())
         );
     }
+
+    #[test]
+    fn test_strip_tags_ignores_braces_in_reasoning() {
+        let extractor = FlexibleExtractor::new().with_strip_tags(&["think", "reasoning"]);
+        let text = r#"<think>
+The schema looks like {"answer": ...} but maybe {wrong} too.
+</think>
+{"answer": 42}"#;
+
+        assert_eq!(extractor.extract(text).unwrap(), r#"{"answer": 42}"#);
+        // Without stripping, the brace scan picks up the reasoning
+        assert_ne!(
+            FlexibleExtractor::new().extract(text).unwrap(),
+            r#"{"answer": 42}"#
+        );
+    }
+
+    #[test]
+    fn test_strip_tags_handles_unclosed_tag() {
+        let extractor = FlexibleExtractor::new().with_strip_tags(&["think"]);
+        let text = r#"{"status": "ok"}
+<think>trailing reasoning about {braces} that never closes"#;
+
+        assert_eq!(extractor.extract(text).unwrap(), r#"{"status": "ok"}"#);
+
+        // A lone unclosed tag leaves nothing to extract, without panicking
+        let result = extractor.extract("<think>{ half a thought");
+        assert!(matches!(result, Err(ParseError::NoContentFound { .. })));
+    }
+
+    #[test]
+    fn test_strip_tagged_blocks_nested_and_malformed() {
+        let tags = vec!["think".to_string(), "reasoning".to_string()];
+
+        // Nested blocks are removed with their parent
+        let text = "a<think>x<think>y</think>z<reasoning>w</reasoning></think>b";
+        assert_eq!(strip_tagged_blocks(text, &tags), "ab");
+
+        // Attributes and self-closing tags
+        let text = r#"a<think mode="deep">x</think>b<think/>c"#;
+        assert_eq!(strip_tagged_blocks(text, &tags), "abc");
+
+        // A closing tag without an opening one drops everything before it
+        assert_eq!(strip_tagged_blocks("x {y}</think>z", &tags), "z");
+
+        // Other tags and prefixes of tag names are left alone
+        let text = "<thinking>keep</thinking><thin>keep</thin> 1 < 2";
+        assert_eq!(strip_tagged_blocks(text, &tags), text);
+    }
+
+    #[test]
+    fn test_strip_tagged_blocks_multibyte() {
+        let tags = vec!["think".to_string()];
+        let text = "<think>考え中🤔 {\"x\": 1}</think>答え: {\"y\": \"😀\"} <思考>";
+        assert_eq!(
+            strip_tagged_blocks(text, &tags),
+            "答え: {\"y\": \"😀\"} <思考>"
+        );
+
+        let extractor = FlexibleExtractor::new().with_strip_tags(&["think"]);
+        assert_eq!(extractor.extract(text).unwrap(), "{\"y\": \"😀\"}");
+    }
 }
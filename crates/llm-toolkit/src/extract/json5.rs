@@ -0,0 +1,129 @@
+//! Normalization of JSON5/JSONC-flavoured output into strict JSON.
+
+/// Strip `//` and `/* */` comments and trailing commas, leaving strict JSON.
+///
+/// String literals are left untouched, so `"http://example.com"` and `"a,]"`
+/// survive. Other JSON5 extensions (single quotes, unquoted keys) are not
+/// rewritten.
+///
+/// # Examples
+///
+/// ```rust
+/// use llm_toolkit::extract::normalize_json5;
+///
+/// let json = normalize_json5("{\n  // retries\n  \"max\": 3,\n}");
+/// assert_eq!(serde_json::from_str::<serde_json::Value>(&json).unwrap()["max"], 3);
+/// ```
+pub fn normalize_json5(text: &str) -> String {
+    strip_trailing_commas(&strip_comments(text))
+}
+
+fn strip_comments(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+    let mut escape_next = false;
+
+    while let Some(ch) = chars.next() {
+        if in_string {
+            output.push(ch);
+            if escape_next {
+                escape_next = false;
+            } else if ch == '\\' {
+                escape_next = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match (ch, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                output.push(ch);
+            }
+            ('/', Some('/')) => {
+                // Keep the newline so line structure is preserved
+                while chars.next_if(|&c| c != '\n').is_some() {}
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = None;
+                for c in chars.by_ref() {
+                    if previous == Some('*') && c == '/' {
+                        break;
+                    }
+                    previous = Some(c);
+                }
+                output.push(' ');
+            }
+            _ => output.push(ch),
+        }
+    }
+
+    output
+}
+
+fn strip_trailing_commas(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut output = String::with_capacity(text.len());
+    let mut in_string = false;
+    let mut escape_next = false;
+
+    for (i, &ch) in chars.iter().enumerate() {
+        if in_string {
+            if escape_next {
+                escape_next = false;
+            } else if ch == '\\' {
+                escape_next = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+        } else if ch == '"' {
+            in_string = true;
+        } else if ch == ','
+            && matches!(
+                chars[i + 1..].iter().find(|c| !c.is_whitespace()),
+                Some('}' | ']')
+            )
+        {
+            continue;
+        }
+        output.push(ch);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    fn parse(text: &str) -> Value {
+        serde_json::from_str(&normalize_json5(text)).unwrap()
+    }
+
+    #[test]
+    fn test_strips_comments() {
+        let text = "{\n  // line comment\n  \"a\": 1, /* block */ \"b\": [2] // trailing\n}";
+        assert_eq!(parse(text), json!({"a": 1, "b": [2]}));
+    }
+
+    #[test]
+    fn test_strips_trailing_commas() {
+        assert_eq!(
+            parse("{\"a\": [1, 2,\n], \"b\": {\"c\": 3,},\n}"),
+            json!({"a": [1, 2], "b": {"c": 3}})
+        );
+    }
+
+    #[test]
+    fn test_leaves_strings_untouched() {
+        let text = r#"{"url": "http://example.com/*x*/", "s": "a,]", "q": "\"//\""}"#;
+        assert_eq!(
+            parse(text),
+            json!({"url": "http://example.com/*x*/", "s": "a,]", "q": "\"//\""})
+        );
+    }
+}
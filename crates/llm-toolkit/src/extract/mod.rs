@@ -6,6 +6,10 @@
 //! # Features
 //!
 //! - **Content Extraction**: Extract JSON objects, tagged content, and code blocks
+//! - **Tool Calls**: Parse `{"name": ..., "arguments": ...}` tool-call output
+//! - **Enum Variants**: Pick a single enum variant named in free-text output
+//! - **Strategy Chains**: Run extraction strategies in an explicit, custom order
+//! - **Streaming Boundaries**: Detect when a complete JSON value has arrived in a stream
 //! - **JSON Sanitization**: Auto-fix trailing commas, unclosed brackets/strings
 //! - **Fuzzy Repair**: Schema-based typo correction for tagged enums
 //!
@@ -53,13 +57,28 @@
 //! assert!(result.repaired.to_string().contains("AddDerive"));
 //! ```
 
+pub mod boundary;
+pub mod chain;
 pub mod core;
+pub mod enum_match;
 pub mod error;
 pub mod extractors;
+#[cfg(feature = "json5")]
+pub mod json5;
+pub mod tool_call;
 
+pub use self::boundary::JsonBoundaryScanner;
+pub use self::chain::{Strategy, StrategyChain};
 pub use self::core::{ContentExtractor, ExtractionStrategy, ParsingConfig};
+pub use self::enum_match::extract_enum;
 pub use self::error::ParseError;
-pub use self::extractors::{FlexibleExtractor, MarkdownCodeBlockExtractor};
+pub use self::extractors::{
+    FlexibleExtractor, MarkdownCodeBlockExtractor, extract_ndjson, extract_tagged,
+    strip_tagged_blocks,
+};
+#[cfg(feature = "json5")]
+pub use self::json5::normalize_json5;
+pub use self::tool_call::{ToolCall, extract_tool_call, extract_tool_call_args};
 
 // Re-export fuzzy-parser for LLM JSON repair capabilities
 pub use fuzzy_parser::{
@@ -0,0 +1,136 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::error::ParseError;
+
+/// A tool (function) call emitted by a model.
+///
+/// Accepts both the bare `{"name": ..., "arguments": {...}}` shape and the
+/// OpenAI-style wrapper `{"type": "function", "function": {"name": ..., "arguments": "..."}}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolCall {
+    /// Name of the tool to invoke
+    pub name: String,
+    /// Arguments for the tool, always decoded to JSON
+    pub arguments: Value,
+}
+
+/// Extract a tool call from an LLM response.
+///
+/// The call JSON may sit in a Markdown code block or inline in prose. When
+/// `arguments` is a JSON-encoded string (as OpenAI returns it), it is parsed a
+/// second time. A missing `arguments` field is treated as an empty object.
+///
+/// # Examples
+///
+/// ```rust
+/// use llm_toolkit::extract::extract_tool_call;
+///
+/// let response = r#"Calling: {"name": "get_weather", "arguments": "{\"city\": \"Tokyo\"}"}"#;
+/// let call = extract_tool_call(response).unwrap();
+/// assert_eq!(call.name, "get_weather");
+/// assert_eq!(call.arguments["city"], "Tokyo");
+/// ```
+pub fn extract_tool_call(text: &str) -> Result<ToolCall, ParseError> {
+    let json = crate::extract_json(text)?;
+    let value: Value = serde_json::from_str(&json)
+        .map_err(|e| ParseError::JsonParsingFailed(format!("tool call: {}", e)))?;
+
+    let call = match value.get("function") {
+        Some(function) if function.is_object() => function,
+        _ => &value,
+    };
+
+    let name = call
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| ParseError::MissingRequiredField("name".to_string()))?
+        .to_string();
+
+    let arguments = match call.get("arguments") {
+        Some(Value::String(encoded)) => serde_json::from_str(encoded).map_err(|e| {
+            ParseError::JsonParsingFailed(format!("arguments of tool `{}`: {}", name, e))
+        })?,
+        Some(Value::Null) | None => Value::Object(Default::default()),
+        Some(arguments) => arguments.clone(),
+    };
+
+    Ok(ToolCall { name, arguments })
+}
+
+/// Extract a tool call and deserialize its arguments into `T`.
+///
+/// See [`extract_tool_call`] for the accepted formats.
+pub fn extract_tool_call_args<T: DeserializeOwned>(text: &str) -> Result<T, ParseError> {
+    let call = extract_tool_call(text)?;
+    serde_json::from_value(call.arguments).map_err(|e| {
+        ParseError::JsonParsingFailed(format!("arguments of tool `{}`: {}", call.name, e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct WeatherArgs {
+        city: String,
+        days: u32,
+    }
+
+    #[test]
+    fn test_arguments_as_object() {
+        let response = r#"I'll look that up.
+
+```json
+{"name": "get_weather", "arguments": {"city": "Tokyo", "days": 3}}
+```"#;
+
+        let call = extract_tool_call(response).unwrap();
+        assert_eq!(call.name, "get_weather");
+        assert_eq!(call.arguments, json!({"city": "Tokyo", "days": 3}));
+
+        let args: WeatherArgs = extract_tool_call_args(response).unwrap();
+        assert_eq!(
+            args,
+            WeatherArgs {
+                city: "Tokyo".to_string(),
+                days: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_arguments_as_escaped_string() {
+        let response = r#"{"type": "function", "function": {"name": "get_weather", "arguments": "{\"city\": \"Osaka\", \"days\": 1}"}}"#;
+
+        let call = extract_tool_call(response).unwrap();
+        assert_eq!(call.name, "get_weather");
+        assert_eq!(call.arguments, json!({"city": "Osaka", "days": 1}));
+
+        let args: WeatherArgs = extract_tool_call_args(response).unwrap();
+        assert_eq!(args.city, "Osaka");
+    }
+
+    #[test]
+    fn test_missing_name_and_bad_arguments() {
+        assert!(matches!(
+            extract_tool_call(r#"{"arguments": {}}"#),
+            Err(ParseError::MissingRequiredField(field)) if field == "name"
+        ));
+        assert!(matches!(
+            extract_tool_call(r#"{"name": "noop", "arguments": "not json"}"#),
+            Err(ParseError::JsonParsingFailed(_))
+        ));
+        assert!(matches!(
+            extract_tool_call_args::<WeatherArgs>(r#"{"name": "get_weather", "arguments": {}}"#),
+            Err(ParseError::JsonParsingFailed(_))
+        ));
+        assert_eq!(
+            extract_tool_call(r#"{"name": "noop"}"#).unwrap().arguments,
+            json!({})
+        );
+    }
+}
@@ -18,6 +18,9 @@ pub extern crate tracing;
 // Re-export minijinja for use by ToPrompt derive macro
 pub extern crate minijinja;
 
+// Re-export serde_json for use by ToPrompt derive macro
+pub extern crate serde_json;
+
 // Re-export quick_xml for use by define_intent macro
 #[cfg(feature = "derive")]
 pub extern crate quick_xml;
@@ -47,6 +50,12 @@ pub use llm_toolkit_macros::ToPromptFor;
 #[cfg(feature = "derive")]
 pub use llm_toolkit_macros::examples_section;
 
+/// A macro for creating a combined schema-and-examples section for several types.
+///
+/// This macro is available only when the `derive` feature is enabled.
+#[cfg(feature = "derive")]
+pub use llm_toolkit_macros::schema_and_examples_section;
+
 /// A procedural attribute macro for defining intent enums with automatic prompt and extractor generation.
 ///
 /// This macro is available only when the `derive` feature is enabled.
@@ -129,7 +138,10 @@ pub mod orchestrator;
 
 pub use attachment::{Attachment, AttachmentSchema, ToAttachments};
 pub use context::{ContextProfile, Priority, TaskHealth};
-pub use extract::{FlexibleExtractor, MarkdownCodeBlockExtractor};
+pub use extract::{
+    FlexibleExtractor, MarkdownCodeBlockExtractor, ToolCall, extract_enum, extract_ndjson,
+    extract_tagged, extract_tool_call, extract_tool_call_args,
+};
 #[cfg(feature = "agent")]
 pub use intent::expandable::{
     Expandable, ReActConfig, ReActError, ReActResult, RegistryError, Selectable, SelectionRegistry,
@@ -140,9 +152,15 @@ pub use intent::frame::IntentFrame;
 pub use intent::{IntentError, IntentExtractor, PromptBasedExtractor};
 pub use models::{ClaudeModel, GeminiModel, Model, ModelError, OpenAIModel};
 pub use multimodal::ImageData;
-pub use prompt::{PromptPart, PromptSetError, ToPrompt, ToPromptFor, ToPromptSet};
+pub use prompt::{
+    PromptPart, PromptSetError, ProvideExample, RenderError, RuntimeTemplate, RuntimeTemplateError,
+    ToPrompt, ToPromptFor, ToPromptSet,
+};
 pub use retrieval::Document;
 
+#[cfg(feature = "agent")]
+pub use prompt::PromptBuilder;
+
 #[cfg(feature = "agent")]
 pub use agent::{Agent, AgentError, AnyAgent, ToExpertise};
 
@@ -164,6 +182,9 @@ pub use orchestrator::{
 
 use extract::ParseError;
 
+/// Fence language hints treated as JSON by [`extract_json`], in priority order.
+const JSON_FENCE_LANGUAGES: [&str; 3] = ["json", "json5", "jsonc"];
+
 /// Extracts a JSON string from a raw LLM response string.
 ///
 /// This function uses a `FlexibleExtractor` with its standard strategies
@@ -172,14 +193,25 @@ use extract::ParseError;
 ///
 /// For more advanced control over extraction strategies, see the `extract::FlexibleExtractor` struct.
 ///
+/// Code blocks fenced as `json5` or `jsonc` are recognized as well. With the
+/// `json5` feature, their comments and trailing commas are stripped so the
+/// result parses as strict JSON; without it, their content is returned as is.
+///
 /// # Returns
 ///
-/// A `Result` containing the extracted JSON `String` on success, or a `ParseError`
-/// if no JSON could be extracted.
+/// A `Result` containing the extracted JSON `String` on success, or a
+/// `ParseError::NoContentFound` listing the strategies tried if no JSON could
+/// be extracted.
 pub fn extract_json(text: &str) -> Result<String, ParseError> {
     // Try markdown code block first (common LLM output format)
-    if let Ok(content) = extract_markdown_block_with_lang(text, "json") {
-        return Ok(content);
+    for lang in JSON_FENCE_LANGUAGES {
+        if let Ok(content) = extract_markdown_block_with_lang(text, lang) {
+            #[cfg(feature = "json5")]
+            if lang != "json" {
+                return Ok(extract::normalize_json5(&content));
+            }
+            return Ok(content);
+        }
     }
 
     // Also try generic markdown block (might contain JSON without language hint)
@@ -193,7 +225,59 @@ pub fn extract_json(text: &str) -> Result<String, ParseError> {
 
     // Fall back to standard extraction strategies
     let extractor = FlexibleExtractor::new();
-    extractor.extract(text)
+    extractor.extract(text).map_err(|e| match e {
+        ParseError::NoContentFound {
+            attempted_strategies,
+            failures,
+        } => ParseError::NoContentFound {
+            attempted_strategies: ["json-fence", "generic-fence"]
+                .into_iter()
+                .map(str::to_string)
+                .chain(attempted_strategies)
+                .collect(),
+            failures,
+        },
+        other => other,
+    })
+}
+
+/// Extracts the first JSON value from a raw LLM response, whatever its type.
+///
+/// Objects found by [`extract_json`] take priority. If none is found, the
+/// fenced block (if any) or the whole trimmed response is parsed as a bare
+/// JSON value, so responses such as `true`, `"yes"` or `42` are accepted.
+///
+/// # Returns
+///
+/// A `Result` containing the parsed `serde_json::Value` on success, or a
+/// `ParseError` if the response holds no JSON value.
+pub fn extract_json_value(text: &str) -> Result<serde_json::Value, ParseError> {
+    let error = match extract_json(text) {
+        Ok(json) => match serde_json::from_str(&json) {
+            Ok(value) => return Ok(value),
+            Err(e) => ParseError::JsonParsingFailed(e.to_string()),
+        },
+        Err(ParseError::NoContentFound {
+            mut attempted_strategies,
+            failures,
+        }) => {
+            attempted_strategies.push("bare-value".to_string());
+            ParseError::NoContentFound {
+                attempted_strategies,
+                failures,
+            }
+        }
+        Err(other) => other,
+    };
+
+    // The whole response (or its fenced block) may be a bare scalar
+    let fenced = extract_markdown_block(text).ok();
+    fenced
+        .as_deref()
+        .into_iter()
+        .chain([text])
+        .find_map(|candidate| serde_json::from_str(candidate.trim()).ok())
+        .ok_or(error)
 }
 
 /// Extracts content from any Markdown code block in the text.
@@ -239,6 +323,52 @@ mod tests {
         assert_eq!(extract_json(input).unwrap(), "{\"key\": \"value\"}");
     }
 
+    #[test]
+    fn test_extract_json_value_bare_boolean() {
+        assert_eq!(extract_json_value("true").unwrap(), serde_json::json!(true));
+    }
+
+    #[test]
+    fn test_extract_json_value_bare_string() {
+        assert_eq!(
+            extract_json_value("  \"yes\"\n").unwrap(),
+            serde_json::json!("yes")
+        );
+    }
+
+    #[test]
+    fn test_extract_json_value_bare_number_in_fence() {
+        assert_eq!(
+            extract_json_value("```json\n42\n```").unwrap(),
+            serde_json::json!(42)
+        );
+    }
+
+    #[test]
+    fn test_extract_json_value_prefers_objects() {
+        let text = "42 items: {\"count\": 42}";
+        assert_eq!(
+            extract_json_value(text).unwrap(),
+            serde_json::json!({ "count": 42 })
+        );
+    }
+
+    #[test]
+    fn test_extract_json_value_reports_strategies_on_failure() {
+        match extract_json_value("no json here") {
+            Err(ParseError::NoContentFound {
+                attempted_strategies,
+                ..
+            }) => {
+                assert_eq!(
+                    attempted_strategies.last().map(String::as_str),
+                    Some("bare-value")
+                )
+            }
+            other => panic!("expected NoContentFound, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_standard_extraction_from_tagged_content() {
         let text = "<answer>{\"type\": \"success\"}</answer>";
@@ -305,6 +435,28 @@ And more text after with various spacing.
         );
     }
 
+    #[test]
+    fn test_extract_json_error_lists_attempted_strategies() {
+        let err = extract_json("I could not produce any data.").unwrap_err();
+        assert_eq!(
+            err.attempted_strategies(),
+            [
+                "json-fence",
+                "generic-fence",
+                "tagged:answer",
+                "json-brackets",
+                "first-json-object"
+            ]
+        );
+        assert!(
+            err.to_string().contains(
+                "tried: json-fence, generic-fence, tagged:answer, json-brackets, first-json-object"
+            ),
+            "{}",
+            err
+        );
+    }
+
     #[test]
     fn test_extract_json_from_json_markdown_block() {
         // Test extraction from JSON markdown block (highest priority)
@@ -374,6 +526,37 @@ But this is JSON: {"fallback": "value"}"#;
         assert_eq!(result.unwrap(), r#"{"fallback": "value"}"#);
     }
 
+    #[test]
+    fn test_extract_json_recognizes_json5_and_jsonc_fences() {
+        let text = "Result:\n```jsonc\n{\"ok\": true}\n```\nDone.";
+        assert_eq!(extract_json(text).unwrap(), r#"{"ok": true}"#);
+
+        // The generic fallback would pick the first block; the hinted one wins
+        let text = "```text\nnot json\n```\n```json5\n{\"ok\": 1}\n```";
+        assert_eq!(extract_json(text).unwrap(), r#"{"ok": 1}"#);
+    }
+
+    #[cfg(feature = "json5")]
+    #[test]
+    fn test_extract_json_json5_fence_with_trailing_comma() {
+        let text = "Here you go:\n```json5\n{\n  \"name\": \"Alice\",\n  \"tags\": [\"a\", \"b\",],\n}\n```";
+        let json = extract_json(text).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({"name": "Alice", "tags": ["a", "b"]})
+        );
+    }
+
+    #[cfg(feature = "json5")]
+    #[test]
+    fn test_extract_json_jsonc_fence_with_comment() {
+        let text = "```jsonc\n{\n  // upper bound for retries\n  \"max_retries\": 3\n}\n```";
+        let json = extract_json(text).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, serde_json::json!({"max_retries": 3}));
+    }
+
     #[test]
     fn test_extract_json_from_rust_block_fallback() {
         // When only non-JSON markdown blocks exist, fallback to inline extraction
@@ -407,4 +590,37 @@ The result is {"data": "inline"}"#;
         assert!(json.contains("\"values\": [1, 2, 3]"));
         assert!(json.contains("\"nested\""));
     }
+
+    #[test]
+    fn test_extract_json_multibyte_around_and_inside() {
+        let text = "结果如下 🎉 {\"名前\": \"太郎\", \"mood\": \"😀\"} 以上です✅";
+        assert_eq!(
+            extract_json(text).unwrap(),
+            "{\"名前\": \"太郎\", \"mood\": \"😀\"}"
+        );
+
+        let text = "🚀 ```json\n{\"emoji\": \"🦀\", \"cjk\": \"漢字\"}\n```\n👍";
+        let json = extract_json(text).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::json!({"emoji": "🦀", "cjk": "漢字"})
+        );
+
+        // Braces inside multibyte strings don't end the object early
+        let text = "前置き{\"text\": \"}😀{ 括弧 ]\"}後書き";
+        assert_eq!(extract_json(text).unwrap(), "{\"text\": \"}😀{ 括弧 ]\"}");
+    }
+
+    #[test]
+    fn test_extract_json_multibyte_truncations_do_not_panic() {
+        let text = "説明🎉```json\n{\"a\": [\"😀\", {\"b\": \"漢字\"}]}\n```終わり🦀";
+        let boundaries = text.char_indices().map(|(i, _)| i).chain([text.len()]);
+
+        // Every prefix and suffix leaves brackets, fences and multibyte chars in odd places
+        for i in boundaries {
+            let _ = extract_json(&text[..i]);
+            let _ = extract_json(&text[i..]);
+            let _ = extract_json_value(&text[..i]);
+        }
+    }
 }
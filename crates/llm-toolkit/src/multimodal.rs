@@ -77,6 +77,51 @@ impl ImageData {
     pub fn to_base64(&self) -> String {
         STANDARD.encode(&self.data)
     }
+
+    /// Converts the image data to a base64 data URL
+    /// (e.g., `data:image/png;base64,iVBORw0...`) using the stored media type.
+    pub fn to_data_url(&self) -> String {
+        format!("data:{};base64,{}", self.media_type, self.to_base64())
+    }
+
+    /// Creates an `ImageData` instance from a data URL
+    /// (e.g., `data:image/png;base64,iVBORw0...`).
+    ///
+    /// A missing media type defaults to `application/octet-stream`. Data URLs
+    /// without the `;base64` marker are taken as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a data URL or if its base64
+    /// payload cannot be decoded.
+    pub fn from_data_url(data_url: &str) -> Result<Self, String> {
+        let content = data_url
+            .strip_prefix("data:")
+            .ok_or_else(|| "Not a data URL".to_string())?;
+
+        let (header, payload) = content
+            .split_once(',')
+            .ok_or_else(|| "Invalid data URL format".to_string())?;
+
+        let mut params = header.split(';');
+        let media_type = match params.next() {
+            Some(media_type) if !media_type.is_empty() => media_type.to_string(),
+            _ => "application/octet-stream".to_string(),
+        };
+
+        let is_base64 = params.any(|param| param == "base64");
+
+        let data = if is_base64 {
+            STANDARD
+                .decode(payload)
+                .map_err(|e| format!("Failed to decode base64: {}", e))?
+        } else {
+            // URL-encoded data
+            payload.as_bytes().to_vec()
+        };
+
+        Ok(Self { media_type, data })
+    }
 }
 
 impl ToPrompt for ImageData {
@@ -116,35 +161,7 @@ impl TryFrom<&str> for ImageData {
     type Error = String;
 
     fn try_from(data_url: &str) -> Result<Self, Self::Error> {
-        if !data_url.starts_with("data:") {
-            return Err("Not a data URL".to_string());
-        }
-
-        let content = data_url.strip_prefix("data:").ok_or("Invalid data URL")?;
-
-        let parts: Vec<&str> = content.splitn(2, ',').collect();
-        if parts.len() != 2 {
-            return Err("Invalid data URL format".to_string());
-        }
-
-        let media_type = parts[0]
-            .split(';')
-            .next()
-            .unwrap_or("application/octet-stream")
-            .to_string();
-
-        let is_base64 = parts[0].contains("base64");
-
-        let data = if is_base64 {
-            STANDARD
-                .decode(parts[1])
-                .map_err(|e| format!("Failed to decode base64: {}", e))?
-        } else {
-            // URL-encoded data
-            parts[1].as_bytes().to_vec()
-        };
-
-        Ok(Self { media_type, data })
+        Self::from_data_url(data_url)
     }
 }
 
@@ -200,4 +217,41 @@ mod tests {
         assert_eq!(img.media_type, "image/png");
         assert_eq!(img.data, b"Hello");
     }
+
+    /// A 1x1 transparent PNG
+    const PNG_1X1: [u8; 70] = [
+        0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44,
+        0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1F,
+        0x15, 0xC4, 0x89, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x44, 0x41, 0x54, 0x78, 0xDA, 0x63, 0x64,
+        0x60, 0xF8, 0x5F, 0x0F, 0x00, 0x02, 0x87, 0x01, 0x80, 0xEB, 0x47, 0xBA, 0x92, 0x00, 0x00,
+        0x00, 0x00, 0x49, 0x45, 0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82,
+    ];
+    const PNG_1X1_BASE64: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+    #[test]
+    fn test_data_url_round_trip() {
+        let img = ImageData::new("image/png", PNG_1X1.to_vec());
+
+        assert_eq!(img.to_base64(), PNG_1X1_BASE64);
+
+        let data_url = img.to_data_url();
+        assert_eq!(
+            data_url,
+            format!("data:image/png;base64,{}", PNG_1X1_BASE64)
+        );
+
+        let parsed = ImageData::from_data_url(&data_url).unwrap();
+        assert_eq!(parsed.media_type, "image/png");
+        assert_eq!(parsed.data, PNG_1X1);
+    }
+
+    #[test]
+    fn test_data_url_errors() {
+        assert!(ImageData::from_data_url("https://example.com/image.png").is_err());
+        assert!(ImageData::from_data_url("data:image/png;base64").is_err());
+        assert!(ImageData::from_data_url("data:image/png;base64,not base64!").is_err());
+
+        let untyped = ImageData::from_data_url("data:;base64,SGVsbG8=").unwrap();
+        assert_eq!(untyped.media_type, "application/octet-stream");
+    }
 }
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+
+use super::strategy::StrategyMap;
+
+/// Serializable snapshot of an orchestrator run, used to resume after a failure.
+///
+/// A checkpoint captures everything needed to continue a workflow without
+/// re-running expensive steps that already completed:
+/// - The strategy being executed
+/// - The runtime context (step outputs, `output_key` entries, type-marked outputs)
+/// - The ids of top-level steps that completed successfully
+/// - The ids of top-level loops that completed, and of steps skipped by their condition
+/// - The ids of approval steps that were granted
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let checkpoint = orchestrator.export_checkpoint();
+/// std::fs::write("checkpoint.json", serde_json::to_string(&checkpoint)?)?;
+///
+/// // Later, in a fresh process:
+/// let checkpoint: OrchestratorCheckpoint =
+///     serde_json::from_str(&std::fs::read_to_string("checkpoint.json")?)?;
+/// orchestrator.resume_from(checkpoint);
+/// let result = orchestrator.execute("Write article about Rust").await;
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrchestratorCheckpoint {
+    /// The original task description, if one was recorded.
+    #[serde(default)]
+    pub task: Option<String>,
+    /// The strategy being executed when the checkpoint was taken.
+    #[serde(default)]
+    pub strategy_map: Option<StrategyMap>,
+    /// Runtime context keyed by step output name or `output_key`.
+    #[serde(default)]
+    pub context: HashMap<String, JsonValue>,
+    /// Ids of top-level steps that completed, in execution order.
+    #[serde(default)]
+    pub completed_steps: Vec<String>,
+    /// Ids of top-level `Loop`s (`loop_id`) and `LoopStep`s (body `step_id`) that completed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub completed_loops: Vec<String>,
+    /// Ids of top-level steps that were skipped because their condition was not met.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_steps: Vec<String>,
+    /// Ids of `ApprovalStep`s that were granted, kept apart from step ids.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub granted_approvals: Vec<String>,
+    /// The approval the run is waiting on, if it was paused by an `ApprovalStep`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_approval: Option<PendingApproval>,
+}
+
+/// An `ApprovalStep` waiting for a human decision.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingApproval {
+    /// The `approval_id` of the step that paused the run.
+    pub approval_id: String,
+    /// The reviewer note from the `ApprovalStep`, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The output of the instruction preceding the approval, for review.
+    pub output: JsonValue,
+}
+
+/// State of a run paused at an `ApprovalStep`, reported in `OrchestrationResult::paused`.
+///
+/// Resume it with `Orchestrator::approve` or abort it with `Orchestrator::reject`.
+/// Because the pending approval is part of `OrchestratorCheckpoint`, a paused run
+/// can also be exported and approved later in another process.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrchestratorPaused {
+    /// The approval the run is waiting on.
+    pub pending: PendingApproval,
+}
+
+impl OrchestratorCheckpoint {
+    /// Returns true if the step with the given id already completed.
+    pub fn is_step_completed(&self, step_id: &str) -> bool {
+        self.completed_steps.iter().any(|id| id == step_id)
+    }
+
+    /// Returns the index of the next step to run in the strategy, if any remain.
+    ///
+    /// This is the current position in the `StrategyMap`: the first top-level
+    /// step whose id has not been recorded as completed.
+    pub fn next_step_index(&self) -> Option<usize> {
+        let strategy = self.strategy_map.as_ref()?;
+        let step_ids: Vec<&str> = if strategy.elements.is_empty() {
+            strategy.steps.iter().map(|s| s.step_id.as_str()).collect()
+        } else {
+            strategy
+                .elements
+                .iter()
+                .filter_map(|instruction| match instruction {
+                    super::strategy::StrategyInstruction::Step(step) => Some(step.step_id.as_str()),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        step_ids.iter().position(|id| !self.is_step_completed(id))
+    }
+}
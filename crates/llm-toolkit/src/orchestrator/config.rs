@@ -1,5 +1,6 @@
 //! Configuration for orchestrator execution behavior.
 
+use super::strategy::StepPolicy;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -247,6 +248,32 @@ pub struct OrchestratorConfig {
     /// **Default:** `DetectionMode::None` (disabled for backward compatibility)
     #[serde(default)]
     pub detection_mode: DetectionMode,
+
+    /// Workflow-level step policy applied to steps without their own `policy`.
+    ///
+    /// Controls per-step timeouts and what happens when a step fails:
+    /// - `FailurePolicy::Abort`: stop the workflow with the step's error
+    /// - `FailurePolicy::Skip`: mark the step as skipped and continue
+    /// - `FailurePolicy::Retry(n)`: re-run the step up to `n` times, then abort
+    ///
+    /// **Example:**
+    /// ```ignore
+    /// use llm_toolkit::orchestrator::{FailurePolicy, OrchestratorConfig, StepPolicy};
+    /// use std::time::Duration;
+    ///
+    /// let config = OrchestratorConfig {
+    ///     default_step_policy: Some(
+    ///         StepPolicy::default()
+    ///             .with_timeout(Duration::from_secs(120))
+    ///             .with_on_failure(FailurePolicy::Retry(1)),
+    ///     ),
+    ///     ..Default::default()
+    /// };
+    /// ```
+    ///
+    /// **Default:** `None` (no timeout; a failing step aborts the workflow)
+    #[serde(default)]
+    pub default_step_policy: Option<StepPolicy>,
 }
 
 /// Helper function for serde default value of `true`.
@@ -264,6 +291,7 @@ impl Default for OrchestratorConfig {
             max_total_loop_iterations: 50,
             enable_validation: true,
             detection_mode: DetectionMode::None,
+            default_step_policy: None,
         }
     }
 }
@@ -10,9 +10,18 @@ pub enum OrchestratorError {
     #[error("A redesign has been triggered. Restarting execution.")]
     RedesignAndRestart,
 
-    /// An agent execution failed.
-    #[error("Agent error: {0}")]
-    AgentError(#[from] AgentError),
+    /// An agent execution failed, optionally within a named step.
+    ///
+    /// Created from an [`AgentError`] via `?` or `From`; attach the step
+    /// afterwards with [`OrchestratorError::with_step`].
+    #[error(
+        "Agent error{}: {source}",
+        .step.as_ref().map(|step| format!(" in step '{}'", step)).unwrap_or_default()
+    )]
+    Agent {
+        step: Option<String>,
+        source: AgentError,
+    },
 
     /// No agent with the specified name was found in the registry.
     #[error("No agent found with name: {0}")]
@@ -57,6 +66,15 @@ pub enum OrchestratorError {
     #[error("Total number of loop iterations exceeded the maximum limit ({0})")]
     MaxLoopIterationsExceeded(usize),
 
+    /// A `LoopStep` ran `max_iterations` times without its `until` predicate holding.
+    #[error(
+        "Step '{step_id}' did not satisfy its loop predicate within {max_iterations} iterations"
+    )]
+    LoopLimitExceeded {
+        step_id: String,
+        max_iterations: usize,
+    },
+
     /// The internal agent failed to recover even after a fallback attempt.
     #[error("The internal agent failed to recover even after a fallback attempt: {0}")]
     InternalAgentUnrecoverable(String),
@@ -68,12 +86,53 @@ pub enum OrchestratorError {
         timeout: std::time::Duration,
     },
 
+    /// A step's `condition` expression could not be parsed or evaluated.
+    #[error("Invalid condition for step '{step_id}': {message}")]
+    InvalidCondition { step_id: String, message: String },
+
     /// A step execution was cancelled.
     #[error("Step '{step_id}' was cancelled")]
     Cancelled { step_id: String },
+
+    /// A reviewer rejected the workflow at an `ApprovalStep`.
+    #[error("Approval '{approval_id}' was rejected: {reason}")]
+    ApprovalRejected { approval_id: String, reason: String },
+
+    /// `approve` or `reject` was called while no approval was pending.
+    #[error("No approval is pending")]
+    NoPendingApproval,
+}
+
+impl From<AgentError> for OrchestratorError {
+    fn from(source: AgentError) -> Self {
+        Self::Agent { step: None, source }
+    }
 }
 
 impl OrchestratorError {
+    /// Attaches the step name to an agent error.
+    ///
+    /// Other variants are returned unchanged, so this can be applied to any
+    /// error coming out of a step.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let output = agent
+    ///     .execute(payload)
+    ///     .await
+    ///     .map_err(|e| OrchestratorError::from(e).with_step("summarize"))?;
+    /// ```
+    pub fn with_step(self, step: impl Into<String>) -> Self {
+        match self {
+            Self::Agent { source, .. } => Self::Agent {
+                step: Some(step.into()),
+                source,
+            },
+            other => other,
+        }
+    }
+
     /// Creates an error indicating no strategy is available.
     pub fn no_strategy() -> Self {
         Self::Other("No strategy available".to_string())
@@ -84,3 +143,44 @@ impl OrchestratorError {
         Self::Other(format!("Invalid blueprint: {}", reason.into()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agent_error_converts_with_step_context() {
+        fn run_step() -> Result<String, OrchestratorError> {
+            let result: Result<String, AgentError> =
+                Err(AgentError::ExecutionFailed("backend down".to_string()));
+            let output = result?;
+            Ok(output)
+        }
+
+        let err = run_step().unwrap_err();
+        assert!(matches!(err, OrchestratorError::Agent { step: None, .. }));
+        assert_eq!(
+            err.to_string(),
+            "Agent error: Agent execution failed: backend down"
+        );
+
+        let err = err.with_step("summarize");
+        match &err {
+            OrchestratorError::Agent { step, source } => {
+                assert_eq!(step.as_deref(), Some("summarize"));
+                assert!(matches!(source, AgentError::ExecutionFailed(_)));
+            }
+            other => panic!("expected Agent error, got {:?}", other),
+        }
+        assert_eq!(
+            err.to_string(),
+            "Agent error in step 'summarize': Agent execution failed: backend down"
+        );
+
+        // Non-agent errors pass through unchanged
+        assert!(matches!(
+            OrchestratorError::AgentNotFound("x".to_string()).with_step("summarize"),
+            OrchestratorError::AgentNotFound(_)
+        ));
+    }
+}
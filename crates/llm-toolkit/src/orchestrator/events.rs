@@ -0,0 +1,83 @@
+//! Structured progress events emitted during orchestrator execution.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use super::OrchestrationStatus;
+
+/// A completed step's output, yielded by `Orchestrator::run_streaming`.
+///
+/// Unlike `OrchestratorEvent::StepCompleted`, this carries the output itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepOutput {
+    /// The step's `step_id`.
+    pub name: String,
+
+    /// The output's `__type` marker, if it has one (see `TypeMarker`).
+    pub type_name: Option<String>,
+
+    /// The step's output value.
+    pub value: JsonValue,
+}
+
+impl StepOutput {
+    /// Builds a `StepOutput`, reading the type marker from the output.
+    pub(crate) fn new(name: &str, value: &JsonValue) -> Self {
+        Self {
+            name: name.to_string(),
+            type_name: value
+                .get("__type")
+                .and_then(|t| t.as_str())
+                .map(str::to_string),
+            value: value.clone(),
+        }
+    }
+}
+
+/// A progress event emitted by `Orchestrator::run_with_events`.
+///
+/// Events are sent in execution order, so a consumer (e.g. a TUI) can
+/// render step-by-step progress without parsing logs. `name` is the
+/// step's `step_id`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OrchestratorEvent {
+    /// A step's intent was built and its agent is about to be executed.
+    ///
+    /// Always followed by `StepCompleted`, `StepSkipped` or at least one `StepFailed`
+    /// for the same step.
+    StepStarted { name: String },
+
+    /// A step finished successfully.
+    ///
+    /// `output_type` is the output's `__type` marker, if it has one (see `TypeMarker`).
+    StepCompleted {
+        name: String,
+        output_type: Option<String>,
+    },
+
+    /// A step attempt failed or timed out.
+    StepFailed { name: String, error: String },
+
+    /// A step was skipped (restored from a checkpoint, or `FailurePolicy::Skip`).
+    StepSkipped { name: String },
+
+    /// The workflow paused at an `ApprovalStep`; `name` is its `approval_id`.
+    ApprovalRequested { name: String },
+
+    /// The workflow finished. Always the last event of a run.
+    WorkflowCompleted { status: OrchestrationStatus },
+}
+
+impl OrchestratorEvent {
+    /// Builds a `StepCompleted` event, reading the type marker from the output.
+    pub(crate) fn step_completed(name: &str, output: &JsonValue) -> Self {
+        Self::StepCompleted {
+            name: name.to_string(),
+            output_type: output
+                .get("__type")
+                .and_then(|t| t.as_str())
+                .map(str::to_string),
+        }
+    }
+}
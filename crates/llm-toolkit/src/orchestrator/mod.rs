@@ -49,18 +49,25 @@
 //!         OrchestrationStatus::Failure => {
 //!             eprintln!("Failed: {:?}", result.error_message);
 //!         }
+//!         OrchestrationStatus::Paused => {
+//!             // Waiting at an ApprovalStep; call approve() or reject()
+//!             println!("Paused: {:?}", result.paused);
+//!         }
 //!     }
 //! }
 //! ```
 
 pub mod blueprint;
+pub mod checkpoint;
 pub mod config;
 pub mod error;
+pub mod events;
 pub mod journal;
 #[cfg(feature = "agent")]
 pub mod lifecycle;
 pub mod parallel;
 mod parallel_orchestrator;
+pub mod plan;
 pub mod strategy;
 
 // Prompt definitions require both derive (ToPrompt macro) and agent (for usage)
@@ -71,22 +78,27 @@ use crate::agent::{Agent, AgentAdapter, AgentOutput, DynamicAgent};
 #[cfg(feature = "agent")]
 use async_trait::async_trait;
 pub use blueprint::BlueprintWorkflow;
+pub use checkpoint::{OrchestratorCheckpoint, OrchestratorPaused, PendingApproval};
 pub use config::{DetectionMode, OrchestratorConfig};
 pub use error::OrchestratorError;
+pub use events::{OrchestratorEvent, StepOutput};
 pub use journal::{ExecutionJournal, StepRecord, StepStatus, current_timestamp_ms};
 #[cfg(feature = "agent")]
 pub use lifecycle::StrategyLifecycle;
 pub use parallel_orchestrator::{
     OrchestrationState, ParallelOrchestrationResult, ParallelOrchestrator,
 };
+pub use plan::{PlanIssue, PlannedStep, WorkflowPlan};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 pub use strategy::{
-    AggregationMode, LoopAggregation, LoopBlock, LoopType, RedesignStrategy, StrategyInstruction,
-    StrategyMap, StrategyStep, TerminateInstruction,
+    AggregationMode, ApprovalStep, FailurePolicy, LoopAggregation, LoopBlock, LoopStep, LoopType,
+    ParallelGroup, RedesignStrategy, StepPolicy, StrategyInstruction, StrategyMap, StrategyStep,
+    TerminateInstruction,
 };
+use tokio::sync::mpsc::UnboundedSender;
 use tracing::{debug, error, info, info_span, instrument, warn};
 
 /// TypeMarker trait for identifying output types in orchestrator context.
@@ -128,6 +140,8 @@ pub trait TypeMarker {
 pub enum OrchestrationStatus {
     Success,
     Failure,
+    /// Waiting for a human decision at an `ApprovalStep` (see `OrchestrationResult::paused`).
+    Paused,
 }
 
 /// Structured result returned by the orchestrator.
@@ -141,6 +155,9 @@ pub struct OrchestrationResult {
     pub terminations_triggered: usize,
     pub error_message: Option<String>,
     pub journal: Option<ExecutionJournal>,
+    /// The pending approval when `status` is `Paused`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paused: Option<OrchestratorPaused>,
 }
 
 /// Result of executing a sequence of instructions (internal).
@@ -149,6 +166,8 @@ enum InstructionExecutionResult {
     Completed(JsonValue),
     /// Early termination triggered
     Terminated(JsonValue),
+    /// Paused at an approval step, with the output produced so far
+    Paused(JsonValue),
 }
 
 #[cfg(feature = "agent")]
@@ -192,6 +211,42 @@ pub struct Orchestrator {
     /// The original task description (stored for regeneration).
     current_task: Option<String>,
 
+    /// Ids of top-level steps completed in the current run.
+    completed_steps: Vec<String>,
+
+    /// Completed step ids restored from a checkpoint, consumed by the next run.
+    resumed_steps: Vec<String>,
+
+    /// Ids of `ApprovalStep`s granted in the current run.
+    granted_approvals: Vec<String>,
+
+    /// Granted approval ids restored from a checkpoint, consumed by the next run.
+    resumed_approvals: Vec<String>,
+
+    /// Ids of top-level `Loop`s and `LoopStep`s completed in the current run.
+    completed_loops: Vec<String>,
+
+    /// Completed loop ids restored from a checkpoint, consumed by the next run.
+    resumed_loops: Vec<String>,
+
+    /// Ids of top-level steps skipped by their condition in the current run.
+    skipped_steps: Vec<String>,
+
+    /// Condition-skipped step ids restored from a checkpoint, consumed by the next run.
+    resumed_skipped_steps: Vec<String>,
+
+    /// Nesting depth of loop bodies currently being executed.
+    loop_depth: usize,
+
+    /// The approval step the current run is paused at, if any.
+    pending_approval: Option<PendingApproval>,
+
+    /// Receiver of progress events for the current run, if any.
+    event_sender: Option<UnboundedSender<OrchestratorEvent>>,
+
+    /// Receiver of step outputs for the current `run_streaming` call, if any.
+    output_sender: Option<UnboundedSender<StepOutput>>,
+
     /// Configuration for orchestrator execution behavior.
     config: OrchestratorConfig,
 
@@ -231,6 +286,18 @@ impl Orchestrator {
             context: HashMap::new(),
             execution_journal: None,
             current_task: None,
+            completed_steps: Vec::new(),
+            resumed_steps: Vec::new(),
+            granted_approvals: Vec::new(),
+            resumed_approvals: Vec::new(),
+            completed_loops: Vec::new(),
+            resumed_loops: Vec::new(),
+            skipped_steps: Vec::new(),
+            resumed_skipped_steps: Vec::new(),
+            loop_depth: 0,
+            pending_approval: None,
+            event_sender: None,
+            output_sender: None,
             config: OrchestratorConfig::default(),
             detector: None,
         };
@@ -291,6 +358,18 @@ impl Orchestrator {
             context: HashMap::new(),
             execution_journal: None,
             current_task: None,
+            completed_steps: Vec::new(),
+            resumed_steps: Vec::new(),
+            granted_approvals: Vec::new(),
+            resumed_approvals: Vec::new(),
+            completed_loops: Vec::new(),
+            resumed_loops: Vec::new(),
+            skipped_steps: Vec::new(),
+            resumed_skipped_steps: Vec::new(),
+            loop_depth: 0,
+            pending_approval: None,
+            event_sender: None,
+            output_sender: None,
             config: OrchestratorConfig::default(),
             detector: None,
         };
@@ -311,6 +390,18 @@ impl Orchestrator {
             context: HashMap::new(),
             execution_journal: None,
             current_task: None,
+            completed_steps: Vec::new(),
+            resumed_steps: Vec::new(),
+            granted_approvals: Vec::new(),
+            resumed_approvals: Vec::new(),
+            completed_loops: Vec::new(),
+            resumed_loops: Vec::new(),
+            skipped_steps: Vec::new(),
+            resumed_skipped_steps: Vec::new(),
+            loop_depth: 0,
+            pending_approval: None,
+            event_sender: None,
+            output_sender: None,
             config: OrchestratorConfig::default(),
         }
     }
@@ -860,6 +951,42 @@ impl Orchestrator {
             .collect()
     }
 
+    /// Returns the output stored under a step's `output_key`, deserialized into `T`.
+    ///
+    /// Complements `get_typed_output` when several steps produce the same type:
+    /// give each step its own `output_key` (e.g. `"draft_title"` and `"final_title"`)
+    /// and retrieve them independently by name. `T` does not need `TypeMarker`.
+    ///
+    /// # Returns
+    ///
+    /// The deserialized output, or an error if:
+    /// - No output is stored under `name`
+    /// - Deserialization failed
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let step = StrategyStep::new(/* ... */).with_output_key("draft_title");
+    ///
+    /// // After executing the orchestrator
+    /// let draft: String = orchestrator.get_output_by_name("draft_title")?;
+    /// ```
+    pub fn get_output_by_name<T>(&self, name: &str) -> Result<T, OrchestratorError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let value = self.context.get(name).ok_or_else(|| {
+            OrchestratorError::ExecutionFailed(format!("No output found with name \"{}\"", name))
+        })?;
+
+        serde_json::from_value(value.clone()).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to deserialize output with name \"{}\": {}",
+                name, e
+            ))
+        })
+    }
+
     /// Returns a typed output from the orchestrator context using the TypeMarker trait.
     ///
     /// This method searches for an output with a matching `__type` field and deserializes
@@ -876,6 +1003,9 @@ impl Orchestrator {
     /// - No output with matching `__type` was found
     /// - Deserialization failed
     ///
+    /// When several steps produced the same type (loops, parallel groups), the most
+    /// recent one is returned. Use `get_typed_outputs` to get all of them.
+    ///
     /// # Examples
     ///
     /// ```ignore
@@ -898,17 +1028,13 @@ impl Orchestrator {
     {
         let type_name = T::type_marker();
 
-        // Find the value with matching __type field
-        let value = self
-            .context
-            .values()
-            .find(|v| v.get("__type").and_then(|t| t.as_str()) == Some(type_name))
-            .ok_or_else(|| {
-                OrchestratorError::ExecutionFailed(format!(
-                    "No output found with __type = \"{}\"",
-                    type_name
-                ))
-            })?;
+        // Take the most recently produced value with matching __type field
+        let value = self.typed_output_values(type_name).pop().ok_or_else(|| {
+            OrchestratorError::ExecutionFailed(format!(
+                "No output found with __type = \"{}\"",
+                type_name
+            ))
+        })?;
 
         // Deserialize into the requested type
         serde_json::from_value(value.clone()).map_err(|e| {
@@ -919,6 +1045,307 @@ impl Orchestrator {
         })
     }
 
+    /// Retrieves every output of type `T`, in the order the steps produced them.
+    ///
+    /// Loops and parallel groups can run several steps that emit the same type;
+    /// this collects all of them instead of only the latest one. Outputs already in
+    /// the context before the current run (e.g. restored via `resume_from()` or
+    /// inserted through `context_mut()`) come first. Values that fail to deserialize
+    /// into `T` are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let drafts = orchestrator.get_typed_outputs::<DraftResponse>();
+    /// for (round, draft) in drafts.iter().enumerate() {
+    ///     println!("Round {}: {}", round + 1, draft.summary);
+    /// }
+    /// ```
+    pub fn get_typed_outputs<T>(&self) -> Vec<T>
+    where
+        T: TypeMarker + serde::de::DeserializeOwned,
+    {
+        let type_name = T::type_marker();
+
+        self.typed_output_values(type_name)
+            .into_iter()
+            .filter_map(|value| match serde_json::from_value(value.clone()) {
+                Ok(output) => Some(output),
+                Err(e) => {
+                    warn!(
+                        "Skipping output with __type = \"{}\" that failed to deserialize: {}",
+                        type_name, e
+                    );
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Collects stored outputs whose `__type` matches `type_name`, oldest first.
+    ///
+    /// Journal entries of the current run give the production order; every
+    /// completed execution counts, even when two steps produce equal values.
+    /// Context values not produced in this run are listed before them: entries
+    /// set directly (sorted by key), then step outputs restored from a checkpoint
+    /// in the order their steps completed. Alias keys (`previous_output`, step
+    /// `output_key`s, loop iteration copies) are not counted separately.
+    fn typed_output_values(&self, type_name: &str) -> Vec<&JsonValue> {
+        let matches =
+            |value: &JsonValue| value.get("__type").and_then(|t| t.as_str()) == Some(type_name);
+
+        let completed_records: Vec<&StepRecord> = self
+            .execution_journal
+            .iter()
+            .flat_map(|journal| journal.steps.iter())
+            .filter(|record| record.status == StepStatus::Completed)
+            .collect();
+        let produced_ids: HashSet<&str> = completed_records
+            .iter()
+            .map(|record| record.step_id.as_str())
+            .collect();
+        let alias_keys = self
+            .strategy_map
+            .as_ref()
+            .map(output_alias_keys)
+            .unwrap_or_default();
+
+        // (restored step position, key): direct entries first, then restored outputs
+        let mut earlier: Vec<((usize, &str), &JsonValue)> = Vec::new();
+        for (key, value) in &self.context {
+            if !matches(value) || key == "previous_output" || alias_keys.contains(key.as_str()) {
+                continue;
+            }
+            if key.starts_with("loop_") && key.contains("_iter_") {
+                continue;
+            }
+            let rank = match step_id_of_output_key(key) {
+                Some(step_id) if produced_ids.contains(step_id) => continue,
+                Some(step_id) => self
+                    .completed_steps
+                    .iter()
+                    .position(|id| id == step_id)
+                    .map_or(usize::MAX, |position| position + 1),
+                None => 0,
+            };
+            earlier.push(((rank, key.as_str()), value));
+        }
+        earlier.sort_by(|a, b| a.0.cmp(&b.0));
+
+        earlier
+            .into_iter()
+            .map(|(_, value)| value)
+            .chain(
+                completed_records
+                    .into_iter()
+                    .filter_map(|record| record.output.as_ref())
+                    .filter(|value| matches(value)),
+            )
+            .collect()
+    }
+
+    /// Builds a dry-run plan of the current strategy without invoking any agent.
+    ///
+    /// For each step (in execution order) the plan lists the assigned agent's
+    /// expertise, its output type name, and the context entries or output types
+    /// the step's intent template and condition reference. Wiring problems are
+    /// collected in `WorkflowPlan::issues`, for example:
+    /// - a step assigned to an unregistered agent
+    /// - a step referencing an output or type that no prior step produces
+    /// - an intent template or condition that fails to parse
+    ///
+    /// Values already in the context (e.g. set via `context_mut()`) count as available.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// orchestrator.set_strategy_map(strategy);
+    /// let plan = orchestrator.plan();
+    /// for issue in &plan.issues {
+    ///     eprintln!("{}", issue);
+    /// }
+    /// assert!(plan.is_valid());
+    /// ```
+    pub fn plan(&self) -> WorkflowPlan {
+        match &self.strategy_map {
+            Some(strategy) => plan::build_plan(strategy, &self.agents, &self.context),
+            None => WorkflowPlan {
+                issues: vec![PlanIssue::NoStrategy],
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Exports a serializable checkpoint of the current run.
+    ///
+    /// The checkpoint contains the active strategy, the runtime context (including
+    /// type-marked outputs used by `get_typed_output`), and the ids of top-level
+    /// steps and loops that have completed so far. Pass it to `resume_from()` to
+    /// continue the workflow without re-running them.
+    ///
+    /// Steps inside loop bodies are not tracked individually; a loop that was
+    /// interrupted is re-executed from its first iteration on resume.
+    pub fn export_checkpoint(&self) -> OrchestratorCheckpoint {
+        OrchestratorCheckpoint {
+            task: self.current_task.clone(),
+            strategy_map: self.strategy_map.clone(),
+            context: self.context.clone(),
+            completed_steps: self.completed_steps.clone(),
+            completed_loops: self.completed_loops.clone(),
+            skipped_steps: self.skipped_steps.clone(),
+            granted_approvals: self.granted_approvals.clone(),
+            pending_approval: self.pending_approval.clone(),
+        }
+    }
+
+    /// Restores orchestrator state from a checkpoint.
+    ///
+    /// The next call to `execute()` reuses the checkpoint's strategy and context,
+    /// and skips every top-level step and loop recorded as completed, as well as
+    /// the steps recorded as skipped by their condition. Their stored outputs
+    /// remain available to templates and to `get_typed_output`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let checkpoint = failed_orchestrator.export_checkpoint();
+    ///
+    /// let mut orchestrator = Orchestrator::new(blueprint);
+    /// orchestrator.add_agent(writer_agent);
+    /// orchestrator.resume_from(checkpoint);
+    /// let result = orchestrator.execute("Write article about Rust").await;
+    /// ```
+    pub fn resume_from(&mut self, checkpoint: OrchestratorCheckpoint) {
+        if checkpoint.task.is_some() {
+            self.current_task = checkpoint.task;
+        }
+        if checkpoint.strategy_map.is_some() {
+            self.strategy_map = checkpoint.strategy_map;
+        }
+        self.context = checkpoint.context;
+        self.completed_steps = checkpoint.completed_steps.clone();
+        self.resumed_steps = checkpoint.completed_steps;
+        self.granted_approvals = checkpoint.granted_approvals.clone();
+        self.resumed_approvals = checkpoint.granted_approvals;
+        self.completed_loops = checkpoint.completed_loops.clone();
+        self.resumed_loops = checkpoint.completed_loops;
+        self.skipped_steps = checkpoint.skipped_steps.clone();
+        self.resumed_skipped_steps = checkpoint.skipped_steps;
+        self.pending_approval = checkpoint.pending_approval;
+    }
+
+    /// Returns the approval the workflow is paused at, if any.
+    pub fn pending_approval(&self) -> Option<&PendingApproval> {
+        self.pending_approval.as_ref()
+    }
+
+    /// Approves the pending `ApprovalStep` and resumes the workflow after it.
+    ///
+    /// `decision` is stored in the context as `approval_{approval_id}_decision`,
+    /// so later steps can reference the reviewer's notes in their templates.
+    /// Steps and top-level loops that completed before the pause are not re-run,
+    /// and steps skipped by their condition stay skipped. Returns a `Failure`
+    /// result with `OrchestratorError::NoPendingApproval` if the workflow is not
+    /// paused.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let result = orchestrator.execute("Write article about Rust").await;
+    /// if let Some(paused) = &result.paused {
+    ///     println!("Review: {}", paused.pending.output);
+    ///     let result = orchestrator.approve("Looks good, keep it short").await;
+    /// }
+    /// ```
+    pub async fn approve(&mut self, decision: impl Into<String>) -> OrchestrationResult {
+        let Some(pending) = self.pending_approval.take() else {
+            return self.failure_result(&OrchestratorError::NoPendingApproval);
+        };
+
+        info!(
+            "Approval {} granted, resuming workflow",
+            pending.approval_id
+        );
+        self.context.insert(
+            format!("approval_{}_decision", pending.approval_id),
+            JsonValue::String(decision.into()),
+        );
+        self.granted_approvals.push(pending.approval_id);
+        self.resumed_steps = self.completed_steps.clone();
+        self.resumed_approvals = self.granted_approvals.clone();
+        self.resumed_loops = self.completed_loops.clone();
+        self.resumed_skipped_steps = self.skipped_steps.clone();
+
+        let task = self.current_task.clone().unwrap_or_default();
+        self.execute(&task).await
+    }
+
+    /// Rejects the pending `ApprovalStep` and aborts the workflow.
+    ///
+    /// Returns a `Failure` result whose error message carries `reason`
+    /// (`OrchestratorError::ApprovalRejected`). The context keeps the outputs
+    /// produced before the pause.
+    pub fn reject(&mut self, reason: impl Into<String>) -> OrchestrationResult {
+        let error = match self.pending_approval.take() {
+            Some(pending) => {
+                let reason = reason.into();
+                warn!("Approval {} rejected: {}", pending.approval_id, reason);
+                OrchestratorError::ApprovalRejected {
+                    approval_id: pending.approval_id,
+                    reason,
+                }
+            }
+            None => OrchestratorError::NoPendingApproval,
+        };
+        self.failure_result(&error)
+    }
+
+    /// Builds a `Failure` result for an error raised outside of step execution.
+    fn failure_result(&self, error: &OrchestratorError) -> OrchestrationResult {
+        OrchestrationResult {
+            status: OrchestrationStatus::Failure,
+            final_output: None,
+            steps_executed: 0,
+            redesigns_triggered: 0,
+            loops_executed: 0,
+            terminations_triggered: 0,
+            error_message: Some(error.to_string()),
+            journal: self.execution_journal.clone(),
+            paused: None,
+        }
+    }
+
+    /// Returns true if the step was completed earlier in this run (or restored from a checkpoint).
+    fn is_step_completed(&self, step_id: &str) -> bool {
+        self.completed_steps.iter().any(|id| id == step_id)
+    }
+
+    /// Returns true if the top-level loop was completed earlier in this run.
+    fn is_loop_completed(&self, loop_id: &str) -> bool {
+        self.completed_loops.iter().any(|id| id == loop_id)
+    }
+
+    /// Returns true if the step should be skipped because its condition is not met.
+    ///
+    /// A top-level step skipped this way stays skipped when the run resumes,
+    /// rather than having its condition re-evaluated against a changed context.
+    fn is_step_condition_skipped(
+        &mut self,
+        step: &StrategyStep,
+    ) -> Result<bool, OrchestratorError> {
+        if self.loop_depth > 0 {
+            return Ok(!self.evaluate_step_condition(step)?);
+        }
+        if self.skipped_steps.contains(&step.step_id) {
+            return Ok(true);
+        }
+        let skipped = !self.evaluate_step_condition(step)?;
+        if skipped {
+            self.skipped_steps.push(step.step_id.clone());
+        }
+        Ok(skipped)
+    }
+
     /// Executes the workflow with the given task description.
     ///
     /// This is the main entry point for orchestration. The orchestrator will:
@@ -956,8 +1383,143 @@ impl Orchestrator {
     /// orchestrator.set_strategy_map(my_strategy);
     /// let result = orchestrator.execute("Write article about Rust").await;
     /// ```
-    #[instrument(skip(self), fields(task = %task))]
     pub async fn execute(&mut self, task: &str) -> OrchestrationResult {
+        let (events, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let result = self.run_with_events(task, events).await;
+
+        while let Ok(event) = receiver.try_recv() {
+            debug!(?event, "Orchestrator event");
+        }
+
+        result
+    }
+
+    /// Executes the workflow, sending structured progress events to `events`.
+    ///
+    /// Behaves exactly like `execute()`, but additionally reports each step's
+    /// lifecycle as an `OrchestratorEvent`. The final event of every run is
+    /// `WorkflowCompleted`, sent for both success and failure. Send errors
+    /// (e.g. a dropped receiver) are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use llm_toolkit::orchestrator::OrchestratorEvent;
+    ///
+    /// let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    /// tokio::spawn(async move {
+    ///     while let Some(event) = rx.recv().await {
+    ///         if let OrchestratorEvent::StepStarted { name } = event {
+    ///             println!("Running {}", name);
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// let result = orchestrator.run_with_events("Write article about Rust", tx).await;
+    /// ```
+    pub async fn run_with_events(
+        &mut self,
+        task: &str,
+        events: UnboundedSender<OrchestratorEvent>,
+    ) -> OrchestrationResult {
+        self.event_sender = Some(events);
+        let result = self.run_workflow(task).await;
+        self.emit_event(OrchestratorEvent::WorkflowCompleted {
+            status: result.status.clone(),
+        });
+        self.event_sender = None;
+        result
+    }
+
+    /// Executes the workflow, yielding each step's output as soon as it completes.
+    ///
+    /// Outputs arrive in completion order, each carrying the step name, its
+    /// `__type` marker, and the JSON value. If strategy generation or execution
+    /// fails, the error is yielded after any outputs produced so far and the
+    /// stream ends. Complements `run_with_events`, which only sends notifications.
+    ///
+    /// The workflow makes progress only while the stream is polled.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use futures::StreamExt;
+    ///
+    /// let mut outputs = Box::pin(orchestrator.run_streaming("Write article about Rust"));
+    /// while let Some(output) = outputs.next().await {
+    ///     let output = output?;
+    ///     println!("{} ({:?}): {}", output.name, output.type_name, output.value);
+    /// }
+    /// ```
+    pub fn run_streaming<'a>(
+        &'a mut self,
+        task: &'a str,
+    ) -> impl futures::Stream<Item = Result<StepOutput, OrchestratorError>> + 'a {
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        self.output_sender = Some(sender);
+
+        let workflow = Box::pin(async move {
+            self.current_task = Some(task.to_string());
+            let result = async {
+                if self.strategy_map.is_none() {
+                    self.generate_strategy(task).await?;
+                }
+                self.execute_strategy().await.map(|_| ())
+            }
+            .await;
+            // Dropping the sender lets the stream end once buffered outputs are read
+            self.output_sender = None;
+            result
+        });
+
+        futures::stream::unfold(
+            (Some(workflow), receiver, None),
+            |(mut workflow, mut receiver, mut error)| async move {
+                use futures::future::Either;
+
+                while let Some(running) = workflow.as_mut() {
+                    let next = tokio::select! {
+                        biased;
+                        Some(output) = receiver.recv() => Either::Left(output),
+                        result = running => Either::Right(result),
+                    };
+                    match next {
+                        Either::Left(output) => {
+                            return Some((Ok(output), (workflow, receiver, error)));
+                        }
+                        Either::Right(result) => {
+                            workflow = None;
+                            error = result.err();
+                        }
+                    }
+                }
+
+                // The workflow is done: drain buffered outputs, then report its error
+                match receiver.recv().await {
+                    Some(output) => Some((Ok(output), (workflow, receiver, error))),
+                    None => error.take().map(|e| (Err(e), (workflow, receiver, None))),
+                }
+            },
+        )
+    }
+
+    /// Sends a progress event to the current run's listener, if any.
+    fn emit_event(&self, event: OrchestratorEvent) {
+        if let Some(sender) = &self.event_sender {
+            let _ = sender.send(event);
+        }
+    }
+
+    /// Reports a completed step to the event listener and the output stream, if any.
+    fn emit_step_completed(&self, step_id: &str, output: &JsonValue) {
+        self.emit_event(OrchestratorEvent::step_completed(step_id, output));
+        if let Some(sender) = &self.output_sender {
+            let _ = sender.send(StepOutput::new(step_id, output));
+        }
+    }
+
+    #[instrument(skip(self), fields(task = %task))]
+    async fn run_workflow(&mut self, task: &str) -> OrchestrationResult {
         info!("Starting orchestrator execution for task: {}", task);
 
         // Store task for potential regeneration
@@ -977,6 +1539,7 @@ impl Orchestrator {
                     terminations_triggered: 0,
                     error_message: Some(e.to_string()),
                     journal: None,
+                    paused: None,
                 };
             }
         } else {
@@ -995,9 +1558,19 @@ impl Orchestrator {
                 loops_executed,
                 terminations_triggered,
             )) => {
-                info!("Orchestrator execution completed successfully");
+                let paused = self.pending_approval.clone().map(|pending| {
+                    info!("Orchestrator paused for approval: {}", pending.approval_id);
+                    OrchestratorPaused { pending }
+                });
+                if paused.is_none() {
+                    info!("Orchestrator execution completed successfully");
+                }
                 OrchestrationResult {
-                    status: OrchestrationStatus::Success,
+                    status: if paused.is_some() {
+                        OrchestrationStatus::Paused
+                    } else {
+                        OrchestrationStatus::Success
+                    },
                     final_output: Some(final_output),
                     steps_executed,
                     redesigns_triggered,
@@ -1005,6 +1578,7 @@ impl Orchestrator {
                     terminations_triggered,
                     error_message: None,
                     journal: self.execution_journal.clone(),
+                    paused,
                 }
             }
             Err(e) => {
@@ -1018,6 +1592,7 @@ impl Orchestrator {
                     terminations_triggered: 0,
                     error_message: Some(e.to_string()),
                     journal: self.execution_journal.clone(),
+                    paused: None,
                 }
             }
         }
@@ -1300,6 +1875,8 @@ impl Orchestrator {
     ) -> Result<String, OrchestratorError> {
         // Fast path: Check if we can use simple template substitution
         if self.config.enable_fast_path_intent_generation {
+            // Type-marked outputs are also reachable by type name, e.g. {{ ReviewResponse.notes }}
+            let context = &self.with_type_aliases(context);
             let placeholders = Self::extract_placeholders(&step.intent_template);
             // Check if all placeholders can be resolved (supports dot notation like step_1_output.field)
             let all_resolved = placeholders.iter().all(|p| {
@@ -1414,6 +1991,14 @@ impl Orchestrator {
             self.execution_journal = Some(ExecutionJournal::new(strategy.clone()));
         }
 
+        // Steps, loops and approvals restored via resume_from() are skipped; a fresh run starts with none
+        self.completed_steps = std::mem::take(&mut self.resumed_steps);
+        self.granted_approvals = std::mem::take(&mut self.resumed_approvals);
+        self.completed_loops = std::mem::take(&mut self.resumed_loops);
+        self.skipped_steps = std::mem::take(&mut self.resumed_skipped_steps);
+        self.loop_depth = 0;
+        self.pending_approval = None;
+
         // Check if we should use the new instruction-based execution path
         let use_new_path = self
             .strategy_map
@@ -1457,91 +2042,58 @@ impl Orchestrator {
             );
             let _enter = step_span.enter();
 
+            if self.is_step_completed(&step.step_id) {
+                info!(
+                    "Skipping step {}/{} (completed in checkpoint): {}",
+                    step_index + 1,
+                    step_count,
+                    step.description
+                );
+                self.record_step_skipped(&step, None);
+                if let Some(output) = self.context.get(&format!("step_{}_output", step.step_id)) {
+                    final_result = output.clone();
+                }
+                step_index += 1;
+                continue;
+            }
+
+            if self.is_step_condition_skipped(&step)? {
+                info!(
+                    "Skipping step {}/{} (condition not met): {}",
+                    step_index + 1,
+                    step_count,
+                    step.description
+                );
+                self.record_step_skipped(&step, None);
+                step_index += 1;
+                continue;
+            }
+
             info!(
                 "Executing step {}/{}: {}",
                 step_index + 1,
                 step_count,
                 step.description
             );
-
             // Build intent using template rendering with full context
             let intent = self.build_intent(&step, &self.context).await?;
 
             debug!("Generated intent:\n{}", intent);
 
-            // Execute agent
-            let agent = self
-                .agents
-                .get(&step.assigned_agent)
-                .ok_or_else(|| OrchestratorError::AgentNotFound(step.assigned_agent.clone()))?;
-
-            // Enrich payload with context if detection is enabled
-            #[cfg(feature = "agent")]
-            let payload = self
-                .enrich_payload_with_context(intent.into(), &step.step_id, &step.description)
-                .await?;
-
-            #[cfg(not(feature = "agent"))]
-            let payload = intent.into();
+            self.emit_event(OrchestratorEvent::StepStarted {
+                name: step.step_id.clone(),
+            });
 
-            match agent.execute_dynamic(payload).await {
-                Ok(agent_output) => {
-                    // Unwrap AgentOutput to get the JsonValue
-                    let output = match agent_output {
-                        AgentOutput::Success(json_value) => json_value,
-                        AgentOutput::RequiresApproval { .. } => {
-                            self.record_step_outcome(
-                                &step,
-                                StepStatus::PausedForApproval,
-                                None,
-                                Some("Approval requested".to_string()),
-                            );
-                            return Err(OrchestratorError::ExecutionFailed(
-                                "Agent requires approval but orchestrator does not support HIL"
-                                    .to_string(),
-                            ));
-                        }
-                    };
+            // Execute agent under the step's policy (timeout, retry/skip on failure)
+            match self.run_step_with_policy(&step, &intent).await {
+                Ok(None) => {
+                    step_index += 1;
+                }
+                Ok(Some(agent_output)) => {
+                    let output = self.finish_step(&step, agent_output)?;
 
                     info!("Step {} completed successfully", step_index + 1);
 
-                    // Store JSON result in context with default key
-                    self.context
-                        .insert(format!("step_{}_output", step.step_id), output.clone());
-
-                    // Store with custom output_key if specified (for easier reference)
-                    if let Some(ref output_key) = step.output_key {
-                        self.context.insert(output_key.clone(), output.clone());
-                    }
-
-                    // Store prompt version if available (for ToPrompt implementations)
-                    if let Some(prompt_str) = agent.try_to_prompt(&output) {
-                        debug!("Storing prompt representation for step {}", step.step_id);
-                        self.context.insert(
-                            format!("step_{}_output_prompt", step.step_id),
-                            JsonValue::String(prompt_str.clone()),
-                        );
-
-                        // Also store prompt version with output_key if specified
-                        if let Some(ref output_key) = step.output_key {
-                            self.context.insert(
-                                format!("{}_prompt", output_key),
-                                JsonValue::String(prompt_str),
-                            );
-                        }
-                    }
-
-                    // Update previous_output for convenience (commonly used in templates)
-                    self.context
-                        .insert("previous_output".to_string(), output.clone());
-
-                    self.record_step_outcome(
-                        &step,
-                        StepStatus::Completed,
-                        Some(output.clone()),
-                        None,
-                    );
-
                     final_result = output;
                     steps_executed += 1;
                     step_index += 1;
@@ -1557,8 +2109,7 @@ impl Orchestrator {
                         tokio::time::sleep(self.config.min_step_interval).await;
                     }
                 }
-                Err(e) => {
-                    self.record_step_outcome(&step, StepStatus::Failed, None, Some(e.to_string()));
+                Err(OrchestratorError::Agent { source: e, .. }) => {
                     warn!(error = ?e, "Step {} failed", step_index + 1);
 
                     // Increment step remediation count
@@ -1625,6 +2176,7 @@ impl Orchestrator {
                             step_index = 0;
                             // Clear context to start fresh
                             self.context.clear();
+                            self.completed_steps.clear();
                             // Clear step remediation counts on full regeneration
                             // This is important: When we regenerate the entire strategy,
                             // step indices may change (step 0 might become a different task),
@@ -1634,6 +2186,8 @@ impl Orchestrator {
                         }
                     }
                 }
+                // Timeouts and lookup failures are not agent errors and can't be redesigned
+                Err(e) => return Err(e),
             }
         }
 
@@ -1658,6 +2212,187 @@ impl Orchestrator {
         }
     }
 
+    /// Records a failed step attempt in the journal and event stream.
+    fn record_step_failed(&mut self, step: &StrategyStep, error: &str) {
+        self.record_step_outcome(step, StepStatus::Failed, None, Some(error.to_string()));
+        self.emit_event(OrchestratorEvent::StepFailed {
+            name: step.step_id.clone(),
+            error: error.to_string(),
+        });
+    }
+
+    /// Records a skipped step in the journal and event stream.
+    fn record_step_skipped(&mut self, step: &StrategyStep, reason: Option<String>) {
+        self.record_step_outcome(step, StepStatus::Skipped, None, reason);
+        self.emit_event(OrchestratorEvent::StepSkipped {
+            name: step.step_id.clone(),
+        });
+    }
+
+    /// Stores a successful step's output in the context and records its completion.
+    ///
+    /// Returns an error if the agent requested human approval, which this
+    /// orchestrator does not support.
+    fn finish_step(
+        &mut self,
+        step: &StrategyStep,
+        agent_output: AgentOutput,
+    ) -> Result<JsonValue, OrchestratorError> {
+        // Unwrap AgentOutput to get the JsonValue
+        let output = match agent_output {
+            AgentOutput::Success(json_value) => json_value,
+            AgentOutput::RequiresApproval { .. } => {
+                let error = "Agent requires approval but orchestrator does not support HIL";
+                self.record_step_outcome(
+                    step,
+                    StepStatus::PausedForApproval,
+                    None,
+                    Some("Approval requested".to_string()),
+                );
+                self.emit_event(OrchestratorEvent::StepFailed {
+                    name: step.step_id.clone(),
+                    error: error.to_string(),
+                });
+                return Err(OrchestratorError::ExecutionFailed(error.to_string()));
+            }
+        };
+
+        // Store result in context
+        self.context
+            .insert(format!("step_{}_output", step.step_id), output.clone());
+
+        if let Some(ref output_key) = step.output_key {
+            self.context.insert(output_key.clone(), output.clone());
+        }
+
+        // Store prompt version if available (for ToPrompt implementations)
+        let prompt_repr = self
+            .agents
+            .get(&step.assigned_agent)
+            .and_then(|agent| agent.try_to_prompt(&output));
+        if let Some(prompt_str) = prompt_repr {
+            debug!("Storing prompt representation for step {}", step.step_id);
+            self.context.insert(
+                format!("step_{}_output_prompt", step.step_id),
+                JsonValue::String(prompt_str.clone()),
+            );
+
+            if let Some(ref output_key) = step.output_key {
+                self.context.insert(
+                    format!("{}_prompt", output_key),
+                    JsonValue::String(prompt_str),
+                );
+            }
+        }
+
+        self.context
+            .insert("previous_output".to_string(), output.clone());
+
+        self.record_step_outcome(step, StepStatus::Completed, Some(output.clone()), None);
+        self.emit_step_completed(&step.step_id, &output);
+        if self.loop_depth == 0 {
+            self.completed_steps.push(step.step_id.clone());
+        }
+
+        Ok(output)
+    }
+
+    /// Returns the effective policy for a step (step-level, then workflow default).
+    fn step_policy(&self, step: &StrategyStep) -> StepPolicy {
+        step.policy
+            .clone()
+            .or_else(|| self.config.default_step_policy.clone())
+            .unwrap_or_default()
+    }
+
+    /// Runs a step's agent under its [`StepPolicy`].
+    ///
+    /// Applies the policy timeout to each attempt and retries or skips on failure
+    /// as configured. Every failed attempt is recorded. Returns `None` when the
+    /// step was skipped after failing; errors are returned once the policy gives up.
+    async fn run_step_with_policy(
+        &mut self,
+        step: &StrategyStep,
+        intent: &str,
+    ) -> Result<Option<AgentOutput>, OrchestratorError> {
+        let policy = self.step_policy(step);
+        let mut retries = 0;
+
+        loop {
+            // Enrich payload with context if detection is enabled
+            #[cfg(feature = "agent")]
+            let payload = match self
+                .enrich_payload_with_context(intent.into(), &step.step_id, &step.description)
+                .await
+            {
+                Ok(payload) => payload,
+                Err(err) => {
+                    self.record_step_failed(step, &err.to_string());
+                    return Err(err);
+                }
+            };
+
+            #[cfg(not(feature = "agent"))]
+            let payload = intent.into();
+
+            let Some(agent) = self.agents.get(&step.assigned_agent) else {
+                let err = OrchestratorError::AgentNotFound(step.assigned_agent.clone());
+                self.record_step_failed(step, &err.to_string());
+                return Err(err);
+            };
+            let profiled = agent_for_step(agent.as_ref(), step);
+            let agent = profiled.as_deref().unwrap_or(agent.as_ref());
+
+            let err = match Self::invoke_step_agent(agent, &step.step_id, payload, policy.timeout)
+                .await
+            {
+                Ok(result) => return Ok(Some(result)),
+                Err(err) => err,
+            };
+
+            self.record_step_failed(step, &err.to_string());
+
+            match policy.on_failure {
+                FailurePolicy::Retry(max_retries) if retries < max_retries => {
+                    retries += 1;
+                    warn!(
+                        error = %err,
+                        "Step {} failed, retrying ({}/{})",
+                        step.step_id, retries, max_retries
+                    );
+                }
+                FailurePolicy::Skip => {
+                    warn!(error = %err, "Step {} failed, skipping", step.step_id);
+                    self.record_step_skipped(step, Some(err.to_string()));
+                    return Ok(None);
+                }
+                _ => return Err(err),
+            }
+        }
+    }
+
+    /// Invokes an agent for a step, enforcing the optional timeout.
+    async fn invoke_step_agent(
+        agent: &dyn DynamicAgent,
+        step_id: &str,
+        payload: crate::agent::Payload,
+        timeout: Option<Duration>,
+    ) -> Result<AgentOutput, OrchestratorError> {
+        match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, agent.execute_dynamic(payload))
+                .await
+                .map_err(|_| OrchestratorError::StepTimeout {
+                    step_id: step_id.to_string(),
+                    timeout,
+                })?
+                .map_err(OrchestratorError::from),
+            None => agent
+                .execute_dynamic(payload)
+                .await
+                .map_err(OrchestratorError::from),
+        }
+    }
+
     /// Executes the strategy using the new instruction-based path (for Loop/Terminate support).
     ///
     /// This method handles execution when `strategy.elements` is not empty.
@@ -1699,6 +2434,7 @@ impl Orchestrator {
                 info!("Workflow terminated early via Terminate instruction");
                 output
             }
+            InstructionExecutionResult::Paused(output) => output,
         };
 
         // Return (final_output, steps_executed, redesigns_triggered, loops_executed, terminations_triggered)
@@ -1739,6 +2475,101 @@ impl Orchestrator {
         }
     }
 
+    /// Evaluates a step's `condition` expression, returning `true` if the step should run.
+    ///
+    /// The expression sees the full context plus every type-marked output under its
+    /// `__type` name (e.g. `ReviewResponse.approved == false`). Steps without a
+    /// condition always run.
+    fn evaluate_step_condition(&self, step: &StrategyStep) -> Result<bool, OrchestratorError> {
+        let Some(condition) = &step.condition else {
+            return Ok(true);
+        };
+
+        let result =
+            Self::evaluate_expression(condition, &self.condition_scope()).map_err(|e| {
+                OrchestratorError::InvalidCondition {
+                    step_id: step.step_id.clone(),
+                    message: e.to_string(),
+                }
+            })?;
+
+        debug!(
+            "Condition '{}' for step {} evaluated to {}",
+            condition, step.step_id, result
+        );
+        Ok(result)
+    }
+
+    /// Evaluates a loop step's `until` predicate against the body's latest output.
+    ///
+    /// Besides the scope used for step conditions, the output is exposed as
+    /// `output` and, when type-marked, under its `__type` name (taking precedence
+    /// over older outputs of the same type).
+    fn evaluate_loop_until(
+        &self,
+        loop_step: &LoopStep,
+        output: &JsonValue,
+    ) -> Result<bool, OrchestratorError> {
+        let mut scope = self.condition_scope();
+        if let Some(type_name) = output.get("__type").and_then(|t| t.as_str()) {
+            scope.insert(type_name.to_string(), output.clone());
+        }
+        scope.insert("output".to_string(), output.clone());
+
+        let result = Self::evaluate_expression(&loop_step.until, &scope).map_err(|e| {
+            OrchestratorError::InvalidCondition {
+                step_id: loop_step.body.step_id.clone(),
+                message: e.to_string(),
+            }
+        })?;
+
+        debug!(
+            "Loop predicate '{}' for step {} evaluated to {}",
+            loop_step.until, loop_step.body.step_id, result
+        );
+        Ok(result)
+    }
+
+    /// Returns the context plus every type-marked output under its `__type` name.
+    ///
+    /// When several outputs share a type, the alias binds to the most recent one.
+    fn condition_scope(&self) -> HashMap<String, JsonValue> {
+        self.with_type_aliases(&self.context)
+    }
+
+    /// Returns `context` plus every type-marked output under its `__type` name.
+    ///
+    /// Keys already present in `context` win over type aliases.
+    fn with_type_aliases(
+        &self,
+        context: &HashMap<String, JsonValue>,
+    ) -> HashMap<String, JsonValue> {
+        let mut scope = context.clone();
+        let type_names: HashSet<&str> = context
+            .values()
+            .filter_map(|value| value.get("__type").and_then(|t| t.as_str()))
+            .collect();
+        for type_name in type_names {
+            if scope.contains_key(type_name) {
+                continue;
+            }
+            if let Some(latest) = self.typed_output_values(type_name).last() {
+                scope.insert(type_name.to_string(), (*latest).clone());
+            }
+        }
+        scope
+    }
+
+    /// Compiles and evaluates a minijinja expression, returning its truthiness.
+    fn evaluate_expression(
+        expression: &str,
+        scope: &HashMap<String, JsonValue>,
+    ) -> Result<bool, minijinja::Error> {
+        let env = minijinja::Environment::new();
+        let expression = env.compile_expression(expression)?;
+        Ok(expression.eval(scope)?.is_true())
+    }
+
     /// Aggregates loop iteration results based on the aggregation mode.
     ///
     /// Retrieves all iteration results from context and combines them according to the mode:
@@ -1829,83 +2660,171 @@ impl Orchestrator {
         for instruction in instructions.iter() {
             match instruction {
                 StrategyInstruction::Step(step) => {
+                    if self.loop_depth == 0 && self.is_step_completed(&step.step_id) {
+                        info!("Skipping step {} (completed in checkpoint)", step.step_id);
+                        self.record_step_skipped(step, None);
+                        if let Some(output) =
+                            self.context.get(&format!("step_{}_output", step.step_id))
+                        {
+                            final_result = output.clone();
+                        }
+                        continue;
+                    }
+
+                    if self.is_step_condition_skipped(step)? {
+                        info!("Skipping step {} (condition not met)", step.step_id);
+                        self.record_step_skipped(step, None);
+                        continue;
+                    }
+
                     debug!("Executing step: {}", step.step_id);
 
                     // Execute the step (simplified - full logic will be integrated later)
                     let intent = self.build_intent(step, &self.context).await?;
 
-                    let agent = self.agents.get(&step.assigned_agent).ok_or_else(|| {
-                        OrchestratorError::AgentNotFound(step.assigned_agent.clone())
-                    })?;
-
-                    // Enrich payload with context if detection is enabled
-                    #[cfg(feature = "agent")]
-                    let payload = self
-                        .enrich_payload_with_context(
-                            intent.into(),
-                            &step.step_id,
-                            &step.description,
-                        )
-                        .await?;
-
-                    #[cfg(not(feature = "agent"))]
-                    let payload = intent.into();
-
-                    // Note: Error handling for agent execution will be integrated later
-                    // For now, we convert the error directly
-                    let agent_output = match agent.execute_dynamic(payload).await {
-                        Ok(result) => result,
-                        Err(err) => {
-                            self.record_step_outcome(
-                                step,
-                                StepStatus::Failed,
-                                None,
-                                Some(err.to_string()),
-                            );
-                            return Err(err.into());
-                        }
+                    self.emit_event(OrchestratorEvent::StepStarted {
+                        name: step.step_id.clone(),
+                    });
+
+                    let Some(agent_output) = self.run_step_with_policy(step, &intent).await? else {
+                        continue;
                     };
 
-                    // Unwrap AgentOutput to get the JsonValue
-                    let output = match agent_output {
-                        AgentOutput::Success(json_value) => json_value,
-                        AgentOutput::RequiresApproval { .. } => {
-                            self.record_step_outcome(
-                                step,
-                                StepStatus::PausedForApproval,
-                                None,
-                                Some("Approval requested".to_string()),
-                            );
-                            return Err(OrchestratorError::ExecutionFailed(
-                                "Agent requires approval but orchestrator does not support HIL"
-                                    .to_string(),
+                    let output = self.finish_step(step, agent_output)?;
+
+                    *steps_executed += 1;
+                    final_result = output;
+                }
+
+                StrategyInstruction::Parallel(group) => {
+                    debug!("Executing parallel group: {}", group.group_id);
+
+                    // Prepare every runnable member up front (intent building uses the
+                    // context as it stood before the group started).
+                    let mut batch = Vec::new();
+                    for step in &group.steps {
+                        if self.loop_depth == 0 && self.is_step_completed(&step.step_id) {
+                            info!("Skipping step {} (completed in checkpoint)", step.step_id);
+                            self.record_step_skipped(step, None);
+                            continue;
+                        }
+                        if self.is_step_condition_skipped(step)? {
+                            info!("Skipping step {} (condition not met)", step.step_id);
+                            self.record_step_skipped(step, None);
+                            continue;
+                        }
+                        if !self.agents.contains_key(&step.assigned_agent) {
+                            return Err(OrchestratorError::AgentNotFound(
+                                step.assigned_agent.clone(),
                             ));
                         }
-                    };
 
-                    // Store result in context
-                    self.context
-                        .insert(format!("step_{}_output", step.step_id), output.clone());
+                        let intent = self.build_intent(step, &self.context).await?;
+
+                        #[cfg(feature = "agent")]
+                        let payload = self
+                            .enrich_payload_with_context(
+                                intent.into(),
+                                &step.step_id,
+                                &step.description,
+                            )
+                            .await?;
+
+                        #[cfg(not(feature = "agent"))]
+                        let payload: crate::agent::Payload = intent.into();
 
-                    if let Some(ref output_key) = step.output_key {
-                        self.context.insert(output_key.clone(), output.clone());
+                        batch.push((step, self.step_policy(step), payload));
                     }
 
-                    self.context
-                        .insert("previous_output".to_string(), output.clone());
+                    for (step, _, _) in &batch {
+                        self.emit_event(OrchestratorEvent::StepStarted {
+                            name: step.step_id.clone(),
+                        });
+                    }
 
-                    self.record_step_outcome(
-                        step,
-                        StepStatus::Completed,
-                        Some(output.clone()),
-                        None,
-                    );
+                    // Run all members concurrently; retries happen inside each member's future.
+                    let agents = &self.agents;
+                    let attempts = futures::future::join_all(batch.iter().map(
+                        |(step, policy, payload)| async move {
+                            let agent = agents[&step.assigned_agent].as_ref();
+                            let profiled = agent_for_step(agent, step);
+                            let agent = profiled.as_deref().unwrap_or(agent);
+                            let mut retried_errors = Vec::new();
+                            loop {
+                                match Self::invoke_step_agent(
+                                    agent,
+                                    &step.step_id,
+                                    payload.clone(),
+                                    policy.timeout,
+                                )
+                                .await
+                                {
+                                    Ok(output) => return (Ok(output), retried_errors),
+                                    Err(err) => match policy.on_failure {
+                                        FailurePolicy::Retry(max_retries)
+                                            if (retried_errors.len() as u32) < max_retries =>
+                                        {
+                                            retried_errors.push(err.to_string());
+                                        }
+                                        _ => return (Err(err), retried_errors),
+                                    },
+                                }
+                            }
+                        },
+                    ))
+                    .await;
 
-                    *steps_executed += 1;
+                    let mut group_output = serde_json::Map::new();
+                    let mut abort_error = None;
+                    for ((step, policy, _), (result, retried_errors)) in batch.iter().zip(attempts)
+                    {
+                        for error in &retried_errors {
+                            self.record_step_failed(step, error);
+                        }
+
+                        match result {
+                            Ok(agent_output) => {
+                                let output = self.finish_step(step, agent_output)?;
+                                *steps_executed += 1;
+                                group_output.insert(step.step_id.clone(), output);
+                            }
+                            Err(err) => {
+                                self.record_step_failed(step, &err.to_string());
+                                if policy.on_failure == FailurePolicy::Skip {
+                                    warn!(error = %err, "Step {} failed, skipping", step.step_id);
+                                    self.record_step_skipped(step, Some(err.to_string()));
+                                } else if abort_error.is_none() {
+                                    abort_error = Some(err);
+                                }
+                            }
+                        }
+                    }
+
+                    if let Some(err) = abort_error {
+                        return Err(err);
+                    }
+
+                    let output = JsonValue::Object(group_output);
+                    self.context.insert(
+                        format!("parallel_{}_output", group.group_id),
+                        output.clone(),
+                    );
                     final_result = output;
                 }
 
                 StrategyInstruction::Loop(loop_block) => {
+                    if self.loop_depth == 0 && self.is_loop_completed(&loop_block.loop_id) {
+                        info!("Skipping loop {} (already completed)", loop_block.loop_id);
+                        if let Some(output) = loop_block
+                            .aggregation
+                            .as_ref()
+                            .and_then(|aggregation| self.context.get(&aggregation.output_key))
+                        {
+                            final_result = output.clone();
+                        }
+                        continue;
+                    }
+
                     debug!("Executing loop: {}", loop_block.loop_id);
 
                     // Track number of iterations actually executed
@@ -1930,13 +2849,16 @@ impl Orchestrator {
                         }
 
                         // Execute loop body (using Box::pin for recursion)
+                        self.loop_depth += 1;
                         let result = Box::pin(self.execute_instructions(
                             &loop_block.body,
                             steps_executed,
                             loops_executed,
                             terminations_triggered,
                         ))
-                        .await?;
+                        .await;
+                        self.loop_depth -= 1;
+                        let result = result?;
 
                         match result {
                             InstructionExecutionResult::Completed(output) => {
@@ -1973,6 +2895,9 @@ impl Orchestrator {
                                 // Termination within loop - propagate it
                                 return Ok(InstructionExecutionResult::Terminated(output));
                             }
+                            InstructionExecutionResult::Paused(output) => {
+                                return Ok(InstructionExecutionResult::Paused(output));
+                            }
                         }
                     }
 
@@ -1993,6 +2918,125 @@ impl Orchestrator {
                             .insert(aggregation.output_key.clone(), aggregated_value.clone());
                         final_result = aggregated_value;
                     }
+
+                    if self.loop_depth == 0 {
+                        self.completed_loops.push(loop_block.loop_id.clone());
+                    }
+                }
+
+                StrategyInstruction::LoopStep(loop_step) => {
+                    let step_id = &loop_step.body.step_id;
+                    if self.loop_depth == 0 && self.is_loop_completed(step_id) {
+                        info!("Skipping loop step {} (already completed)", step_id);
+                        self.record_step_skipped(&loop_step.body, None);
+                        if let Some(output) = self.context.get(&format!("step_{}_output", step_id))
+                        {
+                            final_result = output.clone();
+                        }
+                        continue;
+                    }
+
+                    debug!("Executing loop step: {}", step_id);
+
+                    let body = [StrategyInstruction::Step(loop_step.body.clone())];
+                    let mut satisfied = false;
+                    let mut body_skipped = false;
+
+                    for iteration in 0..loop_step.max_iterations {
+                        debug!(
+                            "Loop step {} iteration {}/{}",
+                            step_id,
+                            iteration + 1,
+                            loop_step.max_iterations
+                        );
+
+                        *loops_executed += 1;
+
+                        // Check global loop iteration limit
+                        if *loops_executed > self.config.max_total_loop_iterations {
+                            return Err(OrchestratorError::MaxLoopIterationsExceeded(
+                                self.config.max_total_loop_iterations,
+                            ));
+                        }
+
+                        let executed_before = *steps_executed;
+                        self.loop_depth += 1;
+                        let result = Box::pin(self.execute_instructions(
+                            &body,
+                            steps_executed,
+                            loops_executed,
+                            terminations_triggered,
+                        ))
+                        .await;
+                        self.loop_depth -= 1;
+
+                        let output = match result? {
+                            InstructionExecutionResult::Completed(output) => output,
+                            InstructionExecutionResult::Terminated(output) => {
+                                return Ok(InstructionExecutionResult::Terminated(output));
+                            }
+                            InstructionExecutionResult::Paused(output) => {
+                                return Ok(InstructionExecutionResult::Paused(output));
+                            }
+                        };
+
+                        // A skipped body produced nothing to test the predicate against
+                        if *steps_executed == executed_before {
+                            debug!(
+                                "Loop step {} body was skipped at iteration {}/{}; ending the loop",
+                                step_id,
+                                iteration + 1,
+                                loop_step.max_iterations
+                            );
+                            body_skipped = true;
+                            break;
+                        }
+
+                        satisfied = self.evaluate_loop_until(loop_step, &output)?;
+                        final_result = output;
+
+                        if satisfied {
+                            debug!(
+                                "Loop step {} satisfied its predicate at iteration {}/{}",
+                                step_id,
+                                iteration + 1,
+                                loop_step.max_iterations
+                            );
+                            break;
+                        }
+                    }
+
+                    if !satisfied && !body_skipped {
+                        return Err(OrchestratorError::LoopLimitExceeded {
+                            step_id: step_id.clone(),
+                            max_iterations: loop_step.max_iterations,
+                        });
+                    }
+
+                    if self.loop_depth == 0 {
+                        self.completed_loops.push(step_id.clone());
+                    }
+                }
+
+                StrategyInstruction::Approval(approval) => {
+                    if self.granted_approvals.contains(&approval.approval_id) {
+                        info!(
+                            "Skipping approval {} (already approved)",
+                            approval.approval_id
+                        );
+                        continue;
+                    }
+
+                    info!("Pausing for approval: {}", approval.approval_id);
+                    self.emit_event(OrchestratorEvent::ApprovalRequested {
+                        name: approval.approval_id.clone(),
+                    });
+                    self.pending_approval = Some(PendingApproval {
+                        approval_id: approval.approval_id.clone(),
+                        message: approval.message.clone(),
+                        output: final_result.clone(),
+                    });
+                    return Ok(InstructionExecutionResult::Paused(final_result));
                 }
 
                 StrategyInstruction::Terminate(terminate) => {
@@ -2314,6 +3358,14 @@ impl Orchestrator {
         self.strategy_map = None;
         self.context.clear();
         self.current_task = None;
+        self.completed_steps.clear();
+        self.resumed_steps.clear();
+        self.granted_approvals.clear();
+        self.resumed_approvals.clear();
+        self.completed_loops.clear();
+        self.resumed_loops.clear();
+        self.skipped_steps.clear();
+        self.resumed_skipped_steps.clear();
     }
 }
 
@@ -2356,11 +3408,73 @@ fn count_steps_in_instructions(instructions: &[StrategyInstruction]) -> usize {
             StrategyInstruction::Terminate(_) => {
                 // Terminate instructions don't count as steps
             }
+            StrategyInstruction::Parallel(group) => {
+                count += group.steps.len();
+            }
+            StrategyInstruction::LoopStep(_) => {
+                count += 1;
+            }
+            StrategyInstruction::Approval(_) => {
+                // Approval gates pause execution but don't run an agent
+            }
         }
     }
     count
 }
 
+/// Returns a copy of `agent` reconfigured with the step's execution profile.
+///
+/// Returns `None` when the step has no profile or the agent does not support
+/// profiles, in which case the caller should run the original agent unchanged.
+pub(crate) fn agent_for_step(
+    agent: &dyn DynamicAgent,
+    step: &StrategyStep,
+) -> Option<Box<dyn DynamicAgent>> {
+    let profile = step.execution_profile?;
+    let profiled = agent.clone_with_execution_profile(profile);
+    if profiled.is_none() {
+        warn!(
+            "Agent {} does not support execution profiles; ignoring the execution_profile of step {}",
+            agent.name(),
+            step.step_id
+        );
+    }
+    profiled
+}
+
+/// Returns the step id of a `step_{id}_output` context key.
+fn step_id_of_output_key(key: &str) -> Option<&str> {
+    key.strip_prefix("step_")?.strip_suffix("_output")
+}
+
+/// Collects the `output_key`s of every step in `strategy`, including nested ones.
+fn output_alias_keys(strategy: &StrategyMap) -> HashSet<&str> {
+    fn add<'a>(step: &'a StrategyStep, keys: &mut HashSet<&'a str>) {
+        if let Some(key) = &step.output_key {
+            keys.insert(key.as_str());
+        }
+    }
+
+    fn collect<'a>(instructions: &'a [StrategyInstruction], keys: &mut HashSet<&'a str>) {
+        for instruction in instructions {
+            match instruction {
+                StrategyInstruction::Step(step) => add(step, keys),
+                StrategyInstruction::LoopStep(loop_step) => add(&loop_step.body, keys),
+                StrategyInstruction::Parallel(group) => {
+                    group.steps.iter().for_each(|step| add(step, keys))
+                }
+                StrategyInstruction::Loop(loop_block) => collect(&loop_block.body, keys),
+                StrategyInstruction::Terminate(_) | StrategyInstruction::Approval(_) => {}
+            }
+        }
+    }
+
+    let mut keys = HashSet::new();
+    collect(&strategy.elements, &mut keys);
+    strategy.steps.iter().for_each(|step| add(step, &mut keys));
+    keys
+}
+
 #[cfg(all(test, feature = "agent"))]
 mod tests {
     use super::*;
@@ -2707,6 +3821,9 @@ mod tests {
                 expected_output: "Result 1".to_string(),
                 requires_validation: false,
                 output_key: None,
+                policy: None,
+                condition: None,
+                execution_profile: None,
             }),
             StrategyInstruction::Step(StrategyStep {
                 step_id: "step2".to_string(),
@@ -2716,6 +3833,9 @@ mod tests {
                 expected_output: "Result 2".to_string(),
                 requires_validation: false,
                 output_key: None,
+                policy: None,
+                condition: None,
+                execution_profile: None,
             }),
         ];
 
@@ -2735,6 +3855,9 @@ mod tests {
             expected_output: "Loop result".to_string(),
             requires_validation: false,
             output_key: None,
+            policy: None,
+            condition: None,
+            execution_profile: None,
         })];
 
         let instructions = vec![
@@ -2746,6 +3869,9 @@ mod tests {
                 expected_output: "Before result".to_string(),
                 requires_validation: false,
                 output_key: None,
+                policy: None,
+                condition: None,
+                execution_profile: None,
             }),
             StrategyInstruction::Loop(LoopBlock {
                 loop_id: "loop1".to_string(),
@@ -2764,6 +3890,9 @@ mod tests {
                 expected_output: "After result".to_string(),
                 requires_validation: false,
                 output_key: None,
+                policy: None,
+                condition: None,
+                execution_profile: None,
             }),
         ];
 
@@ -2787,6 +3916,9 @@ mod tests {
                 expected_output: "Result".to_string(),
                 requires_validation: false,
                 output_key: None,
+                policy: None,
+                condition: None,
+                execution_profile: None,
             }),
             StrategyInstruction::Terminate(TerminateInstruction {
                 terminate_id: "term1".to_string(),
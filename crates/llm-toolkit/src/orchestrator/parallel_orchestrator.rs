@@ -7,7 +7,7 @@ use crate::agent::{Agent, DynamicAgent};
 use crate::orchestrator::prompts::ParallelRedesignDecisionRequest;
 use crate::orchestrator::{
     ExecutionJournal, OrchestratorError, StepRecord, StepStatus, StrategyInstruction,
-    StrategyLifecycle, StrategyMap, StrategyStep, TerminateInstruction,
+    StrategyLifecycle, StrategyMap, StrategyStep, TerminateInstruction, agent_for_step,
 };
 use crate::prompt::ToPrompt;
 #[cfg(feature = "agent")]
@@ -497,7 +497,7 @@ impl ParallelOrchestrator {
 
             if truncated_due_to_loop {
                 debug!(
-                    "Loop boundary encountered; limiting parallel execution to {} instruction(s)",
+                    "Loop or approval boundary encountered; limiting parallel execution to {} instruction(s)",
                     prefix_instructions.len()
                 );
             }
@@ -964,7 +964,7 @@ impl ParallelOrchestrator {
                     }
                     Err(ref err) => {
                         // Check if error is transient and we haven't exceeded retry limit
-                        let is_transient = matches!(err, OrchestratorError::AgentError(agent_err) if agent_err.is_transient());
+                        let is_transient = matches!(err, OrchestratorError::Agent { source, .. } if source.is_transient());
                         let current_retries = retry_counts.get(&step_id).copied().unwrap_or(0);
 
                         if is_transient && current_retries < max_retries {
@@ -1038,8 +1038,11 @@ impl ParallelOrchestrator {
             };
 
             // Find the agent
-            let agent = match self.agents.get(&step.assigned_agent) {
-                Some(a) => Arc::clone(a),
+            let agent: Arc<dyn DynamicAgent> = match self.agents.get(&step.assigned_agent) {
+                Some(a) => match agent_for_step(a.as_ref(), &step) {
+                    Some(profiled) => Arc::from(profiled),
+                    None => Arc::clone(a) as Arc<dyn DynamicAgent>,
+                },
                 None => {
                     tasks.push(tokio::spawn(async move {
                         (
@@ -1089,7 +1092,7 @@ impl ParallelOrchestrator {
                             ) => {
                                 match timeout_result {
                                     Ok(Ok(agent_output)) => Ok(agent_output),
-                                    Ok(Err(e)) => Err(e.into()),
+                                    Ok(Err(e)) => Err(OrchestratorError::from(e).with_step(&step_id)),
                                     Err(_) => {
                                         warn!(
                                             step_id = %step_id,
@@ -1113,7 +1116,7 @@ impl ParallelOrchestrator {
                                 })
                             }
                             agent_result = agent.execute_dynamic(intent.into()) => {
-                                agent_result.map_err(|e| e.into())
+                                agent_result.map_err(|e| OrchestratorError::from(e).with_step(&step_id))
                             }
                         }
                     };
@@ -1312,7 +1315,9 @@ impl ParallelOrchestrator {
 
             for instruction in &strategy.elements {
                 match instruction {
-                    StrategyInstruction::Loop(_) => {
+                    StrategyInstruction::Loop(_)
+                    | StrategyInstruction::LoopStep(_)
+                    | StrategyInstruction::Approval(_) => {
                         truncated = true;
                         break;
                     }
@@ -1341,14 +1346,20 @@ impl ParallelOrchestrator {
         for instruction in instructions {
             match instruction {
                 StrategyInstruction::Step(step) => current_steps.push(step.clone()),
+                StrategyInstruction::Parallel(group) => {
+                    // Dependency analysis already runs independent steps concurrently.
+                    current_steps.extend(group.steps.iter().cloned());
+                }
                 StrategyInstruction::Terminate(term) => {
                     segments.push(ExecutionSegment {
                         steps: mem::take(&mut current_steps),
                         terminate: Some(term.clone()),
                     });
                 }
-                StrategyInstruction::Loop(_) => {
-                    // Loop instructions should have been truncated already.
+                StrategyInstruction::Loop(_)
+                | StrategyInstruction::LoopStep(_)
+                | StrategyInstruction::Approval(_) => {
+                    // Loop and approval instructions should have been truncated already.
                 }
             }
         }
@@ -0,0 +1,206 @@
+//! Dry-run planning: inspect a strategy's wiring without invoking any agent.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+
+use super::parallel::extract_template_variables;
+use super::strategy::{StrategyInstruction, StrategyMap, StrategyStep};
+use crate::agent::DynamicAgent;
+
+/// The result of `Orchestrator::plan()`: what would run, and what looks wrong.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowPlan {
+    /// The strategy goal.
+    pub goal: String,
+    /// Every step in execution order (loop bodies and parallel members included).
+    pub steps: Vec<PlannedStep>,
+    /// Wiring problems found while walking the strategy.
+    pub issues: Vec<PlanIssue>,
+}
+
+impl WorkflowPlan {
+    /// Returns true if no issues were found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns the planned step with the given id.
+    pub fn step(&self, step_id: &str) -> Option<&PlannedStep> {
+        self.steps.iter().find(|step| step.step_id == step_id)
+    }
+}
+
+/// A single step as it would be executed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlannedStep {
+    pub step_id: String,
+    pub description: String,
+    /// Name of the assigned agent.
+    pub agent: String,
+    /// The agent's catalog description, or `None` if the agent is not registered.
+    pub agent_expertise: Option<String>,
+    /// Natural language description of the expected output.
+    pub expected_output: String,
+    /// Short name of the agent's output type (matches the `__type` marker for `TypeMarker` types).
+    pub output_type: Option<String>,
+    /// Context entries and output types referenced by the intent template and condition.
+    pub references: Vec<String>,
+}
+
+/// A wiring problem detected by `Orchestrator::plan()`.
+#[derive(Debug, Clone, Error, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PlanIssue {
+    /// No strategy has been set or generated yet.
+    #[error("No strategy available to plan")]
+    NoStrategy,
+
+    /// A step is assigned to an agent that is not registered.
+    #[error("Step '{step_id}' is assigned to unknown agent '{agent}'")]
+    AgentNotFound { step_id: String, agent: String },
+
+    /// A step references an output or type that no earlier step produces.
+    #[error("Step '{step_id}' references '{reference}', which no prior step produces")]
+    UnresolvedReference { step_id: String, reference: String },
+
+    /// A step's intent template or condition could not be parsed.
+    #[error("Step '{step_id}' has an invalid template: {message}")]
+    InvalidTemplate { step_id: String, message: String },
+}
+
+/// Walks a strategy and builds its plan.
+pub(crate) fn build_plan(
+    strategy: &StrategyMap,
+    agents: &HashMap<String, Box<dyn DynamicAgent>>,
+    context: &HashMap<String, JsonValue>,
+) -> WorkflowPlan {
+    let mut strategy = strategy.clone();
+    strategy.migrate_legacy_steps();
+
+    let mut planner = Planner {
+        agents,
+        available: context.keys().cloned().collect(),
+        plan: WorkflowPlan {
+            goal: strategy.goal.clone(),
+            ..Default::default()
+        },
+    };
+    planner.walk(&strategy.elements);
+    planner.plan
+}
+
+struct Planner<'a> {
+    agents: &'a HashMap<String, Box<dyn DynamicAgent>>,
+    /// Names that templates may reference at the current point of the walk.
+    available: HashSet<String>,
+    plan: WorkflowPlan,
+}
+
+impl Planner<'_> {
+    fn walk(&mut self, instructions: &[StrategyInstruction]) {
+        for instruction in instructions {
+            match instruction {
+                StrategyInstruction::Step(step) => {
+                    self.plan_step(step);
+                    self.produce(step);
+                }
+                StrategyInstruction::Loop(loop_block) => {
+                    self.walk(&loop_block.body);
+                    if let Some(aggregation) = &loop_block.aggregation {
+                        self.available.insert(aggregation.output_key.clone());
+                    }
+                }
+                StrategyInstruction::Parallel(group) => {
+                    // Members run concurrently, so they only see outputs from before the group.
+                    for step in &group.steps {
+                        self.plan_step(step);
+                    }
+                    for step in &group.steps {
+                        self.produce(step);
+                    }
+                    self.available
+                        .insert(format!("parallel_{}_output", group.group_id));
+                }
+                StrategyInstruction::LoopStep(loop_step) => {
+                    self.plan_step(&loop_step.body);
+                    self.produce(&loop_step.body);
+                }
+                StrategyInstruction::Terminate(_) | StrategyInstruction::Approval(_) => {}
+            }
+        }
+    }
+
+    fn plan_step(&mut self, step: &StrategyStep) {
+        let agent = self.agents.get(&step.assigned_agent);
+        if agent.is_none() {
+            self.plan.issues.push(PlanIssue::AgentNotFound {
+                step_id: step.step_id.clone(),
+                agent: step.assigned_agent.clone(),
+            });
+        }
+
+        let mut templates = vec![step.intent_template.clone()];
+        if let Some(condition) = &step.condition {
+            templates.push(format!("{{{{ {} }}}}", condition));
+        }
+
+        let mut references = Vec::new();
+        for template in &templates {
+            match extract_template_variables(template) {
+                Ok(variables) => {
+                    let mut variables: Vec<String> = variables.into_iter().collect();
+                    variables.sort();
+                    for variable in variables {
+                        if !references.contains(&variable) {
+                            references.push(variable);
+                        }
+                    }
+                }
+                Err(e) => self.plan.issues.push(PlanIssue::InvalidTemplate {
+                    step_id: step.step_id.clone(),
+                    message: e.to_string(),
+                }),
+            }
+        }
+
+        for reference in &references {
+            if !self.available.contains(reference) {
+                self.plan.issues.push(PlanIssue::UnresolvedReference {
+                    step_id: step.step_id.clone(),
+                    reference: reference.clone(),
+                });
+            }
+        }
+
+        self.plan.steps.push(PlannedStep {
+            step_id: step.step_id.clone(),
+            description: step.description.clone(),
+            agent: step.assigned_agent.clone(),
+            agent_expertise: agent.map(|a| a.description().to_string()),
+            expected_output: step.expected_output.clone(),
+            output_type: agent.and_then(|a| a.output_type_name()),
+            references,
+        });
+    }
+
+    /// Marks everything a completed step puts into the context as available.
+    fn produce(&mut self, step: &StrategyStep) {
+        self.available.insert("previous_output".to_string());
+        self.available
+            .insert(format!("step_{}_output", step.step_id));
+        self.available
+            .insert(format!("step_{}_output_prompt", step.step_id));
+        if let Some(output_key) = &step.output_key {
+            self.available.insert(output_key.clone());
+            self.available.insert(format!("{}_prompt", output_key));
+        }
+        if let Some(type_name) = self
+            .agents
+            .get(&step.assigned_agent)
+            .and_then(|a| a.output_type_name())
+        {
+            self.available.insert(type_name);
+        }
+    }
+}
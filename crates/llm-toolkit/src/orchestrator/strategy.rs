@@ -1,6 +1,8 @@
 //! StrategyMap - Execution strategy generated from blueprint and available agents.
 
+use crate::agent::ExecutionProfile;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Helper function for serde to determine if a bool value is false.
 fn is_false(value: &bool) -> bool {
@@ -95,6 +97,101 @@ pub struct StrategyStep {
     /// Next step can reference: `{{ world_concept.theme }}`
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub output_key: Option<String>,
+
+    /// Optional timeout and failure handling for this step.
+    ///
+    /// When `None`, the orchestrator falls back to `OrchestratorConfig::default_step_policy`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<StepPolicy>,
+
+    /// Optional minijinja expression deciding whether this step runs.
+    ///
+    /// Evaluated against the orchestrator context before the step executes; the
+    /// step is skipped when the result is falsey. Besides the usual context keys
+    /// (`step_{id}_output`, `output_key` aliases), every type-marked output is
+    /// exposed under its `__type` name.
+    ///
+    /// Example:
+    /// ```json
+    /// {
+    ///   "step_id": "refine",
+    ///   "condition": "ReviewResponse.approved == false"
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+
+    /// Optional execution profile applied to the assigned agent for this step only.
+    ///
+    /// The orchestrator asks the agent for a reconfigured copy via
+    /// `clone_with_execution_profile` before executing the step. Wrapper agents
+    /// forward the request to the agent they wrap. Agents that do not support
+    /// execution profiles run unchanged, and a warning is logged.
+    ///
+    /// Example:
+    /// ```json
+    /// {
+    ///   "step_id": "brainstorm",
+    ///   "execution_profile": "creative"
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_profile: Option<ExecutionProfile>,
+}
+
+/// What the orchestrator does when a step fails or times out.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FailurePolicy {
+    /// Stop the workflow and return the error (default).
+    #[default]
+    Abort,
+
+    /// Record the step as skipped and continue with the next instruction.
+    Skip,
+
+    /// Re-run the step up to the given number of times, then abort.
+    Retry(u32),
+}
+
+/// Per-step execution policy: an optional timeout plus a failure policy.
+///
+/// # Example
+///
+/// ```rust
+/// use llm_toolkit::orchestrator::{FailurePolicy, StepPolicy};
+/// use std::time::Duration;
+///
+/// let policy = StepPolicy::default()
+///     .with_timeout(Duration::from_secs(30))
+///     .with_on_failure(FailurePolicy::Retry(2));
+///
+/// assert_eq!(policy.timeout, Some(Duration::from_secs(30)));
+/// assert_eq!(policy.on_failure, FailurePolicy::Retry(2));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StepPolicy {
+    /// Maximum time a single agent invocation may take. `None` means no limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<Duration>,
+
+    /// How to react when the step errors or times out.
+    #[serde(default)]
+    pub on_failure: FailurePolicy,
+}
+
+impl StepPolicy {
+    /// Sets the timeout for each agent invocation.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the failure policy.
+    pub fn with_on_failure(mut self, on_failure: FailurePolicy) -> Self {
+        self.on_failure = on_failure;
+        self
+    }
 }
 
 impl StrategyMap {
@@ -241,8 +338,35 @@ impl StrategyStep {
             expected_output,
             requires_validation: false,
             output_key: None,
+            policy: None,
+            condition: None,
+            execution_profile: None,
         }
     }
+
+    /// Sets the execution policy for this step.
+    pub fn with_policy(mut self, policy: StepPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Sets the name under which this step's output is stored in the context.
+    pub fn with_output_key(mut self, output_key: impl Into<String>) -> Self {
+        self.output_key = Some(output_key.into());
+        self
+    }
+
+    /// Sets a condition expression that must be truthy for this step to run.
+    pub fn with_condition(mut self, condition: impl Into<String>) -> Self {
+        self.condition = Some(condition.into());
+        self
+    }
+
+    /// Sets the execution profile the assigned agent should use for this step.
+    pub fn with_execution_profile(mut self, profile: ExecutionProfile) -> Self {
+        self.execution_profile = Some(profile);
+        self
+    }
 }
 
 /// A single instruction in the execution strategy.
@@ -263,6 +387,107 @@ pub enum StrategyInstruction {
     /// An early termination instruction.
     #[serde(rename = "terminate")]
     Terminate(TerminateInstruction),
+
+    /// A group of independent steps executed concurrently.
+    #[serde(rename = "parallel")]
+    Parallel(ParallelGroup),
+
+    /// A single step repeated until a predicate over its output holds.
+    #[serde(rename = "loop_step")]
+    LoopStep(LoopStep),
+
+    /// A pause for human approval before the workflow continues.
+    #[serde(rename = "approval")]
+    Approval(ApprovalStep),
+}
+
+/// A step that is re-run until its `until` predicate holds.
+///
+/// After each run, `until` is evaluated as a minijinja expression against the
+/// context. The latest output of the body is available as `output`, and also
+/// under its `__type` name when it is type-marked. The loop stops as soon as
+/// the predicate is truthy. If it is still falsey after `max_iterations` runs,
+/// execution fails with `OrchestratorError::LoopLimitExceeded`.
+///
+/// If an iteration produces no output (the body's `condition` is false, or it
+/// failed under `FailurePolicy::Skip`), the loop ends there without evaluating
+/// `until`.
+///
+/// Example:
+/// ```json
+/// {
+///   "type": "loop_step",
+///   "body": { "step_id": "refine", "assigned_agent": "WriterAgent", ... },
+///   "until": "CritiqueResponse.approved",
+///   "max_iterations": 3
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LoopStep {
+    /// The step to run on each iteration.
+    pub body: StrategyStep,
+
+    /// Minijinja expression that ends the loop when truthy.
+    pub until: String,
+
+    /// Maximum number of times the body runs.
+    pub max_iterations: usize,
+}
+
+impl LoopStep {
+    /// Creates a loop that re-runs `body` until `until` holds, at most `max_iterations` times.
+    pub fn new(body: StrategyStep, until: impl Into<String>, max_iterations: usize) -> Self {
+        Self {
+            body,
+            until: until.into(),
+            max_iterations,
+        }
+    }
+}
+
+/// A batch of independent steps that run concurrently.
+///
+/// All member steps are started together and their outputs are collected
+/// before the next instruction runs. Each step's output is stored as usual
+/// (`step_{id}_output`, `output_key`), and the whole batch is available as
+/// `parallel_{group_id}_output`: an object mapping step ids to outputs.
+///
+/// Member steps must not depend on each other's outputs. Failures are handled
+/// per step according to its `StepPolicy`.
+///
+/// Example:
+/// ```json
+/// {
+///   "type": "parallel",
+///   "group_id": "research",
+///   "steps": [
+///     { "step_id": "market", "assigned_agent": "MarketAgent", ... },
+///     { "step_id": "tech", "assigned_agent": "TechAgent", ... }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ParallelGroup {
+    /// Unique identifier for this group (for namespacing the collected output).
+    pub group_id: String,
+
+    /// Human-readable description of what this group does.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Steps to execute concurrently.
+    pub steps: Vec<StrategyStep>,
+}
+
+impl ParallelGroup {
+    /// Creates a new parallel group from its member steps.
+    pub fn new(group_id: impl Into<String>, steps: Vec<StrategyStep>) -> Self {
+        Self {
+            group_id: group_id.into(),
+            description: None,
+            steps,
+        }
+    }
 }
 
 /// The type of loop to execute.
@@ -347,11 +572,11 @@ pub struct LoopBlock {
 }
 
 impl LoopBlock {
-    /// Validates that the loop body does not contain nested loops.
+    /// Validates that the loop body does not contain nested loops or approvals.
     ///
     /// # Errors
     ///
-    /// Returns an error if any instruction in the body is a `Loop`.
+    /// Returns an error if any instruction in the body is a `Loop` or an `Approval`.
     ///
     /// # Examples
     ///
@@ -366,6 +591,9 @@ impl LoopBlock {
                     "Nested loops are not supported. Loop body cannot contain other Loop instructions.",
                 );
             }
+            if matches!(instruction, StrategyInstruction::Approval(_)) {
+                return Err("Approval steps are not supported inside loops.");
+            }
         }
         Ok(())
     }
@@ -394,6 +622,48 @@ pub struct TerminateInstruction {
     pub final_output_template: Option<String>,
 }
 
+/// A point where the workflow pauses until a human approves or rejects it.
+///
+/// When reached, `Orchestrator::execute` returns with
+/// `OrchestrationStatus::Paused` and a `PendingApproval` carrying the output of
+/// the preceding instruction. `Orchestrator::approve` resumes after this point;
+/// `Orchestrator::reject` aborts the workflow. Approvals must be top-level
+/// instructions (not inside a loop body).
+///
+/// Example:
+/// ```json
+/// {
+///   "type": "approval",
+///   "approval_id": "review_outline",
+///   "message": "Check the outline before the article is written"
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ApprovalStep {
+    /// Unique identifier for this approval point.
+    pub approval_id: String,
+
+    /// Optional note for the reviewer describing what to check.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ApprovalStep {
+    /// Creates a new ApprovalStep.
+    pub fn new(approval_id: impl Into<String>) -> Self {
+        Self {
+            approval_id: approval_id.into(),
+            message: None,
+        }
+    }
+
+    /// Sets the note shown to the reviewer.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
 /// The type of redesign strategy to apply when a step fails.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RedesignStrategy {
@@ -740,6 +1010,33 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_loop_step_deserialization() {
+        let json = r#"{
+            "type": "loop_step",
+            "body": {
+                "step_id": "critique",
+                "description": "Critique the draft",
+                "assigned_agent": "CriticAgent",
+                "intent_template": "Critique it",
+                "expected_output": "Verdict"
+            },
+            "until": "output.approved",
+            "max_iterations": 3
+        }"#;
+
+        let instruction: StrategyInstruction = serde_json::from_str(json).unwrap();
+
+        match instruction {
+            StrategyInstruction::LoopStep(loop_step) => {
+                assert_eq!(loop_step.body.step_id, "critique");
+                assert_eq!(loop_step.until, "output.approved");
+                assert_eq!(loop_step.max_iterations, 3);
+            }
+            other => panic!("expected LoopStep, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_loop_block_validation_success() {
         // Valid loop with only Steps and Terminate in body
@@ -2,6 +2,19 @@
 
 use minijinja::Environment;
 use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::{BTreeMap, HashMap};
+
+#[cfg(feature = "agent")]
+pub mod builder;
+pub mod lint;
+pub mod runtime;
+pub mod tokens;
+
+#[cfg(feature = "agent")]
+pub use builder::PromptBuilder;
+pub use lint::{TemplateLint, lint_template};
+pub use runtime::{RuntimeTemplate, RuntimeTemplateError};
 
 /// Represents a part of a multimodal prompt.
 ///
@@ -119,6 +132,37 @@ pub trait ToPrompt {
         self.to_prompt_with_mode("full")
     }
 
+    /// Converts the object into a prompt string for the given language code.
+    ///
+    /// Structs deriving `ToPrompt` with `#[prompt(template_i18n(en = "...", ja = "..."))]`
+    /// render the template registered for `lang`, falling back to the default
+    /// template for unknown codes. The default implementation ignores `lang`
+    /// and returns `to_prompt()`.
+    fn to_prompt_localized(&self, lang: &str) -> String {
+        let _ = lang;
+        self.to_prompt()
+    }
+
+    /// Converts the object into a prompt string with every line indented.
+    ///
+    /// Each non-empty line of `to_prompt()` is prefixed with `level * 2` spaces.
+    /// The key-value `derive(ToPrompt)` uses this to render nested structures
+    /// beneath their field key.
+    fn to_prompt_with_indent(&self, level: usize) -> String {
+        let indent = " ".repeat(level * 2);
+        self.to_prompt()
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    String::new()
+                } else {
+                    format!("{}{}", indent, line)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Returns a schema-level prompt for the type itself.
     ///
     /// For enums, this returns all possible variants with their descriptions.
@@ -139,6 +183,18 @@ pub trait ToPrompt {
     fn prompt_schema() -> String {
         String::new() // Default implementation returns empty string
     }
+
+    /// Returns a machine-readable summary of the type's expected JSON shape.
+    ///
+    /// Derived structs return an object of the form
+    /// `{"properties": {"field": {"type": "string"}}, "required": ["field"]}`,
+    /// using the same type names as `prompt_schema()`. This is used to explain
+    /// schema mismatches when parsing LLM output.
+    ///
+    /// The default implementation returns `None`.
+    fn prompt_schema_json() -> Option<serde_json::Value> {
+        None
+    }
 }
 
 // Add implementations for common types
@@ -234,6 +290,161 @@ impl<T: ToPrompt> ToPrompt for Option<T> {
     }
 }
 
+// Implement ToPrompt for maps, rendering one `key: value` line per entry.
+// HashMap entries are sorted by key so the output is deterministic.
+impl<K: ToPrompt, V: ToPrompt, S> ToPrompt for HashMap<K, V, S> {
+    fn to_prompt_parts(&self) -> Vec<PromptPart> {
+        vec![PromptPart::Text(self.to_prompt())]
+    }
+
+    fn to_prompt(&self) -> String {
+        let mut lines: Vec<(String, String)> = self
+            .iter()
+            .map(|(key, value)| (key.to_prompt(), value.to_prompt()))
+            .collect();
+        lines.sort_by(|a, b| a.0.cmp(&b.0));
+        lines
+            .into_iter()
+            .map(|(key, value)| format!("{}: {}", key, value))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<K: ToPrompt, V: ToPrompt> ToPrompt for BTreeMap<K, V> {
+    fn to_prompt_parts(&self) -> Vec<PromptPart> {
+        vec![PromptPart::Text(self.to_prompt())]
+    }
+
+    fn to_prompt(&self) -> String {
+        self.iter()
+            .map(|(key, value)| format!("{}: {}", key.to_prompt(), value.to_prompt()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+// Implement ToPrompt for 2- and 3-element tuples of ToPrompt types
+impl<A: ToPrompt, B: ToPrompt> ToPrompt for (A, B) {
+    fn to_prompt_parts(&self) -> Vec<PromptPart> {
+        vec![PromptPart::Text(self.to_prompt())]
+    }
+
+    fn to_prompt(&self) -> String {
+        format!("({}, {})", self.0.to_prompt(), self.1.to_prompt())
+    }
+}
+
+impl<A: ToPrompt, B: ToPrompt, C: ToPrompt> ToPrompt for (A, B, C) {
+    fn to_prompt_parts(&self) -> Vec<PromptPart> {
+        vec![PromptPart::Text(self.to_prompt())]
+    }
+
+    fn to_prompt(&self) -> String {
+        format!(
+            "({}, {}, {})",
+            self.0.to_prompt(),
+            self.1.to_prompt(),
+            self.2.to_prompt()
+        )
+    }
+}
+
+// Date/time types render as ISO-8601 strings
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> ToPrompt for chrono::DateTime<Tz>
+where
+    Tz::Offset: std::fmt::Display,
+{
+    fn to_prompt_parts(&self) -> Vec<PromptPart> {
+        vec![PromptPart::Text(self.to_prompt())]
+    }
+
+    fn to_prompt(&self) -> String {
+        self.to_rfc3339()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToPrompt for chrono::NaiveDate {
+    fn to_prompt_parts(&self) -> Vec<PromptPart> {
+        vec![PromptPart::Text(self.to_prompt())]
+    }
+
+    fn to_prompt(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToPrompt for chrono::NaiveDateTime {
+    fn to_prompt_parts(&self) -> Vec<PromptPart> {
+        vec![PromptPart::Text(self.to_prompt())]
+    }
+
+    fn to_prompt(&self) -> String {
+        self.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToPrompt for chrono::NaiveTime {
+    fn to_prompt_parts(&self) -> Vec<PromptPart> {
+        vec![PromptPart::Text(self.to_prompt())]
+    }
+
+    fn to_prompt(&self) -> String {
+        self.format("%H:%M:%S%.f").to_string()
+    }
+}
+
+#[cfg(feature = "time")]
+impl ToPrompt for time::OffsetDateTime {
+    fn to_prompt_parts(&self) -> Vec<PromptPart> {
+        vec![PromptPart::Text(self.to_prompt())]
+    }
+
+    fn to_prompt(&self) -> String {
+        // RFC 3339 can't represent years outside 0..=9999; fall back to Display
+        self.format(&time::format_description::well_known::Rfc3339)
+            .unwrap_or_else(|_| self.to_string())
+    }
+}
+
+/// Supplies a hand-written example in place of the derive-generated one.
+///
+/// By default `#[derive(ToPrompt)]` builds the `example_only` output from
+/// `#[prompt(example = "...")]` attributes and `Default` values. For types where a
+/// single canonical example should teach the model edge cases, implement this
+/// trait and opt in with `#[prompt(custom_example)]`; the derive then renders
+/// `Self::example()` in `example_only` mode, in the example part of `full`
+/// mode, and therefore in `examples_section!`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use llm_toolkit::{ProvideExample, ToPrompt};
+/// use serde_json::json;
+///
+/// #[derive(ToPrompt, Default, Serialize)]
+/// #[prompt(mode = "full", custom_example)]
+/// struct Invoice {
+///     number: String,
+///     discount: Option<f64>,
+/// }
+///
+/// impl ProvideExample for Invoice {
+///     fn example() -> serde_json::Value {
+///         json!({"number": "INV-0042", "discount": null})
+///     }
+/// }
+/// ```
+pub trait ProvideExample {
+    /// Returns the example value shown to the model.
+    fn example() -> serde_json::Value;
+}
+
 /// Renders a prompt from a template string and a serializable context.
 ///
 /// This is the underlying function for the `prompt!` macro.
@@ -244,6 +455,39 @@ pub fn render_prompt<T: Serialize>(template: &str, context: T) -> Result<String,
     tmpl.render(context)
 }
 
+/// Checks that the example shown to the model deserializes back into `T`.
+///
+/// Renders `T::default()` in `example_only` mode, extracts the JSON with
+/// [`extract_json`](crate::extract_json), and deserializes it. Use it in tests
+/// to catch drift between the prompt example and serde attributes such as
+/// `rename` or `rename_all`.
+///
+/// The example is rendered from a default instance, the same way
+/// `examples_section!` does, so `T` must implement `Default`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[test]
+/// fn user_example_matches_serde() {
+///     llm_toolkit::prompt::verify_example::<User>().unwrap();
+/// }
+/// ```
+pub fn verify_example<T>() -> Result<T, crate::extract::ParseError>
+where
+    T: ToPrompt + Default + DeserializeOwned,
+{
+    let example = T::default().to_prompt_with_mode("example_only");
+    let json = crate::extract_json(&example)?;
+    serde_json::from_str(&json).map_err(|e| {
+        crate::extract::ParseError::JsonParsingFailed(format!(
+            "example for `{}` does not deserialize: {}",
+            std::any::type_name::<T>(),
+            e
+        ))
+    })
+}
+
 /// Creates a prompt string from a template and key-value pairs.
 ///
 /// This macro provides a `println!`-like experience for building prompts
@@ -463,6 +707,13 @@ mod tests {
         assert_eq!(vec_opts.to_prompt(), "[hello, , world]");
     }
 
+    #[test]
+    fn test_to_prompt_with_indent() {
+        let text = "first: 1\nsecond: 2";
+        assert_eq!(text.to_prompt_with_indent(0), "first: 1\nsecond: 2");
+        assert_eq!(text.to_prompt_with_indent(2), "    first: 1\n    second: 2");
+    }
+
     #[test]
     fn test_to_prompt_for_option_none_with_parts() {
         let opt: Option<String> = None;
@@ -474,6 +725,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_to_prompt_for_hashmap() {
+        let mut map = HashMap::new();
+        map.insert("port".to_string(), "8080".to_string());
+        map.insert("host".to_string(), "localhost".to_string());
+        // Entries are sorted by key for deterministic output
+        assert_eq!(map.to_prompt(), "host: localhost\nport: 8080");
+    }
+
+    #[test]
+    fn test_to_prompt_for_btreemap() {
+        let mut map = BTreeMap::new();
+        map.insert("beta", 2);
+        map.insert("alpha", 1);
+        assert_eq!(map.to_prompt(), "alpha: 1\nbeta: 2");
+
+        let empty: BTreeMap<String, String> = BTreeMap::new();
+        assert_eq!(empty.to_prompt(), "");
+    }
+
+    #[test]
+    fn test_to_prompt_for_tuples() {
+        let pair = ("retries".to_string(), 3u32);
+        assert_eq!(pair.to_prompt(), "(retries, 3)");
+
+        let triple = ("x", 1.5, true);
+        assert_eq!(triple.to_prompt(), "(x, 1.5, true)");
+
+        let nested = vec![("a", 1), ("b", 2)];
+        assert_eq!(nested.to_prompt(), "[(a, 1), (b, 2)]");
+    }
+
+    #[test]
+    fn test_to_prompt_for_option_map() {
+        let mut map = BTreeMap::new();
+        map.insert("key", "value");
+        assert_eq!(Some(map).to_prompt(), "key: value");
+
+        let none: Option<BTreeMap<String, String>> = None;
+        assert_eq!(none.to_prompt(), "");
+    }
+
     #[test]
     fn test_prompt_macro_no_args() {
         let prompt = prompt!("This is a static prompt.",).unwrap();
@@ -618,6 +911,46 @@ pub enum PromptSetError {
         target: String,
         source: minijinja::Error,
     },
+    #[error("Failed to serialize field for JSON target '{target}': {source}")]
+    SerializationFailed {
+        target: String,
+        source: serde_json::Error,
+    },
+}
+
+/// Strips module paths from a fully qualified type name.
+///
+/// `alloc::vec::Vec<my_crate::Review>` becomes `Vec<Review>`. Used by derived
+/// schemas to name generic type parameters after their concrete type.
+#[doc(hidden)]
+pub fn short_type_name(full: &str) -> String {
+    let mut short = String::with_capacity(full.len());
+    let mut segment_start = 0;
+    let mut chars = full.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            short.truncate(segment_start);
+        } else {
+            short.push(c);
+            if !(c.is_alphanumeric() || c == '_') {
+                segment_start = short.len();
+            }
+        }
+    }
+
+    short
+}
+
+/// Error returned when a [`ToPromptFor`] template fails to compile or render.
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to render template '{template}': {source}")]
+pub struct RenderError {
+    /// Name of the template, e.g. `Tool for Agent`.
+    pub template: String,
+    /// The underlying minijinja error.
+    pub source: minijinja::Error,
 }
 
 /// A trait for types that can generate multiple named prompt targets.
@@ -665,6 +998,14 @@ pub trait ToPromptSet {
     /// Generates multimodal prompt parts for the specified target.
     fn to_prompt_parts_for(&self, target: &str) -> Result<Vec<PromptPart>, PromptSetError>;
 
+    /// Returns the names of all targets this type can render, in declaration order.
+    ///
+    /// Struct-level targets come first, followed by targets only introduced on fields.
+    /// The default implementation returns an empty list.
+    fn available_targets(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
     /// Generates a text prompt for the specified target.
     ///
     /// This method extracts only the text portions from `to_prompt_parts_for()`
@@ -689,8 +1030,26 @@ pub trait ToPromptSet {
 /// in a prompt when provided with a target context (e.g., an `Agent`).
 pub trait ToPromptFor<T> {
     /// Generates a prompt for the given target, using a specific mode.
+    ///
+    /// Derived implementations return `"Failed to render prompt: ..."` if the
+    /// template fails to render. Use
+    /// [`try_to_prompt_for_with_mode`](Self::try_to_prompt_for_with_mode) to
+    /// handle the error instead.
+    ///
+    /// # Panics
+    ///
+    /// Derived implementations panic if the template has invalid syntax.
     fn to_prompt_for_with_mode(&self, target: &T, mode: &str) -> String;
 
+    /// Generates a prompt for the given target, returning an error instead of
+    /// panicking when the template fails to render.
+    ///
+    /// The default implementation delegates to `to_prompt_for_with_mode`, so it
+    /// never fails for hand-written implementations.
+    fn try_to_prompt_for_with_mode(&self, target: &T, mode: &str) -> Result<String, RenderError> {
+        Ok(self.to_prompt_for_with_mode(target, mode))
+    }
+
     /// Generates a prompt for the given target using the default "full" mode.
     ///
     /// This method provides backward compatibility by calling the `_with_mode`
@@ -0,0 +1,178 @@
+//! A builder for composing multiple prompt parts into a single `Payload`.
+
+use super::{PromptPart, ToPrompt};
+use crate::agent::payload::Payload;
+use crate::attachment::Attachment;
+use crate::multimodal::ImageData;
+
+/// Composes raw text, `ToPrompt` values, and images into one [`Payload`].
+///
+/// Parts are kept in insertion order. When building, consecutive text parts
+/// are merged with newlines while image parts stay in place as in-memory
+/// attachments.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use llm_toolkit::prompt::PromptBuilder;
+///
+/// let payload = PromptBuilder::new()
+///     .add_text("Review the following request:")
+///     .add(&request)
+///     .add_image(screenshot)
+///     .add_text("Answer in JSON.")
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PromptBuilder {
+    parts: Vec<PromptPart>,
+}
+
+impl PromptBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends raw text.
+    pub fn add_text(mut self, text: impl Into<String>) -> Self {
+        self.parts.push(PromptPart::Text(text.into()));
+        self
+    }
+
+    /// Appends all prompt parts produced by a `ToPrompt` value.
+    // A by-value builder step, not arithmetic; `std::ops::Add` would not fit
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, value: &impl ToPrompt) -> Self {
+        self.parts.extend(value.to_prompt_parts());
+        self
+    }
+
+    /// Appends an image.
+    pub fn add_image(mut self, image: ImageData) -> Self {
+        self.parts.push(PromptPart::Image {
+            media_type: image.media_type,
+            data: image.data,
+        });
+        self
+    }
+
+    /// Returns the collected parts without merging.
+    pub fn parts(&self) -> &[PromptPart] {
+        &self.parts
+    }
+
+    /// Builds a `Payload`, merging adjacent text parts with newlines.
+    ///
+    /// Empty text parts are dropped. Images become in-memory attachments
+    /// carrying their media type.
+    pub fn build(self) -> Payload {
+        let mut payload = Payload::new();
+        let mut pending_text: Vec<String> = Vec::new();
+
+        for part in self.parts {
+            match part {
+                PromptPart::Text(text) => {
+                    if !text.is_empty() {
+                        pending_text.push(text);
+                    }
+                }
+                PromptPart::Image { media_type, data } => {
+                    if !pending_text.is_empty() {
+                        payload = payload.with_text(pending_text.join("\n"));
+                        pending_text.clear();
+                    }
+                    payload = payload.with_attachment(Attachment::in_memory_with_meta(
+                        data,
+                        None,
+                        Some(media_type),
+                    ));
+                }
+            }
+        }
+
+        if !pending_text.is_empty() {
+            payload = payload.with_text(pending_text.join("\n"));
+        }
+
+        payload
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::payload::PayloadContent;
+
+    struct Profile {
+        name: String,
+    }
+
+    impl ToPrompt for Profile {
+        fn to_prompt_parts(&self) -> Vec<PromptPart> {
+            vec![PromptPart::Text(self.to_prompt())]
+        }
+
+        fn to_prompt(&self) -> String {
+            format!("name: {}", self.name)
+        }
+    }
+
+    struct Task {
+        goal: String,
+    }
+
+    impl ToPrompt for Task {
+        fn to_prompt_parts(&self) -> Vec<PromptPart> {
+            vec![PromptPart::Text(self.to_prompt())]
+        }
+
+        fn to_prompt(&self) -> String {
+            format!("goal: {}", self.goal)
+        }
+    }
+
+    #[test]
+    fn test_build_merges_text_and_preserves_images() {
+        let profile = Profile {
+            name: "Mai".to_string(),
+        };
+        let task = Task {
+            goal: "Describe the chart".to_string(),
+        };
+        let image = ImageData::new("image/png", vec![1, 2, 3]);
+
+        let payload = PromptBuilder::new()
+            .add(&profile)
+            .add_text("See the attached chart.")
+            .add_image(image)
+            .add(&task)
+            .build();
+
+        let contents = payload.contents();
+        assert_eq!(contents.len(), 3);
+        assert!(matches!(
+            &contents[0],
+            PayloadContent::Text(text) if text == "name: Mai\nSee the attached chart."
+        ));
+        match &contents[1] {
+            PayloadContent::Attachment(Attachment::InMemory {
+                bytes, mime_type, ..
+            }) => {
+                assert_eq!(bytes, &vec![1, 2, 3]);
+                assert_eq!(mime_type.as_deref(), Some("image/png"));
+            }
+            other => panic!("Expected in-memory attachment, got {:?}", other),
+        }
+        assert!(matches!(
+            &contents[2],
+            PayloadContent::Text(text) if text == "goal: Describe the chart"
+        ));
+    }
+
+    #[test]
+    fn test_build_empty() {
+        let payload = PromptBuilder::new().build();
+        assert!(payload.contents().is_empty());
+    }
+}
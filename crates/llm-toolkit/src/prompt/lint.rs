@@ -0,0 +1,218 @@
+//! Runtime linting for prompt templates.
+//!
+//! `#[prompt(validate = true)]` reports template problems as build warnings.
+//! [`lint_template`] runs the same checks at runtime and returns them as
+//! structured [`TemplateLint`] values, so tooling and tests can act on them.
+
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// The placeholder parser used by `#[derive(ToPrompt)]`, so lints match what
+/// the macro sees.
+pub use llm_toolkit_template::parse_template_placeholders_with_mode;
+
+/// A problem found in a prompt template by [`lint_template`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateLint {
+    /// The template is not valid Jinja.
+    SyntaxError {
+        /// The parser's description of the problem
+        message: String,
+        /// 1-based line of the error, when the parser reports one
+        line: Option<usize>,
+    },
+    /// The template references a variable that is not a known field.
+    UndefinedVariable { name: String },
+    /// A known field is never referenced by the template.
+    UnusedField { name: String },
+}
+
+impl fmt::Display for TemplateLint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateLint::SyntaxError {
+                message,
+                line: Some(line),
+            } => write!(f, "Invalid Jinja syntax at line {}: {}", line, message),
+            TemplateLint::SyntaxError {
+                message,
+                line: None,
+            } => write!(f, "Invalid Jinja syntax: {}", message),
+            TemplateLint::UndefinedVariable { name } => write!(
+                f,
+                "Variable '{}' used in template but not found in fields",
+                name
+            ),
+            TemplateLint::UnusedField { name } => {
+                write!(f, "Field '{}' is never used in template", name)
+            }
+        }
+    }
+}
+
+/// Checks a prompt template against the fields available when rendering it.
+///
+/// Reports, in order:
+/// - a [`SyntaxError`](TemplateLint::SyntaxError) if the template does not parse,
+/// - an [`UndefinedVariable`](TemplateLint::UndefinedVariable) for each referenced
+///   variable missing from `known_fields`,
+/// - an [`UnusedField`](TemplateLint::UnusedField) for each known field the
+///   template never references (skipped when there is a syntax error, since
+///   references can't be fully resolved then).
+///
+/// `{{ field:mode }}` placeholders are accepted, and `self` is always treated
+/// as defined. An empty result means the template is clean.
+///
+/// # Example
+///
+/// ```
+/// use llm_toolkit::prompt::{TemplateLint, lint_template};
+///
+/// let lints = lint_template("Hello {{ name }}, you are {{ agee }}", &["name", "age"]);
+/// assert_eq!(
+///     lints,
+///     vec![
+///         TemplateLint::UndefinedVariable { name: "agee".to_string() },
+///         TemplateLint::UnusedField { name: "age".to_string() },
+///     ]
+/// );
+/// ```
+pub fn lint_template(template: &str, known_fields: &[&str]) -> Vec<TemplateLint> {
+    let mut lints = Vec::new();
+
+    // Ordered by first appearance; `{{ field:mode }}` is not Jinja, so it is
+    // rewritten to `{{ field }}` before parsing
+    let mut referenced: Vec<String> = Vec::new();
+    for (name, _) in parse_template_placeholders_with_mode(template) {
+        if !referenced.contains(&name) {
+            referenced.push(name);
+        }
+    }
+    let mode_pattern = Regex::new(llm_toolkit_template::MODE_PLACEHOLDER_PATTERN).unwrap();
+    let jinja_source = mode_pattern.replace_all(template, "{{ $1 }}");
+
+    let mut env = minijinja::Environment::new();
+    let parsed = match env.add_template("lint", &jinja_source) {
+        Ok(()) => {
+            // The parser knows which names are template-local (loop variables,
+            // `{% set %}`) and catches uses outside plain placeholders, such as
+            // `{% if flag %}` or `{{ user.name }}`
+            let compiled = env.get_template("lint").expect("template was just added");
+            let mut undeclared: BTreeSet<String> =
+                compiled.undeclared_variables(false).into_iter().collect();
+            referenced.retain(|name| undeclared.remove(name));
+            referenced.extend(undeclared);
+            true
+        }
+        Err(e) => {
+            lints.push(TemplateLint::SyntaxError {
+                message: e.detail().unwrap_or("failed to parse template").to_string(),
+                line: e.line(),
+            });
+            false
+        }
+    };
+
+    for name in &referenced {
+        if name != "self" && !known_fields.contains(&name.as_str()) {
+            lints.push(TemplateLint::UndefinedVariable { name: name.clone() });
+        }
+    }
+
+    if parsed {
+        for field in known_fields {
+            if !referenced.iter().any(|name| name == field) {
+                lints.push(TemplateLint::UnusedField {
+                    name: field.to_string(),
+                });
+            }
+        }
+    }
+
+    lints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_template_has_no_lints() {
+        let template = "Name: {{ name }}\n{% if email %}Email: {{ email }}{% endif %}";
+        assert!(lint_template(template, &["name", "email"]).is_empty());
+    }
+
+    #[test]
+    fn test_undefined_variable() {
+        let lints = lint_template("Hello {{ name }} from {{ city }}", &["name"]);
+        assert_eq!(
+            lints,
+            vec![TemplateLint::UndefinedVariable {
+                name: "city".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_loop_variables_are_not_undefined() {
+        let template = "{% for item in items %}- {{ item }}\n{% endfor %}";
+        assert!(lint_template(template, &["items"]).is_empty());
+    }
+
+    #[test]
+    fn test_undefined_variable_in_expression() {
+        let lints = lint_template("{{ user.name }}", &["name"]);
+        assert!(lints.contains(&TemplateLint::UndefinedVariable {
+            name: "user".to_string()
+        }));
+    }
+
+    #[test]
+    fn test_syntax_error_reports_line() {
+        let lints = lint_template("Line one\n{% if name %}\n{{ name }}", &["name"]);
+        match lints.as_slice() {
+            [TemplateLint::SyntaxError { message, line }] => {
+                assert!(!message.is_empty());
+                assert!(line.is_some_and(|line| line >= 2), "line: {:?}", line);
+            }
+            other => panic!("expected a single syntax error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unused_field() {
+        let lints = lint_template("{{ name }}", &["name", "email"]);
+        assert_eq!(
+            lints,
+            vec![TemplateLint::UnusedField {
+                name: "email".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_mode_placeholders_and_self_are_accepted() {
+        let template = "{{ profile:schema_only }}\n{{ self:example_only }}";
+        assert!(lint_template(template, &["profile"]).is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        let lint = TemplateLint::SyntaxError {
+            message: "unexpected end of input".to_string(),
+            line: Some(3),
+        };
+        assert_eq!(
+            lint.to_string(),
+            "Invalid Jinja syntax at line 3: unexpected end of input"
+        );
+        assert_eq!(
+            TemplateLint::UndefinedVariable {
+                name: "city".to_string()
+            }
+            .to_string(),
+            "Variable 'city' used in template but not found in fields"
+        );
+    }
+}
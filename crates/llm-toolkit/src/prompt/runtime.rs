@@ -0,0 +1,151 @@
+//! Prompt templates loaded from disk at runtime.
+
+use super::render_prompt;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::{PoisonError, RwLock};
+
+/// Errors that can occur when loading or rendering a [`RuntimeTemplate`].
+#[derive(Debug, thiserror::Error)]
+pub enum RuntimeTemplateError {
+    #[error("Failed to read template file '{}': {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to render template file '{}': {source}", path.display())]
+    Render {
+        path: PathBuf,
+        source: minijinja::Error,
+    },
+}
+
+/// A minijinja template read from disk when the program runs.
+///
+/// Unlike `#[prompt(template_file = "...")]`, which embeds the template at
+/// compile time, a `RuntimeTemplate` lets you edit the prompt file without
+/// rebuilding. Call [`reload`](Self::reload) to re-read it, or enable the
+/// `template-reload` feature and [`with_auto_reload`](Self::with_auto_reload)
+/// to re-read it whenever its modification time changes.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use llm_toolkit::prompt::RuntimeTemplate;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Review<'a> {
+///     language: &'a str,
+///     code: &'a str,
+/// }
+///
+/// let template = RuntimeTemplate::load("prompts/review.jinja")?;
+/// let prompt = template.render(&Review { language: "Rust", code: "fn main() {}" })?;
+/// ```
+#[derive(Debug)]
+pub struct RuntimeTemplate {
+    path: PathBuf,
+    state: RwLock<TemplateState>,
+    #[cfg(feature = "template-reload")]
+    auto_reload: bool,
+}
+
+#[derive(Debug)]
+struct TemplateState {
+    source: String,
+    #[cfg(feature = "template-reload")]
+    modified: Option<std::time::SystemTime>,
+}
+
+impl RuntimeTemplate {
+    /// Reads the template at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RuntimeTemplateError> {
+        let path = path.as_ref().to_path_buf();
+        let state = read_template(&path)?;
+        Ok(Self {
+            path,
+            state: RwLock::new(state),
+            #[cfg(feature = "template-reload")]
+            auto_reload: false,
+        })
+    }
+
+    /// Re-reads the template whenever its modification time changes before a render.
+    #[cfg(feature = "template-reload")]
+    pub fn with_auto_reload(mut self, enabled: bool) -> Self {
+        self.auto_reload = enabled;
+        self
+    }
+
+    /// Returns the path the template was loaded from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the current template source.
+    pub fn source(&self) -> String {
+        self.state
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .source
+            .clone()
+    }
+
+    /// Renders the template with a serializable context.
+    pub fn render<T: Serialize>(&self, context: &T) -> Result<String, RuntimeTemplateError> {
+        #[cfg(feature = "template-reload")]
+        if self.auto_reload {
+            self.reload_if_modified()?;
+        }
+
+        let state = self.state.read().unwrap_or_else(PoisonError::into_inner);
+        render_prompt(&state.source, context).map_err(|source| RuntimeTemplateError::Render {
+            path: self.path.clone(),
+            source,
+        })
+    }
+
+    /// Re-reads the template from disk unconditionally.
+    pub fn reload(&self) -> Result<(), RuntimeTemplateError> {
+        let state = read_template(&self.path)?;
+        *self.state.write().unwrap_or_else(PoisonError::into_inner) = state;
+        Ok(())
+    }
+
+    /// Re-reads the template if its modification time changed since the last read.
+    ///
+    /// Returns true if the template was reloaded.
+    #[cfg(feature = "template-reload")]
+    pub fn reload_if_modified(&self) -> Result<bool, RuntimeTemplateError> {
+        let modified = std::fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        let current = self
+            .state
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .modified;
+
+        if modified.is_some() && modified == current {
+            return Ok(false);
+        }
+
+        self.reload()?;
+        Ok(true)
+    }
+}
+
+fn read_template(path: &Path) -> Result<TemplateState, RuntimeTemplateError> {
+    let source = std::fs::read_to_string(path).map_err(|source| RuntimeTemplateError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(TemplateState {
+        source,
+        #[cfg(feature = "template-reload")]
+        modified: std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok(),
+    })
+}
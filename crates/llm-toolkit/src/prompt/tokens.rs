@@ -0,0 +1,128 @@
+//! Rough token counting for prompts.
+//!
+//! These functions are an approximation, not a real tokenizer. They are meant
+//! for budgeting (e.g. deciding whether a prompt risks overflowing a context
+//! window), and can be off by 20% or more depending on the model and the text.
+//! Leave a safety margin when comparing against hard limits.
+
+/// Approximate token cost charged for a single image.
+///
+/// Providers scale image cost with resolution; this is the right order of
+/// magnitude for a ~1 megapixel image.
+pub const DEFAULT_TOKENS_PER_IMAGE: usize = 1000;
+
+/// Estimates the number of tokens in `text`.
+///
+/// The heuristic:
+/// - Letters and digits cost one token per four characters, the usual rule of
+///   thumb for English. Punctuation is denser and costs one token per two.
+/// - ASCII text never costs less than one token per word, since tokenizers
+///   don't merge across whitespace.
+/// - Each non-ASCII character (CJK, emoji, ...) costs one token.
+/// - Each newline costs one token; other whitespace is absorbed into the
+///   following word.
+///
+/// For English prose this tends to err on the high side, which is the safer
+/// direction when checking against a context limit.
+///
+/// # Examples
+///
+/// ```rust
+/// use llm_toolkit::prompt::tokens::estimate_tokens;
+///
+/// assert_eq!(estimate_tokens(""), 0);
+/// assert_eq!(estimate_tokens("The quick brown fox jumps over the lazy dog."), 10);
+/// ```
+pub fn estimate_tokens(text: &str) -> usize {
+    let mut alphanumeric: usize = 0;
+    let mut punctuation: usize = 0;
+    let mut other = 0;
+    let mut words = 0;
+    let mut newlines = 0;
+    let mut in_word = false;
+
+    for ch in text.chars() {
+        if ch.is_whitespace() {
+            if ch == '\n' {
+                newlines += 1;
+            }
+            in_word = false;
+            continue;
+        }
+
+        if !in_word {
+            words += 1;
+            in_word = true;
+        }
+        if ch.is_ascii_punctuation() {
+            punctuation += 1;
+        } else if ch.is_ascii() {
+            alphanumeric += 1;
+        } else {
+            other += 1;
+        }
+    }
+
+    // Words made only of non-ASCII characters are already paid for per character
+    let ascii_tokens = if alphanumeric + punctuation == 0 {
+        0
+    } else {
+        (alphanumeric.div_ceil(4) + punctuation.div_ceil(2)).max(words)
+    };
+
+    ascii_tokens + other + newlines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asserts `estimate` is within `tolerance` (a fraction) of `expected`.
+    fn assert_within(estimate: usize, expected: usize, tolerance: f64) {
+        let diff = (estimate as f64 - expected as f64).abs();
+        assert!(
+            diff <= expected as f64 * tolerance,
+            "estimate {} not within {:.0}% of {}",
+            estimate,
+            tolerance * 100.0,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_empty_and_whitespace() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("   "), 0);
+        assert_eq!(estimate_tokens("\n\n"), 2);
+    }
+
+    // Reference counts below are approximate counts from common BPE tokenizers
+
+    #[test]
+    fn test_english_prose() {
+        let text = "You are a helpful assistant. Summarize the following customer \
+                    review in two sentences, focusing on the product quality and \
+                    the delivery experience. Do not include any personal information.";
+        assert_within(estimate_tokens(text), 34, 0.3);
+    }
+
+    #[test]
+    fn test_code_and_json() {
+        let json = "{\n  \"name\": \"llm-toolkit\",\n  \"version\": \"0.1.0\",\n  \"tags\": [\"llm\", \"rust\"]\n}";
+        assert_within(estimate_tokens(json), 32, 0.3);
+
+        let code = "fn main() {\n    println!(\"Hello, world!\");\n}";
+        assert_within(estimate_tokens(code), 13, 0.3);
+    }
+
+    #[test]
+    fn test_non_ascii_text() {
+        let text = "こんにちは、世界。今日はいい天気ですね。";
+        assert_within(estimate_tokens(text), 20, 0.25);
+    }
+
+    #[test]
+    fn test_short_words_count_at_least_one_each() {
+        assert_eq!(estimate_tokens("a b c d e f g h"), 8);
+    }
+}
@@ -0,0 +1,119 @@
+#![cfg(all(feature = "agent", feature = "derive"))]
+
+use llm_toolkit::ToPrompt;
+use llm_toolkit::agent::{Agent, AgentError, ParseErrorReason, Payload};
+use serde::{Deserialize, Serialize};
+
+/// Mock backend that always returns the same response
+#[derive(Clone, Default)]
+struct FixedAgent {
+    response: String,
+}
+
+impl FixedAgent {
+    fn new(response: &str) -> Self {
+        Self {
+            response: response.to_string(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Agent for FixedAgent {
+    type Output = String;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "mock";
+        &EXPERTISE
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<String, AgentError> {
+        Ok(self.response.clone())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToPrompt, PartialEq)]
+struct Count {
+    count: u32,
+    exact: bool,
+}
+
+#[llm_toolkit_macros::agent(
+    expertise = "Count things",
+    output = "Count",
+    default_inner = "FixedAgent",
+    coerce
+)]
+struct CoercingCountAgent;
+
+#[llm_toolkit_macros::agent(
+    expertise = "Count things",
+    output = "Count",
+    default_inner = "FixedAgent"
+)]
+struct StrictCountAgent;
+
+/// Custom hook: treats a missing `exact` as `false`
+fn default_exact(value: &mut serde_json::Value) {
+    if let Some(object) = value.as_object_mut() {
+        object
+            .entry("exact")
+            .or_insert(serde_json::Value::Bool(false));
+    }
+}
+
+#[llm_toolkit_macros::agent(
+    expertise = "Count things",
+    output = "Count",
+    default_inner = "FixedAgent",
+    coerce = "default_exact"
+)]
+struct CustomCoerceCountAgent;
+
+const STRINGIFIED: &str = r#"{"count": "42", "exact": "true"}"#;
+
+#[tokio::test]
+async fn test_coerce_fixes_stringified_fields() {
+    let agent = CoercingCountAgent::new(FixedAgent::new(STRINGIFIED));
+
+    let output = agent.execute(Payload::text("count")).await.unwrap();
+
+    assert_eq!(
+        output,
+        Count {
+            count: 42,
+            exact: true
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_without_coerce_stringified_fields_fail_to_parse() {
+    let agent = StrictCountAgent::new(FixedAgent::new(STRINGIFIED));
+
+    let err = agent.execute(Payload::text("count")).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        AgentError::ParseError {
+            reason: ParseErrorReason::SchemaMismatch,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_custom_coerce_hook_runs_before_deserialization() {
+    let agent = CustomCoerceCountAgent::new(FixedAgent::new(r#"{"count": 3}"#));
+
+    let output = agent.execute(Payload::text("count")).await.unwrap();
+
+    assert_eq!(
+        output,
+        Count {
+            count: 3,
+            exact: false
+        }
+    );
+}
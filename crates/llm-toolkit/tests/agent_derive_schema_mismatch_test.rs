@@ -0,0 +1,67 @@
+#![cfg(all(feature = "agent", feature = "derive"))]
+// Exercises the deprecated #[derive(Agent)] on purpose
+#![allow(deprecated)]
+
+use llm_toolkit::ToPrompt;
+use llm_toolkit::agent::{Agent, AgentError, ParseErrorReason, Payload};
+use serde::{Deserialize, Serialize};
+
+/// Mock backend answering with snake_case keys and a stringified number
+#[derive(Clone, Default)]
+struct SnakeCaseBackend;
+
+#[async_trait::async_trait]
+impl Agent for SnakeCaseBackend {
+    type Output = String;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "mock";
+        &EXPERTISE
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<String, AgentError> {
+        Ok(r#"{"display_name": "Mai", "age": "thirty"}"#.to_string())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToPrompt)]
+#[serde(rename_all = "camelCase")]
+struct Profile {
+    display_name: String,
+    age: u32,
+}
+
+#[derive(llm_toolkit_macros::Agent)]
+#[agent(
+    expertise = "Describe the user",
+    output = "Profile",
+    backend = "custom",
+    default_inner = "SnakeCaseBackend"
+)]
+struct ProfileAgent;
+
+#[tokio::test]
+async fn test_schema_mismatch_message_uses_serde_field_names() {
+    let err = ProfileAgent
+        .execute(Payload::text("Who is Mai?"))
+        .await
+        .unwrap_err();
+
+    match err {
+        AgentError::ParseError {
+            message,
+            reason: ParseErrorReason::SchemaMismatch,
+        } => {
+            assert!(
+                message.contains(
+                    "missing fields: [displayName]; unexpected fields: [display_name]; \
+                     mistyped fields: [age (expected number, got string)]"
+                ),
+                "{}",
+                message
+            );
+        }
+        other => panic!("expected a SchemaMismatch ParseError, got {:?}", other),
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "agent")]
+
+use llm_toolkit::agent::{Agent, AgentError, Payload};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Mock agent that echoes its input after a delay and tracks peak concurrency.
+///
+/// Earlier inputs sleep longer, so completions arrive out of input order.
+/// Inputs starting with "fail" return an error.
+#[derive(Default)]
+struct EchoAgent {
+    in_flight: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Agent for EchoAgent {
+    type Output = String;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "Echoes its input";
+        &EXPERTISE
+    }
+
+    async fn execute(&self, intent: Payload) -> Result<String, AgentError> {
+        let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(current, Ordering::SeqCst);
+
+        let text = intent.to_text();
+        let index: u64 = text.rsplit('-').next().unwrap().parse().unwrap();
+        tokio::time::sleep(Duration::from_millis(50 - index * 10)).await;
+
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        if text.starts_with("fail") {
+            Err(AgentError::ExecutionFailed(text))
+        } else {
+            Ok(text.to_uppercase())
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_execute_batch_preserves_order_and_isolates_errors() {
+    let agent = EchoAgent::default();
+    let intents = ["in-0", "in-1", "fail-2", "in-3", "in-4"]
+        .into_iter()
+        .map(Payload::text)
+        .collect();
+
+    let results = agent.execute_batch(intents, 2).await;
+
+    assert_eq!(results.len(), 5);
+    assert_eq!(results[0].as_ref().unwrap(), "IN-0");
+    assert_eq!(results[1].as_ref().unwrap(), "IN-1");
+    assert!(matches!(
+        &results[2],
+        Err(AgentError::ExecutionFailed(text)) if text == "fail-2"
+    ));
+    assert_eq!(results[3].as_ref().unwrap(), "IN-3");
+    assert_eq!(results[4].as_ref().unwrap(), "IN-4");
+
+    assert_eq!(agent.peak.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_execute_batch_with_zero_concurrency_runs_sequentially() {
+    let agent = EchoAgent::default();
+    let intents = vec![Payload::text("in-0"), Payload::text("in-1")];
+
+    let results = agent.execute_batch(intents, 0).await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(agent.peak.load(Ordering::SeqCst), 1);
+}
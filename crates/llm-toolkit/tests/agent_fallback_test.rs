@@ -0,0 +1,157 @@
+#![cfg(all(feature = "agent", feature = "derive"))]
+
+use llm_toolkit::ToPrompt;
+use llm_toolkit::agent::{Agent, AgentError, AnyAgent, Payload};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Mock backend that answers every call with the same result
+#[derive(Clone)]
+struct MockBackend {
+    response: Arc<Mutex<Option<Result<String, AgentError>>>>,
+    calls: Arc<AtomicUsize>,
+}
+
+impl Default for MockBackend {
+    fn default() -> Self {
+        Self::failing(AgentError::Other("unused".to_string()))
+    }
+}
+
+impl MockBackend {
+    fn answering(response: &str) -> Self {
+        Self {
+            response: Arc::new(Mutex::new(Some(Ok(response.to_string())))),
+            calls: Arc::default(),
+        }
+    }
+
+    fn failing(error: AgentError) -> Self {
+        Self {
+            response: Arc::new(Mutex::new(Some(Err(error)))),
+            calls: Arc::default(),
+        }
+    }
+
+    fn unavailable() -> Self {
+        Self::failing(AgentError::BackendUnavailable {
+            backend: "claude",
+            hint: "`claude` was not found on PATH".to_string(),
+        })
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait::async_trait]
+impl Agent for MockBackend {
+    type Output = String;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "mock";
+        &EXPERTISE
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<String, AgentError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        // Errors are not Clone, so a failing mock hands out its error once
+        let mut response = self.response.lock().unwrap();
+        match response.as_ref() {
+            Some(Ok(text)) => Ok(text.clone()),
+            _ => response
+                .take()
+                .unwrap_or_else(|| Err(AgentError::Other("mock already failed".to_string()))),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToPrompt, PartialEq)]
+struct Summary {
+    text: String,
+}
+
+#[llm_toolkit_macros::agent(
+    expertise = "Summarize the input",
+    output = "Summary",
+    default_inner = "MockBackend",
+    fallbacks = ["gemini"]
+)]
+struct SummaryAgent;
+
+#[llm_toolkit_macros::agent(
+    expertise = "Answer briefly",
+    default_inner = "MockBackend",
+    fallbacks = ["gemini", "codex"]
+)]
+struct AnswerAgent;
+
+#[tokio::test]
+async fn test_unavailable_primary_falls_back() {
+    let primary = MockBackend::unavailable();
+    let fallback = MockBackend::answering(r#"{"text": "short"}"#);
+    let agent =
+        SummaryAgent::new(primary.clone()).with_fallbacks(vec![AnyAgent::new(fallback.clone())]);
+
+    let output = agent.execute(Payload::text("Summarize")).await.unwrap();
+
+    assert_eq!(
+        output,
+        Summary {
+            text: "short".to_string()
+        }
+    );
+    assert_eq!(primary.calls(), 1);
+    assert_eq!(fallback.calls(), 1);
+}
+
+#[tokio::test]
+async fn test_timed_out_primary_falls_back_in_order() {
+    let primary = MockBackend::failing(AgentError::IoError(std::io::Error::new(
+        std::io::ErrorKind::TimedOut,
+        "timed out",
+    )));
+    let first = MockBackend::unavailable();
+    let second = MockBackend::answering("42");
+    let agent = AnswerAgent::new(primary.clone()).with_fallbacks(vec![
+        AnyAgent::new(first.clone()),
+        AnyAgent::new(second.clone()),
+    ]);
+
+    let output = agent.execute(Payload::text("Answer")).await.unwrap();
+
+    assert_eq!(output, "42");
+    assert_eq!((primary.calls(), first.calls(), second.calls()), (1, 1, 1));
+}
+
+#[tokio::test]
+async fn test_parse_errors_do_not_fall_back() {
+    let primary = MockBackend::answering("not json at all");
+    let fallback = MockBackend::answering(r#"{"text": "short"}"#);
+    let agent =
+        SummaryAgent::new(primary.clone()).with_fallbacks(vec![AnyAgent::new(fallback.clone())]);
+
+    let err = agent.execute(Payload::text("Summarize")).await.unwrap_err();
+
+    assert!(matches!(err, AgentError::ParseError { .. }), "{:?}", err);
+    assert_eq!(fallback.calls(), 0);
+}
+
+#[tokio::test]
+async fn test_last_backend_failure_is_returned() {
+    let primary = MockBackend::unavailable();
+    let fallback = MockBackend::unavailable();
+    let agent = AnswerAgent::new(primary).with_fallbacks(vec![AnyAgent::new(fallback.clone())]);
+
+    let err = agent.execute(Payload::text("Answer")).await.unwrap_err();
+
+    assert!(
+        matches!(err, AgentError::BackendUnavailable { .. }),
+        "{:?}",
+        err
+    );
+    assert_eq!(fallback.calls(), 1);
+}
@@ -0,0 +1,127 @@
+#![cfg(all(feature = "agent", feature = "derive"))]
+
+use llm_toolkit::ToPrompt;
+use llm_toolkit::agent::impls::RetryAgent;
+use llm_toolkit::agent::{Agent, AgentError, Payload, Validate};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// Mock backend that replays queued responses and records every prompt
+#[derive(Clone, Default)]
+struct ScriptedAgent {
+    responses: Arc<Mutex<VecDeque<String>>>,
+    received: Arc<Mutex<Vec<String>>>,
+}
+
+impl ScriptedAgent {
+    fn with_responses(responses: &[&str]) -> Self {
+        Self {
+            responses: Arc::new(Mutex::new(
+                responses.iter().map(|r| r.to_string()).collect(),
+            )),
+            received: Arc::default(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Agent for ScriptedAgent {
+    type Output = String;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "mock";
+        &EXPERTISE
+    }
+
+    async fn execute(&self, intent: Payload) -> Result<String, AgentError> {
+        self.received.lock().unwrap().push(intent.to_text());
+        self.responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .ok_or_else(|| AgentError::ExecutionFailed("no scripted response left".to_string()))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToPrompt, PartialEq)]
+struct Progress {
+    percent: u32,
+}
+
+impl Validate for Progress {
+    fn validate(&self) -> Result<(), String> {
+        if self.percent > 100 {
+            return Err(format!("percent must be at most 100, got {}", self.percent));
+        }
+        Ok(())
+    }
+}
+
+#[llm_toolkit_macros::agent(
+    expertise = "Report task progress",
+    output = "Progress",
+    default_inner = "ScriptedAgent",
+    validate
+)]
+struct ValidatedProgressAgent;
+
+#[llm_toolkit_macros::agent(
+    expertise = "Report task progress",
+    output = "Progress",
+    default_inner = "ScriptedAgent"
+)]
+struct UnvalidatedProgressAgent;
+
+#[tokio::test]
+async fn test_validation_failure_is_retryable_error() {
+    let agent =
+        ValidatedProgressAgent::new(ScriptedAgent::with_responses(&[r#"{"percent": 150}"#]));
+
+    let err = agent
+        .execute(Payload::text("How far along?"))
+        .await
+        .unwrap_err();
+
+    match &err {
+        AgentError::ValidationFailed { message } => {
+            assert_eq!(message, "percent must be at most 100, got 150");
+        }
+        other => panic!("expected ValidationFailed, got {:?}", other),
+    }
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_validation_is_opt_in() {
+    let agent =
+        UnvalidatedProgressAgent::new(ScriptedAgent::with_responses(&[r#"{"percent": 150}"#]));
+
+    let output = agent
+        .execute(Payload::text("How far along?"))
+        .await
+        .unwrap();
+    assert_eq!(output, Progress { percent: 150 });
+}
+
+#[tokio::test]
+async fn test_validation_error_is_fed_back_on_reprompt() {
+    let backend = ScriptedAgent::with_responses(&[r#"{"percent": 150}"#, r#"{"percent": 42}"#]);
+    let received = backend.received.clone();
+    let agent =
+        RetryAgent::new(ValidatedProgressAgent::new(backend), 2).with_reprompt_on_parse_error(true);
+
+    let output = agent
+        .execute(Payload::text("How far along?"))
+        .await
+        .unwrap();
+    assert_eq!(output, Progress { percent: 42 });
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 2);
+    assert!(!received[0].contains("rejected by validation"));
+    assert!(received[1].contains(
+        "Your previous response was rejected by validation: percent must be at most 100, got 150."
+    ));
+}
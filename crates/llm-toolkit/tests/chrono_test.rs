@@ -0,0 +1,67 @@
+//! Tests for ToPrompt on chrono date/time types (`chrono` feature).
+
+#![cfg(all(feature = "chrono", feature = "derive"))]
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use llm_toolkit::ToPrompt;
+use serde::Serialize;
+
+#[derive(ToPrompt)]
+struct Event {
+    name: String,
+    created_at: DateTime<Utc>,
+    due: NaiveDate,
+}
+
+#[derive(Serialize, ToPrompt)]
+#[prompt(mode = "full")]
+struct Deadline {
+    title: String,
+    due_at: DateTime<Utc>,
+}
+
+fn created_at() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 3, 15, 9, 30, 0).unwrap()
+}
+
+#[test]
+fn test_chrono_types_render_as_iso_8601() {
+    assert_eq!(created_at().to_prompt(), "2024-03-15T09:30:00+00:00");
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2024, 4, 1).unwrap().to_prompt(),
+        "2024-04-01"
+    );
+    assert_eq!(created_at().naive_utc().to_prompt(), "2024-03-15T09:30:00");
+}
+
+#[test]
+fn test_datetime_field_renders_as_rfc_3339() {
+    let event = Event {
+        name: "Release".to_string(),
+        created_at: created_at(),
+        due: NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+    };
+
+    assert_eq!(
+        event.to_prompt(),
+        "name: Release\ncreated_at: 2024-03-15T09:30:00+00:00\ndue: 2024-04-01"
+    );
+}
+
+#[test]
+fn test_datetime_field_is_string_in_schema() {
+    let schema = Event::prompt_schema_json().expect("derived structs provide a schema");
+    assert_eq!(schema["properties"]["created_at"]["type"], "string");
+    assert_eq!(schema["properties"]["due"]["type"], "string");
+
+    let deadline = Deadline {
+        title: "Ship".to_string(),
+        due_at: created_at(),
+    };
+    let schema = deadline.to_prompt_with_mode("schema_only");
+    assert!(
+        schema.contains("due_at: string;"),
+        "DateTime should appear as string in the schema: {}",
+        schema
+    );
+}
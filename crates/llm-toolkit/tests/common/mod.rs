@@ -0,0 +1,76 @@
+//! Fixtures shared by the orchestrator integration tests.
+
+// Each test binary compiles this module separately and uses only part of it
+#![allow(dead_code)]
+
+use async_trait::async_trait;
+use llm_toolkit::agent::{Agent, AgentError, Payload};
+use llm_toolkit::orchestrator::StrategyStep;
+use serde_json::Value as JsonValue;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Agent that returns a fixed output (or fails) and counts its invocations.
+#[derive(Clone)]
+pub struct FixedAgent {
+    name: String,
+    output: Result<JsonValue, String>,
+    calls: Arc<AtomicUsize>,
+}
+
+impl FixedAgent {
+    /// Creates an agent that always returns `output`.
+    pub fn new(name: &str, output: JsonValue) -> Self {
+        Self::with_result(name, Ok(output))
+    }
+
+    /// Creates an agent that always fails with `error`.
+    pub fn failing(name: &str, error: &str) -> Self {
+        Self::with_result(name, Err(error.to_string()))
+    }
+
+    /// Creates an agent that returns `output`, failing on `Err`.
+    pub fn with_result(name: &str, output: Result<JsonValue, String>) -> Self {
+        Self {
+            name: name.to_string(),
+            output,
+            calls: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns how many times the agent has been executed.
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl Agent for FixedAgent {
+    type Output = JsonValue;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "Fixed-output agent for orchestrator tests";
+        &EXPERTISE
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<Self::Output, AgentError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.output.clone().map_err(AgentError::ExecutionFailed)
+    }
+}
+
+/// Creates a step with a generic description and intent.
+pub fn step(step_id: &str, agent: &str) -> StrategyStep {
+    StrategyStep::new(
+        step_id.to_string(),
+        format!("Run {}", step_id),
+        agent.to_string(),
+        "Do the work".to_string(),
+        "Output".to_string(),
+    )
+}
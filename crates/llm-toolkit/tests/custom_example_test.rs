@@ -0,0 +1,76 @@
+use llm_toolkit::{ProvideExample, ToPrompt, examples_section};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+#[derive(ToPrompt, Default, Serialize, Deserialize)]
+#[prompt(mode = "full")]
+/// An invoice line using the derive-generated example.
+struct DefaultInvoice {
+    /// The invoice number.
+    #[prompt(example = "INV-0001")]
+    number: String,
+    /// Optional discount rate.
+    discount: Option<f64>,
+}
+
+#[derive(ToPrompt, Default, Serialize, Deserialize)]
+#[prompt(mode = "full", custom_example)]
+/// An invoice line with a hand-crafted example.
+struct CustomInvoice {
+    /// The invoice number.
+    #[prompt(example = "INV-0001")]
+    number: String,
+    /// Optional discount rate.
+    discount: Option<f64>,
+}
+
+impl ProvideExample for CustomInvoice {
+    fn example() -> Value {
+        json!({"number": "INV-0042", "discount": 0.15})
+    }
+}
+
+fn example_json<T: ToPrompt + Default>() -> Value {
+    serde_json::from_str(&T::default().to_prompt_with_mode("example_only")).unwrap()
+}
+
+#[test]
+fn test_custom_example_overrides_generated_example() {
+    assert_eq!(
+        example_json::<DefaultInvoice>(),
+        json!({"number": "INV-0001", "discount": null})
+    );
+    assert_eq!(example_json::<CustomInvoice>(), CustomInvoice::example());
+}
+
+#[test]
+fn test_custom_example_used_in_full_mode() {
+    let prompt = CustomInvoice::default().to_prompt();
+
+    assert!(prompt.contains("INV-0042"));
+    assert!(!prompt.contains("INV-0001"));
+    // The schema part is still derived from the fields
+    assert!(prompt.contains("number: string"));
+}
+
+#[test]
+fn test_custom_example_used_in_examples_section() {
+    let section = examples_section!(DefaultInvoice, CustomInvoice);
+
+    let custom = section
+        .split("#### `CustomInvoice`")
+        .nth(1)
+        .expect("CustomInvoice section");
+    assert!(custom.contains("\"INV-0042\""));
+    assert!(custom.contains("0.15"));
+
+    let default = section.split("#### `CustomInvoice`").next().unwrap();
+    assert!(default.contains("\"INV-0001\""));
+}
+
+#[test]
+fn test_custom_example_deserializes() {
+    let invoice: CustomInvoice = serde_json::from_value(CustomInvoice::example()).unwrap();
+    assert_eq!(invoice.number, "INV-0042");
+    assert_eq!(invoice.discount, Some(0.15));
+}
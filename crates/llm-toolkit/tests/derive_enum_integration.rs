@@ -33,6 +33,33 @@ mod tests {
         assert!(!schema.contains("Third"));
     }
 
+    #[test]
+    fn test_enum_prompt_schema_is_cached() {
+        let first = TestEnum::prompt_schema();
+        let second = TestEnum::prompt_schema();
+        assert_eq!(first, second);
+        assert!(first.contains("type TestEnum ="));
+    }
+
+    #[test]
+    fn test_enum_derive_display() {
+        #[derive(ToPrompt)]
+        #[prompt(derive_display)]
+        #[allow(dead_code)]
+        enum Status {
+            /// Work is in progress
+            Active,
+            #[prompt("Work is finished")]
+            Done,
+            Archived,
+        }
+
+        for status in [Status::Active, Status::Done, Status::Archived] {
+            assert_eq!(format!("{}", status), status.to_prompt());
+        }
+        assert_eq!(Status::Active.to_string(), "Active: Work is in progress");
+    }
+
     #[test]
     fn test_all_fallback_priorities() {
         #[derive(ToPrompt)]
@@ -270,4 +270,104 @@ mod tests {
         assert!(schema.contains("enabled: boolean"));
         assert!(schema.contains("name: string"));
     }
+
+    #[test]
+    fn test_struct_variant_example_hints() {
+        #[derive(ToPrompt, Serialize, Deserialize)]
+        #[serde(tag = "type")]
+        #[allow(dead_code)]
+        enum Ticket {
+            Open {
+                #[prompt(example = "Login page returns 500")]
+                title: String,
+                /// Name of the reporting user. Used for follow-ups.
+                reporter: String,
+                #[prompt(example = "3")]
+                priority: u8,
+                /// Whether the issue blocks a release
+                blocking: bool,
+            },
+        }
+
+        let schema = Ticket::prompt_schema();
+
+        // Explicit example wins, doc comment's first sentence is the fallback,
+        // and hints that do not fit the type fall back to the generic placeholder
+        assert!(schema.contains(
+            "Example value: { type: \"Open\", title: \"Login page returns 500\", \
+             reporter: \"Name of the reporting user\", priority: 3, blocking: false }"
+        ));
+    }
+
+    #[test]
+    fn test_tuple_variant_example_hints() {
+        #[derive(ToPrompt, Serialize, Deserialize)]
+        #[allow(dead_code)]
+        enum Command {
+            Rename(
+                /// New display name
+                String,
+                #[prompt(example = "true")] bool,
+            ),
+        }
+
+        let schema = Command::prompt_schema();
+        assert!(schema.contains("Example value: { \"Rename\": [\"New display name\", true] }"));
+    }
+
+    #[derive(ToPrompt, Serialize, Deserialize, Debug, PartialEq)]
+    #[serde(tag = "t", content = "c")]
+    enum Event {
+        /// Session started
+        Started,
+        /// Progress update
+        Progress { percent: u8, message: String },
+    }
+
+    #[test]
+    fn test_adjacently_tagged_schema() {
+        let schema = Event::prompt_schema();
+
+        // Unit variant carries only the tag key
+        assert!(schema.contains("| { t: \"Started\" }  // Session started"));
+
+        // Struct variant nests its fields under the content key
+        assert!(schema.contains("| { t: \"Progress\", c: { percent: number, message: string } }"));
+
+        assert!(schema.contains("Example values:"));
+        assert!(schema.contains("  { t: \"Started\" }"));
+        assert!(schema.contains("  { t: \"Progress\", c: { percent: "));
+    }
+
+    #[test]
+    fn test_adjacently_tagged_schema_matches_serde_shape() {
+        let progress = Event::Progress {
+            percent: 50,
+            message: "halfway".to_string(),
+        };
+        let json = serde_json::to_value(&progress).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "t": "Progress", "c": { "percent": 50, "message": "halfway" } })
+        );
+
+        let started = serde_json::to_value(Event::Started).unwrap();
+        assert_eq!(started, serde_json::json!({ "t": "Started" }));
+
+        let deserialized: Event = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, progress);
+    }
+
+    #[test]
+    fn test_adjacently_tagged_tuple_variant() {
+        #[derive(ToPrompt, Serialize, Deserialize)]
+        #[serde(tag = "kind", content = "args")]
+        #[allow(dead_code)]
+        enum Command {
+            Move(i32, i32),
+        }
+
+        let schema = Command::prompt_schema();
+        assert!(schema.contains("| { kind: \"Move\", args: [number, number] };"));
+    }
 }
@@ -220,3 +220,116 @@ fn test_multiple_targets() {
     assert!(debug.contains("debug_info: Debug data"));
     assert!(!debug.contains("status")); // Status is only for Detailed target
 }
+
+#[test]
+fn test_available_targets() {
+    let task = MultiTargetTask {
+        title: "Task Title".to_string(),
+        description: "Task Description".to_string(),
+        status: "Active".to_string(),
+        debug_info: "Debug data".to_string(),
+    };
+
+    assert_eq!(task.available_targets(), vec!["Brief", "Detailed", "Debug"]);
+
+    // Every listed target renders without error
+    for target in task.available_targets() {
+        assert!(task.to_prompt_for(target).is_ok(), "target {}", target);
+    }
+}
+
+// Test default target fallback
+#[derive(ToPromptSet, Serialize, Debug)]
+#[prompt_for(name = "Default", template = "{{title}}", default)]
+#[prompt_for(name = "Full", template = "{{title}}: {{description}}")]
+struct FallbackTask {
+    title: String,
+    description: String,
+}
+
+#[test]
+fn test_unknown_target_uses_default() {
+    let task = FallbackTask {
+        title: "Ship it".to_string(),
+        description: "Release v1".to_string(),
+    };
+
+    assert_eq!(task.to_prompt_for("Full").unwrap(), "Ship it: Release v1");
+    assert_eq!(task.to_prompt_for("Default").unwrap(), "Ship it");
+    assert_eq!(task.to_prompt_for("Unknown").unwrap(), "Ship it");
+    assert_eq!(task.available_targets(), vec!["Default", "Full"]);
+}
+
+#[test]
+fn test_unknown_target_without_default_still_errors() {
+    let task = MultiTargetTask {
+        title: "Task Title".to_string(),
+        description: "Task Description".to_string(),
+        status: "Active".to_string(),
+        debug_info: "Debug data".to_string(),
+    };
+
+    assert!(matches!(
+        task.to_prompt_for("Unknown"),
+        Err(llm_toolkit::PromptSetError::TargetNotFound { .. })
+    ));
+}
+
+// Test JSON targets
+fn format_status(done: &bool) -> String {
+    if *done { "done" } else { "open" }.to_string()
+}
+
+#[derive(ToPromptSet, Serialize)]
+#[prompt_for(name = "ApiJson", format = "json")]
+struct ApiTask {
+    title: String,
+
+    #[prompt_for(name = "ApiJson", rename = "task_id")]
+    id: u64,
+
+    #[prompt_for(name = "ApiJson")]
+    tags: Vec<String>,
+
+    #[prompt_for(name = "ApiJson", rename = "status", format_with = "format_status")]
+    done: bool,
+
+    #[prompt_for(name = "Agent")]
+    notes: String,
+
+    #[prompt_for(skip)]
+    is_dirty: bool,
+}
+
+#[test]
+fn test_json_target_applies_renames_and_skips() {
+    let task = ApiTask {
+        title: "Ship \"v1\"".to_string(),
+        id: 42,
+        tags: vec!["release".to_string()],
+        done: false,
+        notes: "Agent-only notes".to_string(),
+        is_dirty: true,
+    };
+
+    let parts = task.to_prompt_parts_for("ApiJson").unwrap();
+    let [PromptPart::Text(json)] = parts.as_slice() else {
+        panic!("expected a single text part, got {:?}", parts);
+    };
+
+    assert!(!json.contains('\n'), "JSON should be compact: {}", json);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(json).unwrap(),
+        serde_json::json!({
+            "title": "Ship \"v1\"",
+            "task_id": 42,
+            "tags": ["release"],
+            "status": "open"
+        })
+    );
+
+    // Key-value targets are unaffected
+    let agent = task.to_prompt_for("Agent").unwrap();
+    assert!(agent.contains("notes: Agent-only notes"));
+    assert!(!agent.contains("task_id"));
+}
@@ -0,0 +1,256 @@
+#[cfg(feature = "derive")]
+mod tests {
+    use llm_toolkit::ToPrompt;
+
+    #[derive(ToPrompt)]
+    struct ServerConfig {
+        host: String,
+        port: u16,
+    }
+
+    #[test]
+    fn test_flatten_inlines_nested_fields() {
+        #[derive(ToPrompt)]
+        struct Nested {
+            name: String,
+            config: ServerConfig,
+        }
+
+        #[derive(ToPrompt)]
+        struct Flattened {
+            name: String,
+            #[prompt(flatten)]
+            config: ServerConfig,
+        }
+
+        let nested = Nested {
+            name: "api".to_string(),
+            config: ServerConfig {
+                host: "localhost".to_string(),
+                port: 8080,
+            },
+        };
+        let flattened = Flattened {
+            name: "api".to_string(),
+            config: ServerConfig {
+                host: "localhost".to_string(),
+                port: 8080,
+            },
+        };
+
+        // Without flatten, the nested struct is rendered under its field key
+        assert_eq!(
+            nested.to_prompt(),
+            "name: api\nconfig:\n  host: localhost\n  port: 8080"
+        );
+
+        // With flatten, the nested lines are emitted at the top level
+        let prompt = flattened.to_prompt();
+        assert_eq!(prompt, "name: api\nhost: localhost\nport: 8080");
+        assert!(
+            !prompt.contains("config:"),
+            "Flattened field key should not appear: {}",
+            prompt
+        );
+    }
+
+    #[test]
+    fn test_nested_struct_indentation() {
+        #[derive(ToPrompt)]
+        struct Service {
+            name: String,
+            server: ServerConfig,
+        }
+
+        #[derive(ToPrompt)]
+        struct Deployment {
+            region: String,
+            replicas: u32,
+            service: Service,
+        }
+
+        let deployment = Deployment {
+            region: "us-east-1".to_string(),
+            replicas: 3,
+            service: Service {
+                name: "api".to_string(),
+                server: ServerConfig {
+                    host: "localhost".to_string(),
+                    port: 8080,
+                },
+            },
+        };
+
+        let prompt = deployment.to_prompt();
+        let lines: Vec<&str> = prompt.lines().collect();
+
+        // Primitive fields are rendered inline without indentation
+        assert_eq!(lines[0], "region: us-east-1");
+        assert_eq!(lines[1], "replicas: 3");
+
+        // First level of nesting is indented by two spaces
+        assert_eq!(lines[2], "service:");
+        assert_eq!(lines[3], "  name: api");
+        assert_eq!(lines[4], "  server:");
+
+        // Second level of nesting is indented by four spaces
+        assert_eq!(lines[5], "    host: localhost");
+        assert_eq!(lines[6], "    port: 8080");
+        assert_eq!(lines.len(), 7);
+    }
+
+    #[test]
+    fn test_nested_block_uses_to_prompt_with_indent() {
+        struct Quoted;
+
+        impl ToPrompt for Quoted {
+            fn to_prompt_parts(&self) -> Vec<llm_toolkit::prompt::PromptPart> {
+                vec![llm_toolkit::prompt::PromptPart::Text(
+                    "first: 1\nsecond: 2".to_string(),
+                )]
+            }
+
+            fn to_prompt_with_indent(&self, level: usize) -> String {
+                let prefix = "> ".repeat(level);
+                format!("{}first: 1\n{}second: 2", prefix, prefix)
+            }
+        }
+
+        #[derive(ToPrompt)]
+        struct Report {
+            section: Quoted,
+        }
+
+        // A type's own indentation is honored for multi-line nested values
+        assert_eq!(
+            Report { section: Quoted }.to_prompt(),
+            "section:\n> first: 1\n> second: 2"
+        );
+    }
+
+    #[test]
+    fn test_order_attribute_controls_field_order() {
+        #[derive(ToPrompt)]
+        struct Ticket {
+            title: String,
+            description: String,
+            #[prompt(order = 0)]
+            priority: String,
+        }
+
+        let ticket = Ticket {
+            title: "Login fails".to_string(),
+            description: "Users cannot log in".to_string(),
+            priority: "high".to_string(),
+        };
+
+        // The later-declared field with order = 0 comes first; the rest keep declaration order
+        assert_eq!(
+            ticket.to_prompt(),
+            "priority: high\ntitle: Login fails\ndescription: Users cannot log in"
+        );
+    }
+
+    #[test]
+    fn test_rename_all_title_case() {
+        #[derive(ToPrompt)]
+        #[prompt(rename_all = "Title Case")]
+        struct Ticket {
+            ticket_title: String,
+            assigned_user_name: String,
+            #[prompt(rename = "prio")]
+            priority_level: u8,
+        }
+
+        let ticket = Ticket {
+            ticket_title: "Login fails".to_string(),
+            assigned_user_name: "alice".to_string(),
+            priority_level: 1,
+        };
+
+        // Explicit per-field rename still wins over the struct-level rule
+        assert_eq!(
+            ticket.to_prompt(),
+            "Ticket Title: Login fails\nAssigned User Name: alice\nprio: 1"
+        );
+    }
+
+    #[test]
+    fn test_rename_all_camel_case() {
+        #[derive(ToPrompt)]
+        #[prompt(rename_all = "camelCase")]
+        struct Request {
+            user_id: u64,
+            max_retry_count: u32,
+            query: String,
+        }
+
+        let request = Request {
+            user_id: 42,
+            max_retry_count: 3,
+            query: "status".to_string(),
+        };
+
+        assert_eq!(
+            request.to_prompt(),
+            "userId: 42\nmaxRetryCount: 3\nquery: status"
+        );
+    }
+
+    #[test]
+    fn test_prompt_schema_json_lists_fields_and_required() {
+        #[derive(ToPrompt)]
+        #[allow(dead_code)]
+        struct Profile {
+            name: String,
+            age: u32,
+            email: Option<String>,
+            tags: Vec<String>,
+        }
+
+        let schema = Profile::prompt_schema_json().expect("derived structs provide a schema");
+        assert_eq!(
+            schema,
+            serde_json::json!({
+                "properties": {
+                    "name": { "type": "string" },
+                    "age": { "type": "number" },
+                    "email": { "type": "string | null" },
+                    "tags": { "type": "string[]" }
+                },
+                "required": ["name", "age", "tags"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_prompt_schema_json_uses_serde_field_names() {
+        #[derive(ToPrompt, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        #[allow(dead_code)]
+        struct Request {
+            user_id: u64,
+            #[serde(rename = "q")]
+            query: String,
+            r#type: String,
+            #[prompt(skip)]
+            retry_count: u32,
+            #[serde(skip)]
+            cache_hit: bool,
+        }
+
+        let schema = Request::prompt_schema_json().expect("derived structs provide a schema");
+        assert_eq!(
+            schema,
+            serde_json::json!({
+                "properties": {
+                    "userId": { "type": "number" },
+                    "q": { "type": "string" },
+                    "type": { "type": "string" },
+                    "retryCount": { "type": "number" }
+                },
+                "required": ["userId", "q", "type", "retryCount"]
+            })
+        );
+    }
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "derive")]
+
+use llm_toolkit::ToPrompt;
+use llm_toolkit::extract::{ParseError, extract_enum};
+use serde::Deserialize;
+
+#[derive(ToPrompt, Deserialize, Debug, PartialEq)]
+#[allow(dead_code)]
+enum Priority {
+    /// Can wait
+    Low,
+    /// Needs attention today
+    Urgent,
+    #[serde(rename = "very-high")]
+    VeryHigh,
+    #[prompt(skip)]
+    Internal,
+}
+
+#[derive(ToPrompt, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum Sentiment {
+    Positive,
+    Negative,
+    MostlyPositive,
+}
+
+#[test]
+fn test_extract_enum_exact_match() {
+    let priority: Priority = extract_enum("I'd classify this as Urgent.").unwrap();
+    assert_eq!(priority, Priority::Urgent);
+}
+
+#[test]
+fn test_extract_enum_case_mismatch() {
+    let priority: Priority = extract_enum("Priority: LOW").unwrap();
+    assert_eq!(priority, Priority::Low);
+}
+
+#[test]
+fn test_extract_enum_honors_serde_rename() {
+    let priority: Priority = extract_enum("This one is Very-High 🔥").unwrap();
+    assert_eq!(priority, Priority::VeryHigh);
+
+    let sentiment: Sentiment = extract_enum("Overall: mostly_positive").unwrap();
+    assert_eq!(sentiment, Sentiment::MostlyPositive);
+
+    // The Rust variant name is not what serde accepts, so it doesn't match
+    let result = extract_enum::<Sentiment>("Overall: MostlyPositive");
+    assert!(matches!(result, Err(ParseError::NoVariantMatched { .. })));
+}
+
+#[test]
+fn test_extract_enum_requires_whole_words() {
+    // "Lowest" and "slow" contain "low" but don't name the variant
+    let result = extract_enum::<Priority>("The slowest, lowest-effort fix");
+    assert!(matches!(result, Err(ParseError::NoVariantMatched { .. })));
+}
+
+#[test]
+fn test_extract_enum_no_match() {
+    match extract_enum::<Priority>("I'm not sure how to classify this.") {
+        Err(ParseError::NoVariantMatched { candidates }) => {
+            assert_eq!(candidates, vec!["Low", "Urgent", "very-high"]);
+        }
+        other => panic!("expected NoVariantMatched, got {:?}", other),
+    }
+
+    // Skipped variants are never candidates
+    let result = extract_enum::<Priority>("Internal");
+    assert!(matches!(result, Err(ParseError::NoVariantMatched { .. })));
+}
+
+#[test]
+fn test_extract_enum_ambiguous() {
+    match extract_enum::<Priority>("Either Low or Urgent, hard to say.") {
+        Err(ParseError::AmbiguousVariant { matches }) => {
+            assert_eq!(matches, vec!["Low", "Urgent"]);
+        }
+        other => panic!("expected AmbiguousVariant, got {:?}", other),
+    }
+
+    // Repeating the same variant is not ambiguous
+    let priority: Priority = extract_enum("Urgent. Definitely urgent.").unwrap();
+    assert_eq!(priority, Priority::Urgent);
+}
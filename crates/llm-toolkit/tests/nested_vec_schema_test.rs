@@ -76,3 +76,81 @@ fn test_nested_schema_with_comments() {
         "Schema should include all fields from nested type"
     );
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToPrompt)]
+#[prompt(mode = "full")]
+pub struct Page<T: Serialize> {
+    /// Items on this page
+    pub items: Vec<T>,
+    /// Index of this page
+    pub page: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToPrompt)]
+#[prompt(mode = "full")]
+pub struct Candidate {
+    /// Display name of the candidate
+    pub name: String,
+}
+
+#[test]
+fn test_generic_vec_schema_uses_concrete_type() {
+    let schema = Page::<EvaluationResult>::prompt_schema();
+
+    println!("Page<EvaluationResult> schema:\n{}", schema);
+
+    assert!(schema.contains("type Page = {"));
+    assert!(schema.contains("items: EvaluationResult[];"));
+    assert!(!schema.contains("T[]"));
+    assert!(
+        schema.contains("type EvaluationResult = {"),
+        "Schema should include the concrete type's definition"
+    );
+    assert!(schema.contains("rule: string;"));
+
+    // Each instantiation gets its own schema
+    let other = Page::<Candidate>::prompt_schema();
+    assert!(other.contains("items: Candidate[];"));
+    assert!(other.contains("type Candidate = {"));
+    assert!(!other.contains("EvaluationResult"));
+}
+
+/// A payload type that deliberately does not implement `ToPrompt`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Opaque {
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToPrompt)]
+#[prompt(mode = "full")]
+pub struct Tagged<T: Serialize, M> {
+    /// Tagged items
+    pub items: Vec<T>,
+    #[serde(skip)]
+    pub cache: Option<M>,
+    #[serde(skip)]
+    pub marker: std::marker::PhantomData<M>,
+}
+
+/// Key-value rendering never needs the skipped field's type to be promptable
+#[derive(Serialize, Debug, Clone, ToPrompt)]
+pub struct Envelope<T: Serialize> {
+    pub id: String,
+    #[prompt(skip)]
+    pub payload: T,
+}
+
+#[test]
+fn test_generic_params_outside_schema_fields_stay_unbounded() {
+    // Opaque has no ToPrompt impl, so these only compile without a blanket bound
+    let schema = Tagged::<Candidate, Opaque>::prompt_schema();
+    assert!(schema.contains("items: Candidate[];"));
+    assert!(!schema.contains("cache"));
+    assert!(!schema.contains("marker"));
+
+    let envelope = Envelope {
+        id: "msg-1".to_string(),
+        payload: Opaque::default(),
+    };
+    assert_eq!(envelope.to_prompt(), "id: msg-1");
+}
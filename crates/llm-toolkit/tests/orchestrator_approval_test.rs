@@ -0,0 +1,197 @@
+//! Tests for human-in-the-loop pauses via `ApprovalStep`.
+
+mod common;
+
+use common::{FixedAgent, step};
+use llm_toolkit::orchestrator::{
+    ApprovalStep, BlueprintWorkflow, LoopStep, OrchestrationStatus, Orchestrator,
+    OrchestratorConfig, StrategyInstruction, StrategyMap,
+};
+use serde_json::json;
+
+/// Draft -> approval -> publish
+fn draft_review_publish() -> (Orchestrator, FixedAgent, FixedAgent) {
+    let drafter = FixedAgent::new("DraftAgent", json!("first draft"));
+    let publisher = FixedAgent::new("PublishAgent", json!("published"));
+
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Publish".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent(drafter.clone());
+    orchestrator.add_agent(publisher.clone());
+
+    let mut strategy = StrategyMap::new("Draft, review, publish".to_string());
+    strategy.add_step(step("draft", "DraftAgent"));
+    strategy.add_instruction(StrategyInstruction::Approval(
+        ApprovalStep::new("review").with_message("Check the draft"),
+    ));
+    strategy.add_step(step("publish", "PublishAgent"));
+    orchestrator.set_strategy_map(strategy);
+
+    (orchestrator, drafter, publisher)
+}
+
+#[tokio::test]
+async fn test_approval_pauses_then_resumes_to_completion() {
+    let (mut orchestrator, drafter, publisher) = draft_review_publish();
+
+    let result = orchestrator.execute("publish").await;
+
+    assert_eq!(result.status, OrchestrationStatus::Paused);
+    let pending = &result.paused.as_ref().unwrap().pending;
+    assert_eq!(pending.approval_id, "review");
+    assert_eq!(pending.message.as_deref(), Some("Check the draft"));
+    assert_eq!(pending.output, json!("first draft"));
+    assert_eq!(orchestrator.pending_approval(), Some(pending));
+    assert_eq!(publisher.calls(), 0);
+
+    let result = orchestrator.approve("Ship it").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(result.final_output, Some(json!("published")));
+    assert!(orchestrator.pending_approval().is_none());
+    assert_eq!(drafter.calls(), 1, "completed steps are not re-run");
+    assert_eq!(publisher.calls(), 1);
+    assert_eq!(
+        orchestrator.context().get("approval_review_decision"),
+        Some(&json!("Ship it"))
+    );
+}
+
+#[tokio::test]
+async fn test_rejection_aborts_with_reason() {
+    let (mut orchestrator, _drafter, publisher) = draft_review_publish();
+
+    let result = orchestrator.execute("publish").await;
+    assert_eq!(result.status, OrchestrationStatus::Paused);
+
+    let result = orchestrator.reject("Tone is off");
+
+    assert_eq!(result.status, OrchestrationStatus::Failure);
+    let message = result.error_message.unwrap();
+    assert!(message.contains("review"), "message: {}", message);
+    assert!(message.contains("Tone is off"), "message: {}", message);
+    assert!(orchestrator.pending_approval().is_none());
+    assert_eq!(publisher.calls(), 0);
+}
+
+#[tokio::test]
+async fn test_approve_without_pending_approval_fails() {
+    let (mut orchestrator, _drafter, _publisher) = draft_review_publish();
+
+    let result = orchestrator.approve("Ship it").await;
+
+    assert_eq!(result.status, OrchestrationStatus::Failure);
+    assert_eq!(
+        result.error_message.as_deref(),
+        Some("No approval is pending")
+    );
+}
+
+#[tokio::test]
+async fn test_paused_run_can_be_approved_from_checkpoint() {
+    let (mut orchestrator, _drafter, _publisher) = draft_review_publish();
+    orchestrator.execute("publish").await;
+    let checkpoint = orchestrator.export_checkpoint();
+
+    let (mut resumed, drafter, publisher) = draft_review_publish();
+    resumed.resume_from(checkpoint);
+    let result = resumed.approve("Ship it").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(drafter.calls(), 0);
+    assert_eq!(publisher.calls(), 1);
+}
+
+#[tokio::test]
+async fn test_approval_sharing_a_step_id_still_pauses() {
+    let reviewer = FixedAgent::new("ReviewAgent", json!("reviewed"));
+    let publisher = FixedAgent::new("PublishAgent", json!("published"));
+
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Publish".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent(reviewer.clone());
+    orchestrator.add_agent(publisher.clone());
+
+    let mut strategy = StrategyMap::new("Review, approve, publish".to_string());
+    strategy.add_step(step("review", "ReviewAgent"));
+    strategy.add_instruction(StrategyInstruction::Approval(ApprovalStep::new("review")));
+    strategy.add_step(step("publish", "PublishAgent"));
+    orchestrator.set_strategy_map(strategy);
+
+    // The completed "review" step does not count as a granted "review" approval
+    let result = orchestrator.execute("publish").await;
+    assert_eq!(result.status, OrchestrationStatus::Paused);
+    assert_eq!(publisher.calls(), 0);
+
+    let checkpoint = orchestrator.export_checkpoint();
+    assert_eq!(checkpoint.completed_steps, vec!["review".to_string()]);
+    assert!(checkpoint.granted_approvals.is_empty());
+
+    let result = orchestrator.approve("Ship it").await;
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(reviewer.calls(), 1);
+    assert_eq!(publisher.calls(), 1);
+    assert_eq!(
+        orchestrator.export_checkpoint().granted_approvals,
+        vec!["review".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_loop_step_completed_before_approval_is_not_rerun() {
+    let refiner = FixedAgent::new("RefineAgent", json!({ "approved": true }));
+    let publisher = FixedAgent::new("PublishAgent", json!("published"));
+
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Publish".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent(refiner.clone());
+    orchestrator.add_agent(publisher.clone());
+
+    let mut strategy = StrategyMap::new("Refine, review, publish".to_string());
+    strategy.add_instruction(StrategyInstruction::LoopStep(LoopStep::new(
+        step("refine", "RefineAgent"),
+        "output.approved",
+        3,
+    )));
+    strategy.add_instruction(StrategyInstruction::Approval(ApprovalStep::new("review")));
+    strategy.add_step(step("publish", "PublishAgent"));
+    orchestrator.set_strategy_map(strategy);
+
+    let result = orchestrator.execute("publish").await;
+    assert_eq!(result.status, OrchestrationStatus::Paused);
+    assert_eq!(refiner.calls(), 1);
+
+    let result = orchestrator.approve("Ship it").await;
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(refiner.calls(), 1, "completed loops are not re-run");
+    assert_eq!(publisher.calls(), 1);
+}
@@ -0,0 +1,155 @@
+//! Tests for exporting an orchestrator checkpoint and resuming from it.
+
+mod common;
+
+use common::{FixedAgent, step};
+use llm_toolkit::TypeMarker;
+use llm_toolkit::orchestrator::{
+    BlueprintWorkflow, LoopStep, OrchestrationStatus, Orchestrator, OrchestratorCheckpoint,
+    OrchestratorConfig, StrategyInstruction, StrategyMap, StrategyStep,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Serialize, Deserialize, Debug, Clone, TypeMarker, PartialEq)]
+struct OutlineResponse {
+    #[serde(default = "default_outline_type")]
+    __type: String,
+    pub sections: Vec<String>,
+}
+
+fn default_outline_type() -> String {
+    "OutlineResponse".to_string()
+}
+
+fn two_step_strategy() -> StrategyMap {
+    let mut strategy = StrategyMap::new("Write an article".to_string());
+    strategy.add_instruction(StrategyInstruction::Step(StrategyStep::new(
+        "outline".to_string(),
+        "Create outline".to_string(),
+        "OutlineAgent".to_string(),
+        "Outline the article".to_string(),
+        "Outline".to_string(),
+    )));
+    strategy.add_instruction(StrategyInstruction::Step(StrategyStep::new(
+        "write".to_string(),
+        "Write article".to_string(),
+        "WriterAgent".to_string(),
+        "Write from {{ step_outline_output.sections }}".to_string(),
+        "Article".to_string(),
+    )));
+    strategy
+}
+
+fn orchestrator_with(outline: &FixedAgent, writer: &FixedAgent) -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Article".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent(outline.clone());
+    orchestrator.add_agent(writer.clone());
+    orchestrator
+}
+
+#[tokio::test]
+async fn test_resume_from_checkpoint_skips_completed_steps() {
+    let outline_output = json!({
+        "__type": "OutlineResponse",
+        "sections": ["Intro", "Body"],
+    });
+
+    // First run: step one succeeds, step two fails.
+    let outline = FixedAgent::new("OutlineAgent", outline_output.clone());
+    let failing_writer = FixedAgent::failing("WriterAgent", "writer unavailable");
+    let mut first = orchestrator_with(&outline, &failing_writer);
+    first.set_strategy_map(two_step_strategy());
+
+    let result = first.execute("Write an article").await;
+    assert_eq!(result.status, OrchestrationStatus::Failure);
+    assert_eq!(outline.calls(), 1);
+    assert_eq!(failing_writer.calls(), 1);
+
+    let checkpoint = first.export_checkpoint();
+    assert_eq!(checkpoint.completed_steps, vec!["outline".to_string()]);
+    assert_eq!(checkpoint.next_step_index(), Some(1));
+
+    // Round-trip through JSON, as if persisted to disk.
+    let serialized = serde_json::to_string(&checkpoint).unwrap();
+    let restored: OrchestratorCheckpoint = serde_json::from_str(&serialized).unwrap();
+
+    // Second run: resume and complete step two only.
+    let outline = FixedAgent::new("OutlineAgent", outline_output);
+    let writer = FixedAgent::new("WriterAgent", json!("Full article"));
+    let mut second = orchestrator_with(&outline, &writer);
+    second.resume_from(restored);
+
+    let result = second.execute("Write an article").await;
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(outline.calls(), 0);
+    assert_eq!(writer.calls(), 1);
+    assert_eq!(result.steps_executed, 1);
+    assert_eq!(result.final_output, Some(json!("Full article")));
+
+    let typed: OutlineResponse = second.get_typed_output().unwrap();
+    assert_eq!(typed.sections, vec!["Intro", "Body"]);
+
+    let checkpoint = second.export_checkpoint();
+    assert_eq!(
+        checkpoint.completed_steps,
+        vec!["outline".to_string(), "write".to_string()]
+    );
+    assert_eq!(checkpoint.next_step_index(), None);
+}
+
+#[tokio::test]
+async fn test_resume_from_checkpoint_skips_completed_loops() {
+    let refine_strategy = || {
+        let mut strategy = StrategyMap::new("Refine then write".to_string());
+        strategy.add_instruction(StrategyInstruction::LoopStep(LoopStep::new(
+            step("refine", "OutlineAgent"),
+            "output.approved",
+            3,
+        )));
+        strategy.add_step(step("write", "WriterAgent"));
+        strategy
+    };
+
+    // First run: the loop completes, the step after it fails.
+    let outline = FixedAgent::new("OutlineAgent", json!({ "approved": true }));
+    let failing_writer = FixedAgent::failing("WriterAgent", "writer unavailable");
+    let mut first = orchestrator_with(&outline, &failing_writer);
+    first.set_strategy_map(refine_strategy());
+
+    let result = first.execute("Write an article").await;
+    assert_eq!(result.status, OrchestrationStatus::Failure);
+    assert_eq!(outline.calls(), 1);
+
+    let checkpoint = first.export_checkpoint();
+    assert_eq!(checkpoint.completed_loops, vec!["refine".to_string()]);
+
+    let serialized = serde_json::to_string(&checkpoint).unwrap();
+    let restored: OrchestratorCheckpoint = serde_json::from_str(&serialized).unwrap();
+
+    // Second run: resume without re-running the loop.
+    let outline = FixedAgent::new("OutlineAgent", json!({ "approved": true }));
+    let writer = FixedAgent::new("WriterAgent", json!("Full article"));
+    let mut second = orchestrator_with(&outline, &writer);
+    second.resume_from(restored);
+
+    let result = second.execute("Write an article").await;
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(outline.calls(), 0, "completed loops are not re-run");
+    assert_eq!(writer.calls(), 1);
+    assert_eq!(result.final_output, Some(json!("Full article")));
+}
@@ -0,0 +1,149 @@
+//! Tests for conditional step execution via `StrategyStep::condition`.
+
+mod common;
+
+use common::FixedAgent;
+use llm_toolkit::orchestrator::{
+    BlueprintWorkflow, OrchestrationStatus, Orchestrator, OrchestratorConfig, StrategyMap,
+    StrategyStep,
+};
+use serde_json::json;
+
+fn review_then_refine(approved: bool, condition: &str) -> (Orchestrator, FixedAgent) {
+    let reviewer = FixedAgent::new(
+        "ReviewAgent",
+        json!({ "__type": "ReviewResponse", "approved": approved }),
+    );
+    let refiner = FixedAgent::new("RefineAgent", json!("refined"));
+
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Review".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent(reviewer);
+    orchestrator.add_agent(refiner.clone());
+
+    let mut strategy = StrategyMap::new("Review and refine".to_string());
+    strategy.add_step(StrategyStep::new(
+        "review".to_string(),
+        "Review the draft".to_string(),
+        "ReviewAgent".to_string(),
+        "Review it".to_string(),
+        "Review verdict".to_string(),
+    ));
+    strategy.add_step(
+        StrategyStep::new(
+            "refine".to_string(),
+            "Refine the draft".to_string(),
+            "RefineAgent".to_string(),
+            "Refine it".to_string(),
+            "Refined draft".to_string(),
+        )
+        .with_condition(condition),
+    );
+    orchestrator.set_strategy_map(strategy);
+
+    (orchestrator, refiner)
+}
+
+#[tokio::test]
+async fn test_condition_false_skips_step() {
+    let (mut orchestrator, refiner) = review_then_refine(true, "ReviewResponse.approved == false");
+
+    let result = orchestrator.execute("review").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(refiner.calls(), 0);
+    assert_eq!(result.steps_executed, 1);
+}
+
+#[tokio::test]
+async fn test_condition_true_runs_step() {
+    let (mut orchestrator, refiner) = review_then_refine(false, "not step_review_output.approved");
+
+    let result = orchestrator.execute("review").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(refiner.calls(), 1);
+    assert_eq!(result.final_output, Some(json!("refined")));
+}
+
+#[tokio::test]
+async fn test_malformed_condition_is_an_error() {
+    let (mut orchestrator, refiner) = review_then_refine(false, "ReviewResponse.approved ==");
+
+    let result = orchestrator.execute("review").await;
+
+    assert_eq!(result.status, OrchestrationStatus::Failure);
+    let error = result.error_message.expect("error message");
+    assert!(
+        error.contains("Invalid condition for step 'refine'"),
+        "error: {}",
+        error
+    );
+    assert_eq!(refiner.calls(), 0);
+}
+
+#[tokio::test]
+async fn test_condition_uses_latest_output_of_a_type() {
+    let first_review = FixedAgent::new(
+        "FirstReviewAgent",
+        json!({ "__type": "ReviewResponse", "approved": false }),
+    );
+    let second_review = FixedAgent::new(
+        "SecondReviewAgent",
+        json!({ "__type": "ReviewResponse", "approved": true }),
+    );
+    let refiner = FixedAgent::new("RefineAgent", json!("refined"));
+
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Review".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent(first_review);
+    orchestrator.add_agent(second_review);
+    orchestrator.add_agent(refiner.clone());
+
+    let mut strategy = StrategyMap::new("Review twice, then refine".to_string());
+    for (id, agent) in [
+        ("review_1", "FirstReviewAgent"),
+        ("review_2", "SecondReviewAgent"),
+    ] {
+        strategy.add_step(StrategyStep::new(
+            id.to_string(),
+            "Review the draft".to_string(),
+            agent.to_string(),
+            "Review it".to_string(),
+            "Review verdict".to_string(),
+        ));
+    }
+    strategy.add_step(
+        StrategyStep::new(
+            "refine".to_string(),
+            "Refine the draft".to_string(),
+            "RefineAgent".to_string(),
+            "Refine it".to_string(),
+            "Refined draft".to_string(),
+        )
+        .with_condition("ReviewResponse.approved == false"),
+    );
+    orchestrator.set_strategy_map(strategy);
+
+    let result = orchestrator.execute("review").await;
+
+    assert_eq!(result.status, OrchestrationStatus::Success);
+    // The second review approved the draft, so refinement is skipped
+    assert_eq!(refiner.calls(), 0);
+}
@@ -56,6 +56,7 @@ async fn test_orchestrator_with_dialogue_agent() {
         communication_style: "Technical".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let persona2 = Persona {
@@ -65,6 +66,7 @@ async fn test_orchestrator_with_dialogue_agent() {
         communication_style: "Creative".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let agent1 = MockAgent::new("Alice", "Technical analysis: looks good");
@@ -140,6 +142,7 @@ async fn test_orchestrator_with_sequential_dialogue() {
         communication_style: "Analytical".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let persona2 = Persona {
@@ -149,6 +152,7 @@ async fn test_orchestrator_with_sequential_dialogue() {
         communication_style: "Clear".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let agent1 = MockAgent::new("Analyzer", "Data shows positive trend");
@@ -218,6 +222,7 @@ async fn test_multiple_dialogue_agents_in_orchestrator() {
         communication_style: "Technical".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let persona_tech2 = Persona {
@@ -227,6 +232,7 @@ async fn test_multiple_dialogue_agents_in_orchestrator() {
         communication_style: "User-focused".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let mut tech_team = Dialogue::broadcast();
@@ -243,6 +249,7 @@ async fn test_multiple_dialogue_agents_in_orchestrator() {
         communication_style: "Strategic".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let persona_biz2 = Persona {
@@ -252,6 +259,7 @@ async fn test_multiple_dialogue_agents_in_orchestrator() {
         communication_style: "Customer-centric".to_string(),
         visual_identity: None,
         capabilities: None,
+        priority: None,
     };
 
     let mut business_team = Dialogue::broadcast();
@@ -0,0 +1,113 @@
+//! Tests for structured progress events emitted by `Orchestrator::run_with_events`.
+
+mod common;
+
+use common::{FixedAgent, step};
+use llm_toolkit::orchestrator::{
+    BlueprintWorkflow, OrchestrationStatus, Orchestrator, OrchestratorConfig, OrchestratorEvent,
+    StrategyMap,
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_run_with_events_reports_steps_in_order() {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Events".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent(FixedAgent::new(
+        "OutlineAgent",
+        json!({ "__type": "OutlineResponse", "sections": ["Intro"] }),
+    ));
+    orchestrator.add_agent(FixedAgent::new("DraftAgent", json!("draft")));
+    orchestrator.add_agent(FixedAgent::new("ReviewAgent", json!("approved")));
+
+    let mut strategy = StrategyMap::new("Write and review".to_string());
+    strategy.add_step(step("outline", "OutlineAgent"));
+    strategy.add_step(step("draft", "DraftAgent"));
+    strategy.add_step(step("review", "ReviewAgent"));
+    orchestrator.set_strategy_map(strategy);
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let result = orchestrator.run_with_events("write", tx).await;
+    assert_eq!(result.status, OrchestrationStatus::Success);
+
+    let mut events = Vec::new();
+    while let Some(event) = rx.recv().await {
+        events.push(event);
+    }
+
+    assert_eq!(
+        events,
+        vec![
+            OrchestratorEvent::StepStarted {
+                name: "outline".to_string()
+            },
+            OrchestratorEvent::StepCompleted {
+                name: "outline".to_string(),
+                output_type: Some("OutlineResponse".to_string()),
+            },
+            OrchestratorEvent::StepStarted {
+                name: "draft".to_string()
+            },
+            OrchestratorEvent::StepCompleted {
+                name: "draft".to_string(),
+                output_type: None,
+            },
+            OrchestratorEvent::StepStarted {
+                name: "review".to_string()
+            },
+            OrchestratorEvent::StepCompleted {
+                name: "review".to_string(),
+                output_type: None,
+            },
+            OrchestratorEvent::WorkflowCompleted {
+                status: OrchestrationStatus::Success,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_run_with_events_reports_failure() {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Events".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+
+    let mut strategy = StrategyMap::new("Missing agent".to_string());
+    strategy.add_step(step("only", "MissingAgent"));
+    orchestrator.set_strategy_map(strategy);
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let result = orchestrator.run_with_events("write", tx).await;
+    assert_eq!(result.status, OrchestrationStatus::Failure);
+
+    let mut events = Vec::new();
+    while let Some(event) = rx.recv().await {
+        events.push(event);
+    }
+
+    assert_eq!(events.len(), 3, "events: {:?}", events);
+    assert_eq!(
+        events[0],
+        OrchestratorEvent::StepStarted {
+            name: "only".to_string()
+        }
+    );
+    // Every started step is closed by a matching failure event
+    assert!(
+        matches!(&events[1], OrchestratorEvent::StepFailed { name, error }
+            if name == "only" && error.contains("MissingAgent")),
+        "events: {:?}",
+        events
+    );
+    assert_eq!(
+        events[2],
+        OrchestratorEvent::WorkflowCompleted {
+            status: OrchestrationStatus::Failure,
+        }
+    );
+}
@@ -0,0 +1,232 @@
+//! Tests for per-step execution profile overrides in the Orchestrator.
+
+mod common;
+
+use async_trait::async_trait;
+use common::step;
+use llm_toolkit::agent::impls::{Cached, RetryAgent};
+use llm_toolkit::agent::{Agent, AgentError, ExecutionProfile, Payload};
+use llm_toolkit::orchestrator::{
+    BlueprintWorkflow, OrchestrationStatus, Orchestrator, OrchestratorConfig, ParallelGroup,
+    StrategyInstruction, StrategyMap, StrategyStep,
+};
+use serde_json::{Value as JsonValue, json};
+use std::sync::{Arc, Mutex};
+
+/// Agent that supports execution profiles and records the profile of every run.
+#[derive(Clone)]
+struct ProfiledAgent {
+    profile: Option<ExecutionProfile>,
+    runs: Arc<Mutex<Vec<Option<ExecutionProfile>>>>,
+}
+
+impl ProfiledAgent {
+    fn new() -> Self {
+        Self {
+            profile: None,
+            runs: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn runs(&self) -> Vec<Option<ExecutionProfile>> {
+        self.runs.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Agent for ProfiledAgent {
+    type Output = JsonValue;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "Agent that records its execution profile";
+        &EXPERTISE
+    }
+
+    fn name(&self) -> String {
+        "ProfiledAgent".to_string()
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<Self::Output, AgentError> {
+        self.runs.lock().unwrap().push(self.profile);
+        Ok(json!(format!("{:?}", self.profile)))
+    }
+
+    fn clone_with_execution_profile(&self, profile: ExecutionProfile) -> Option<Self> {
+        Some(Self {
+            profile: Some(profile),
+            runs: Arc::clone(&self.runs),
+        })
+    }
+}
+
+/// Agent without execution profile support.
+#[derive(Clone)]
+struct PlainAgent;
+
+#[async_trait]
+impl Agent for PlainAgent {
+    type Output = JsonValue;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "Agent without execution profiles";
+        &EXPERTISE
+    }
+
+    fn name(&self) -> String {
+        "PlainAgent".to_string()
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<Self::Output, AgentError> {
+        Ok(json!("plain"))
+    }
+}
+
+fn build_orchestrator(strategy: StrategyMap) -> Orchestrator {
+    let mut orch = Orchestrator::new(BlueprintWorkflow::new("Profiles".to_string())).with_config(
+        OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..OrchestratorConfig::default()
+        },
+    );
+    orch.set_strategy_map(strategy);
+    orch
+}
+
+#[tokio::test]
+async fn test_steps_run_with_their_own_execution_profile() {
+    let profiled = ProfiledAgent::new();
+
+    let mut strategy = StrategyMap::new("Profile per step".to_string());
+    strategy.add_step(
+        step("brainstorm", "ProfiledAgent").with_execution_profile(ExecutionProfile::Creative),
+    );
+    strategy.add_step(
+        step("finalize", "ProfiledAgent").with_execution_profile(ExecutionProfile::Deterministic),
+    );
+    strategy.add_step(step("default", "ProfiledAgent"));
+    strategy.add_step(
+        step("plain", "PlainAgent").with_execution_profile(ExecutionProfile::Deterministic),
+    );
+
+    let mut orch = build_orchestrator(strategy);
+    orch.add_agent(profiled.clone());
+    orch.add_agent(PlainAgent);
+
+    let result = orch.execute("run").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(result.steps_executed, 4);
+    assert_eq!(
+        profiled.runs(),
+        vec![
+            Some(ExecutionProfile::Creative),
+            Some(ExecutionProfile::Deterministic),
+            None,
+        ]
+    );
+    assert_eq!(result.final_output, Some(json!("plain")));
+}
+
+#[tokio::test]
+async fn test_parallel_group_members_run_with_their_own_execution_profile() {
+    let profiled = ProfiledAgent::new();
+
+    let mut strategy = StrategyMap::new("Profile per parallel member".to_string());
+    strategy.add_instruction(StrategyInstruction::Parallel(ParallelGroup::new(
+        "ideas",
+        vec![
+            step("wild", "ProfiledAgent").with_execution_profile(ExecutionProfile::Creative),
+            step("strict", "ProfiledAgent").with_execution_profile(ExecutionProfile::Deterministic),
+        ],
+    )));
+
+    let mut orch = build_orchestrator(strategy);
+    orch.add_agent(profiled.clone());
+
+    let result = orch.execute("run").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    let mut runs = profiled.runs();
+    runs.sort_by_key(|profile| format!("{:?}", profile));
+    assert_eq!(
+        runs,
+        vec![
+            Some(ExecutionProfile::Creative),
+            Some(ExecutionProfile::Deterministic),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_wrapper_agents_forward_execution_profile() {
+    let profiled = ProfiledAgent::new();
+
+    let mut strategy = StrategyMap::new("Profile through wrappers".to_string());
+    strategy.add_step(
+        step("draft", "ProfiledAgent").with_execution_profile(ExecutionProfile::Creative),
+    );
+
+    let mut orch = build_orchestrator(strategy);
+    orch.add_agent(Cached::new(RetryAgent::new(profiled.clone(), 2)));
+
+    let result = orch.execute("run").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(profiled.runs(), vec![Some(ExecutionProfile::Creative)]);
+}
+
+#[test]
+fn test_execution_profile_round_trips_through_strategy_json() {
+    let profiled_step =
+        step("brainstorm", "ProfiledAgent").with_execution_profile(ExecutionProfile::Creative);
+
+    let value = serde_json::to_value(&profiled_step).unwrap();
+    assert_eq!(value["execution_profile"], json!("creative"));
+
+    let parsed: StrategyStep = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed.execution_profile, Some(ExecutionProfile::Creative));
+
+    let without = serde_json::to_value(step("plain", "PlainAgent")).unwrap();
+    assert!(without.get("execution_profile").is_none());
+}
+
+#[test]
+fn test_custom_execution_profile_keeps_step_equality() {
+    let custom = ExecutionProfile::custom(0.2, Some(0.9), Some(7));
+    let profiled_step = step("draft", "ProfiledAgent").with_execution_profile(custom);
+
+    let parsed: StrategyStep =
+        serde_json::from_value(serde_json::to_value(&profiled_step).unwrap()).unwrap();
+    assert_eq!(parsed, profiled_step);
+
+    let other_seed = ExecutionProfile::custom(0.2, Some(0.9), Some(8));
+    assert_ne!(custom, other_seed);
+    assert_ne!(custom, ExecutionProfile::Deterministic);
+    assert_eq!(
+        ExecutionProfile::custom(0.0, None, None),
+        ExecutionProfile::custom(-0.0, None, None)
+    );
+}
+
+#[test]
+#[should_panic(expected = "temperature must not be NaN")]
+fn test_custom_execution_profile_rejects_nan() {
+    ExecutionProfile::custom(f32::NAN, None, None);
+}
@@ -0,0 +1,184 @@
+//! Tests for `StrategyInstruction::LoopStep` (repeat a step until a predicate holds).
+
+mod common;
+
+use async_trait::async_trait;
+use common::{FixedAgent, step};
+use llm_toolkit::agent::{Agent, AgentError, Payload};
+use llm_toolkit::orchestrator::{
+    BlueprintWorkflow, FailurePolicy, LoopStep, OrchestrationStatus, Orchestrator,
+    OrchestratorConfig, StepPolicy, StrategyInstruction, StrategyMap, StrategyStep,
+};
+use serde_json::{Value as JsonValue, json};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Critic that approves from the `approve_on`-th call onwards (never when 0).
+#[derive(Clone)]
+struct CriticAgent {
+    approve_on: usize,
+    calls: Arc<AtomicUsize>,
+}
+
+impl CriticAgent {
+    fn new(approve_on: usize) -> Self {
+        Self {
+            approve_on,
+            calls: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl Agent for CriticAgent {
+    type Output = JsonValue;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "Critic agent for loop step tests";
+        &EXPERTISE
+    }
+
+    fn name(&self) -> String {
+        "CriticAgent".to_string()
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<Self::Output, AgentError> {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        let approved = self.approve_on != 0 && call >= self.approve_on;
+        Ok(json!({ "__type": "CritiqueResponse", "approved": approved, "round": call }))
+    }
+}
+
+fn refine_until_approved(critic: CriticAgent, max_iterations: usize) -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Refine".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent(critic);
+
+    let mut strategy = StrategyMap::new("Refine until the critic approves".to_string());
+    strategy.add_instruction(StrategyInstruction::LoopStep(LoopStep::new(
+        StrategyStep::new(
+            "critique".to_string(),
+            "Critique the draft".to_string(),
+            "CriticAgent".to_string(),
+            "Critique it".to_string(),
+            "Critique verdict".to_string(),
+        ),
+        "CritiqueResponse.approved",
+        max_iterations,
+    )));
+    orchestrator.set_strategy_map(strategy);
+
+    orchestrator
+}
+
+#[tokio::test]
+async fn test_loop_step_stops_when_predicate_holds() {
+    let critic = CriticAgent::new(2);
+    let mut orchestrator = refine_until_approved(critic.clone(), 5);
+
+    let result = orchestrator.execute("refine").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(critic.calls(), 2);
+    assert_eq!(result.steps_executed, 2);
+    assert_eq!(result.loops_executed, 2);
+    assert_eq!(
+        result.final_output,
+        Some(json!({ "__type": "CritiqueResponse", "approved": true, "round": 2 }))
+    );
+}
+
+#[tokio::test]
+async fn test_loop_step_fails_at_iteration_cap() {
+    let critic = CriticAgent::new(0);
+    let mut orchestrator = refine_until_approved(critic.clone(), 3);
+
+    let result = orchestrator.execute("refine").await;
+
+    assert_eq!(result.status, OrchestrationStatus::Failure);
+    let error = result.error_message.expect("error message");
+    assert!(
+        error.contains("Step 'critique' did not satisfy its loop predicate within 3 iterations"),
+        "error: {}",
+        error
+    );
+    assert_eq!(critic.calls(), 3);
+}
+
+fn loop_over(agent: &FixedAgent, body: StrategyStep) -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Refine".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent(agent.clone());
+
+    let mut strategy = StrategyMap::new("Refine until approved".to_string());
+    strategy.add_instruction(StrategyInstruction::LoopStep(LoopStep::new(
+        body,
+        "CritiqueResponse.approved",
+        3,
+    )));
+    orchestrator.set_strategy_map(strategy);
+
+    orchestrator
+}
+
+#[tokio::test]
+async fn test_loop_step_ends_when_body_condition_is_false() {
+    let critic = FixedAgent::new(
+        "CriticAgent",
+        json!({ "__type": "CritiqueResponse", "approved": false }),
+    );
+    let mut orchestrator = loop_over(
+        &critic,
+        step("critique", "CriticAgent").with_condition("false"),
+    );
+
+    let result = orchestrator.execute("refine").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(critic.calls(), 0);
+    assert_eq!(result.steps_executed, 0);
+    assert_eq!(result.loops_executed, 1);
+}
+
+#[tokio::test]
+async fn test_loop_step_ends_when_failed_body_is_skipped() {
+    let critic = FixedAgent::failing("CriticAgent", "critic unavailable");
+    let mut orchestrator = loop_over(
+        &critic,
+        step("critique", "CriticAgent")
+            .with_policy(StepPolicy::default().with_on_failure(FailurePolicy::Skip)),
+    );
+
+    let result = orchestrator.execute("refine").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    // The predicate is never evaluated against a stale value, so the body runs once
+    assert_eq!(critic.calls(), 1);
+    assert_eq!(result.steps_executed, 0);
+}
@@ -0,0 +1,130 @@
+//! Tests for name-based output retrieval via `StrategyStep::output_key`.
+
+mod common;
+
+use async_trait::async_trait;
+use common::FixedAgent;
+use llm_toolkit::ToPrompt;
+use llm_toolkit::agent::{Agent, AgentError, Payload};
+use llm_toolkit::orchestrator::{
+    BlueprintWorkflow, OrchestrationStatus, Orchestrator, OrchestratorConfig, StrategyMap,
+    StrategyStep,
+};
+use serde_json::json;
+
+fn title_step(step_id: &str, agent: &str, output_key: &str) -> StrategyStep {
+    StrategyStep::new(
+        step_id.to_string(),
+        format!("Write the {}", output_key),
+        agent.to_string(),
+        "Write a title".to_string(),
+        "A title".to_string(),
+    )
+    .with_output_key(output_key)
+}
+
+async fn run_title_workflow() -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Titles".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent(FixedAgent::new("DraftAgent", json!("A Rough Title")));
+    orchestrator.add_agent(FixedAgent::new("EditorAgent", json!("The Polished Title")));
+
+    let mut strategy = StrategyMap::new("Draft and polish a title".to_string());
+    strategy.add_step(title_step("draft", "DraftAgent", "draft_title"));
+    strategy.add_step(title_step("polish", "EditorAgent", "final_title"));
+    orchestrator.set_strategy_map(strategy);
+
+    let result = orchestrator.execute("title").await;
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+
+    orchestrator
+}
+
+#[tokio::test]
+async fn test_same_type_outputs_retrieved_by_name() {
+    let orchestrator = run_title_workflow().await;
+
+    let draft: String = orchestrator.get_output_by_name("draft_title").unwrap();
+    let final_title: String = orchestrator.get_output_by_name("final_title").unwrap();
+
+    assert_eq!(draft, "A Rough Title");
+    assert_eq!(final_title, "The Polished Title");
+}
+
+#[tokio::test]
+async fn test_unknown_name_and_wrong_type_are_errors() {
+    let orchestrator = run_title_workflow().await;
+
+    let missing = orchestrator.get_output_by_name::<String>("subtitle");
+    assert!(missing.unwrap_err().to_string().contains("subtitle"));
+
+    assert!(
+        orchestrator
+            .get_output_by_name::<u32>("draft_title")
+            .is_err()
+    );
+}
+
+/// Agent whose output type implements `ToPrompt`.
+#[derive(Clone)]
+struct SectionsAgent;
+
+#[async_trait]
+impl Agent for SectionsAgent {
+    type Output = Vec<String>;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "Returns outline sections";
+        &EXPERTISE
+    }
+
+    fn name(&self) -> String {
+        "SectionsAgent".to_string()
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<Self::Output, AgentError> {
+        Ok(vec!["Intro".to_string(), "Body".to_string()])
+    }
+}
+
+#[tokio::test]
+async fn test_prompt_representation_stored_for_instruction_steps() {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Outline".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent_with_to_prompt(SectionsAgent);
+
+    let mut strategy = StrategyMap::new("Outline an article".to_string());
+    strategy.add_step(title_step("outline", "SectionsAgent", "sections"));
+    orchestrator.set_strategy_map(strategy);
+    assert!(!orchestrator.strategy_map().unwrap().elements.is_empty());
+
+    let result = orchestrator.execute("outline").await;
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+
+    let expected = vec!["Intro".to_string(), "Body".to_string()].to_prompt();
+    assert_eq!(
+        orchestrator.get_step_output_prompt("outline"),
+        Some(expected.as_str())
+    );
+    assert_eq!(
+        orchestrator.context().get("sections_prompt"),
+        Some(&json!(expected))
+    );
+}
@@ -0,0 +1,191 @@
+//! Tests for `ParallelGroup` fan-out in the sequential Orchestrator.
+
+use async_trait::async_trait;
+use llm_toolkit::agent::{Agent, AgentError, Payload};
+use llm_toolkit::orchestrator::{
+    BlueprintWorkflow, FailurePolicy, OrchestrationStatus, Orchestrator, OrchestratorConfig,
+    ParallelGroup, StepPolicy, StrategyInstruction, StrategyMap, StrategyStep,
+};
+use serde_json::{Value as JsonValue, json};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Tracks how many agents are running at the same time.
+#[derive(Default)]
+struct Concurrency {
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+#[derive(Clone)]
+struct SlowAgent {
+    name: String,
+    output: Option<JsonValue>,
+    concurrency: Arc<Concurrency>,
+    intents: Arc<Mutex<Vec<String>>>,
+}
+
+impl SlowAgent {
+    fn new(name: &str, output: Option<JsonValue>, concurrency: &Arc<Concurrency>) -> Self {
+        Self {
+            name: name.to_string(),
+            output,
+            concurrency: Arc::clone(concurrency),
+            intents: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl Agent for SlowAgent {
+    type Output = JsonValue;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "Slow agent for parallel group tests";
+        &EXPERTISE
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    async fn execute(&self, intent: Payload) -> Result<Self::Output, AgentError> {
+        self.intents.lock().unwrap().push(intent.to_text());
+
+        let now = self.concurrency.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.concurrency
+            .max_in_flight
+            .fetch_max(now, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        self.concurrency.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        self.output
+            .clone()
+            .ok_or_else(|| AgentError::ExecutionFailed("research failed".to_string()))
+    }
+}
+
+fn step(step_id: &str, agent: &str, intent: &str) -> StrategyStep {
+    StrategyStep::new(
+        step_id.to_string(),
+        format!("Run {}", step_id),
+        agent.to_string(),
+        intent.to_string(),
+        "Output".to_string(),
+    )
+}
+
+fn build_orchestrator(agents: &[&SlowAgent]) -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Fan-out".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    for agent in agents {
+        orchestrator.add_agent((*agent).clone());
+    }
+    orchestrator
+}
+
+#[tokio::test]
+async fn test_parallel_group_runs_members_concurrently() {
+    let concurrency = Arc::new(Concurrency::default());
+    let market = SlowAgent::new("MarketAgent", Some(json!("market notes")), &concurrency);
+    let tech = SlowAgent::new("TechAgent", Some(json!("tech notes")), &concurrency);
+    let writer = SlowAgent::new("WriterAgent", Some(json!("report")), &concurrency);
+
+    let mut strategy = StrategyMap::new("Research then write".to_string());
+    strategy.add_instruction(StrategyInstruction::Parallel(ParallelGroup::new(
+        "research",
+        vec![
+            step("market", "MarketAgent", "Research the market"),
+            step("tech", "TechAgent", "Research the tech"),
+        ],
+    )));
+    strategy.add_step(step(
+        "write",
+        "WriterAgent",
+        "Write using {{ step_market_output }} and {{ step_tech_output }}",
+    ));
+
+    let mut orchestrator = build_orchestrator(&[&market, &tech, &writer]);
+    orchestrator.set_strategy_map(strategy);
+
+    let result = orchestrator.execute("report").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(result.steps_executed, 3);
+    assert_eq!(result.final_output, Some(json!("report")));
+    assert_eq!(concurrency.max_in_flight.load(Ordering::SeqCst), 2);
+
+    // Both parallel outputs were collected before the sequential step ran.
+    assert_eq!(
+        writer.intents.lock().unwrap().as_slice(),
+        ["Write using market notes and tech notes"]
+    );
+    assert_eq!(
+        orchestrator.context().get("parallel_research_output"),
+        Some(&json!({ "market": "market notes", "tech": "tech notes" }))
+    );
+}
+
+#[tokio::test]
+async fn test_parallel_group_respects_failure_policy() {
+    let concurrency = Arc::new(Concurrency::default());
+    let market = SlowAgent::new("MarketAgent", Some(json!("market notes")), &concurrency);
+    let flaky = SlowAgent::new("FlakyAgent", None, &concurrency);
+    let writer = SlowAgent::new("WriterAgent", Some(json!("report")), &concurrency);
+
+    let group = |on_failure| {
+        StrategyInstruction::Parallel(ParallelGroup::new(
+            "research",
+            vec![
+                step("market", "MarketAgent", "Research the market"),
+                step("flaky", "FlakyAgent", "Research something")
+                    .with_policy(StepPolicy::default().with_on_failure(on_failure)),
+            ],
+        ))
+    };
+
+    // Skip: the failing member is dropped and the workflow continues.
+    let mut strategy = StrategyMap::new("Skip failures".to_string());
+    strategy.add_instruction(group(FailurePolicy::Skip));
+    strategy.add_step(step("write", "WriterAgent", "Write"));
+
+    let mut orchestrator = build_orchestrator(&[&market, &flaky, &writer]);
+    orchestrator.set_strategy_map(strategy);
+    let result = orchestrator.execute("report").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(result.steps_executed, 2);
+    assert_eq!(
+        orchestrator.context().get("parallel_research_output"),
+        Some(&json!({ "market": "market notes" }))
+    );
+
+    // Abort: the workflow stops after the group; the sequential step never runs.
+    let mut strategy = StrategyMap::new("Abort on failure".to_string());
+    strategy.add_instruction(group(FailurePolicy::Abort));
+    strategy.add_step(step("write", "WriterAgent", "Write"));
+
+    let writer = SlowAgent::new("WriterAgent", Some(json!("report")), &concurrency);
+    let mut orchestrator = build_orchestrator(&[&market, &flaky, &writer]);
+    orchestrator.set_strategy_map(strategy);
+    let result = orchestrator.execute("report").await;
+
+    assert_eq!(result.status, OrchestrationStatus::Failure);
+    assert!(writer.intents.lock().unwrap().is_empty());
+    assert!(orchestrator.get_step_output("market").is_some());
+}
@@ -0,0 +1,249 @@
+//! Tests for `Orchestrator::plan()` dry runs.
+
+use async_trait::async_trait;
+use llm_toolkit::TypeMarker;
+use llm_toolkit::agent::{Agent, AgentError, Payload};
+use llm_toolkit::orchestrator::{
+    BlueprintWorkflow, Orchestrator, PlanIssue, StrategyMap, StrategyStep,
+};
+use serde::{Deserialize, Serialize};
+
+#[allow(dead_code)]
+#[derive(Serialize, Deserialize, Debug, Clone, TypeMarker)]
+struct DraftResponse {
+    #[serde(default = "default_draft_type")]
+    __type: String,
+    pub text: String,
+}
+
+fn default_draft_type() -> String {
+    "DraftResponse".to_string()
+}
+
+#[allow(dead_code)]
+#[derive(Serialize, Deserialize, Debug, Clone, TypeMarker)]
+struct ReviewResponse {
+    #[serde(default = "default_review_type")]
+    __type: String,
+    pub approved: bool,
+}
+
+fn default_review_type() -> String {
+    "ReviewResponse".to_string()
+}
+
+/// Agent that must never be invoked during planning.
+struct UnreachableAgent<T> {
+    name: &'static str,
+    expertise: &'static str,
+    _output: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> UnreachableAgent<T> {
+    fn new(name: &'static str, expertise: &'static str) -> Self {
+        Self {
+            name,
+            expertise,
+            _output: std::marker::PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<T> Agent for UnreachableAgent<T>
+where
+    T: Serialize + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    type Output = T;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        &self.expertise
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<Self::Output, AgentError> {
+        panic!("plan() must not invoke agents");
+    }
+}
+
+fn step(step_id: &str, agent: &str, intent: &str) -> StrategyStep {
+    StrategyStep::new(
+        step_id.to_string(),
+        format!("Run {}", step_id),
+        agent.to_string(),
+        intent.to_string(),
+        format!("{} output", step_id),
+    )
+}
+
+fn orchestrator() -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Drafting".to_string()));
+    orchestrator.add_agent(UnreachableAgent::<DraftResponse>::new(
+        "DraftAgent",
+        "Writes drafts",
+    ));
+    orchestrator.add_agent(UnreachableAgent::<ReviewResponse>::new(
+        "ReviewAgent",
+        "Reviews drafts",
+    ));
+    orchestrator.add_agent(UnreachableAgent::<String>::new(
+        "RefineAgent",
+        "Refines drafts",
+    ));
+    orchestrator
+}
+
+#[test]
+fn test_plan_valid_workflow() {
+    let mut orchestrator = orchestrator();
+    orchestrator
+        .context_mut()
+        .insert("topic".to_string(), serde_json::json!("Rust"));
+
+    let mut strategy = StrategyMap::new("Draft, review, refine".to_string());
+    strategy.add_step(step("draft", "DraftAgent", "Write about {{ topic }}"));
+    strategy.add_step(step(
+        "review",
+        "ReviewAgent",
+        "Review {{ step_draft_output.text }}",
+    ));
+    strategy.add_step(
+        step("refine", "RefineAgent", "Refine {{ DraftResponse.text }}")
+            .with_condition("ReviewResponse.approved == false"),
+    );
+    orchestrator.set_strategy_map(strategy);
+
+    let plan = orchestrator.plan();
+
+    assert!(plan.is_valid(), "issues: {:?}", plan.issues);
+    assert_eq!(plan.goal, "Draft, review, refine");
+    assert_eq!(plan.steps.len(), 3);
+
+    let draft = plan.step("draft").unwrap();
+    assert_eq!(draft.agent, "DraftAgent");
+    assert_eq!(draft.agent_expertise.as_deref(), Some("Writes drafts"));
+    assert_eq!(draft.output_type.as_deref(), Some("DraftResponse"));
+    assert_eq!(draft.references, vec!["topic"]);
+
+    let review = plan.step("review").unwrap();
+    assert_eq!(review.output_type.as_deref(), Some("ReviewResponse"));
+    assert_eq!(review.references, vec!["step_draft_output"]);
+
+    let refine = plan.step("refine").unwrap();
+    assert_eq!(refine.output_type.as_deref(), Some("String"));
+    assert_eq!(refine.references, vec!["DraftResponse", "ReviewResponse"]);
+}
+
+#[test]
+fn test_plan_reports_dangling_type_reference() {
+    let mut orchestrator = orchestrator();
+
+    // "refine" needs a ReviewResponse, but the review step was never added.
+    let mut strategy = StrategyMap::new("Draft and refine".to_string());
+    strategy.add_step(step("draft", "DraftAgent", "Write a draft"));
+    strategy.add_step(
+        step("refine", "RefineAgent", "Refine {{ DraftResponse.text }}")
+            .with_condition("ReviewResponse.approved == false"),
+    );
+    strategy.add_step(step("publish", "PublishAgent", "Publish"));
+    orchestrator.set_strategy_map(strategy);
+
+    let plan = orchestrator.plan();
+
+    assert!(!plan.is_valid());
+    assert_eq!(
+        plan.issues,
+        vec![
+            PlanIssue::UnresolvedReference {
+                step_id: "refine".to_string(),
+                reference: "ReviewResponse".to_string(),
+            },
+            PlanIssue::AgentNotFound {
+                step_id: "publish".to_string(),
+                agent: "PublishAgent".to_string(),
+            },
+        ]
+    );
+    assert_eq!(plan.step("publish").unwrap().agent_expertise, None);
+}
+
+#[test]
+fn test_plan_without_strategy() {
+    let plan = orchestrator().plan();
+    assert_eq!(plan.issues, vec![PlanIssue::NoStrategy]);
+}
+
+/// Agent that returns a fixed output and records the intents it receives.
+struct RecordingAgent<T> {
+    name: &'static str,
+    output: T,
+    intents: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl<T> Agent for RecordingAgent<T>
+where
+    T: Serialize + for<'de> Deserialize<'de> + Clone + Send + Sync + 'static,
+{
+    type Output = T;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        &"Records intents"
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    async fn execute(&self, intent: Payload) -> Result<Self::Output, AgentError> {
+        self.intents.lock().unwrap().push(intent.to_text());
+        Ok(self.output.clone())
+    }
+}
+
+#[tokio::test]
+async fn test_type_name_references_render_at_runtime() {
+    let refine_intents = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Drafting".to_string()))
+        .with_config(llm_toolkit::orchestrator::OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..Default::default()
+        });
+    orchestrator.add_agent(RecordingAgent {
+        name: "DraftAgent",
+        output: DraftResponse {
+            __type: default_draft_type(),
+            text: "first draft".to_string(),
+        },
+        intents: Default::default(),
+    });
+    orchestrator.add_agent(RecordingAgent {
+        name: "RefineAgent",
+        output: "refined".to_string(),
+        intents: refine_intents.clone(),
+    });
+
+    let mut strategy = StrategyMap::new("Draft and refine".to_string());
+    strategy.add_step(step("draft", "DraftAgent", "Write a draft"));
+    strategy.add_step(step(
+        "refine",
+        "RefineAgent",
+        "Refine {{ DraftResponse.text }}",
+    ));
+    orchestrator.set_strategy_map(strategy);
+
+    let plan = orchestrator.plan();
+    assert!(plan.is_valid(), "issues: {:?}", plan.issues);
+
+    let result = orchestrator.execute("draft").await;
+    assert!(result.error_message.is_none(), "{:?}", result.error_message);
+    assert_eq!(
+        refine_intents.lock().unwrap().as_slice(),
+        ["Refine first draft"]
+    );
+}
@@ -0,0 +1,175 @@
+//! Tests for per-step timeout and failure policies in the Orchestrator.
+
+mod common;
+
+use async_trait::async_trait;
+use common::step;
+use llm_toolkit::agent::{Agent, AgentError, Payload};
+use llm_toolkit::orchestrator::{
+    BlueprintWorkflow, FailurePolicy, OrchestrationStatus, Orchestrator, OrchestratorConfig,
+    StepPolicy, StepStatus, StrategyMap,
+};
+use serde_json::{Value as JsonValue, json};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[derive(Clone)]
+enum Behavior {
+    Succeed(JsonValue),
+    Fail,
+    Hang,
+}
+
+/// Agent with scripted behavior that counts invocations.
+#[derive(Clone)]
+struct ScriptedAgent {
+    name: String,
+    behavior: Behavior,
+    calls: Arc<AtomicUsize>,
+}
+
+impl ScriptedAgent {
+    fn new(name: &str, behavior: Behavior) -> Self {
+        Self {
+            name: name.to_string(),
+            behavior,
+            calls: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl Agent for ScriptedAgent {
+    type Output = JsonValue;
+    type Expertise = &'static str;
+
+    fn expertise(&self) -> &&'static str {
+        const EXPERTISE: &str = "Scripted agent for step policy tests";
+        &EXPERTISE
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    async fn execute(&self, _intent: Payload) -> Result<Self::Output, AgentError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        match &self.behavior {
+            Behavior::Succeed(value) => Ok(value.clone()),
+            Behavior::Fail => Err(AgentError::ExecutionFailed("step failed".to_string())),
+            Behavior::Hang => {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                Ok(json!("too late"))
+            }
+        }
+    }
+}
+
+fn build_orchestrator(config: OrchestratorConfig, agents: &[&ScriptedAgent]) -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Policy workflow".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..config
+        });
+    for agent in agents {
+        orchestrator.add_agent((*agent).clone());
+    }
+    orchestrator
+}
+
+#[tokio::test]
+async fn test_skip_policy_continues_after_failing_step() {
+    let first = ScriptedAgent::new("FirstAgent", Behavior::Succeed(json!("first")));
+    let flaky = ScriptedAgent::new("FlakyAgent", Behavior::Fail);
+    let last = ScriptedAgent::new("LastAgent", Behavior::Succeed(json!("last")));
+
+    let mut strategy = StrategyMap::new("Continue past failures".to_string());
+    strategy.add_step(step("first", "FirstAgent"));
+    strategy.add_step(
+        step("flaky", "FlakyAgent")
+            .with_policy(StepPolicy::default().with_on_failure(FailurePolicy::Skip)),
+    );
+    strategy.add_step(step("last", "LastAgent"));
+
+    let mut orch = build_orchestrator(OrchestratorConfig::default(), &[&first, &flaky, &last]);
+    orch.set_strategy_map(strategy);
+
+    let result = orch.execute("run").await;
+
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+    assert_eq!(result.steps_executed, 2);
+    assert_eq!(result.final_output, Some(json!("last")));
+    assert_eq!(flaky.calls(), 1);
+    assert_eq!(last.calls(), 1);
+
+    let journal = result.journal.expect("journal");
+    let flaky_statuses: Vec<&StepStatus> = journal
+        .steps
+        .iter()
+        .filter(|record| record.step_id == "flaky")
+        .map(|record| &record.status)
+        .collect();
+    assert_eq!(
+        flaky_statuses,
+        vec![&StepStatus::Failed, &StepStatus::Skipped]
+    );
+}
+
+#[tokio::test]
+async fn test_abort_policy_stops_workflow_on_timeout() {
+    let slow = ScriptedAgent::new("SlowAgent", Behavior::Hang);
+    let next = ScriptedAgent::new("NextAgent", Behavior::Succeed(json!("next")));
+
+    let mut strategy = StrategyMap::new("Stop on hang".to_string());
+    strategy.add_step(step("slow", "SlowAgent"));
+    strategy.add_step(step("next", "NextAgent"));
+
+    // Workflow-level default applies to steps without their own policy.
+    let config = OrchestratorConfig {
+        default_step_policy: Some(
+            StepPolicy::default()
+                .with_timeout(Duration::from_millis(50))
+                .with_on_failure(FailurePolicy::Abort),
+        ),
+        ..Default::default()
+    };
+    let mut orch = build_orchestrator(config, &[&slow, &next]);
+    orch.set_strategy_map(strategy);
+
+    let result = orch.execute("run").await;
+
+    assert_eq!(result.status, OrchestrationStatus::Failure);
+    let error = result.error_message.expect("error message");
+    assert!(error.contains("'slow' timed out"), "error: {}", error);
+    assert_eq!(slow.calls(), 1);
+    assert_eq!(next.calls(), 0);
+}
+
+#[tokio::test]
+async fn test_retry_policy_reruns_step_before_aborting() {
+    let flaky = ScriptedAgent::new("FlakyAgent", Behavior::Fail);
+
+    let mut strategy = StrategyMap::new("Retry then abort".to_string());
+    strategy.add_step(
+        step("flaky", "FlakyAgent")
+            .with_policy(StepPolicy::default().with_on_failure(FailurePolicy::Retry(2))),
+    );
+
+    let mut orch = build_orchestrator(OrchestratorConfig::default(), &[&flaky]);
+    orch.set_strategy_map(strategy);
+
+    let result = orch.execute("run").await;
+
+    assert_eq!(result.status, OrchestrationStatus::Failure);
+    assert_eq!(flaky.calls(), 3);
+}
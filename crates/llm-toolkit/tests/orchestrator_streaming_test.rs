@@ -0,0 +1,71 @@
+//! Tests for `Orchestrator::run_streaming`.
+
+mod common;
+
+use common::{FixedAgent, step};
+use futures::StreamExt;
+use llm_toolkit::orchestrator::{
+    BlueprintWorkflow, FailurePolicy, Orchestrator, OrchestratorConfig, StepOutput, StepPolicy,
+    StrategyMap,
+};
+use serde_json::{Value as JsonValue, json};
+
+fn orchestrator(second: Result<JsonValue, String>) -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Streaming".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            // Fail fast instead of asking the internal agent to redesign
+            default_step_policy: Some(StepPolicy::default().with_on_failure(FailurePolicy::Abort)),
+            ..Default::default()
+        });
+    orchestrator.add_agent(FixedAgent::with_result(
+        "OutlineAgent",
+        Ok(json!({ "__type": "OutlineResponse", "sections": ["Intro"] })),
+    ));
+    orchestrator.add_agent(FixedAgent::with_result("DraftAgent", second));
+
+    let mut strategy = StrategyMap::new("Outline and draft".to_string());
+    strategy.add_step(step("outline", "OutlineAgent"));
+    strategy.add_step(step("draft", "DraftAgent"));
+    orchestrator.set_strategy_map(strategy);
+
+    orchestrator
+}
+
+#[tokio::test]
+async fn test_run_streaming_yields_step_outputs_in_order() {
+    let mut orchestrator = orchestrator(Ok(json!("draft text")));
+
+    let outputs: Vec<StepOutput> = orchestrator
+        .run_streaming("write")
+        .map(|output| output.expect("step output"))
+        .collect()
+        .await;
+
+    assert_eq!(
+        outputs,
+        vec![
+            StepOutput {
+                name: "outline".to_string(),
+                type_name: Some("OutlineResponse".to_string()),
+                value: json!({ "__type": "OutlineResponse", "sections": ["Intro"] }),
+            },
+            StepOutput {
+                name: "draft".to_string(),
+                type_name: None,
+                value: json!("draft text"),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_run_streaming_ends_with_workflow_error() {
+    let mut orchestrator = orchestrator(Err("draft exploded".to_string()));
+
+    let items: Vec<_> = orchestrator.run_streaming("write").collect().await;
+
+    assert_eq!(items.len(), 2, "items: {:?}", items);
+    assert_eq!(items[0].as_ref().unwrap().name, "outline");
+    assert!(items[1].is_err());
+}
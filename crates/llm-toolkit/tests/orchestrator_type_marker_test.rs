@@ -1,6 +1,13 @@
+mod common;
+
+use common::{FixedAgent, step};
+use llm_toolkit::orchestrator::{
+    OrchestrationStatus, OrchestratorCheckpoint, OrchestratorConfig, StrategyMap,
+};
 use llm_toolkit::{BlueprintWorkflow, Orchestrator, TypeMarker};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::HashMap;
 
 #[derive(Serialize, Deserialize, Debug, Clone, TypeMarker, PartialEq)]
 struct HighConceptResponse {
@@ -146,3 +153,98 @@ fn test_get_typed_output_ignores_step_id() {
     assert_eq!(retrieved.__type, "HighConceptResponse");
     assert_eq!(retrieved.reasoning, "Deep analysis...");
 }
+
+#[test]
+fn test_get_typed_outputs_empty_when_missing() {
+    let orchestrator = Orchestrator::new(BlueprintWorkflow::new("Test workflow".to_string()));
+
+    let outputs: Vec<HighConceptResponse> = orchestrator.get_typed_outputs();
+
+    assert!(outputs.is_empty());
+}
+
+fn concept(high_concept: &str) -> HighConceptResponse {
+    HighConceptResponse {
+        __type: "HighConceptResponse".to_string(),
+        reasoning: "Deep analysis...".to_string(),
+        high_concept: high_concept.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_get_typed_outputs_returns_all_in_production_order() {
+    let mut strategy = StrategyMap::new("Two concepts".to_string());
+    strategy.add_step(step("draft", "DraftAgent"));
+    strategy.add_step(step("profile", "ProfileAgent"));
+    strategy.add_step(step("refine", "RefineAgent"));
+
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Test".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..OrchestratorConfig::default()
+        });
+    orchestrator.add_agent(FixedAgent::new("DraftAgent", json!(concept("first draft"))));
+    orchestrator.add_agent(FixedAgent::new(
+        "ProfileAgent",
+        json!({"__type": "ProfileResponse", "name": "Alice", "age": 30}),
+    ));
+    orchestrator.add_agent(FixedAgent::new("RefineAgent", json!(concept("refined"))));
+    orchestrator.set_strategy_map(strategy);
+
+    let result = orchestrator.execute("run").await;
+    assert_eq!(
+        result.status,
+        OrchestrationStatus::Success,
+        "error: {:?}",
+        result.error_message
+    );
+
+    let concepts: Vec<HighConceptResponse> = orchestrator.get_typed_outputs();
+    assert_eq!(concepts, vec![concept("first draft"), concept("refined")]);
+
+    let profiles: Vec<ProfileResponse> = orchestrator.get_typed_outputs();
+    assert_eq!(profiles.len(), 1);
+
+    // The singular accessor returns the latest one
+    let latest: HighConceptResponse = orchestrator.get_typed_output().unwrap();
+    assert_eq!(latest, concept("refined"));
+}
+
+#[tokio::test]
+async fn test_get_typed_outputs_keeps_equal_outputs_of_different_steps() {
+    let mut strategy = StrategyMap::new("Two identical concepts".to_string());
+    strategy.add_step(step("first", "ConceptAgent"));
+    strategy.add_step(step("second", "ConceptAgent"));
+
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Test".to_string()))
+        .with_config(OrchestratorConfig {
+            enable_fast_path_intent_generation: true,
+            ..OrchestratorConfig::default()
+        });
+    orchestrator.add_agent(FixedAgent::new("ConceptAgent", json!(concept("same"))));
+    orchestrator.set_strategy_map(strategy);
+
+    let result = orchestrator.execute("run").await;
+    assert_eq!(result.status, OrchestrationStatus::Success);
+
+    let concepts: Vec<HighConceptResponse> = orchestrator.get_typed_outputs();
+    assert_eq!(concepts, vec![concept("same"), concept("same")]);
+}
+
+#[test]
+fn test_get_typed_outputs_orders_restored_steps_by_completion() {
+    let mut orchestrator = Orchestrator::new(BlueprintWorkflow::new("Test".to_string()));
+    orchestrator.resume_from(OrchestratorCheckpoint {
+        context: HashMap::from([
+            ("step_9_output".to_string(), json!(concept("ninth"))),
+            ("step_10_output".to_string(), json!(concept("tenth"))),
+            // Alias of the latest output, not a separate one
+            ("previous_output".to_string(), json!(concept("tenth"))),
+        ]),
+        completed_steps: vec!["9".to_string(), "10".to_string()],
+        ..OrchestratorCheckpoint::default()
+    });
+
+    let concepts: Vec<HighConceptResponse> = orchestrator.get_typed_outputs();
+    assert_eq!(concepts, vec![concept("ninth"), concept("tenth")]);
+}
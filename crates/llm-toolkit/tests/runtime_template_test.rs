@@ -0,0 +1,111 @@
+use llm_toolkit::prompt::{RuntimeTemplate, RuntimeTemplateError};
+use serde::Serialize;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+#[derive(Serialize)]
+struct Review {
+    language: String,
+    lines: u32,
+}
+
+fn review() -> Review {
+    Review {
+        language: "Rust".to_string(),
+        lines: 120,
+    }
+}
+
+fn template_file(content: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    file
+}
+
+#[test]
+fn test_runtime_template_initial_render() {
+    let file = template_file("Review this {{ language }} change ({{ lines }} lines).");
+
+    let template = RuntimeTemplate::load(file.path()).unwrap();
+
+    assert_eq!(template.path(), file.path());
+    assert_eq!(
+        template.render(&review()).unwrap(),
+        "Review this Rust change (120 lines)."
+    );
+}
+
+#[test]
+fn test_runtime_template_reload_picks_up_changes() {
+    let file = template_file("Review this {{ language }} change.");
+    let template = RuntimeTemplate::load(file.path()).unwrap();
+    assert_eq!(
+        template.render(&review()).unwrap(),
+        "Review this Rust change."
+    );
+
+    std::fs::write(
+        file.path(),
+        "Summarize {{ lines }} lines of {{ language }}.",
+    )
+    .unwrap();
+
+    // Nothing changes until the template is reloaded
+    assert_eq!(
+        template.render(&review()).unwrap(),
+        "Review this Rust change."
+    );
+
+    template.reload().unwrap();
+    assert_eq!(
+        template.render(&review()).unwrap(),
+        "Summarize 120 lines of Rust."
+    );
+}
+
+#[test]
+fn test_runtime_template_errors() {
+    let missing = RuntimeTemplate::load("does/not/exist.jinja");
+    assert!(matches!(missing, Err(RuntimeTemplateError::Io { .. })));
+
+    let file = template_file("{% if %}");
+    let template = RuntimeTemplate::load(file.path()).unwrap();
+    assert!(matches!(
+        template.render(&review()),
+        Err(RuntimeTemplateError::Render { .. })
+    ));
+}
+
+#[cfg(feature = "template-reload")]
+#[test]
+fn test_runtime_template_auto_reload_on_mtime_change() {
+    use std::time::{Duration, SystemTime};
+
+    let file = template_file("Review this {{ language }} change.");
+    let template = RuntimeTemplate::load(file.path())
+        .unwrap()
+        .with_auto_reload(true);
+    assert_eq!(
+        template.render(&review()).unwrap(),
+        "Review this Rust change."
+    );
+
+    std::fs::write(
+        file.path(),
+        "Summarize {{ lines }} lines of {{ language }}.",
+    )
+    .unwrap();
+    // Bump the mtime explicitly so coarse filesystem timestamps can't hide the edit
+    std::fs::File::options()
+        .write(true)
+        .open(file.path())
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(5))
+        .unwrap();
+
+    assert_eq!(
+        template.render(&review()).unwrap(),
+        "Summarize 120 lines of Rust."
+    );
+    assert!(!template.reload_if_modified().unwrap());
+}
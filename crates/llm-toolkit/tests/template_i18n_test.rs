@@ -0,0 +1,67 @@
+use llm_toolkit::ToPrompt;
+use serde::Serialize;
+
+#[derive(ToPrompt, Serialize)]
+#[prompt(
+    template = "Hello, {{ name }}! You have {{ count }} new messages.",
+    template_i18n(
+        en = "Hello, {{ name }}! You have {{ count }} new messages.",
+        ja = "こんにちは、{{ name }}さん！新着メッセージが{{ count }}件あります。"
+    )
+)]
+struct Greeting {
+    name: String,
+    count: u32,
+}
+
+#[derive(ToPrompt, Serialize)]
+#[prompt(template_i18n(ja = "タスク: {{ title }}", en = "Task: {{ title }}"))]
+struct Task {
+    title: String,
+}
+
+fn greeting() -> Greeting {
+    Greeting {
+        name: "Yui".to_string(),
+        count: 3,
+    }
+}
+
+#[test]
+fn test_renders_each_language() {
+    let greeting = greeting();
+
+    assert_eq!(
+        greeting.to_prompt_localized("en"),
+        "Hello, Yui! You have 3 new messages."
+    );
+    assert_eq!(
+        greeting.to_prompt_localized("ja"),
+        "こんにちは、Yuiさん！新着メッセージが3件あります。"
+    );
+}
+
+#[test]
+fn test_unknown_language_falls_back_to_template() {
+    let greeting = greeting();
+
+    assert_eq!(greeting.to_prompt_localized("fr"), greeting.to_prompt());
+}
+
+#[test]
+fn test_first_localized_template_is_default_without_template() {
+    let task = Task {
+        title: "Review".to_string(),
+    };
+
+    assert_eq!(task.to_prompt(), "タスク: Review");
+    assert_eq!(task.to_prompt_localized("en"), "Task: Review");
+    assert_eq!(task.to_prompt_localized("de"), "タスク: Review");
+}
+
+#[test]
+fn test_default_implementation_ignores_language() {
+    let name = "plain".to_string();
+
+    assert_eq!(name.to_prompt_localized("ja"), "plain");
+}
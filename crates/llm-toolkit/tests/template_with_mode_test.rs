@@ -34,3 +34,45 @@ fn test_template_with_field_mode() {
     // The schema_only mode should show the schema (TypeScript format)
     assert!(output.contains("type Inner = {"));
 }
+
+#[derive(ToPrompt, Serialize)]
+#[prompt(template = "Schema: {{inner:schema_only}}")]
+struct CompactSpacing {
+    inner: Inner,
+}
+
+#[derive(ToPrompt, Serialize)]
+#[prompt(template = "Schema: {{ inner : schema_only }}")]
+struct SpacedColon {
+    inner: Inner,
+}
+
+#[derive(ToPrompt, Serialize)]
+#[prompt(template = "Schema: {{ inner:schema_only}}")]
+struct UnevenSpacing {
+    inner: Inner,
+}
+
+#[test]
+fn test_template_mode_tolerates_whitespace() {
+    let inner = || Inner {
+        value: "test".to_string(),
+    };
+    let expected = Outer { inner: inner() }
+        .to_prompt()
+        .split("Schema: ")
+        .nth(1)
+        .unwrap()
+        .to_string();
+
+    let outputs = [
+        CompactSpacing { inner: inner() }.to_prompt(),
+        SpacedColon { inner: inner() }.to_prompt(),
+        UnevenSpacing { inner: inner() }.to_prompt(),
+    ];
+
+    for output in outputs {
+        assert!(output.contains("type Inner = {"), "output: {}", output);
+        assert_eq!(output, format!("Schema: {}", expected));
+    }
+}
@@ -18,6 +18,16 @@ struct SimpleFields {
     count: u32,
 }
 
+// Test a template that reads a field the target does not have
+#[derive(Serialize, ToPrompt, ToPromptFor, Default)]
+#[prompt_for(
+    target = "Target",
+    template = "{{ name }} is owned by {{ target.owner.name }}"
+)]
+struct MissingTargetField {
+    name: String,
+}
+
 // Test {self} placeholder
 #[derive(Serialize, ToPrompt, ToPromptFor, Default)]
 #[prompt_for(target = "Target", template = "Configuration:\n{{ self }}")]
@@ -150,3 +160,55 @@ fn test_mode_parameter_affects_self_placeholder() {
     assert!(result.contains("## Example"));
     assert!(result.contains("\"field1\": \"test\""));
 }
+
+#[test]
+fn test_try_to_prompt_for_with_mode_renders() {
+    let config = SimpleFields {
+        name: "Bob".to_string(),
+        count: 7,
+    };
+    let target = Target {
+        id: "t6".to_string(),
+    };
+
+    let result = config.try_to_prompt_for_with_mode(&target, "full").unwrap();
+    assert_eq!(result, "User Bob has value 7");
+}
+
+#[test]
+fn test_try_to_prompt_for_with_mode_reports_missing_field() {
+    let config = MissingTargetField {
+        name: "Widget".to_string(),
+    };
+    let target = Target {
+        id: "t7".to_string(),
+    };
+
+    let err = config
+        .try_to_prompt_for_with_mode(&target, "full")
+        .unwrap_err();
+    assert_eq!(err.template, "MissingTargetField for Target");
+    assert_eq!(
+        err.source.kind(),
+        llm_toolkit::minijinja::ErrorKind::UndefinedError
+    );
+    assert!(
+        err.to_string()
+            .starts_with("Failed to render template 'MissingTargetField for Target'")
+    );
+}
+
+#[test]
+fn test_to_prompt_for_with_mode_falls_back_on_render_error() {
+    let config = MissingTargetField::default();
+    let target = Target {
+        id: "t8".to_string(),
+    };
+
+    let prompt = config.to_prompt_for_with_mode(&target, "full");
+    assert!(
+        prompt.starts_with("Failed to render prompt: "),
+        "{}",
+        prompt
+    );
+}
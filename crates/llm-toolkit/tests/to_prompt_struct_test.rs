@@ -201,3 +201,65 @@ fn test_struct_without_default_uses_actual_values() {
     // Field without example and no Default should use actual value
     assert_eq!(json["priority"].as_u64(), Some(5));
 }
+
+#[derive(ToPrompt, Default, Serialize)]
+#[prompt(mode = "schema_only")]
+/// Report whose serialized form omits some fields
+struct SerializedReport {
+    /// Report title
+    #[prompt(example = "Weekly summary")]
+    title: String,
+
+    /// Internal cache key, never serialized
+    #[serde(skip_serializing)]
+    #[allow(dead_code)]
+    cache_key: String,
+
+    /// Reviewer notes, only written when present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notes: Option<String>,
+
+    /// Tags, only written when present; the example hints at the field anyway
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[prompt(example = "urgent")]
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_example_respects_serde_skip_serializing() {
+    let example_str = SerializedReport::default().to_prompt_with_mode("example_only");
+    let json: Value = serde_json::from_str(&example_str).expect("Invalid JSON");
+
+    assert_eq!(json["title"].as_str(), Some("Weekly summary"));
+    assert!(json.get("cache_key").is_none(), "{}", example_str);
+    // Skipped for its (default) value, just like serde would
+    assert!(json.get("notes").is_none(), "{}", example_str);
+    // An explicit example is kept as a hint
+    assert_eq!(json["tags"].as_str(), Some("urgent"));
+}
+
+#[test]
+fn test_example_keeps_skip_serializing_if_field_with_value() {
+    let report = TaskReport {
+        summary: "done".to_string(),
+        blocker: Some("waiting on review".to_string()),
+    };
+
+    let example_str = report.to_prompt_with_mode("example_only");
+    let json: Value = serde_json::from_str(&example_str).expect("Invalid JSON");
+
+    assert_eq!(json["summary"].as_str(), Some("done"));
+    assert_eq!(json["blocker"].as_str(), Some("waiting on review"));
+}
+
+#[derive(ToPrompt, Serialize)]
+#[prompt(mode = "schema_only")]
+/// Report without Default, so its own values are used
+struct TaskReport {
+    /// Summary
+    summary: String,
+
+    /// Blocker, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blocker: Option<String>,
+}
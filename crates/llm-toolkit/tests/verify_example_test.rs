@@ -0,0 +1,51 @@
+use llm_toolkit::ToPrompt;
+use llm_toolkit::extract::ParseError;
+use llm_toolkit::prompt::verify_example;
+use serde::{Deserialize, Serialize};
+
+#[derive(ToPrompt, Default, Serialize, Deserialize, Debug, PartialEq)]
+#[prompt(mode = "full")]
+/// A user profile.
+struct Profile {
+    /// Display name
+    #[prompt(example = "Alice")]
+    name: String,
+    /// Age in years
+    age: u32,
+    /// Optional biography
+    bio: Option<String>,
+}
+
+#[derive(ToPrompt, Default, Serialize, Deserialize, Debug)]
+#[prompt(mode = "full")]
+/// A user whose JSON key differs from the Rust field name.
+struct RenamedUser {
+    /// Login handle
+    #[serde(rename = "userName")]
+    user_name: String,
+}
+
+#[test]
+fn test_well_formed_example_round_trips() {
+    let profile = verify_example::<Profile>().unwrap();
+
+    assert_eq!(
+        profile,
+        Profile {
+            name: "Alice".to_string(),
+            age: 0,
+            bio: None,
+        }
+    );
+}
+
+#[test]
+fn test_serde_rename_drift_is_reported() {
+    match verify_example::<RenamedUser>() {
+        Err(ParseError::JsonParsingFailed(message)) => {
+            assert!(message.contains("RenamedUser"), "{}", message);
+            assert!(message.contains("userName"), "{}", message);
+        }
+        other => panic!("expected JsonParsingFailed, got {:?}", other),
+    }
+}